@@ -408,4 +408,206 @@ pub enum LumentixError {
     PromoCodeUserLimitReached = 219,
     /// Discount basis points must be between 1 and 10000
     InvalidPromoDiscount = 220,
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Gate Validator & Multi-Sig Escrow errors (221–223)
+    // ═══════════════════════════════════════════════════════════════════════
+    /// No multi-sig escrow configuration exists for this event
+    EscrowConfigNotFound = 221,
+    /// Threshold must be greater than zero and at most the number of signers
+    InvalidEscrowThreshold = 222,
+    /// Not enough escrow signers have approved the release
+    EscrowThresholdNotMet = 223,
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Sponsorship errors (224–229)
+    // ═══════════════════════════════════════════════════════════════════════
+    /// Sponsor tier does not exist for this event
+    SponsorTierNotFound = 224,
+    /// Sponsor tier has reached its maximum number of sponsors
+    SponsorTierFull = 225,
+    /// Sponsor has already contributed to this tier
+    AlreadySponsored = 226,
+    /// No contribution from this sponsor exists for the tier
+    SponsorContributionNotFound = 227,
+    /// Sponsor contribution has already been refunded
+    SponsorAlreadyRefunded = 228,
+    /// Tier price and capacity must be positive, with a booth for every sponsor slot
+    InvalidSponsorTier = 229,
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Multi-Token Payment errors (230–231)
+    // ═══════════════════════════════════════════════════════════════════════
+    /// Token is not in the event's accepted payment tokens
+    TokenNotAccepted = 230,
+    /// Token is already accepted for this event
+    TokenAlreadyAccepted = 231,
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Price Oracle errors (232–233)
+    // ═══════════════════════════════════════════════════════════════════════
+    /// Neither the oracle feed nor the fallback price is recent enough
+    OraclePriceStale = 232,
+    /// Oracle price moved further from the last accepted price than allowed
+    OraclePriceDeviation = 233,
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Price Quote errors (234–235)
+    // ═══════════════════════════════════════════════════════════════════════
+    /// Price quote does not exist or was already redeemed
+    PriceQuoteNotFound = 234,
+    /// Price quote lock has expired
+    PriceQuoteExpired = 235,
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // VIP Purchase & Benefit errors (236–239)
+    // ═══════════════════════════════════════════════════════════════════════
+    /// Ticket already holds this VIP tier
+    VipTierAlreadyOwned = 236,
+    /// VIP upgrades must move to a more expensive tier
+    VipDowngradeNotAllowed = 237,
+    /// Benefit is not part of the ticket's VIP tier
+    VipBenefitNotFound = 238,
+    /// Benefit has already been redeemed for this ticket
+    VipBenefitAlreadyRedeemed = 239,
+    /// Accessibility booking has already been approved or denied
+    AccessibilityBookingNotPending = 240,
+    /// Seat is not designated accessible for this accommodation type
+    SeatNotAccessible = 241,
+    /// Ticket already has a pending or approved accessibility booking
+    AccessibilityBookingExists = 242,
+    /// Accessibility booking was already denied or cancelled
+    AccessibilityBookingInactive = 243,
+    /// Event was not created as a hybrid event
+    NotHybridEvent = 244,
+    /// No virtual ticket class configured for event
+    VirtualTicketClassNotFound = 245,
+    /// Virtual ticket capacity reached
+    VirtualTicketsSoldOut = 246,
+    /// CDN delivery is not configured for event
+    StreamDeliveryNotConfigured = 247,
+    /// Attendee already has an open virtual session
+    VirtualSessionAlreadyOpen = 248,
+    /// Attendee has no open virtual session
+    VirtualSessionNotOpen = 249,
+    /// No replay configured for event
+    ReplayNotConfigured = 250,
+    /// Replay window has closed or the replay was withdrawn
+    ReplayUnavailable = 251,
+    /// Viewer already holds a replay entitlement
+    ReplayAlreadyPurchased = 252,
+    /// Viewer has no active replay entitlement
+    ReplayEntitlementNotFound = 253,
+    /// Replay entitlements are only refundable once the replay is withdrawn
+    ReplayNotWithdrawn = 254,
+    /// Reporter already submitted a report for this time bucket
+    QosReportAlreadySubmitted = 255,
+    /// No streaming SLA declared for event
+    StreamingSlaNotConfigured = 256,
+    /// Aggregated quality for the bucket meets the SLA
+    SlaNotBreached = 257,
+    /// SLA refund already claimed for this ticket
+    SlaRefundAlreadyClaimed = 258,
+    /// Time bucket has not closed yet
+    QosBucketOpen = 259,
+    /// Insurance product not found
+    InsuranceProductNotFound = 260,
+    /// Insurance can no longer be bought for this event
+    InsurancePurchaseClosed = 261,
+    /// Insurance product parameters are invalid
+    InvalidInsuranceProduct = 262,
+    /// Pool capital would fall below the minimum reserve ratio
+    InsuranceReserveBreached = 263,
+    /// Provider does not hold enough LP shares
+    InsufficientInsuranceShares = 264,
+    /// No insurance withdrawal has been requested
+    InsuranceWithdrawalNotRequested = 265,
+    /// Insurance withdrawal cooldown has not elapsed
+    InsuranceWithdrawalCoolingDown = 266,
+    /// Insurance exposure for the event cannot be released yet
+    InsuranceExposureNotReleasable = 267,
+    /// No cancellation reason has been recorded for the event
+    CancellationReasonNotRecorded = 268,
+    /// Review is already hidden by moderation
+    ReviewAlreadyHidden = 269,
+    /// Review is not hidden
+    ReviewNotHidden = 270,
+    /// Upgrade proposal has not been approved
+    UpgradeNotApproved = 271,
+    /// Upgrade timelock has not elapsed
+    UpgradeTimelockActive = 272,
+    /// Upgrade voting is still open
+    UpgradeVotingOpen = 273,
+    /// Upgrade proposal can no longer be vetoed
+    UpgradeVetoNotAllowed = 274,
+    /// No previous WASM hash is recorded to roll back to
+    NoPreviousWasmHash = 275,
+    /// Deployed WASM hash is already recorded
+    WasmHashAlreadyRecorded = 276,
+    /// Proposal kind does not match the requested action
+    UpgradeKindMismatch = 277,
+    /// Upgrade timelock is below the minimum
+    InvalidUpgradeTimelock = 278,
+    /// Migration source version does not match the stored schema version
+    StorageVersionMismatch = 279,
+    /// Storage is already at the current schema version
+    StorageAlreadyMigrated = 280,
+    /// Stored record matches no known schema version
+    StorageRecordUnreadable = 281,
+    /// Parameter is controlled by governance; the admin may only act in emergencies
+    GovernanceControlled = 282,
+    /// Governance has no members configured
+    GovernanceNotConfigured = 283,
+    /// Governance action not found for the proposal
+    GovernanceActionNotFound = 284,
+    /// No admin handover is pending for this candidate
+    AdminHandoverNotFound = 285,
+    /// Admin handover was not accepted before it expired
+    AdminHandoverExpired = 286,
+    /// Admin council signers or threshold are invalid
+    InvalidAdminCouncil = 287,
+    /// Not enough distinct admin council signers approved
+    InsufficientAdminApprovals = 288,
+    /// Platform is paused; only refunds are available
+    ContractPaused = 289,
+    /// This category of activity is switched off platform-wide
+    FeaturePaused = 290,
+    /// Feature flags are empty or contain unknown bits
+    InvalidFeatureFlags = 291,
+    /// Carbon project ID is already registered
+    CarbonProjectAlreadyRegistered = 292,
+    /// Carbon project is not accepting purchases
+    CarbonProjectInactive = 293,
+    /// Carbon project price must be positive
+    InvalidCarbonProject = 294,
+    /// Offset cost at the project's price exceeds the purchaser's maximum
+    CarbonOffsetCostExceeded = 295,
+    /// Carbon offset retirement has already been attested
+    CarbonOffsetAlreadyVerified = 296,
+    /// Retirement serial has already been used for another offset
+    RetirementSerialAlreadyUsed = 297,
+    /// Event does not offer a carbon offset at checkout
+    CheckoutOffsetNotEnabled = 298,
+    /// Checkout offset project cannot change once attendees have opted in
+    CheckoutOffsetProjectLocked = 299,
+    /// Emission factors are missing a category entry or are negative
+    InvalidEmissionFactors = 300,
+    /// Credential issuer is not registered or has been deactivated
+    CredentialIssuerNotTrusted = 301,
+    /// Credential issuer public key is already registered
+    CredentialIssuerAlreadyRegistered = 302,
+    /// Credential level exceeds what the issuer may attest
+    CredentialLevelNotAllowed = 303,
+    /// Holder has no valid credential satisfying the event's requirement
+    CredentialRequirementNotMet = 304,
+    /// Credential requirement must list at least one provider
+    InvalidCredentialRequirement = 305,
+    /// Insurance pool has outstanding shares but no balance left to back them
+    InsurancePoolDrained = 306,
+    /// Platform fees to return were already withdrawn
+    InsufficientPlatformBalance = 307,
+    /// Escrow signers are already configured; rotate them with the signers' approval
+    EscrowSignersLocked = 308,
+    /// Footprint inputs are frozen once the event completes or its actual footprint is recorded
+    CarbonFootprintFinalized = 309,
 }
@@ -2,28 +2,6 @@
 
 use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol, Vec};
 
-/// A type for transfer of event
-pub struct TransferEvent;
-
-impl TransferEvent {
-    pub fn emit(env: &Env, ticket_id: Symbol, from: Address, to: Address) {
-        env.events()
-            .publish((symbol_short!("transfer"),), (ticket_id, from, to));
-    }
-}
-
-/// Event emitted when a ticket is checked in (validated)
-pub struct CheckInEvent;
-
-impl CheckInEvent {
-    pub fn emit(env: &Env, ticket_id: Symbol, validator: Address, event_id: Symbol) {
-        env.events().publish(
-            (symbol_short!("checkin"),),
-            (ticket_id, validator, event_id),
-        );
-    }
-}
-
 /// Event emitted when a new event is created
 pub struct EventCreated;
 
@@ -98,6 +76,21 @@ impl EventCancelled {
     }
 }
 
+/// Event emitted when the admin attests an event's cancellation reason
+pub struct CancellationReasonAttested;
+
+impl CancellationReasonAttested {
+    pub fn emit(
+        env: &Env,
+        event_id: u64,
+        admin: Address,
+        reason: crate::types::CancellationReason,
+    ) {
+        env.events()
+            .publish((symbol_short!("cnclattst"),), (event_id, admin, reason));
+    }
+}
+
 /// Event emitted when an event status transitions
 pub struct EventStatusChanged;
 
@@ -566,6 +559,164 @@ impl VipTicketAssigned {
     }
 }
 
+/// Event emitted when a ticket holder buys or upgrades a VIP tier
+pub struct VipUpgradePurchased;
+
+impl VipUpgradePurchased {
+    pub fn emit(
+        env: &Env,
+        ticket_id: u64,
+        event_id: u64,
+        tier_name: String,
+        buyer: Address,
+        amount: i128,
+    ) {
+        env.events().publish(
+            (symbol_short!("vipbuy"),),
+            (ticket_id, event_id, tier_name, buyer, amount),
+        );
+    }
+}
+
+/// Event emitted when gate staff redeem a VIP benefit for a ticket
+pub struct VipBenefitRedeemed;
+
+impl VipBenefitRedeemed {
+    pub fn emit(env: &Env, ticket_id: u64, benefit: String, redeemed_by: Address) {
+        env.events().publish(
+            (symbol_short!("vipredeem"),),
+            (ticket_id, benefit, redeemed_by),
+        );
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// VIRTUAL TICKET / STREAMING EVENTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Event emitted when an organizer configures the virtual ticket class
+pub struct VirtualTicketClassSet;
+
+impl VirtualTicketClassSet {
+    pub fn emit(env: &Env, event_id: u64, price: i128, capacity: u32) {
+        env.events().publish(
+            (symbol_short!("virtclass"),),
+            (event_id, price, capacity),
+        );
+    }
+}
+
+/// Event emitted when a virtual ticket is purchased
+pub struct VirtualTicketPurchased;
+
+impl VirtualTicketPurchased {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, buyer: Address, price: i128) {
+        env.events().publish(
+            (symbol_short!("virtbuy"),),
+            (ticket_id, event_id, buyer, price),
+        );
+    }
+}
+
+/// Event emitted when a CDN access token is issued for a ticket
+pub struct StreamAccessTokenIssued;
+
+impl StreamAccessTokenIssued {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, holder: Address, expires_at: u64) {
+        env.events().publish(
+            (symbol_short!("strmtoken"),),
+            (ticket_id, event_id, holder, expires_at),
+        );
+    }
+}
+
+/// Event emitted when the admin registers or removes a QoS reporter
+pub struct QosReporterUpdated;
+
+impl QosReporterUpdated {
+    pub fn emit(env: &Env, reporter: Address, registered: bool) {
+        env.events()
+            .publish((symbol_short!("qosrptr"),), (reporter, registered));
+    }
+}
+
+/// Event emitted when a QoS reporter submits a measurement
+pub struct QosReportSubmitted;
+
+impl QosReportSubmitted {
+    pub fn emit(
+        env: &Env,
+        event_id: u64,
+        bucket: u64,
+        reporter: Address,
+        avg_bitrate_kbps: u32,
+        rebuffer_ratio_bps: u32,
+    ) {
+        env.events().publish(
+            (symbol_short!("qosreport"),),
+            (event_id, bucket, reporter, avg_bitrate_kbps, rebuffer_ratio_bps),
+        );
+    }
+}
+
+/// Event emitted when a virtual ticket holder claims an SLA refund
+pub struct SlaRefundClaimed;
+
+impl SlaRefundClaimed {
+    pub fn emit(env: &Env, ticket_id: u64, event_id: u64, bucket: u64, amount: i128) {
+        env.events().publish(
+            (symbol_short!("slarefund"),),
+            (ticket_id, event_id, bucket, amount),
+        );
+    }
+}
+
+/// Event emitted when an organizer configures an event's replay
+pub struct ReplayConfigured;
+
+impl ReplayConfigured {
+    pub fn emit(env: &Env, event_id: u64, price: i128, window_secs: u64) {
+        env.events().publish(
+            (symbol_short!("replaycfg"),),
+            (event_id, price, window_secs),
+        );
+    }
+}
+
+/// Event emitted when a viewer buys a replay entitlement
+pub struct ReplayPurchased;
+
+impl ReplayPurchased {
+    pub fn emit(env: &Env, event_id: u64, viewer: Address, amount: i128, platform_fee: i128) {
+        env.events().publish(
+            (symbol_short!("replaybuy"),),
+            (event_id, viewer, amount, platform_fee),
+        );
+    }
+}
+
+/// Event emitted when an organizer withdraws an event's replay
+pub struct ReplayWithdrawn;
+
+impl ReplayWithdrawn {
+    pub fn emit(env: &Env, event_id: u64) {
+        env.events()
+            .publish((symbol_short!("replaywd"),), event_id);
+    }
+}
+
+/// Event emitted when a replay entitlement is refunded
+pub struct ReplayRefunded;
+
+impl ReplayRefunded {
+    pub fn emit(env: &Env, event_id: u64, viewer: Address, amount: i128) {
+        env.events().publish(
+            (symbol_short!("replayref"),),
+            (event_id, viewer, amount),
+        );
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // ACCESSIBILITY EVENTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -600,6 +751,42 @@ impl AccessibilityBooked {
     }
 }
 
+/// Event emitted when an organizer defines or resizes an accommodation type
+pub struct AccommodationTypeConfigured;
+
+impl AccommodationTypeConfigured {
+    pub fn emit(env: &Env, event_id: u64, name: String, total: u32, companion_ticket: bool) {
+        env.events().publish(
+            (symbol_short!("acctype"),),
+            (event_id, name, total, companion_ticket),
+        );
+    }
+}
+
+/// Event emitted when an accessibility booking's slot is returned to inventory
+pub struct AccessibilityBookingReleased;
+
+impl AccessibilityBookingReleased {
+    pub fn emit(env: &Env, booking_id: u64, event_id: u64, ticket_id: u64) {
+        env.events().publish(
+            (symbol_short!("accfree"),),
+            (booking_id, event_id, ticket_id),
+        );
+    }
+}
+
+/// Event emitted when an organizer approves or denies an accessibility booking
+pub struct AccessibilityBookingReviewed;
+
+impl AccessibilityBookingReviewed {
+    pub fn emit(env: &Env, booking_id: u64, event_id: u64, approved: bool, notes: String) {
+        env.events().publish(
+            (symbol_short!("accreview"),),
+            (booking_id, event_id, approved, notes),
+        );
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // VENUE / SEAT EVENTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -660,6 +847,28 @@ impl OraclePriceUpdated {
     }
 }
 
+/// Emitted when a currency's staleness and deviation guards change
+pub struct CurrencyPriceGuardsUpdated;
+
+impl CurrencyPriceGuardsUpdated {
+    pub fn emit(env: &Env, currency: String, max_staleness: u64, max_deviation_bps: u32) {
+        env.events().publish(
+            (symbol_short!("prcguard"),),
+            (currency, max_staleness, max_deviation_bps),
+        );
+    }
+}
+
+/// Emitted when a currency is pointed at an external price feed
+pub struct OracleFeedConfigured;
+
+impl OracleFeedConfigured {
+    pub fn emit(env: &Env, currency: String, oracle: Address) {
+        env.events()
+            .publish((symbol_short!("orclfeed"),), (currency, oracle));
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // INSURANCE EVENTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -718,6 +927,18 @@ impl InsuranceClaimProcessed {
     }
 }
 
+/// Event emitted when the admin creates or updates an insurance product
+pub struct InsuranceProductConfigured;
+
+impl InsuranceProductConfigured {
+    pub fn emit(env: &Env, product_id: u32, premium_bps: u32, coverage_bps: u32, active: bool) {
+        env.events().publish(
+            (symbol_short!("insprod"),),
+            (product_id, premium_bps, coverage_bps, active),
+        );
+    }
+}
+
 /// Event emitted when insurance pool balance is updated
 pub struct InsurancePoolUpdated;
 
@@ -730,6 +951,36 @@ impl InsurancePoolUpdated {
     }
 }
 
+/// Event emitted when an underwriter deposits into the insurance pool
+pub struct InsuranceLiquidityDeposited;
+
+impl InsuranceLiquidityDeposited {
+    pub fn emit(env: &Env, provider: Address, amount: i128, shares: i128) {
+        env.events()
+            .publish((symbol_short!("insdep"),), (provider, amount, shares));
+    }
+}
+
+/// Event emitted when an underwriter starts the withdrawal cooldown
+pub struct InsuranceWithdrawalRequested;
+
+impl InsuranceWithdrawalRequested {
+    pub fn emit(env: &Env, provider: Address, shares: i128, available_at: u64) {
+        env.events()
+            .publish((symbol_short!("inswreq"),), (provider, shares, available_at));
+    }
+}
+
+/// Event emitted when an underwriter withdraws from the insurance pool
+pub struct InsuranceLiquidityWithdrawn;
+
+impl InsuranceLiquidityWithdrawn {
+    pub fn emit(env: &Env, provider: Address, shares: i128, amount: i128) {
+        env.events()
+            .publish((symbol_short!("inswdraw"),), (provider, shares, amount));
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // REVIEW & REPUTATION EVENTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -806,6 +1057,26 @@ impl ReputationUpdated {
     }
 }
 
+/// Event emitted when an organizer responds to a review
+pub struct ReviewResponded;
+
+impl ReviewResponded {
+    pub fn emit(env: &Env, review_id: u64, organizer: Address) {
+        env.events()
+            .publish((symbol_short!("revresp"),), (review_id, organizer));
+    }
+}
+
+/// Event emitted when the admin hides or restores a review
+pub struct ReviewModerated;
+
+impl ReviewModerated {
+    pub fn emit(env: &Env, review_id: u64, admin: Address, hidden: bool, reason: String) {
+        env.events()
+            .publish((symbol_short!("revmod"),), (review_id, admin, hidden, reason));
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // UPGRADE MECHANISM EVENTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -866,6 +1137,276 @@ impl UpgradeExecuted {
     }
 }
 
+/// Emitted when voting closes on an upgrade proposal
+pub struct UpgradeFinalized;
+
+impl UpgradeFinalized {
+    pub fn emit(
+        env: &Env,
+        proposal_id: u64,
+        state: crate::types::UpgradeState,
+        executable_at: u64,
+    ) {
+        env.events().publish(
+            (symbol_short!("upgfinal"),),
+            (proposal_id, state, executable_at),
+        );
+    }
+}
+
+/// Emitted when the guardian vetoes an upgrade proposal
+pub struct UpgradeVetoed;
+
+impl UpgradeVetoed {
+    pub fn emit(env: &Env, proposal_id: u64, guardian: Address, reason: String) {
+        env.events()
+            .publish((symbol_short!("upgveto"),), (proposal_id, guardian, reason));
+    }
+}
+
+/// Emitted when governance rolls the contract back to its previous WASM
+pub struct UpgradeRolledBack;
+
+impl UpgradeRolledBack {
+    pub fn emit(env: &Env, proposal_id: u64, executor: Address, restored_wasm_hash: BytesN<32>) {
+        env.events().publish(
+            (symbol_short!("upgrollbk"),),
+            (proposal_id, executor, restored_wasm_hash),
+        );
+    }
+}
+
+/// Emitted when a storage migration batch has been applied
+pub struct StorageMigrated;
+
+impl StorageMigrated {
+    pub fn emit(
+        env: &Env,
+        from_version: u32,
+        to_version: u32,
+        records_migrated: u32,
+        complete: bool,
+    ) {
+        env.events().publish(
+            (symbol_short!("migrated"),),
+            (from_version, to_version, records_migrated, complete),
+        );
+    }
+}
+
+/// Emitted when a governance proposal carrying a parameter change is created
+pub struct GovernanceActionProposed;
+
+impl GovernanceActionProposed {
+    pub fn emit(
+        env: &Env,
+        proposal_id: u64,
+        proposer: Address,
+        action: crate::types::GovernanceAction,
+    ) {
+        env.events().publish(
+            (symbol_short!("govprop"),),
+            (proposal_id, proposer, action),
+        );
+    }
+}
+
+/// Emitted when an approved governance action is applied
+pub struct GovernanceActionExecuted;
+
+impl GovernanceActionExecuted {
+    pub fn emit(
+        env: &Env,
+        proposal_id: u64,
+        executor: Address,
+        action: crate::types::GovernanceAction,
+    ) {
+        env.events().publish(
+            (symbol_short!("govexec"),),
+            (proposal_id, executor, action),
+        );
+    }
+}
+
+/// Emitted when a new admin is proposed and awaits acceptance
+pub struct AdminProposed;
+
+impl AdminProposed {
+    pub fn emit(env: &Env, current_admin: Address, candidate: Address, expires_at: u64) {
+        env.events().publish(
+            (symbol_short!("admprop"),),
+            (current_admin, candidate, expires_at),
+        );
+    }
+}
+
+/// Emitted when a pending admin handover is withdrawn
+pub struct AdminProposalCancelled;
+
+impl AdminProposalCancelled {
+    pub fn emit(env: &Env, candidate: Address) {
+        env.events().publish((symbol_short!("admcncl"),), (candidate,));
+    }
+}
+
+/// Emitted when the admin council is configured or cleared
+pub struct AdminCouncilUpdated;
+
+impl AdminCouncilUpdated {
+    pub fn emit(env: &Env, signers: Vec<Address>, threshold: u32) {
+        env.events()
+            .publish((symbol_short!("admcouncl"),), (signers, threshold));
+    }
+}
+
+/// Emitted when a council signer signs off on an admin action
+pub struct AdminActionApproved;
+
+impl AdminActionApproved {
+    pub fn emit(
+        env: &Env,
+        action: Symbol,
+        action_hash: BytesN<32>,
+        signer: Address,
+        expires_at: u64,
+    ) {
+        env.events().publish(
+            (symbol_short!("admappr"),),
+            (action, action_hash, signer, expires_at),
+        );
+    }
+}
+
+/// Emitted when the emergency global pause is switched on or off
+pub struct GlobalPauseUpdated;
+
+impl GlobalPauseUpdated {
+    pub fn emit(env: &Env, paused: bool) {
+        env.events().publish((symbol_short!("gpause"),), (paused,));
+    }
+}
+
+/// Emitted when a carbon project is registered or its terms change
+pub struct CarbonProjectConfigured;
+
+impl CarbonProjectConfigured {
+    pub fn emit(
+        env: &Env,
+        project_id: String,
+        price_per_kg: i128,
+        beneficiary: Address,
+        attestor: Address,
+        active: bool,
+    ) {
+        env.events().publish(
+            (symbol_short!("cproj"),),
+            (project_id, price_per_kg, beneficiary, attestor, active),
+        );
+    }
+}
+
+/// Emitted when an organizer enables or disables the checkout offset
+pub struct CheckoutOffsetConfigured;
+
+impl CheckoutOffsetConfigured {
+    pub fn emit(
+        env: &Env,
+        event_id: u64,
+        project_id: String,
+        offset_kg_per_ticket: i128,
+        surcharge_per_ticket: i128,
+        enabled: bool,
+    ) {
+        env.events().publish(
+            (symbol_short!("ckoffcfg"),),
+            (
+                event_id,
+                project_id,
+                offset_kg_per_ticket,
+                surcharge_per_ticket,
+                enabled,
+            ),
+        );
+    }
+}
+
+/// Emitted when an attendee opts into the carbon offset at checkout
+pub struct CheckoutOffsetContributed;
+
+impl CheckoutOffsetContributed {
+    pub fn emit(
+        env: &Env,
+        ticket_id: u64,
+        event_id: u64,
+        attendee: Address,
+        offset_kg: i128,
+        surcharge: i128,
+    ) {
+        env.events().publish(
+            (symbol_short!("ckoffset"),),
+            (ticket_id, event_id, attendee, offset_kg, surcharge),
+        );
+    }
+}
+
+/// Emitted when an event's checkout surcharges are spent on an offset purchase
+pub struct CheckoutOffsetSettled;
+
+impl CheckoutOffsetSettled {
+    pub fn emit(
+        env: &Env,
+        event_id: u64,
+        purchase_id: u64,
+        offset_kg: i128,
+        cost: i128,
+        remainder: i128,
+    ) {
+        env.events().publish(
+            (symbol_short!("ckoffsetl"),),
+            (event_id, purchase_id, offset_kg, cost, remainder),
+        );
+    }
+}
+
+/// Emitted when a project's attestor confirms an offset was retired
+pub struct CarbonRetirementAttested;
+
+impl CarbonRetirementAttested {
+    pub fn emit(
+        env: &Env,
+        purchase_id: u64,
+        project_id: String,
+        retirement_serial: BytesN<32>,
+        offset_amount_kg: i128,
+    ) {
+        env.events().publish(
+            (symbol_short!("cretire"),),
+            (purchase_id, project_id, retirement_serial, offset_amount_kg),
+        );
+    }
+}
+
+/// Emitted when feature kill switches are flipped; carries the resulting bitmap
+pub struct FeaturePauseUpdated;
+
+impl FeaturePauseUpdated {
+    pub fn emit(env: &Env, caller: Address, features: u32, paused: bool, paused_features: u32) {
+        env.events().publish(
+            (symbol_short!("featpause"),),
+            (caller, features, paused, paused_features),
+        );
+    }
+}
+
+/// Emitted when platform parameters are handed over to governance
+pub struct GovernanceControlEnabled;
+
+impl GovernanceControlEnabled {
+    pub fn emit(env: &Env, admin: Address) {
+        env.events().publish((symbol_short!("govctrl"),), (admin,));
+    }
+}
+
 /// Emitted when governance config is updated
 pub struct UpgradeGovernanceConfigUpdated;
 
@@ -918,6 +1459,34 @@ impl CarbonFootprintCalculated {
     }
 }
 
+/// Emitted when the admin changes emission factors
+pub struct EmissionFactorsUpdated;
+
+impl EmissionFactorsUpdated {
+    pub fn emit(env: &Env, admin: Address, factors: crate::types::EmissionFactors) {
+        env.events()
+            .publish((symbol_short!("emfactors"),), (admin, factors));
+    }
+}
+
+/// Emitted when an event's footprint is recalculated from check-ins
+pub struct ActualFootprintRecorded;
+
+impl ActualFootprintRecorded {
+    pub fn emit(
+        env: &Env,
+        event_id: u64,
+        estimated_kg: i128,
+        actual_kg: i128,
+        checked_in_attendance: u32,
+    ) {
+        env.events().publish(
+            (symbol_short!("cfootact"),),
+            (event_id, estimated_kg, actual_kg, checked_in_attendance),
+        );
+    }
+}
+
 /// Emitted when a carbon offset is purchased
 pub struct CarbonOffsetPurchased;
 
@@ -974,6 +1543,24 @@ impl EnvironmentalImpactUpdated {
 // IDENTITY VERIFICATION EVENTS
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Emitted when a trusted credential issuer is registered or (de)activated
+pub struct CredentialIssuerUpdated;
+
+impl CredentialIssuerUpdated {
+    pub fn emit(
+        env: &Env,
+        public_key: BytesN<32>,
+        provider: crate::types::IdentityProvider,
+        max_level: u32,
+        active: bool,
+    ) {
+        env.events().publish(
+            (symbol_short!("credissr"),),
+            (public_key, provider, max_level, active),
+        );
+    }
+}
+
 /// Emitted when an identity credential is issued
 pub struct IdentityCredentialIssued;
 
@@ -1340,6 +1927,24 @@ impl TicketDidRevoked {
     }
 }
 
+/// Emitted when an organizer sets or clears an event's credential
+/// requirement; a cleared requirement has no providers
+pub struct EventCredentialRequirementUpdated;
+impl EventCredentialRequirementUpdated {
+    pub fn emit(
+        env: &Env,
+        event_id: u64,
+        organizer: Address,
+        providers: Vec<crate::types::IdentityProvider>,
+        min_level: u32,
+    ) {
+        env.events().publish(
+            (symbol_short!("evcredreq"),),
+            (event_id, organizer, providers, min_level),
+        );
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // RESALE PRICE CEILING EVENTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1677,3 +2282,166 @@ impl PromoCodeApplied {
         );
     }
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Multi-Token Payments
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Emitted when an organizer accepts a new payment token for an event
+pub struct AcceptedTokenAdded;
+impl AcceptedTokenAdded {
+    pub fn emit(env: &Env, event_id: u64, token: Address, currency: String) {
+        env.events()
+            .publish((symbol_short!("tokenadd"),), (event_id, token, currency));
+    }
+}
+
+/// Emitted when an organizer stops accepting a payment token for an event
+pub struct AcceptedTokenRemoved;
+impl AcceptedTokenRemoved {
+    pub fn emit(env: &Env, event_id: u64, token: Address) {
+        env.events()
+            .publish((symbol_short!("tokenrem"),), (event_id, token));
+    }
+}
+
+/// Emitted when a buyer locks a ticket price quote in an accepted token
+pub struct PriceQuoteIssued;
+impl PriceQuoteIssued {
+    pub fn emit(
+        env: &Env,
+        quote_id: u64,
+        event_id: u64,
+        buyer: Address,
+        token: Address,
+        amount: i128,
+        expires_at: u64,
+    ) {
+        env.events().publish(
+            (symbol_short!("quoteiss"),),
+            (quote_id, event_id, buyer, token, amount, expires_at),
+        );
+    }
+}
+
+/// Emitted when escrow held in an accepted token is released to the organizer
+pub struct TokenEscrowReleased;
+impl TokenEscrowReleased {
+    pub fn emit(env: &Env, event_id: u64, organizer: Address, token: Address, amount: i128) {
+        env.events().publish(
+            (symbol_short!("tkescrrel"),),
+            (event_id, organizer, token, amount),
+        );
+    }
+}
+
+/// Emitted when a holder claims platform fees owed back after a refund.
+/// `token` is `None` for the platform token.
+pub struct PlatformFeeRefundClaimed;
+impl PlatformFeeRefundClaimed {
+    pub fn emit(env: &Env, holder: Address, token: Option<Address>, amount: i128) {
+        env.events()
+            .publish((symbol_short!("feeclaim"),), (holder, token, amount));
+    }
+}
+
+/// Emitted when the admin withdraws platform fees collected in an accepted token
+pub struct TokenPlatformFeesWithdrawn;
+impl TokenPlatformFeesWithdrawn {
+    pub fn emit(env: &Env, admin: Address, token: Address, amount: i128) {
+        env.events()
+            .publish((symbol_short!("tkfeewdr"),), (admin, token, amount));
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Gate Validators & Multi-Sig Escrow
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Emitted when an organizer authorizes a gate validator for an event
+pub struct ValidatorAdded;
+impl ValidatorAdded {
+    pub fn emit(env: &Env, event_id: u64, organizer: Address, validator: Address) {
+        env.events().publish(
+            (symbol_short!("validadd"),),
+            (event_id, organizer, validator),
+        );
+    }
+}
+
+/// Emitted when an organizer revokes a gate validator for an event
+pub struct ValidatorRemoved;
+impl ValidatorRemoved {
+    pub fn emit(env: &Env, event_id: u64, organizer: Address, validator: Address) {
+        env.events().publish(
+            (symbol_short!("validrem"),),
+            (event_id, organizer, validator),
+        );
+    }
+}
+
+/// Emitted when the multi-sig escrow signers of an event are configured
+pub struct EscrowSignersSet;
+impl EscrowSignersSet {
+    pub fn emit(env: &Env, event_id: u64, signer_count: u32, threshold: u32) {
+        env.events().publish(
+            (symbol_short!("escsigset"),),
+            (event_id, signer_count, threshold),
+        );
+    }
+}
+
+/// Emitted when an escrow signer approves (or withdraws approval of) a release
+pub struct EscrowReleaseApproval;
+impl EscrowReleaseApproval {
+    pub fn emit(env: &Env, event_id: u64, signer: Address, approved: bool) {
+        env.events().publish(
+            (symbol_short!("escapprv"),),
+            (event_id, signer, approved),
+        );
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Sponsorships
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Emitted when an organizer registers a sponsor tier for an event
+pub struct SponsorTierRegistered;
+impl SponsorTierRegistered {
+    pub fn emit(env: &Env, event_id: u64, tier_id: u32, price: i128, max_sponsors: u32) {
+        env.events().publish(
+            (symbol_short!("sponstier"),),
+            (event_id, tier_id, price, max_sponsors),
+        );
+    }
+}
+
+/// Emitted when a sponsor pays into a tier and receives its benefits
+pub struct SponsorContributed;
+impl SponsorContributed {
+    pub fn emit(
+        env: &Env,
+        event_id: u64,
+        tier_id: u32,
+        sponsor: Address,
+        amount: i128,
+        comp_tickets: u32,
+    ) {
+        env.events().publish(
+            (symbol_short!("sponscont"),),
+            (event_id, tier_id, sponsor, amount, comp_tickets),
+        );
+    }
+}
+
+/// Emitted when a sponsor is refunded after the event is cancelled
+pub struct SponsorRefunded;
+impl SponsorRefunded {
+    pub fn emit(env: &Env, event_id: u64, tier_id: u32, sponsor: Address, amount: i128) {
+        env.events().publish(
+            (symbol_short!("sponsrfnd"),),
+            (event_id, tier_id, sponsor, amount),
+        );
+    }
+}
@@ -13,6 +13,7 @@
 ///   - deposit_funds: success, unauthorized, invalid amount, cancelled event, not initialized
 use crate::error::LumentixError;
 use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
+use crate::types::CancellationReason;
 use soroban_sdk::{
     testutils::{Address as _, Events, Ledger},
     Address, Env,
@@ -104,6 +105,7 @@ fn test_get_protocol_fee_recipient_updates_after_admin_change() {
     let new_admin = Address::generate(&env);
 
     client.update_platform_fee_recipient(&admin, &new_admin);
+    client.accept_admin(&new_admin);
 
     let (_fee, recipient) = client.get_protocol_fee();
     assert_eq!(recipient, new_admin, "recipient should reflect new admin");
@@ -265,7 +267,7 @@ fn test_get_protocol_fee_fee_calculation_at_max() {
         &10u32,
     );
     client.update_event_status(&event_id, &crate::types::EventStatus::Published, &organizer);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // At 100% fee, entire amount goes to platform, escrow gets 0
     assert_eq!(client.get_platform_balance(), 100i128);
@@ -293,7 +295,7 @@ fn test_get_protocol_fee_fee_calculation_at_zero() {
         &10u32,
     );
     client.update_event_status(&event_id, &crate::types::EventStatus::Published, &organizer);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     assert_eq!(client.get_platform_balance(), 0i128);
     assert_eq!(client.get_escrow_balance(&event_id), 100i128);
@@ -462,7 +464,7 @@ fn test_deposit_funds_cancelled_event_rejected() {
         &10u32,
     );
     client.update_event_status(&event_id, &crate::types::EventStatus::Published, &organizer);
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     let result = client.try_deposit_funds(&organizer, &event_id, &100i128);
     assert_eq!(result, Err(Ok(LumentixError::InvalidStatusTransition)));
@@ -860,7 +862,7 @@ fn test_deposit_funds_state_persistence() {
 
     // Perform some other operations (ticket purchases, etc.)
     let buyer = Address::generate(&env);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Check that escrow balance is updated correctly
     let balance_after_purchase = client.get_escrow_balance(&event_id);
@@ -0,0 +1,483 @@
+#![allow(warnings)]
+
+use crate::error::LumentixError;
+use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
+use crate::types::{CancellationReason, EventStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String, Vec,
+};
+
+fn setup(env: &Env) -> (Address, Address, LumentixContractClient<'_>) {
+    let contract_id = env.register(LumentixContract, ());
+    let client = LumentixContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.set_token(&admin, &token);
+    (admin, token, client)
+}
+
+fn create_and_publish_event(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+) -> u64 {
+    let event_id = client.create_event(
+        organizer,
+        &String::from_str(env, "Insured Event"),
+        &String::from_str(env, "Description"),
+        &String::from_str(env, "Venue"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &10u32,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, organizer);
+    event_id
+}
+
+fn buy_ticket(
+    env: &Env,
+    client: &LumentixContractClient,
+    token: &Address,
+    event_id: u64,
+) -> (Address, u64) {
+    let buyer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&buyer, &1_000);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    (buyer, ticket_id)
+}
+
+fn create_half_cover_product(env: &Env, client: &LumentixContractClient, admin: &Address) -> u32 {
+    client.create_insurance_product(
+        admin,
+        &String::from_str(env, "Half cover"),
+        &500u32,
+        &5_000u32,
+        &500u64,
+        &vec![env, CancellationReason::EventCancelledByOrganizer],
+    )
+}
+
+#[test]
+fn test_only_admin_creates_valid_products() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, _, client) = setup(&env);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_create_insurance_product(
+        &outsider,
+        &String::from_str(&env, "Basic"),
+        &500u32,
+        &5_000u32,
+        &0u64,
+        &vec![&env, CancellationReason::ForceMajeure],
+    );
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    let result = client.try_create_insurance_product(
+        &admin,
+        &String::from_str(&env, "Basic"),
+        &500u32,
+        &12_000u32,
+        &0u64,
+        &vec![&env, CancellationReason::ForceMajeure],
+    );
+    assert_eq!(result, Err(Ok(LumentixError::InvalidInsuranceProduct)));
+
+    let product_id = create_half_cover_product(&env, &client, &admin);
+    let product = client.get_insurance_product(&product_id);
+    assert_eq!(product.premium_bps, 500);
+    assert_eq!(product.coverage_bps, 5_000);
+    assert!(product.active);
+
+    client.update_insurance_product(
+        &admin,
+        &product_id,
+        &800u32,
+        &7_500u32,
+        &600u64,
+        &vec![&env, CancellationReason::VenueUnavailable],
+        &false,
+    );
+    let product = client.get_insurance_product(&product_id);
+    assert_eq!(product.coverage_bps, 7_500);
+    assert!(!product.active);
+}
+
+#[test]
+fn test_premium_and_coverage_follow_product_terms() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let product_id = create_half_cover_product(&env, &client, &admin);
+
+    let (buyer, ticket_id) = buy_ticket(&env, &client, &token, event_id);
+    client.purchase_insurance(&ticket_id, &buyer, &product_id);
+
+    let policy = client.get_insurance_policy_by_ticket(&ticket_id);
+    assert_eq!(policy.premium_paid, 5);
+    assert_eq!(policy.coverage_amount, 50);
+    assert_eq!(policy.product_id, product_id);
+    assert_eq!(token::Client::new(&env, &token).balance(&buyer), 895);
+
+    let result = client.try_purchase_insurance(&ticket_id, &buyer, &product_id);
+    assert_eq!(result, Err(Ok(LumentixError::InsuranceAlreadyPurchased)));
+}
+
+#[test]
+fn test_purchase_closes_at_cutoff_and_for_retired_products() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let product_id = create_half_cover_product(&env, &client, &admin);
+    let (buyer, ticket_id) = buy_ticket(&env, &client, &token, event_id);
+
+    env.ledger().with_mut(|li| li.timestamp = 501);
+    let result = client.try_purchase_insurance(&ticket_id, &buyer, &product_id);
+    assert_eq!(result, Err(Ok(LumentixError::InsurancePurchaseClosed)));
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    client.update_insurance_product(
+        &admin,
+        &product_id,
+        &500u32,
+        &5_000u32,
+        &500u64,
+        &vec![&env, CancellationReason::EventCancelledByOrganizer],
+        &false,
+    );
+    let result = client.try_purchase_insurance(&ticket_id, &buyer, &product_id);
+    assert_eq!(result, Err(Ok(LumentixError::InsuranceProductNotFound)));
+}
+
+#[test]
+fn test_claim_requires_covered_reason_and_is_capped_by_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let product_id = create_half_cover_product(&env, &client, &admin);
+    let (buyer, ticket_id) = buy_ticket(&env, &client, &token, event_id);
+    client.purchase_insurance(&ticket_id, &buyer, &product_id);
+
+    client.cancel_event(
+        &organizer,
+        &event_id,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+
+    let result =
+        client.try_process_insurance_claim(&ticket_id, &buyer, &CancellationReason::ForceMajeure);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidCancellationReason)));
+
+    let balance_before = token::Client::new(&env, &token).balance(&buyer);
+    client.process_insurance_claim(
+        &ticket_id,
+        &buyer,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+
+    // Only the single premium is in the pool, so the payout stops there
+    let policy = client.get_insurance_policy_by_ticket(&ticket_id);
+    assert_eq!(policy.paid_out, 5);
+    assert!(policy.claim_processed);
+    assert_eq!(
+        token::Client::new(&env, &token).balance(&buyer),
+        balance_before + 5
+    );
+    assert_eq!(client.get_insurance_pool().total_balance, 0);
+}
+
+#[test]
+fn test_policy_follows_ticket_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let product_id = create_half_cover_product(&env, &client, &admin);
+    let (buyer, ticket_id) = buy_ticket(&env, &client, &token, event_id);
+    client.purchase_insurance(&ticket_id, &buyer, &product_id);
+
+    let recipient = Address::generate(&env);
+    client.transfer_ticket(&ticket_id, &buyer, &recipient);
+    assert_eq!(
+        client.get_insurance_policy_by_ticket(&ticket_id).holder,
+        recipient
+    );
+
+    client.cancel_event(
+        &organizer,
+        &event_id,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+    let result = client.try_process_insurance_claim(
+        &ticket_id,
+        &buyer,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    client.process_insurance_claim(
+        &ticket_id,
+        &recipient,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+}
+
+fn fund_provider(env: &Env, token: &Address, amount: i128) -> Address {
+    let provider = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&provider, &amount);
+    provider
+}
+
+#[test]
+fn test_liquidity_providers_earn_premiums_pro_rata() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let product_id = create_half_cover_product(&env, &client, &admin);
+
+    let alice = fund_provider(&env, &token, 300);
+    let bob = fund_provider(&env, &token, 100);
+    assert_eq!(client.deposit_insurance_liquidity(&alice, &300i128), 300);
+    assert_eq!(client.deposit_insurance_liquidity(&bob, &100i128), 100);
+
+    // Four premiums of 5 lift the pool from 400 to 420
+    for _ in 0..4 {
+        let (buyer, ticket_id) = buy_ticket(&env, &client, &token, event_id);
+        client.purchase_insurance(&ticket_id, &buyer, &product_id);
+    }
+    assert_eq!(client.get_insurance_share_price(), 10_500_000);
+    assert_eq!(client.get_insurance_exposure(), 200);
+    assert_eq!(client.get_insurance_utilisation(), 4_761);
+
+    client.request_insurance_withdrawal(&bob, &100i128);
+    let result = client.try_withdraw_insurance_liquidity(&bob);
+    assert_eq!(
+        result,
+        Err(Ok(LumentixError::InsuranceWithdrawalCoolingDown))
+    );
+
+    let cooldown = client.get_insurance_pool_params().withdrawal_cooldown_secs;
+    env.ledger().with_mut(|li| li.timestamp = cooldown);
+    assert_eq!(client.withdraw_insurance_liquidity(&bob), 105);
+    assert_eq!(token::Client::new(&env, &token).balance(&bob), 105);
+    assert_eq!(client.get_insurance_lp_position(&bob).shares, 0);
+    assert_eq!(client.get_insurance_lp_position(&alice).shares, 300);
+}
+
+#[test]
+fn test_reserve_ratio_blocks_new_cover_and_withdrawals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let product_id = create_half_cover_product(&env, &client, &admin);
+    client.set_insurance_pool_params(&admin, &0u64, &10_000u32);
+
+    let (buyer, ticket_id) = buy_ticket(&env, &client, &token, event_id);
+    let result = client.try_purchase_insurance(&ticket_id, &buyer, &product_id);
+    assert_eq!(result, Err(Ok(LumentixError::InsuranceReserveBreached)));
+
+    let provider = fund_provider(&env, &token, 45);
+    client.deposit_insurance_liquidity(&provider, &45i128);
+    client.purchase_insurance(&ticket_id, &buyer, &product_id);
+    assert_eq!(client.get_insurance_utilisation(), 10_000);
+
+    client.request_insurance_withdrawal(&provider, &1i128);
+    let result = client.try_withdraw_insurance_liquidity(&provider);
+    assert_eq!(result, Err(Ok(LumentixError::InsuranceReserveBreached)));
+
+    // Once the event ends uncancelled its cover no longer ties up capital
+    let result = client.try_release_insurance_exposure(&event_id);
+    assert_eq!(
+        result,
+        Err(Ok(LumentixError::InsuranceExposureNotReleasable))
+    );
+    env.ledger().with_mut(|li| li.timestamp = 2001);
+    assert_eq!(client.release_insurance_exposure(&event_id), 50);
+    assert_eq!(client.get_insurance_exposure(), 0);
+    client.withdraw_insurance_liquidity(&provider);
+}
+
+#[test]
+fn test_deposit_rejected_while_claims_have_drained_the_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let product_id = create_half_cover_product(&env, &client, &admin);
+
+    let provider = fund_provider(&env, &token, 45);
+    client.deposit_insurance_liquidity(&provider, &45i128);
+    let (buyer, ticket_id) = buy_ticket(&env, &client, &token, event_id);
+    client.purchase_insurance(&ticket_id, &buyer, &product_id);
+
+    client.cancel_event(
+        &organizer,
+        &event_id,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+    client.process_insurance_claim(
+        &ticket_id,
+        &buyer,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+    assert_eq!(client.get_insurance_pool().total_balance, 0);
+
+    let newcomer = fund_provider(&env, &token, 100);
+    let result = client.try_deposit_insurance_liquidity(&newcomer, &100i128);
+    assert_eq!(result, Err(Ok(LumentixError::InsurancePoolDrained)));
+    assert_eq!(token::Client::new(&env, &token).balance(&newcomer), 100);
+}
+
+#[test]
+fn test_premiums_collected_before_any_lp_stay_with_the_protocol() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let product_id = create_half_cover_product(&env, &client, &admin);
+    client.set_insurance_pool_params(&admin, &0u64, &0u32);
+
+    let (buyer, ticket_id) = buy_ticket(&env, &client, &token, event_id);
+    client.purchase_insurance(&ticket_id, &buyer, &product_id);
+    assert_eq!(client.get_insurance_pool().total_balance, 5);
+
+    // The first deposit is priced against the premiums already collected
+    let provider = fund_provider(&env, &token, 1);
+    assert_eq!(client.deposit_insurance_liquidity(&provider, &1i128), 1);
+    assert_eq!(client.get_insurance_lp_position(&client.address).shares, 5);
+    assert_eq!(client.get_insurance_pool().total_shares, 6);
+
+    client.request_insurance_withdrawal(&provider, &1i128);
+    assert_eq!(client.withdraw_insurance_liquidity(&provider), 1);
+    assert_eq!(token::Client::new(&env, &token).balance(&provider), 1);
+    assert_eq!(client.get_insurance_pool().total_balance, 5);
+}
+
+#[test]
+fn test_cancel_event_records_reason_and_admin_can_attest() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let product_id = create_half_cover_product(&env, &client, &admin);
+    let (buyer, ticket_id) = buy_ticket(&env, &client, &token, event_id);
+    client.purchase_insurance(&ticket_id, &buyer, &product_id);
+
+    client.cancel_event(&organizer, &event_id, &CancellationReason::ForceMajeure);
+    let cancellation = client.get_event_cancellation(&event_id);
+    assert_eq!(cancellation.reason, CancellationReason::ForceMajeure);
+    assert_eq!(cancellation.declared_by, organizer);
+    assert!(!cancellation.attested);
+
+    // The holder cannot pick a different reason than the recorded one
+    let result = client.try_process_insurance_claim(
+        &ticket_id,
+        &buyer,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::InvalidCancellationReason)));
+
+    client.attest_cancellation_reason(
+        &admin,
+        &event_id,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+    let cancellation = client.get_event_cancellation(&event_id);
+    assert!(cancellation.attested);
+    client.process_insurance_claim(
+        &ticket_id,
+        &buyer,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+}
+
+#[test]
+fn test_batch_settlement_pays_policies_and_resumes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, token, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let product_id = create_half_cover_product(&env, &client, &admin);
+    let narrow_product = client.create_insurance_product(
+        &admin,
+        &String::from_str(&env, "Weather only"),
+        &500u32,
+        &5_000u32,
+        &0u64,
+        &vec![&env, CancellationReason::ForceMajeure],
+    );
+
+    let provider = fund_provider(&env, &token, 1_000);
+    client.deposit_insurance_liquidity(&provider, &1_000i128);
+
+    let mut holders = Vec::new(&env);
+    for _ in 0..3 {
+        let (buyer, ticket_id) = buy_ticket(&env, &client, &token, event_id);
+        client.purchase_insurance(&ticket_id, &buyer, &product_id);
+        holders.push_back((buyer, ticket_id));
+    }
+    let (uncovered_buyer, uncovered_ticket) = buy_ticket(&env, &client, &token, event_id);
+    client.purchase_insurance(&uncovered_ticket, &uncovered_buyer, &narrow_product);
+
+    let result = client.try_settle_event_insurance(&event_id, &2u32);
+    assert_eq!(result, Err(Ok(LumentixError::EventNotCancelled)));
+
+    client.cancel_event(
+        &organizer,
+        &event_id,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+
+    let settlement = client.settle_event_insurance(&event_id, &2u32);
+    assert_eq!(settlement.next_index, 2);
+    assert_eq!(settlement.policies_settled, 2);
+    assert_eq!(settlement.total_paid, 100);
+    assert!(!settlement.complete);
+
+    let settlement = client.settle_event_insurance(&event_id, &2u32);
+    assert_eq!(settlement.policies_settled, 3);
+    assert_eq!(settlement.total_paid, 150);
+    assert!(settlement.complete);
+
+    for (buyer, ticket_id) in holders.iter() {
+        let policy = client.get_insurance_policy_by_ticket(&ticket_id);
+        assert!(policy.claim_processed);
+        assert_eq!(policy.paid_out, 50);
+        assert_eq!(token::Client::new(&env, &token).balance(&buyer), 945);
+    }
+
+    let uncovered = client.get_insurance_policy_by_ticket(&uncovered_ticket);
+    assert!(!uncovered.active);
+    assert_eq!(uncovered.paid_out, 0);
+    assert_eq!(client.get_insurance_exposure(), 0);
+
+    // Settled policies cannot be claimed a second time
+    let (buyer, ticket_id) = holders.get(0).unwrap();
+    let result = client.try_process_insurance_claim(
+        &ticket_id,
+        &buyer,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::InsurancePolicyNotActive)));
+}
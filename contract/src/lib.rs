@@ -1,230 +1,9 @@
 #![no_std]
 
-use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec,
-};
-
-#[contracttype]
-#[derive(Clone)]
-pub struct Tier {
-    pub price: i128,
-    pub max_sponsors: u32,
-    pub sponsor_count: u32,
-}
-
-// Register a sponsor tier for an event.
-#[contract]
-pub struct SponsorsContract;
-
-#[contractimpl]
-impl SponsorsContract {
-    pub fn register_sponsor_tier(
-        env: Env,
-        event_id: String,
-        tier_id: String,
-        price: i128,
-        max_sponsors: u32,
-    ) {
-        let key = (symbol_short!("tier"), event_id.clone(), tier_id.clone());
-        let tier = Tier {
-            price,
-            max_sponsors,
-            sponsor_count: 0,
-        };
-        env.storage().persistent().set(&key, &tier);
-
-        // Emit SponsorTierRegistered event
-        #[allow(deprecated)]
-        env.events().publish(
-            (symbol_short!("sponstier"),),
-            (event_id, tier_id, price, max_sponsors),
-        );
-    }
-
-    pub fn contribute(env: Env, event_id: String, tier_id: String, sponsor: Address, amount: i128) {
-        let key = (symbol_short!("tier"), event_id.clone(), tier_id.clone());
-        let mut tier: Tier = env
-            .storage()
-            .persistent()
-            .get::<(Symbol, String, String), Tier>(&key)
-            .expect("tier not found");
-
-        if tier.sponsor_count >= tier.max_sponsors {
-            panic!("tier is full");
-        }
-
-        if amount != tier.price {
-            panic!("incorrect amount");
-        }
-
-        let ckey = (symbol_short!("contrib"), event_id.clone(), tier_id.clone());
-        let mut list: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get::<(Symbol, String, String), Vec<Address>>(&ckey)
-            .unwrap_or_else(|| Vec::new(&env));
-
-        list.push_back(sponsor.clone());
-        env.storage().persistent().set(&ckey, &list);
-
-        tier.sponsor_count = tier.sponsor_count.saturating_add(1);
-        env.storage().persistent().set(&key, &tier);
-
-        #[allow(deprecated)]
-        env.events().publish(
-            (Symbol::new(&env, "SponsorContributed"),),
-            (event_id, tier_id, sponsor, amount, tier.sponsor_count),
-        );
-    }
-
-    pub fn get_tier_contributions(
-        env: Env,
-        event_id: String,
-        tier_id: String,
-    ) -> (u32, Vec<Address>) {
-        let key = (symbol_short!("contrib"), event_id.clone(), tier_id.clone());
-        let list: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get::<(Symbol, String, String), Vec<Address>>(&key)
-            .unwrap_or_else(|| Vec::new(&env));
-
-        let count = list.len();
-        (count, list)
-    }
-}
-
-#[cfg(test)]
-mod sponsor_tests {
-    use super::*;
-    use soroban_sdk::{testutils::Address as _, testutils::Events, xdr, Env};
-
-    #[test]
-    fn test_simple_register() {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let event = String::from_str(&env, "event1");
-        let tier = String::from_str(&env, "tierA");
-
-        let contract_id = env.register(SponsorsContract, ());
-        let client = SponsorsContractClient::new(&env, &contract_id);
-
-        client.register_sponsor_tier(&event, &tier, &100_i128, &2u32);
-    }
-
-    #[test]
-    fn register_and_contribute_flow() {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let event = String::from_str(&env, "event1");
-        let tier = String::from_str(&env, "tierA");
-
-        let contract_id = env.register(SponsorsContract, ());
-        let client = SponsorsContractClient::new(&env, &contract_id);
-
-        client.register_sponsor_tier(&event, &tier, &100_i128, &2u32);
-
-        let sponsor1 = Address::generate(&env);
-        client.contribute(&event, &tier, &sponsor1, &100_i128);
-
-        let (count, list) = client.get_tier_contributions(&event, &tier);
-        assert_eq!(count, 1u32);
-        assert_eq!(list.len(), 1u32);
-
-        let sponsor2 = Address::generate(&env);
-        client.contribute(&event, &tier, &sponsor2, &100_i128);
-
-        let (count2, list2) = client.get_tier_contributions(&event, &tier);
-        assert_eq!(count2, 2u32);
-        assert_eq!(list2.len(), 2u32);
-    }
-
-    #[test]
-    fn contribute_emits_sponsor_contributed_event() {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let event = String::from_str(&env, "event1");
-        let tier = String::from_str(&env, "tierA");
-
-        let contract_id = env.register(SponsorsContract, ());
-        let client = SponsorsContractClient::new(&env, &contract_id);
-
-        client.register_sponsor_tier(&event, &tier, &100_i128, &2u32);
-
-        let sponsor = Address::generate(&env);
-        client.contribute(&event, &tier, &sponsor, &100_i128);
-
-        let events = env.events().all();
-        assert_eq!(events.events().len(), 1);
-
-        let xdr_event = events.events().get(0).unwrap();
-        #[allow(irrefutable_let_patterns)]
-        if let xdr::ContractEventBody::V0(body) = &xdr_event.body {
-            assert_eq!(body.topics.len(), 1);
-            if let xdr::ScVal::Symbol(topic_sym) = &body.topics[0] {
-                assert_eq!(topic_sym.as_slice(), b"SponsorContributed");
-            } else {
-                panic!("Expected Symbol topic");
-            }
-
-            if let xdr::ScVal::Vec(Some(data_vec)) = &body.data {
-                assert_eq!(data_vec.len(), 5);
-            } else {
-                panic!("Expected Vec data");
-            }
-        } else {
-            panic!("Expected V0 event body");
-        }
-    }
-
-    #[test]
-    #[should_panic(expected = "tier is full")]
-    fn contribute_beyond_capacity_panics() {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let event = String::from_str(&env, "e2");
-        let tier = String::from_str(&env, "tX");
-
-        let contract_id = env.register(SponsorsContract, ());
-        let client = SponsorsContractClient::new(&env, &contract_id);
-
-        client.register_sponsor_tier(&event, &tier, &50_i128, &1u32);
-
-        let s1 = Address::generate(&env);
-        client.contribute(&event, &tier, &s1, &50_i128);
-
-        let s2 = Address::generate(&env);
-        client.contribute(&event, &tier, &s2, &50_i128);
-    }
-
-    #[test]
-    #[should_panic(expected = "incorrect amount")]
-    fn incorrect_amount_panics() {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let event = String::from_str(&env, "e3");
-        let tier = String::from_str(&env, "tY");
-
-        let contract_id = env.register(SponsorsContract, ());
-        let client = SponsorsContractClient::new(&env, &contract_id);
-
-        client.register_sponsor_tier(&event, &tier, &123_i128, &2u32);
-
-        let s = Address::generate(&env);
-        client.contribute(&event, &tier, &s, &1_i128);
-    }
-}
-
-pub mod contract;
 pub mod error;
 pub mod events;
 pub mod lumentix_contract;
-pub mod models;
+pub mod oracle;
 pub mod storage;
 pub mod types;
 pub mod validation;
@@ -253,7 +32,15 @@ mod merchandise_nft_tests;
 #[cfg(test)]
 mod certification_analytics_tests;
 
-pub use contract::TicketContract;
+#[cfg(test)]
+mod sponsor_tests;
+
+#[cfg(test)]
+mod insurance_tests;
+
+#[cfg(test)]
+mod reputation_tests;
+
 pub use error::LumentixError;
 pub use events::{
     AnonymousSurveySubmitted, SurveyResultsCompiled,
@@ -262,7 +49,10 @@ pub use events::{
     AttendanceMemorabiliaMinted, AttendanceVerificationFailed, AttendanceVerified,
     BlockchainIdentityVerified,
     BridgeTransactionValidated, CarbonFootprintCalculated, CarbonOffsetPurchased,
-    CheckInEvent, CheckinProofValidated, CollectibleInventoryUpdated,
+    CarbonProjectConfigured, CarbonRetirementAttested, CheckoutOffsetConfigured,
+    CheckoutOffsetContributed, CheckoutOffsetSettled, ActualFootprintRecorded,
+    EmissionFactorsUpdated, CredentialIssuerUpdated, EventCredentialRequirementUpdated,
+    CheckinProofValidated, CollectibleInventoryUpdated,
     CrossChainTransferCompleted,
     CrossChainTransferInitiated, EnvironmentalImpactUpdated, EventCancelled, EventMetadataUpdated,
     EventSalesPaused, EventSalesResumed, IdentityCredentialIssued, IdentityCredentialRevoked,
@@ -271,28 +61,60 @@ pub use events::{
     MerchandisePurchased, NftMinted, NftTraded, PriceCeilingSet, ReputationUpdated,
     ResaleComplianceEnforced, ResalePriceVerified, ReviewSubmitted,
     SeatUpgradeBidPlaced, SeatUpgradeBidRefunded, SeatUpgradeBidResolved,
-    TicketDidLinked, TicketDidRevoked, TransferEvent,
-    UpgradeExecuted, UpgradeGovernanceConfigUpdated, UpgradeProposed, UpgradeVoteCast,
+    TicketDidLinked, TicketDidRevoked,
+    UpgradeExecuted, UpgradeFinalized, UpgradeGovernanceConfigUpdated, UpgradeProposed,
+    UpgradeRolledBack, UpgradeVetoed, UpgradeVoteCast, StorageMigrated, GovernanceActionExecuted,
+    GovernanceActionProposed, GovernanceControlEnabled, AdminCouncilUpdated, AdminActionApproved,
+    AdminProposalCancelled,
+    AdminProposed, FeaturePauseUpdated, GlobalPauseUpdated,
     VenueSpaceAllocated, SpaceUtilizationOptimized, VenueConflictManaged,
     SubscriptionPlanCreated, RecurringBillingProcessed, SubscriptionStatusValidated,
     SecurityThreatMonitored, SuspiciousActivityDetected, IncidentResponded,
     UserExperiencePersonalized, EventRecommendationsCustomized, UserJourneyOptimized,
     WaitlistOfferExpired, WaitlistSpotReleased,
     EventCertificateIssued, CertificationStandardUpdated,
+    ValidatorAdded, ValidatorRemoved, EscrowSignersSet, EscrowReleaseApproval,
+    SponsorContributed, SponsorRefunded, SponsorTierRegistered,
+    AcceptedTokenAdded, AcceptedTokenRemoved, TokenEscrowReleased, TokenPlatformFeesWithdrawn,
+    PlatformFeeRefundClaimed,
+    CurrencyPriceGuardsUpdated, OracleFeedConfigured, PriceQuoteIssued, VipBenefitRedeemed,
+    VipUpgradePurchased,
+    AccessibilityBookingReleased, AccessibilityBookingReviewed, AccommodationTypeConfigured,
+    StreamAccessTokenIssued, VirtualTicketClassSet, VirtualTicketPurchased,
+    ReplayConfigured, ReplayPurchased, ReplayRefunded, ReplayWithdrawn,
+    QosReportSubmitted, QosReporterUpdated, SlaRefundClaimed, InsuranceProductConfigured,
+    InsuranceLiquidityDeposited, InsuranceLiquidityWithdrawn, InsuranceWithdrawalRequested,
+    CancellationReasonAttested, ReviewModerated, ReviewResponded,
 };
 pub use lumentix_contract::LumentixContract;
-pub use models::{DataKey, EscrowConfig, EventAuth, Ticket as TicketModel, ValidatorKey};
+pub use types::{
+    FEATURE_ALL, FEATURE_ESCROW_RELEASES, FEATURE_MERCHANDISE, FEATURE_NFT_MINTING,
+    FEATURE_PURCHASES, FEATURE_REFUNDS, FEATURE_RESALE, FEATURE_TRANSFERS,
+};
 pub use types::{
     AnonymousSurveyResponse, SurveyResults,
     ScheduleVote, ScheduleVoteCastRecord,
     PromoCode,
-    BridgeTransaction, CancellationReason, CarbonFootprint, CarbonOffsetPurchase,
-    CollectibleInventory, CrossChainTransfer, CrossChainTransferStatus, EnvironmentalImpact,
+    AttendeeCarbonOffset, BridgeTransaction, CancellationReason, CarbonFootprint,
+    CarbonOffsetPurchase, CarbonProject, CheckoutCarbonOffset, EmissionFactors, EnergyMix,
+    FootprintProfile, SustainabilityReport, TransportMode, VenueType, SignedCredentialPayload,
+    TrustedIssuer, EventCredentialRequirement,
+    CollectibleInventory, CrossChainTransfer, CrossChainTransferStatus, EnvironmentalImpact, EscrowConfig,
     Event, EventMerchandise, EventReview, EventStatus, IdentityCredential, IdentityProof,
     IdentityProvider, InsurancePolicy, InsurancePool, MemorabiliaClaim, MerchVoucher, NftCollectible,
     OrganizerReputation,
     RarityTier, ResalePriceCeiling, SeatUpgradeBid, Ticket as LumentixTicket, TicketDidAssociation,
-    UpgradeGovernanceConfig, UpgradeProposal, UpgradeState,
+    UpgradeGovernanceConfig, UpgradeKind, UpgradeProposal, UpgradeSafeguards, UpgradeState,
+    AccessibilityBookingV1, CarbonOffsetPurchaseV1, CurrencyConfigV1, EnvironmentalImpactV1,
+    EventReviewV1, IdentityCredentialV1, InsurancePolicyV1, InsurancePoolV1, OrganizerReputationV1,
+    UpgradeProposalV1,
+    MigrationProgress, MigrationStage, GovernanceAction, AdminCouncil, PendingAdmin,
     UpgradeVote, VenueSpaceAllocation, SubscriptionPlan, SubscriptionStatus, SecurityIncident,
     UserPreferences, CertificationStandard, EventCertificate,
+    SponsorContribution, SponsorTier, AcceptedToken, TicketPayment,
+    OracleAsset, OracleFeed, OraclePriceData, PriceQuote, VipBenefitRedemption, VipPurchase,
+    AccessibilityBookingStatus, AccommodationType,
+    StreamAccessToken, VirtualSession, VirtualTicketClass, ReplayConfig, ReplayEntitlement,
+    QosAggregate, QosReport, StreamingSla, InsuranceProduct, InsuranceLpPosition,
+    InsurancePoolParams, EventCancellation, InsuranceSettlement, EventReputation,
 };
@@ -2,34 +2,58 @@
 
 use crate::error::LumentixError;
 use crate::events::{
-    AccessibilityBooked, AccessibilityInventoryUpdated, AdminChanged, AnonymousSurveySubmitted,
+    AcceptedTokenAdded, AcceptedTokenRemoved, AccessibilityBooked, AccessibilityBookingReleased,
+    AccessibilityBookingReviewed,
+    AccessibilityInventoryUpdated, AccommodationTypeConfigured, AdminChanged,
+    AdminActionApproved, AdminCouncilUpdated, AdminProposalCancelled, AdminProposed,
+    FeaturePauseUpdated,
+    GlobalPauseUpdated,
+    AnonymousSurveySubmitted,
     AttendanceMemorabiliaMinted,
     AttendanceVerificationFailed,
     AttendanceVerified, BatchTicketsPurchased, BatchTicketsTransferred, BatchTicketsUsed,
     BlockchainIdentityVerified, BridgeTransactionValidated, CarbonFootprintCalculated,
-    CarbonOffsetPurchased, CheckinProofValidated, CollectibleInventoryUpdated,
+    CarbonOffsetPurchased, CarbonProjectConfigured, CarbonRetirementAttested,
+    CheckoutOffsetConfigured, CheckoutOffsetContributed, CheckoutOffsetSettled,
+    ActualFootprintRecorded, CredentialIssuerUpdated, EmissionFactorsUpdated,
+    EventCredentialRequirementUpdated,
+    CheckinProofValidated, CollectibleInventoryUpdated,
     CrossChainTransferCompleted,
-    CrossChainTransferInitiated, EnvironmentalImpactUpdated, EscrowReleased, EventCancelled,
+    CrossChainTransferInitiated, EnvironmentalImpactUpdated, EscrowReleaseApproval, EscrowReleased,
+    EscrowSignersSet, EventCancelled,
     EventCapacityChanged, EventCompleted, EventCreated, EventCurrencySet, EventMetadataUpdated,
     EventSalesPaused, EventSalesResumed, EventStatusChanged, EventTimeExtended, EventUpdated,
     FundsDeposited, FundsWithdrawn, GenericEventStateTransition, IdentityCredentialIssued,
-    IdentityCredentialRevoked, InsuranceClaimProcessed, InsurancePoolUpdated, InsurancePurchased,
+    IdentityCredentialRevoked, InsuranceClaimProcessed, InsurancePoolUpdated,
+    CancellationReasonAttested, InsuranceLiquidityDeposited, InsuranceLiquidityWithdrawn,
+    InsuranceProductConfigured,
+    InsurancePurchased, InsuranceWithdrawalRequested,
     MemorabiliaClaimed, MerchandiseCreated, MerchandiseLinkedToTicket, MerchandisePreordered,
     MerchandisePurchased, NftMinted, NftTraded,
-    OraclePriceUpdated,
-    PlatformFeeRecipientUpdated, PlatformFeeUpdated, PlatformFeesWithdrawn, PriceCeilingSet,
+    CurrencyPriceGuardsUpdated, OracleFeedConfigured, OraclePriceUpdated,
+    PlatformFeeRecipientUpdated, PlatformFeeRefundClaimed, PlatformFeeUpdated,
+    PlatformFeesWithdrawn, PriceCeilingSet,
+    PriceQuoteIssued,
     PromoCodeApplied, PromoCodeCreated,
-    ProtocolFeeQueried,
+    ProtocolFeeQueried, QosReportSubmitted, QosReporterUpdated, ReplayConfigured, ReplayPurchased,
+    ReplayRefunded, ReplayWithdrawn,
     ReferralLinkGenerated, ReferralPurchaseProcessed, ReferralRewardsCredited, ReputationUpdated,
+    ReviewModerated, ReviewResponded,
     ResaleComplianceEnforced, ResalePriceVerified, ReviewSubmitted, SeatHoldReleased, SeatSelected,
     ScheduleVoteCast, ScheduleVoteFinalized, ScheduleVoteInitialized,
-    SeatUpgradeBidPlaced, SeatUpgradeBidRefunded, SeatUpgradeBidResolved,
+    SeatUpgradeBidPlaced, SeatUpgradeBidRefunded, SeatUpgradeBidResolved, SlaRefundClaimed,
+    SponsorContributed, SponsorRefunded, SponsorTierRegistered, StreamAccessTokenIssued,
     SurveyResultsCompiled,
+    TokenEscrowReleased, TokenPlatformFeesWithdrawn,
     TicketDidLinked, TicketDidRevoked, TicketPurchased, TicketRefunded,
     TicketRevoked, TicketTransferred, TicketUsed, TransferBlackoutUpdated, TransferLockBypassed,
-    UpgradeExecuted,
-    UpgradeGovernanceConfigUpdated, UpgradeProposed, UpgradeVoteCast, VenueLayoutCreated,
-    VipTicketAssigned, VipTierCreated, WaitlistAvailabilityNotified, WaitlistJoined,
+    UpgradeExecuted, UpgradeFinalized,
+    GovernanceActionExecuted, GovernanceActionProposed, GovernanceControlEnabled,
+    StorageMigrated, UpgradeGovernanceConfigUpdated, UpgradeProposed, UpgradeRolledBack,
+    UpgradeVetoed,
+    UpgradeVoteCast, ValidatorAdded,
+    ValidatorRemoved, VenueLayoutCreated, VirtualTicketClassSet, VirtualTicketPurchased,
+    VipBenefitRedeemed, VipTicketAssigned, VipTierCreated, VipUpgradePurchased, WaitlistAvailabilityNotified, WaitlistJoined,
     WaitlistOfferExpired, WaitlistSpotReleased,
     VenueSpaceAllocated, SpaceUtilizationOptimized, VenueConflictManaged,
     SubscriptionPlanCreated, RecurringBillingProcessed, SubscriptionStatusValidated,
@@ -38,26 +62,48 @@ use crate::events::{
 
   EventCertificateIssued, CertificationStandardUpdated,
 };
+use crate::oracle::PriceOracleClient;
 use crate::storage;
 use crate::types::{
-    AccessibilityBooking, AccessibilityInventory, AnonymousSurveyResponse, BridgeTransaction,
+    AcceptedToken, AccessibilityBooking, AccessibilityBookingStatus, AccessibilityInventory,
+    AccommodationType, AdminCouncil, PendingAdmin, AnonymousSurveyResponse, BridgeTransaction,
     CancellationReason,
-    CarbonFootprint, CarbonOffsetPurchase, CollectibleInventory, CrossChainTransfer,
-    CrossChainTransferStatus, CurrencyConfig, EnvironmentalImpact, Event, EventMerchandise,
+    AttendeeCarbonOffset, CarbonFootprint, CarbonOffsetPurchase, CarbonProject,
+    CheckoutCarbonOffset, CollectibleInventory, CrossChainTransfer, EmissionFactors, EnergyMix,
+    FootprintProfile, SignedCredentialPayload, SustainabilityReport, TransportMode,
+    TrustedIssuer, VenueType, EventCredentialRequirement,
+    CrossChainTransferStatus, CurrencyConfig, EnvironmentalImpact, EscrowConfig, Event,
+    EventMerchandise,
     EventReview, EventStatus, IdentityCredential, IdentityProof, IdentityProvider, InsurancePolicy,
-    MemorabiliaClaim, MerchVoucher, NftCollectible, OrganizerReputation, PromoCode, RarityTier, ReferralLinkRecord,
-    ResalePriceCeiling, ScheduleVote, ScheduleVoteCastRecord, Seat, SeatUpgradeBid, SurveyResults, Ticket,
-    TicketDidAssociation, TicketTransferRecord, TransferBlackout, UpgradeGovernanceConfig,
-    UpgradeProposal,
-    UpgradeState, UpgradeVote, VenueLayout, VenueSection, VipTier, WaitlistOffer, PriceTier,
+    EventCancellation, EventReputation, InsuranceLpPosition, InsurancePoolParams, InsuranceProduct,
+    InsuranceSettlement,
+    MemorabiliaClaim, MerchVoucher, NftCollectible, OracleAsset, OracleFeed, OraclePriceData,
+    PriceQuote, OrganizerReputation, PromoCode, RarityTier, ReferralLinkRecord,
+    ResalePriceCeiling, ScheduleVote, ScheduleVoteCastRecord, Seat, SeatUpgradeBid,
+    SponsorContribution, SponsorTier, SurveyResults, Ticket,
+    TicketDidAssociation, TicketPayment, TicketTransferRecord, TransferBlackout,
+    UpgradeGovernanceConfig,
+    GovernanceAction, MigrationProgress, MigrationStage, UpgradeKind, UpgradeProposal,
+    UpgradeSafeguards,
+    UpgradeState, UpgradeVote, VenueLayout, VenueSection, VipBenefitRedemption, VipPurchase, VipTier, WaitlistOffer, PriceTier,
     PricingSchedule, MintGasUsage, StreamDeliveryConfig, StreamPerformanceMetrics,
+    ADMIN_ACTION_APPROVAL_WINDOW_SECS, ADMIN_HANDOVER_WINDOW_SECS, CURRENCY_PRICE_DECIMALS,
+    FEATURE_ALL, FEATURE_ESCROW_RELEASES,
+    FEATURE_MERCHANDISE, MAX_QUOTE_LOCK_SECS,
+    FEATURE_NFT_MINTING, FEATURE_PURCHASES, FEATURE_REFUNDS, FEATURE_RESALE, FEATURE_TRANSFERS,
     PERSISTENT_LIFETIME,
     VenueSpaceAllocation, SubscriptionPlan,
-    SubscriptionStatus, SecurityIncident, UserPreferences,
+    SubscriptionStatus, SecurityIncident, UserPreferences, StreamAccessToken, VirtualSession,
+    VirtualTicketClass, ReplayConfig, ReplayEntitlement, QosAggregate, QosReport, StreamingSla,
+    QOS_BUCKET_SECS, STREAM_TOKEN_LIFETIME_SECS,
     CertificationStandard,
 };
 use crate::validation;
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Map, String, Vec};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, Val,
+    Vec,
+};
 
 #[contract]
 pub struct LumentixContract;
@@ -82,6 +128,8 @@ impl LumentixContract {
 
         storage::set_admin(&env, &admin);
         storage::set_initialized(&env);
+        // A fresh contract has no records in older layouts
+        storage::set_storage_version(&env, crate::types::STORAGE_SCHEMA_VERSION);
 
         Ok(())
     }
@@ -313,6 +361,15 @@ impl LumentixContract {
         event.status = new_status.clone();
         storage::set_event(&env, event_id, &event);
 
+        if new_status == EventStatus::Cancelled {
+            Self::record_cancellation(
+                &env,
+                event_id,
+                &caller,
+                CancellationReason::EventCancelledByOrganizer,
+            );
+        }
+
         // Emit EventStatusChanged event
         EventStatusChanged::emit(
             &env,
@@ -404,14 +461,55 @@ impl LumentixContract {
     /// Purchase a ticket for a published event.
     /// Checks capacity: rejects with EventSoldOut when tickets_sold >= max_tickets.
     /// Increments tickets_sold on success.
+    /// With `offset_opt_in` the buyer also pays the event's checkout carbon
+    /// surcharge on top of `amount`; fails with CheckoutOffsetNotEnabled
+    /// when the organizer has not enabled it.
+    /// With `quote_id` the ticket is paid in the quoted token at the locked
+    /// amount; quotes are single-use and rejected once expired.
     pub fn purchase_ticket(
         env: Env,
         buyer: Address,
         event_id: u64,
         amount: i128,
+        offset_opt_in: bool,
+        quote_id: Option<u64>,
     ) -> Result<u64, LumentixError> {
         buyer.require_auth();
+        let (payment_token, locked_price) = match quote_id {
+            Some(quote_id) => {
+                let (accepted, locked) =
+                    Self::redeem_price_quote(&env, &buyer, event_id, quote_id)?;
+                (Some(accepted), Some(locked))
+            }
+            None => (None, None),
+        };
+        let ticket_id = Self::purchase_ticket_internal(
+            env.clone(),
+            buyer.clone(),
+            event_id,
+            amount,
+            payment_token,
+            locked_price,
+        )?;
+        if offset_opt_in {
+            Self::collect_checkout_offset(&env, &buyer, event_id, ticket_id)?;
+        }
+        Ok(ticket_id)
+    }
 
+    /// Shared purchase flow. `payment_token` is set when paying in one of the
+    /// event's accepted tokens instead of the global payment token, and
+    /// `locked_price` when a price quote replaces the live price.
+    fn purchase_ticket_internal(
+        env: Env,
+        buyer: Address,
+        event_id: u64,
+        amount: i128,
+        payment_token: Option<AcceptedToken>,
+        locked_price: Option<i128>,
+    ) -> Result<u64, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
         let mut event = storage::get_event(&env, event_id)?;
 
         // Event must be published
@@ -424,6 +522,8 @@ impl LumentixContract {
             return Err(LumentixError::EventPaused);
         }
 
+        Self::require_event_credential(&env, event_id, &buyer)?;
+
         let now = env.ledger().timestamp();
         Self::cleanup_expired_waitlist_offers(&env, event_id, now);
         let reserved_for_waitlist = storage::get_waitlist_reserved(&env, event_id);
@@ -442,15 +542,28 @@ impl LumentixContract {
             return Err(LumentixError::EventSoldOut);
         }
 
-        // Validate payment amount against time-based dynamic pricing
-        let required_price =
-            Self::calculate_dynamic_price(env.clone(), event_id, 0, 0)?;
+        // Validate payment amount against time-based dynamic pricing,
+        // converted into the payment token's currency when needed
+        let required_price = match (locked_price, &payment_token) {
+            (Some(locked), _) => locked,
+            (None, Some(accepted)) => Self::convert_price(
+                env.clone(),
+                event.currency.clone(),
+                accepted.currency.clone(),
+                Self::calculate_dynamic_price(env.clone(), event_id, 0, 0)?,
+            )?,
+            (None, None) => Self::calculate_dynamic_price(env.clone(), event_id, 0, 0)?,
+        };
         if amount < required_price {
             return Err(LumentixError::InsufficientFunds);
         }
 
         // Process token transfer if token is set
-        if let Ok(token_address) = storage::get_token_result(&env) {
+        let token_address = match &payment_token {
+            Some(accepted) => Ok(accepted.token.clone()),
+            None => storage::get_token_result(&env),
+        };
+        if let Ok(token_address) = token_address {
             let token_client = soroban_sdk::token::Client::new(&env, &token_address);
             token_client.transfer(&buyer, &env.current_contract_address(), &amount);
         }
@@ -460,14 +573,19 @@ impl LumentixContract {
         let platform_fee = (amount * fee_bps as i128) / 10000;
         let escrow_amount = amount - platform_fee;
 
-        // Collect platform fee
-        if platform_fee > 0 {
-            storage::add_platform_balance(&env, platform_fee);
+        // Collect platform fee and add to escrow, settled in the token paid
+        if let Some(accepted) = &payment_token {
+            if platform_fee > 0 {
+                storage::add_token_platform_balance(&env, &accepted.token, platform_fee);
+            }
+            storage::add_token_escrow(&env, event_id, &accepted.token, escrow_amount);
+        } else {
+            if platform_fee > 0 {
+                storage::add_platform_balance(&env, platform_fee);
+            }
+            storage::add_escrow(&env, event_id, escrow_amount);
         }
 
-        // Add to escrow
-        storage::add_escrow(&env, event_id, escrow_amount);
-
         // Increment tickets_sold counter
         event.tickets_sold += 1;
         storage::set_event(&env, event_id, &event);
@@ -491,6 +609,20 @@ impl LumentixContract {
 
         storage::set_ticket(&env, ticket_id, &ticket);
 
+        if let Some(accepted) = payment_token {
+            storage::set_ticket_payment(
+                &env,
+                ticket_id,
+                &TicketPayment {
+                    token: accepted.token,
+                    amount,
+                    platform_fee,
+                },
+            );
+        } else {
+            storage::set_ticket_price_paid(&env, ticket_id, amount);
+        }
+
         if consume_waitlist_offer {
             if let Some(mut offer) = storage::get_waitlist_offer(&env, event_id, &buyer) {
                 if offer.quantity > 0 {
@@ -522,13 +654,17 @@ impl LumentixContract {
 
     /// Purchase multiple tickets in a single transaction for a published event.
     /// More efficient than calling purchase_ticket multiple times for groups.
-    /// Batch size is capped at 10 tickets per transaction.
+    /// Batch size is capped at 10 tickets per transaction. `offset_opt_in`
+    /// adds the checkout carbon surcharge for every ticket in the batch.
     pub fn batch_purchase_tickets(
         env: Env,
         event_id: u64,
         quantity: u32,
         buyer: Address,
+        offset_opt_in: bool,
     ) -> Result<Vec<u64>, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
         buyer.require_auth();
 
         // Validate quantity is positive and within batch limit
@@ -551,6 +687,8 @@ impl LumentixContract {
             return Err(LumentixError::EventPaused);
         }
 
+        Self::require_event_credential(&env, event_id, &buyer)?;
+
         let now = env.ledger().timestamp();
         Self::cleanup_expired_waitlist_offers(&env, event_id, now);
         let reserved_for_waitlist = storage::get_waitlist_reserved(&env, event_id);
@@ -614,6 +752,9 @@ impl LumentixContract {
 
             storage::set_ticket(&env, ticket_id, &ticket);
             ticket_ids.push_back(ticket_id);
+            if offset_opt_in {
+                Self::collect_checkout_offset(&env, &buyer, event_id, ticket_id)?;
+            }
         }
 
         // Emit BatchTicketsPurchased event for indexer efficiency
@@ -640,12 +781,13 @@ impl LumentixContract {
         event_id: u64,
         quantity: u32,
         buyer: Address,
+        offset_opt_in: bool,
     ) -> Result<Vec<u64>, LumentixError> {
         let optimal = Self::optimize_mint_gas(env.clone(), event_id, quantity)?;
         if quantity > optimal {
             return Err(LumentixError::BatchMintLimitExceeded);
         }
-        Self::batch_purchase_tickets(env, event_id, quantity, buyer)
+        Self::batch_purchase_tickets(env, event_id, quantity, buyer, offset_opt_in)
     }
 
     /// Return the maximum recommended batch size to minimize per-ticket resource cost.
@@ -722,7 +864,7 @@ impl LumentixContract {
     }
 
     /// Mark a ticket as used (check-in at event).
-    /// Only the event organizer can use tickets.
+    /// Only the event organizer or one of its authorized gate validators can use tickets.
     pub fn use_ticket(env: Env, ticket_id: u64, caller: Address) -> Result<(), LumentixError> {
         caller.require_auth();
 
@@ -736,14 +878,16 @@ impl LumentixContract {
             return Err(LumentixError::TicketAlreadyUsed);
         }
 
-        // Only organizer can validate tickets
+        // Only organizer or gate validators can validate tickets
         let event = storage::get_event(&env, ticket.event_id)?;
-        if event.organizer != caller {
+        if !Self::is_gate_validator(&env, &event, &caller) {
             return Err(LumentixError::Unauthorized);
         }
+        Self::require_check_in_credential(&env, ticket_id, &ticket)?;
 
         ticket.used = true;
         storage::set_ticket(&env, ticket_id, &ticket);
+        storage::increment_check_in_count(&env, ticket.event_id);
 
         // Emit TicketUsed event
         TicketUsed::emit(&env, ticket_id, ticket.event_id, ticket.owner, caller);
@@ -754,11 +898,7 @@ impl LumentixContract {
     /// Administratively revoke a ticket. Only the contract admin may call this.
     /// The ticket must exist, not already be revoked, used, or refunded.
     pub fn revoke_ticket(env: Env, admin: Address, ticket_id: u64) -> Result<(), LumentixError> {
-        admin.require_auth();
-        let stored_admin = storage::get_admin(&env);
-        if stored_admin != admin {
-            return Err(LumentixError::Unauthorized);
-        }
+        Self::require_admin(&env, &admin, "revoke_ticket", vec![&env, ticket_id.into_val(&env)])?;
         let mut ticket = storage::get_ticket(&env, ticket_id)?;
         if ticket.revoked {
             return Err(LumentixError::RevokedTicket);
@@ -769,6 +909,19 @@ impl LumentixContract {
         if ticket.refunded {
             return Err(LumentixError::RefundNotAllowed);
         }
+
+        let mut event = storage::get_event(&env, ticket.event_id)?;
+        Self::release_ticket_accessibility(&env, ticket_id, &mut ticket, &mut event)?;
+        storage::set_event(&env, ticket.event_id, &event);
+        Self::release_vip_on_refund(&env, &ticket, &ticket.owner)?;
+        Self::move_virtual_streaming_access(
+            &env,
+            ticket_id,
+            ticket.event_id,
+            Some(&ticket.owner),
+            None,
+        );
+
         ticket.revoked = true;
         storage::set_ticket(&env, ticket_id, &ticket);
         TicketRevoked::emit(&env, admin, ticket_id, ticket.event_id, None);
@@ -776,7 +929,7 @@ impl LumentixContract {
     }
 
     /// Mark multiple tickets as used in a single transaction.
-    /// Only the event organizer can use tickets. All tickets must belong to the same organizer's event.
+    /// Only the event organizer or an authorized gate validator can use tickets for each event.
     pub fn batch_use_tickets(
         env: Env,
         ticket_ids: Vec<u64>,
@@ -797,14 +950,16 @@ impl LumentixContract {
                 return Err(LumentixError::TicketAlreadyUsed);
             }
 
-            // Only organizer can validate tickets
+            // Only organizer or gate validators can validate tickets
             let event = storage::get_event(&env, ticket.event_id)?;
-            if event.organizer != caller {
+            if !Self::is_gate_validator(&env, &event, &caller) {
                 return Err(LumentixError::Unauthorized);
             }
+            Self::require_check_in_credential(&env, ticket_id, &ticket)?;
 
             ticket.used = true;
             storage::set_ticket(&env, ticket_id, &ticket);
+            storage::increment_check_in_count(&env, ticket.event_id);
 
             let eid = ticket.event_id;
             let mut ids = by_event.get(eid).unwrap_or_else(|| Vec::new(&env));
@@ -830,10 +985,13 @@ impl LumentixContract {
         from: Address,
         to: Address,
     ) -> Result<(), LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_TRANSFERS)?;
         from.require_auth();
 
         let mut ticket = storage::get_ticket(&env, ticket_id)?;
         Self::validate_ticket_transfer(&env, &ticket, &from, true)?;
+        Self::require_event_credential(&env, ticket.event_id, &to)?;
         Self::persist_ticket_transfer(&env, ticket_id, &mut ticket, from, to);
         Ok(())
     }
@@ -895,6 +1053,8 @@ impl LumentixContract {
         from: Address,
         to: Address,
     ) -> Result<(), LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_TRANSFERS)?;
         operator.require_auth();
 
         let mut ticket = storage::get_ticket(&env, ticket_id)?;
@@ -905,6 +1065,7 @@ impl LumentixContract {
         }
 
         Self::validate_ticket_transfer(&env, &ticket, &from, false)?;
+        Self::require_event_credential(&env, ticket.event_id, &to)?;
         let event_id = ticket.event_id;
         Self::persist_ticket_transfer(&env, ticket_id, &mut ticket, from.clone(), to.clone());
         TransferLockBypassed::emit(&env, event_id, ticket_id, operator, from, to);
@@ -961,6 +1122,8 @@ impl LumentixContract {
         event_id: u64,
         link_code: String,
     ) -> Result<(i128, i128), LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
         buyer.require_auth();
         validation::validate_string_not_empty(&link_code)?;
 
@@ -1028,6 +1191,7 @@ impl LumentixContract {
     /// Decrements tickets_sold to free up capacity.
     /// The ticket must not be used or already refunded.
     pub fn refund_ticket(env: Env, ticket_id: u64, buyer: Address) -> Result<(), LumentixError> {
+        Self::require_feature_active(&env, FEATURE_REFUNDS)?;
         buyer.require_auth();
 
         let mut ticket = storage::get_ticket(&env, ticket_id)?;
@@ -1046,8 +1210,8 @@ impl LumentixContract {
             return Err(LumentixError::TicketAlreadyUsed);
         }
 
-        // Cannot refund already refunded tickets
-        if ticket.refunded {
+        // Cannot refund already refunded tickets or complimentary sponsor tickets
+        if ticket.refunded || storage::is_comp_ticket(&env, ticket_id) {
             return Err(LumentixError::RefundNotAllowed);
         }
 
@@ -1058,24 +1222,67 @@ impl LumentixContract {
             return Err(LumentixError::EventNotCancelled);
         }
 
-        // Deduct from escrow
-        let fee_bps = storage::get_platform_fee_bps(&env);
-        let platform_fee = (event.ticket_price * fee_bps as i128) / 10000;
-        let escrow_amount = event.ticket_price - platform_fee;
-        storage::deduct_escrow(&env, ticket.event_id, escrow_amount)?;
+        let virtual_price = storage::get_virtual_ticket_price(&env, ticket_id);
 
-        // Transfer tokens back to buyer
-        if let Ok(token_address) = storage::get_token_result(&env) {
-            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
-            token_client.transfer(&env.current_contract_address(), &buyer, &event.ticket_price);
-        }
+        // Tickets paid in an accepted token are refunded in that token,
+        // including the platform fee collected on them. A fee that was
+        // already withdrawn is owed to the buyer as platform fee debt.
+        let refund_amount = if let Some(payment) = storage::get_ticket_payment(&env, ticket_id) {
+            let escrowed = payment.amount - payment.platform_fee;
+            storage::deduct_token_escrow(&env, ticket.event_id, &payment.token, escrowed)?;
+            let token = Some(payment.token.clone());
+            let refunded =
+                escrowed + storage::return_platform_fee(&env, &token, &buyer, payment.platform_fee);
+
+            let token_client = soroban_sdk::token::Client::new(&env, &payment.token);
+            token_client.transfer(&env.current_contract_address(), &buyer, &refunded);
+            refunded
+        } else {
+            // Virtual tickets are refunded at the virtual class price, less
+            // any SLA refund already paid out of escrow
+            let full_price = virtual_price.unwrap_or(event.ticket_price);
+            let price = full_price - storage::get_sla_refund(&env, ticket_id).unwrap_or(0);
+
+            // Deduct from escrow
+            let fee_bps = storage::get_platform_fee_bps(&env);
+            let platform_fee = (full_price * fee_bps as i128) / 10000;
+            let escrow_amount = price - platform_fee;
+            storage::deduct_escrow(&env, ticket.event_id, escrow_amount)?;
+
+            // Transfer tokens back to buyer
+            if let Ok(token_address) = storage::get_token_result(&env) {
+                let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+                token_client.transfer(&env.current_contract_address(), &buyer, &price);
+            }
+            price
+        };
+
+        // Release the VIP slot and return any VIP payment
+        let refund_amount = refund_amount + Self::release_vip_on_refund(&env, &ticket, &buyer)?;
+        let refund_amount = refund_amount + Self::refund_checkout_offset(&env, ticket_id, &buyer)?;
+
+        // Return any accessibility slot (and companion ticket) to the event
+        Self::release_ticket_accessibility(&env, ticket_id, &mut ticket, &mut event)?;
 
         // Mark ticket as refunded
         ticket.refunded = true;
         storage::set_ticket(&env, ticket_id, &ticket);
 
-        // Decrement tickets_sold to free up capacity
-        event.tickets_sold = event.tickets_sold.saturating_sub(1);
+        // Free up capacity: virtual tickets count against their own class
+        if virtual_price.is_some() {
+            let mut class = storage::get_virtual_ticket_class(&env, ticket.event_id)?;
+            class.sold = class.sold.saturating_sub(1);
+            storage::set_virtual_ticket_class(&env, ticket.event_id, &class);
+            Self::move_virtual_streaming_access(
+                &env,
+                ticket_id,
+                ticket.event_id,
+                Some(&buyer),
+                None,
+            );
+        } else {
+            event.tickets_sold = event.tickets_sold.saturating_sub(1);
+        }
         storage::set_event(&env, ticket.event_id, &event);
 
         if event.status == EventStatus::Cancelled {
@@ -1085,13 +1292,20 @@ impl LumentixContract {
         }
 
         // Emit TicketRefunded event
-        TicketRefunded::emit(&env, ticket_id, ticket.event_id, buyer, event.ticket_price);
+        TicketRefunded::emit(&env, ticket_id, ticket.event_id, buyer, refund_amount);
 
         Ok(())
     }
 
-    /// Cancel a published event. Only the organizer can cancel.
-    pub fn cancel_event(env: Env, organizer: Address, event_id: u64) -> Result<(), LumentixError> {
+    /// Cancel a published event, recording the organizer-declared reason.
+    /// Only the organizer can cancel. The recorded reason is what insurance
+    /// claims are settled against.
+    pub fn cancel_event(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        reason: CancellationReason,
+    ) -> Result<(), LumentixError> {
         organizer.require_auth();
 
         let mut event = storage::get_event(&env, event_id)?;
@@ -1107,6 +1321,7 @@ impl LumentixContract {
         let old_status = event.status.clone();
         event.status = EventStatus::Cancelled;
         storage::set_event(&env, event_id, &event);
+        Self::record_cancellation(&env, event_id, &organizer, reason);
         EventCancelled::emit(&env, event_id, organizer.clone(), event.tickets_sold);
 
         // Emit GenericEventStateTransition event for universal state transition tracking
@@ -1121,6 +1336,59 @@ impl LumentixContract {
         Ok(())
     }
 
+    /// Attest the cancellation reason of an event, replacing the organizer's
+    /// declaration if the admin disagrees. Admin only.
+    pub fn attest_cancellation_reason(
+        env: Env,
+        admin: Address,
+        event_id: u64,
+        reason: CancellationReason,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "attest_cancellation_reason",
+            vec![&env, event_id.into_val(&env), reason.into_val(&env)],
+        )?;
+
+        let mut cancellation = storage::get_event_cancellation(&env, event_id)
+            .ok_or(LumentixError::CancellationReasonNotRecorded)?;
+        cancellation.reason = reason.clone();
+        cancellation.attested = true;
+        storage::set_event_cancellation(&env, &cancellation);
+
+        CancellationReasonAttested::emit(&env, event_id, admin, reason);
+
+        Ok(())
+    }
+
+    /// Get the recorded cancellation of an event.
+    pub fn get_event_cancellation(
+        env: Env,
+        event_id: u64,
+    ) -> Result<EventCancellation, LumentixError> {
+        storage::get_event_cancellation(&env, event_id)
+            .ok_or(LumentixError::CancellationReasonNotRecorded)
+    }
+
+    fn record_cancellation(
+        env: &Env,
+        event_id: u64,
+        declared_by: &Address,
+        reason: CancellationReason,
+    ) {
+        storage::set_event_cancellation(
+            env,
+            &EventCancellation {
+                event_id,
+                reason,
+                declared_by: declared_by.clone(),
+                declared_at: env.ledger().timestamp(),
+                attested: false,
+            },
+        );
+    }
+
     /// Complete a published event after end_time. Only the organizer can complete.
     pub fn complete_event(
         env: Env,
@@ -1164,11 +1432,17 @@ impl LumentixContract {
     }
 
     /// Release escrow funds after event completion. Only the organizer can release.
+    /// Escrow held in accepted tokens is paid out in those tokens; the return value
+    /// is the amount released in the global payment token.
+    /// If multi-sig escrow signers are configured for the event, at least `threshold`
+    /// of them must have approved via `approve_release` first.
     pub fn release_escrow(
         env: Env,
         organizer: Address,
         event_id: u64,
     ) -> Result<i128, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_ESCROW_RELEASES)?;
         organizer.require_auth();
 
         let event = storage::get_event(&env, event_id)?;
@@ -1182,21 +1456,48 @@ impl LumentixContract {
         }
 
         let escrow_balance = storage::get_escrow(&env, event_id)?;
+        let escrow_tokens = storage::get_escrow_tokens(&env, event_id);
+        let has_token_escrow = escrow_tokens
+            .iter()
+            .any(|token| storage::get_token_escrow(&env, event_id, &token) > 0);
 
-        if escrow_balance == 0 {
+        if escrow_balance == 0 && !has_token_escrow {
             return Err(LumentixError::EscrowAlreadyReleased);
         }
 
-        storage::clear_escrow(&env, event_id);
+        if let Some(config) = storage::get_escrow_config(&env, event_id) {
+            if Self::count_escrow_approvals(&env, &config) < config.threshold {
+                return Err(LumentixError::EscrowThresholdNotMet);
+            }
+            // Approvals are single-use: clear them once the release goes through
+            for signer in config.signers.iter() {
+                storage::remove_escrow_approval(&env, event_id, &signer);
+            }
+        }
 
-        // Transfer tokens to organizer
-        if let Ok(token_address) = storage::get_token_result(&env) {
-            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
-            token_client.transfer(&env.current_contract_address(), &organizer, &escrow_balance);
+        if escrow_balance > 0 {
+            storage::clear_escrow(&env, event_id);
+
+            // Transfer tokens to organizer
+            if let Ok(token_address) = storage::get_token_result(&env) {
+                let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+                token_client.transfer(&env.current_contract_address(), &organizer, &escrow_balance);
+            }
+
+            // Emit EscrowReleased event
+            EscrowReleased::emit(&env, event_id, organizer.clone(), escrow_balance);
         }
 
-        // Emit EscrowReleased event
-        EscrowReleased::emit(&env, event_id, organizer, escrow_balance);
+        // Settle escrow held in accepted tokens, each in its own token
+        for token in escrow_tokens.iter() {
+            let token_balance = storage::get_token_escrow(&env, event_id, &token);
+            if token_balance > 0 {
+                storage::clear_token_escrow(&env, event_id, &token);
+                let token_client = soroban_sdk::token::Client::new(&env, &token);
+                token_client.transfer(&env.current_contract_address(), &organizer, &token_balance);
+                TokenEscrowReleased::emit(&env, event_id, organizer.clone(), token, token_balance);
+            }
+        }
 
         Ok(escrow_balance)
     }
@@ -1371,11 +1672,13 @@ impl LumentixContract {
         standard: CertificationStandard,
         enabled: bool,
     ) -> Result<(), LumentixError> {
-        admin.require_auth();
-        let stored_admin = storage::get_admin(&env);
-        if stored_admin != admin {
-            return Err(LumentixError::Unauthorized);
-        }
+        Self::require_admin(
+            &env,
+            &admin,
+            "manage_certification_standards",
+            vec![&env, standard.into_val(&env), enabled.into_val(&env)],
+        )?;
+        Self::require_admin_parameter_control(&env)?;
 
         storage::set_certification_standard_enabled(&env, &standard, enabled);
         CertificationStandardUpdated::emit(&env, standard, enabled);
@@ -1807,11 +2110,14 @@ impl LumentixContract {
         to: Address,
         from: Address,
     ) -> Result<(), LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_TRANSFERS)?;
         from.require_auth();
 
         for ticket_id in ticket_ids.iter() {
             let mut ticket = storage::get_ticket(&env, ticket_id)?;
             Self::validate_ticket_transfer(&env, &ticket, &from, true)?;
+            Self::require_event_credential(&env, ticket.event_id, &to)?;
             Self::persist_ticket_transfer(&env, ticket_id, &mut ticket, from.clone(), to.clone());
         }
 
@@ -1988,24 +2294,24 @@ impl LumentixContract {
     /// Set the platform fee in basis points (e.g., 250 = 2.5%).
     /// Only the admin can set the platform fee. Must be between 0 and 10000.
     pub fn set_platform_fee(env: Env, admin: Address, fee_bps: u32) -> Result<(), LumentixError> {
-        admin.require_auth();
+        Self::require_admin(&env, &admin, "set_platform_fee", vec![&env, fee_bps.into_val(&env)])?;
 
-        let stored_admin = storage::get_admin(&env);
-        if stored_admin != admin {
-            return Err(LumentixError::Unauthorized);
-        }
+        Self::require_admin_parameter_control(&env)?;
+        Self::apply_platform_fee(&env, admin, fee_bps)
+    }
 
+    fn apply_platform_fee(env: &Env, actor: Address, fee_bps: u32) -> Result<(), LumentixError> {
         if fee_bps > 10000 {
             return Err(LumentixError::InvalidPlatformFee);
         }
 
         // Read current fee before updating for event emission
-        let old_fee_bps = storage::get_platform_fee_bps(&env);
+        let old_fee_bps = storage::get_platform_fee_bps(env);
 
-        storage::set_platform_fee_bps(&env, fee_bps);
+        storage::set_platform_fee_bps(env, fee_bps);
 
         // Emit PlatformFeeUpdated event
-        PlatformFeeUpdated::emit(&env, admin, old_fee_bps, fee_bps);
+        PlatformFeeUpdated::emit(env, actor, old_fee_bps, fee_bps);
 
         Ok(())
     }
@@ -2135,6 +2441,8 @@ impl LumentixContract {
         event_id: u64,
         amount: i128,
     ) -> Result<i128, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_ESCROW_RELEASES)?;
         withdrawer.require_auth();
 
         if !storage::is_initialized(&env) {
@@ -2183,19 +2491,17 @@ impl LumentixContract {
 
     /// Withdraw all accumulated platform fees. Only the admin can withdraw.
     pub fn withdraw_platform_fees(env: Env, admin: Address) -> Result<i128, LumentixError> {
-        admin.require_auth();
-
-        let stored_admin = storage::get_admin(&env);
-        if stored_admin != admin {
-            return Err(LumentixError::Unauthorized);
-        }
+        Self::require_not_globally_paused(&env)?;
+        Self::require_admin(&env, &admin, "withdraw_platform_fees", Vec::new(&env))?;
 
-        let balance = storage::get_platform_balance(&env);
-        if balance == 0 {
+        // Fees owed back to refunded holders stay in the contract
+        let debt = storage::get_total_platform_fee_debt(&env, &None);
+        let balance = storage::get_platform_balance(&env) - debt;
+        if balance <= 0 {
             return Err(LumentixError::NoPlatformFees);
         }
 
-        storage::clear_platform_balance(&env);
+        storage::set_platform_balance(&env, debt);
 
         // Transfer tokens to admin
         if let Ok(token_address) = storage::get_token_result(&env) {
@@ -2209,18 +2515,52 @@ impl LumentixContract {
         Ok(balance)
     }
 
+    /// Claim platform fees owed back after a refund whose fee had already
+    /// been withdrawn. `token` is the accepted token the fee was paid in, or
+    /// `None` for the platform token. Pays what the platform holds now; the
+    /// rest stays owed until more fees are collected.
+    pub fn claim_platform_fee_refund(
+        env: Env,
+        holder: Address,
+        token: Option<Address>,
+    ) -> Result<i128, LumentixError> {
+        Self::require_feature_active(&env, FEATURE_REFUNDS)?;
+        holder.require_auth();
+
+        if storage::get_platform_fee_debt(&env, &token, &holder) == 0 {
+            return Err(LumentixError::RefundNotAllowed);
+        }
+        let settled = storage::settle_platform_fee_debt(&env, &token, &holder);
+        if settled == 0 {
+            return Err(LumentixError::InsufficientPlatformBalance);
+        }
+
+        let token_address = match &token {
+            Some(token) => token.clone(),
+            None => storage::get_token_result(&env)?,
+        };
+        let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &holder, &settled);
+
+        PlatformFeeRefundClaimed::emit(&env, holder, token, settled);
+
+        Ok(settled)
+    }
+
+    /// Platform fees still owed back to a holder in `token` (`None` for the
+    /// platform token).
+    pub fn get_platform_fee_debt(env: Env, holder: Address, token: Option<Address>) -> i128 {
+        storage::get_platform_fee_debt(&env, &token, &holder)
+    }
+
     /// Set the payment token address. Only the admin can call this.
     pub fn set_token(env: Env, admin: Address, token: Address) -> Result<(), LumentixError> {
-        admin.require_auth();
-
         if !storage::is_initialized(&env) {
             return Err(LumentixError::NotInitialized);
         }
 
-        let stored_admin = storage::get_admin(&env);
-        if stored_admin != admin {
-            return Err(LumentixError::Unauthorized);
-        }
+        Self::require_admin(&env, &admin, "set_token", vec![&env, token.into_val(&env)])?;
+        Self::require_admin_parameter_control(&env)?;
 
         storage::set_token(&env, &token);
 
@@ -2250,94 +2590,415 @@ impl LumentixContract {
         Ok(storage::get_admin(&env))
     }
 
-    /// Change the admin address. Only the current admin can call this.
-    /// Emits AdminChanged event with old and new admin addresses.
+    /// Propose a new admin, who also receives platform fees. Only the current
+    /// admin can call this, and with an admin council configured only after
+    /// the council signs off. Like `propose_admin`, nothing changes until the
+    /// candidate calls `accept_admin`. Returns the acceptance deadline.
     /// Fails with Unauthorized if caller is not the current admin.
     /// Fails with InvalidAddress if new_admin is the same as current admin.
     pub fn update_platform_fee_recipient(
         env: Env,
         admin: Address,
         new_admin: Address,
-    ) -> Result<(), LumentixError> {
-        admin.require_auth();
+    ) -> Result<u64, LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "update_platform_fee_recipient",
+            vec![&env, new_admin.into_val(&env)],
+        )?;
+        Self::require_admin_parameter_control(&env)?;
 
-        let current_admin = storage::get_admin(&env);
+        Self::apply_admin_proposal(&env, new_admin)
+    }
 
-        // Verify caller is the current admin
-        if current_admin != admin {
-            return Err(LumentixError::Unauthorized);
-        }
+    /// Check if the contract has been initialized.
+    /// Returns true if initialized, false otherwise.
+    /// No auth required - useful for frontends and deployment scripts.
+    pub fn get_is_initialized(env: Env) -> bool {
+        storage::is_initialized(&env)
+    }
+
+    /// Propose a new admin. The candidate must call `accept_admin` within
+    /// ADMIN_HANDOVER_WINDOW_SECS; a new proposal replaces any pending one.
+    /// Requires the admin, or the admin council threshold when one is set.
+    /// Returns the acceptance deadline.
+    pub fn propose_admin(
+        env: Env,
+        approvers: Vec<Address>,
+        new_admin: Address,
+    ) -> Result<u64, LumentixError> {
+        Self::require_admin_approval(&env, &approvers)?;
+        Self::apply_admin_proposal(&env, new_admin)
+    }
 
-        // Prevent changing to the same address
+    fn apply_admin_proposal(env: &Env, new_admin: Address) -> Result<u64, LumentixError> {
+        let current_admin = storage::get_admin(env);
         if current_admin == new_admin {
             return Err(LumentixError::InvalidAddress);
         }
 
-        let old_admin = current_admin;
+        let now = env.ledger().timestamp();
+        let expires_at = now + ADMIN_HANDOVER_WINDOW_SECS;
+        storage::set_pending_admin(
+            env,
+            &PendingAdmin {
+                candidate: new_admin.clone(),
+                proposed_at: now,
+                expires_at,
+            },
+        );
+
+        AdminProposed::emit(env, current_admin, new_admin, expires_at);
+
+        Ok(expires_at)
+    }
+
+    /// Accept a pending admin proposal. Must be signed by the candidate,
+    /// which proves the new key is live before the old one is dropped.
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), LumentixError> {
+        new_admin.require_auth();
+
+        let pending = storage::get_pending_admin(&env)
+            .filter(|pending| pending.candidate == new_admin)
+            .ok_or(LumentixError::AdminHandoverNotFound)?;
+        if env.ledger().timestamp() > pending.expires_at {
+            return Err(LumentixError::AdminHandoverExpired);
+        }
+
+        let old_admin = storage::get_admin(&env);
         storage::set_admin(&env, &new_admin);
+        storage::remove_pending_admin(&env);
+
+        AdminChanged::emit(&env, new_admin.clone(), old_admin.clone(), new_admin.clone());
+        // The admin also receives platform fees
+        PlatformFeeRecipientUpdated::emit(&env, new_admin.clone(), old_admin, new_admin);
+
+        Ok(())
+    }
 
-        // Emit AdminChanged event for backward compatibility
-        AdminChanged::emit(&env, admin.clone(), old_admin.clone(), new_admin.clone());
+    /// Withdraw the pending admin proposal.
+    pub fn cancel_admin_proposal(
+        env: Env,
+        approvers: Vec<Address>,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin_approval(&env, &approvers)?;
 
-        // Emit PlatformFeeRecipientUpdated for Dapp indexing solutions
-        PlatformFeeRecipientUpdated::emit(&env, admin, old_admin, new_admin);
+        let pending =
+            storage::get_pending_admin(&env).ok_or(LumentixError::AdminHandoverNotFound)?;
+        storage::remove_pending_admin(&env);
+
+        AdminProposalCancelled::emit(&env, pending.candidate);
 
         Ok(())
     }
 
-    /// Check if the contract has been initialized.
-    /// Returns true if initialized, false otherwise.
-    /// No auth required - useful for frontends and deployment scripts.
-    pub fn get_is_initialized(env: Env) -> bool {
-        storage::is_initialized(&env)
+    /// Get the pending admin proposal, if any.
+    pub fn get_pending_admin(env: Env) -> Option<PendingAdmin> {
+        storage::get_pending_admin(&env)
     }
 
-    /// Get total revenue for an organizer across all events.
-    /// Iterates through all event IDs from 1 to EVENT_CTR, calculates gross revenue, and sums it up.
-    /// Returns 0 if the organizer has no events or no sales. No auth required.
-    pub fn get_organizer_total_revenue(env: Env, organizer: Address) -> i128 {
-        let mut total_revenue: i128 = 0;
-        let next_event_id = storage::get_next_event_id(&env);
-        let mut event_id: u64 = 1;
+    /// Require `threshold` of `signers` to approve admin handovers and the
+    /// global pause instead of the admin key alone. While a council is set,
+    /// the admin's other entry points also need council sign-off through
+    /// `approve_admin_action`. Passing no signers clears the council.
+    /// Approved by the current admin or council.
+    pub fn configure_admin_council(
+        env: Env,
+        approvers: Vec<Address>,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin_approval(&env, &approvers)?;
 
-        while event_id < next_event_id {
-            if let Ok(event) = storage::get_event(&env, event_id) {
-                if event.organizer == organizer {
-                    total_revenue += event.tickets_sold as i128 * event.ticket_price;
+        if signers.is_empty() {
+            if threshold != 0 {
+                return Err(LumentixError::InvalidAdminCouncil);
+            }
+        } else {
+            if threshold == 0 || threshold > signers.len() {
+                return Err(LumentixError::InvalidAdminCouncil);
+            }
+            for (i, signer) in signers.iter().enumerate() {
+                if signers.first_index_of(&signer) != Some(i as u32) {
+                    return Err(LumentixError::InvalidAdminCouncil);
                 }
             }
-            event_id += 1;
         }
 
-        total_revenue
+        storage::set_admin_council(
+            &env,
+            &AdminCouncil {
+                signers: signers.clone(),
+                threshold,
+            },
+        );
+        AdminCouncilUpdated::emit(&env, signers, threshold);
+
+        Ok(())
     }
 
-    /// Get total tickets sold across all events on the platform.
-    /// Iterates through all events from 1 to EVENT_CTR and sums up the tickets_sold field.
-    /// No auth required.
-    pub fn get_total_tickets_sold(env: Env) -> u64 {
-        let mut total_tickets: u64 = 0;
-        let next_event_id = storage::get_next_event_id(&env);
-        let mut event_id: u64 = 1;
+    /// Get the admin council; no signers means the admin key acts alone.
+    pub fn get_admin_council(env: Env) -> AdminCouncil {
+        storage::get_admin_council(&env)
+    }
 
-        while event_id < next_event_id {
-            if let Ok(event) = storage::get_event(&env, event_id) {
-                total_tickets += event.tickets_sold as u64;
+    /// Emergency stop. While paused, purchases, ticket transfers and
+    /// withdrawals fail with ContractPaused; refunds keep working. Stays
+    /// available to the admin after governance control is enabled.
+    pub fn set_global_pause(
+        env: Env,
+        approvers: Vec<Address>,
+        paused: bool,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin_approval(&env, &approvers)?;
+
+        storage::set_globally_paused(&env, paused);
+        GlobalPauseUpdated::emit(&env, paused);
+
+        Ok(())
+    }
+
+    /// Whether the emergency global pause is active.
+    pub fn is_globally_paused(env: Env) -> bool {
+        storage::is_globally_paused(&env)
+    }
+
+    /// Check that `approvers` carry admin authority: the admin alone, or
+    /// at least `threshold` distinct council signers when a council is set.
+    fn require_admin_approval(env: &Env, approvers: &Vec<Address>) -> Result<(), LumentixError> {
+        let council = storage::get_admin_council(env);
+        if council.signers.is_empty() {
+            let admin = storage::get_admin(env);
+            if !approvers.contains(&admin) {
+                return Err(LumentixError::Unauthorized);
             }
-            event_id += 1;
+            admin.require_auth();
+            return Ok(());
         }
 
-        total_tickets
+        let mut approved: Vec<Address> = Vec::new(env);
+        for approver in approvers.iter() {
+            if !council.signers.contains(&approver) {
+                return Err(LumentixError::Unauthorized);
+            }
+            if approved.contains(&approver) {
+                continue;
+            }
+            approver.require_auth();
+            approved.push_back(approver);
+        }
+        if approved.len() < council.threshold {
+            return Err(LumentixError::InsufficientAdminApprovals);
+        }
+        Ok(())
     }
 
-    /// Get the addresses of all checked-in (used ticket) attendees for an event.
-    /// Verifies the event exists, then iterates all tickets collecting owners of
-    /// used tickets matching event_id. Deduplicates so each address appears once.
-    pub fn get_event_attendees(env: Env, event_id: u64) -> Result<Vec<Address>, LumentixError> {
-        // Verify event exists
-        let _ = storage::get_event(&env, event_id)?;
+    /// Sign off on an admin call while an admin council is configured.
+    /// `action` is the name of the admin entry point (e.g. `set_platform_fee`)
+    /// and `args` its arguments after the admin address, in order. The
+    /// sign-off only authorises a call with exactly those arguments, lapses
+    /// after ADMIN_ACTION_APPROVAL_WINDOW_SECS and is consumed by that call.
+    pub fn approve_admin_action(
+        env: Env,
+        signer: Address,
+        action: Symbol,
+        args: Vec<Val>,
+    ) -> Result<(), LumentixError> {
+        signer.require_auth();
+        if !storage::get_admin_council(&env).signers.contains(&signer) {
+            return Err(LumentixError::Unauthorized);
+        }
 
-        let mut attendees: Vec<Address> = Vec::new(&env);
+        let action_hash = Self::admin_action_hash(&env, &action, &args);
+        let expires_at = env.ledger().timestamp() + ADMIN_ACTION_APPROVAL_WINDOW_SECS;
+        storage::set_admin_action_approval(&env, &action_hash, &signer, expires_at);
+        AdminActionApproved::emit(&env, action, action_hash, signer, expires_at);
+
+        Ok(())
+    }
+
+    /// Number of council signers with a live sign-off on an admin call.
+    pub fn get_admin_action_approvals(env: Env, action: Symbol, args: Vec<Val>) -> u32 {
+        let council = storage::get_admin_council(&env);
+        let action_hash = Self::admin_action_hash(&env, &action, &args);
+        Self::count_admin_action_approvals(&env, &council.signers, &action_hash)
+    }
+
+    /// Gate for single-key admin entry points. Once a council is configured
+    /// the admin also needs a threshold of council sign-offs for `action`
+    /// called with `args`.
+    fn require_admin(
+        env: &Env,
+        admin: &Address,
+        action: &str,
+        args: Vec<Val>,
+    ) -> Result<(), LumentixError> {
+        admin.require_auth();
+        if storage::get_admin(env) != *admin {
+            return Err(LumentixError::Unauthorized);
+        }
+        Self::require_council_sign_off(env, action, args)
+    }
+
+    fn require_council_sign_off(
+        env: &Env,
+        action: &str,
+        args: Vec<Val>,
+    ) -> Result<(), LumentixError> {
+        let council = storage::get_admin_council(env);
+        if council.signers.is_empty() {
+            return Ok(());
+        }
+
+        let action_hash = Self::admin_action_hash(env, &Symbol::new(env, action), &args);
+        let approvals = Self::count_admin_action_approvals(env, &council.signers, &action_hash);
+        if approvals < council.threshold {
+            return Err(LumentixError::InsufficientAdminApprovals);
+        }
+
+        for signer in council.signers.iter() {
+            storage::remove_admin_action_approval(env, &action_hash, &signer);
+        }
+        Ok(())
+    }
+
+    /// Sign-offs are keyed by the entry point together with its arguments,
+    /// so approving one call never authorises a different one.
+    fn admin_action_hash(env: &Env, action: &Symbol, args: &Vec<Val>) -> BytesN<32> {
+        env.crypto()
+            .sha256(&(action.clone(), args.clone()).to_xdr(env))
+            .into()
+    }
+
+    fn count_admin_action_approvals(
+        env: &Env,
+        signers: &Vec<Address>,
+        action_hash: &BytesN<32>,
+    ) -> u32 {
+        let now = env.ledger().timestamp();
+        let mut approvals = 0u32;
+        for signer in signers.iter() {
+            let expires_at = storage::get_admin_action_approval(env, action_hash, &signer);
+            if expires_at.is_some_and(|expires_at| now <= expires_at) {
+                approvals += 1;
+            }
+        }
+        approvals
+    }
+
+    /// Pause or resume categories of activity platform-wide. `features` is a
+    /// mask of FEATURE_* bits; FEATURE_ALL trips the whole circuit breaker.
+    /// The admin may pause and resume; the upgrade guardian may only pause.
+    /// Returns the resulting bitmap of paused features.
+    pub fn set_features_paused(
+        env: Env,
+        caller: Address,
+        features: u32,
+        paused: bool,
+    ) -> Result<u32, LumentixError> {
+        caller.require_auth();
+
+        // The guardian may trip switches on its own; anything else is an
+        // admin action, subject to council sign-off when one is configured
+        let is_guardian = storage::get_upgrade_safeguards(&env).guardian == Some(caller.clone());
+        if !(paused && is_guardian) {
+            if storage::get_admin(&env) != caller {
+                return Err(LumentixError::Unauthorized);
+            }
+            Self::require_council_sign_off(
+                &env,
+                "set_features_paused",
+                vec![&env, features.into_val(&env), paused.into_val(&env)],
+            )?;
+        }
+        if features == 0 || features & !FEATURE_ALL != 0 {
+            return Err(LumentixError::InvalidFeatureFlags);
+        }
+
+        let current = storage::get_paused_features(&env);
+        let updated = if paused {
+            current | features
+        } else {
+            current & !features
+        };
+        storage::set_paused_features(&env, updated);
+
+        FeaturePauseUpdated::emit(&env, caller, features, paused, updated);
+
+        Ok(updated)
+    }
+
+    /// Get the bitmap of paused FEATURE_* flags.
+    pub fn get_paused_features(env: Env) -> u32 {
+        storage::get_paused_features(&env)
+    }
+
+    /// Whether any of the FEATURE_* bits in `features` is paused.
+    pub fn is_feature_paused(env: Env, features: u32) -> bool {
+        storage::get_paused_features(&env) & features != 0
+    }
+
+    fn require_feature_active(env: &Env, feature: u32) -> Result<(), LumentixError> {
+        if storage::get_paused_features(env) & feature != 0 {
+            return Err(LumentixError::FeaturePaused);
+        }
+        Ok(())
+    }
+
+    fn require_not_globally_paused(env: &Env) -> Result<(), LumentixError> {
+        if storage::is_globally_paused(env) {
+            return Err(LumentixError::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Get total revenue for an organizer across all events.
+    /// Iterates through all event IDs from 1 to EVENT_CTR, calculates gross revenue, and sums it up.
+    /// Returns 0 if the organizer has no events or no sales. No auth required.
+    pub fn get_organizer_total_revenue(env: Env, organizer: Address) -> i128 {
+        let mut total_revenue: i128 = 0;
+        let next_event_id = storage::get_next_event_id(&env);
+        let mut event_id: u64 = 1;
+
+        while event_id < next_event_id {
+            if let Ok(event) = storage::get_event(&env, event_id) {
+                if event.organizer == organizer {
+                    total_revenue += event.tickets_sold as i128 * event.ticket_price;
+                }
+            }
+            event_id += 1;
+        }
+
+        total_revenue
+    }
+
+    /// Get total tickets sold across all events on the platform.
+    /// Iterates through all events from 1 to EVENT_CTR and sums up the tickets_sold field.
+    /// No auth required.
+    pub fn get_total_tickets_sold(env: Env) -> u64 {
+        let mut total_tickets: u64 = 0;
+        let next_event_id = storage::get_next_event_id(&env);
+        let mut event_id: u64 = 1;
+
+        while event_id < next_event_id {
+            if let Ok(event) = storage::get_event(&env, event_id) {
+                total_tickets += event.tickets_sold as u64;
+            }
+            event_id += 1;
+        }
+
+        total_tickets
+    }
+
+    /// Get the addresses of all checked-in (used ticket) attendees for an event.
+    /// Verifies the event exists, then iterates all tickets collecting owners of
+    /// used tickets matching event_id. Deduplicates so each address appears once.
+    pub fn get_event_attendees(env: Env, event_id: u64) -> Result<Vec<Address>, LumentixError> {
+        // Verify event exists
+        let _ = storage::get_event(&env, event_id)?;
+
+        let mut attendees: Vec<Address> = Vec::new(&env);
         let next_ticket_id = storage::get_next_ticket_id(&env);
         let mut ticket_id: u64 = 1;
 
@@ -2391,13 +3052,7 @@ impl LumentixContract {
             max_tickets,
         )?;
 
-        let url_key = (soroban_sdk::symbol_short!("STRM_URL"), event_id);
-        env.storage().persistent().set(&url_key, &streaming_url);
-        env.storage().persistent().extend_ttl(
-            &url_key,
-            crate::types::PERSISTENT_LIFETIME,
-            crate::types::PERSISTENT_LIFETIME,
-        );
+        storage::set_stream_url(&env, event_id, &streaming_url);
 
         Ok(event_id)
     }
@@ -2416,1779 +3071,5127 @@ impl LumentixContract {
             return Err(LumentixError::Unauthorized);
         }
 
-        let access_key = (soroban_sdk::symbol_short!("STRM_ACC"), event_id, user);
-        env.storage().persistent().set(&access_key, &has_access);
-        env.storage().persistent().extend_ttl(
-            &access_key,
-            crate::types::PERSISTENT_LIFETIME,
-            crate::types::PERSISTENT_LIFETIME,
-        );
-        Ok(())
-    }
-
-    /// Track virtual attendance of a user for a hybrid event.
-    pub fn track_virtual_attendance(
-        env: Env,
-        event_id: u64,
-        user: Address,
-    ) -> Result<(), LumentixError> {
-        user.require_auth();
-
-        let access_key = (
-            soroban_sdk::symbol_short!("STRM_ACC"),
-            event_id,
-            user.clone(),
-        );
-        let has_access: bool = env.storage().persistent().get(&access_key).unwrap_or(false);
-        if !has_access {
-            return Err(LumentixError::Unauthorized);
-        }
-
-        let att_key = (soroban_sdk::symbol_short!("VIRT_ATT"), event_id, user);
-        env.storage().persistent().set(&att_key, &true);
-        env.storage().persistent().extend_ttl(
-            &att_key,
-            crate::types::PERSISTENT_LIFETIME,
-            crate::types::PERSISTENT_LIFETIME,
-        );
+        storage::set_streaming_access(&env, event_id, &user, has_access);
         Ok(())
     }
 
-    /// Tune adaptive streaming quality for virtual attendees on a hybrid event.
-    pub fn optimize_stream_quality(
+    /// Configure the virtual ticket class of a hybrid event. Virtual tickets
+    /// have their own price and capacity, separate from in-person tickets.
+    pub fn set_virtual_ticket_class(
         env: Env,
         organizer: Address,
         event_id: u64,
-        target_bitrate_kbps: u32,
-    ) -> Result<StreamDeliveryConfig, LumentixError> {
+        price: i128,
+        capacity: u32,
+    ) -> Result<(), LumentixError> {
         organizer.require_auth();
         let event = storage::get_event(&env, event_id)?;
         if event.organizer != organizer {
             return Err(LumentixError::Unauthorized);
         }
+        if !storage::is_hybrid_event(&env, event_id) {
+            return Err(LumentixError::NotHybridEvent);
+        }
+        if price < 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        validation::validate_positive_slots(capacity)?;
 
-        let mut config = storage::get_stream_delivery_config(&env, event_id).unwrap_or(
-            StreamDeliveryConfig {
-                cdn_endpoint: String::from_str(&env, ""),
-                stream_url: String::from_str(&env, ""),
-                quality_profile: String::from_str(&env, "auto"),
-                adaptive_bitrate: true,
-                target_bitrate_kbps: 2_500,
-            },
-        );
-
-        let profile = if target_bitrate_kbps >= 4_500 {
-            String::from_str(&env, "1080p")
-        } else if target_bitrate_kbps >= 2_500 {
-            String::from_str(&env, "720p")
-        } else {
-            String::from_str(&env, "480p")
+        let sold = match storage::get_virtual_ticket_class(&env, event_id) {
+            Ok(existing) => existing.sold,
+            Err(_) => 0,
         };
-
-        config.target_bitrate_kbps = target_bitrate_kbps;
-        config.quality_profile = profile;
-        config.adaptive_bitrate = true;
-        storage::set_stream_delivery_config(&env, event_id, &config);
-        Ok(config)
-    }
-
-    /// Configure CDN endpoints and playback URLs for hybrid content delivery.
-    pub fn manage_content_delivery(
-        env: Env,
-        organizer: Address,
-        event_id: u64,
-        cdn_endpoint: String,
-        stream_url: String,
-        quality_profile: String,
-    ) -> Result<StreamDeliveryConfig, LumentixError> {
-        organizer.require_auth();
-        let event = storage::get_event(&env, event_id)?;
-        if event.organizer != organizer {
-            return Err(LumentixError::Unauthorized);
+        if capacity < sold {
+            return Err(LumentixError::CapacityExceeded);
         }
 
-        validation::validate_string_not_empty(&cdn_endpoint)?;
-        validation::validate_string_not_empty(&stream_url)?;
-        validation::validate_string_not_empty(&quality_profile)?;
-
-        let config = StreamDeliveryConfig {
-            cdn_endpoint: cdn_endpoint.clone(),
-            stream_url: stream_url.clone(),
-            quality_profile,
-            adaptive_bitrate: true,
-            target_bitrate_kbps: 2_500,
+        let class = VirtualTicketClass {
+            event_id,
+            price,
+            capacity,
+            sold,
         };
-        storage::set_stream_delivery_config(&env, event_id, &config);
-
-        let url_key = (soroban_sdk::symbol_short!("STRM_URL"), event_id);
-        env.storage().persistent().set(&url_key, &stream_url);
-        env.storage().persistent().extend_ttl(
-            &url_key,
-            PERSISTENT_LIFETIME,
-            PERSISTENT_LIFETIME,
-        );
+        storage::set_virtual_ticket_class(&env, event_id, &class);
 
-        Ok(config)
+        VirtualTicketClassSet::emit(&env, event_id, price, capacity);
+        Ok(())
     }
 
-    /// Record and return streaming performance metrics for live dashboards.
-    pub fn monitor_streaming_performance(
+    /// Get the virtual ticket class of a hybrid event.
+    pub fn get_virtual_ticket_class(
         env: Env,
         event_id: u64,
-        avg_bitrate_kbps: u32,
-        rebuffer_ratio_bps: u32,
-        concurrent_viewers: u32,
-    ) -> Result<StreamPerformanceMetrics, LumentixError> {
-        let _ = storage::get_event(&env, event_id)?;
-
-        let quality_score = if rebuffer_ratio_bps <= 200 {
-            95u32
-        } else if rebuffer_ratio_bps <= 500 {
-            80u32
-        } else if rebuffer_ratio_bps <= 1_000 {
-            65u32
-        } else {
-            45u32
-        };
-
-        let metrics = StreamPerformanceMetrics {
-            event_id,
-            avg_bitrate_kbps,
-            rebuffer_ratio_bps,
-            concurrent_viewers,
-            quality_score,
-            last_measured_at: env.ledger().timestamp(),
-        };
-        storage::set_stream_performance_metrics(&env, event_id, &metrics);
-        Ok(metrics)
+    ) -> Result<VirtualTicketClass, LumentixError> {
+        storage::get_virtual_ticket_class(&env, event_id)
     }
-    // ═══════════════════════════════════════════════════════════════════════
-    // VIP TIER SYSTEM
-    // ═══════════════════════════════════════════════════════════════════════
 
-    /// Create a VIP tier for an event. Only the organizer can call this.
-    pub fn create_vip_tier(
+    /// Buy a virtual ticket at the class price. Streaming access is granted
+    /// to the buyer immediately and follows the ticket when it changes hands.
+    pub fn purchase_virtual_ticket(
         env: Env,
-        organizer: Address,
+        buyer: Address,
         event_id: u64,
-        tier_name: String,
-        price: i128,
-        max_slots: u32,
-        benefits: Vec<String>,
-    ) -> Result<(), LumentixError> {
-        organizer.require_auth();
+    ) -> Result<u64, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
+        buyer.require_auth();
 
         let event = storage::get_event(&env, event_id)?;
-        if event.organizer != organizer {
-            return Err(LumentixError::Unauthorized);
+        if event.status != EventStatus::Published {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        if event.paused {
+            return Err(LumentixError::EventPaused);
         }
 
-        validation::validate_string_not_empty(&tier_name)?;
-        validation::validate_positive_amount(price)?;
-        validation::validate_positive_slots(max_slots)?;
+        Self::require_event_credential(&env, event_id, &buyer)?;
 
-        if storage::has_vip_tier(&env, event_id, &tier_name) {
-            return Err(LumentixError::VipTierAlreadyExists);
+        let mut class = storage::get_virtual_ticket_class(&env, event_id)?;
+        if class.sold >= class.capacity {
+            return Err(LumentixError::VirtualTicketsSoldOut);
         }
 
-        let tier = VipTier {
-            name: tier_name.clone(),
-            price,
-            max_slots,
-            filled_slots: 0,
-            benefits,
-        };
+        if let Ok(token_address) = storage::get_token_result(&env) {
+            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+            token_client.transfer(&buyer, env.current_contract_address(), &class.price);
+        }
 
-        storage::set_vip_tier(&env, event_id, &tier_name, &tier);
+        let fee_bps = storage::get_platform_fee_bps(&env);
+        let platform_fee = (class.price * fee_bps as i128) / 10000;
+        if platform_fee > 0 {
+            storage::add_platform_balance(&env, platform_fee);
+        }
+        storage::add_escrow(&env, event_id, class.price - platform_fee);
 
-        VipTierCreated::emit(&env, event_id, tier_name, price, max_slots);
+        let ticket_id = storage::get_next_ticket_id(&env);
+        storage::increment_ticket_id(&env);
 
-        Ok(())
-    }
+        let ticket = Ticket {
+            id: ticket_id,
+            event_id,
+            owner: buyer.clone(),
+            purchase_time: env.ledger().timestamp(),
+            used: false,
+            refunded: false,
+            revoked: false,
+            vip_tier: None,
+            seat_id: None,
+            accessibility_type: None,
+        };
+        storage::set_ticket(&env, ticket_id, &ticket);
+        storage::set_virtual_ticket_price(&env, ticket_id, class.price);
 
-    /// Assign VIP benefits to a ticket. Validates the tier has available slots.
-    pub fn assign_vip_benefits(
-        env: Env,
-        organizer: Address,
-        event_id: u64,
-        ticket_id: u64,
-        tier_name: String,
-    ) -> Result<(), LumentixError> {
-        organizer.require_auth();
+        class.sold += 1;
+        storage::set_virtual_ticket_class(&env, event_id, &class);
 
-        let event = storage::get_event(&env, event_id)?;
-        if event.organizer != organizer {
-            return Err(LumentixError::Unauthorized);
-        }
+        Self::move_virtual_streaming_access(&env, ticket_id, event_id, None, Some(&buyer));
 
-        let mut tier = storage::get_vip_tier(&env, event_id, &tier_name)?;
+        VirtualTicketPurchased::emit(&env, ticket_id, event_id, buyer, class.price);
 
-        if tier.filled_slots >= tier.max_slots {
-            return Err(LumentixError::VipTierFull);
-        }
+        Ok(ticket_id)
+    }
 
-        let mut ticket = storage::get_ticket(&env, ticket_id)?;
-        if ticket.event_id != event_id {
-            return Err(LumentixError::Unauthorized);
+    /// Whether a ticket is a virtual (online-only) ticket.
+    pub fn is_virtual_ticket(env: Env, ticket_id: u64) -> bool {
+        storage::get_virtual_ticket_price(&env, ticket_id).is_some()
+    }
+
+    /// Streaming access comes from an organizer grant or from holding at
+    /// least one live virtual ticket for the event.
+    fn has_stream_entitlement(env: &Env, event_id: u64, user: &Address) -> bool {
+        storage::has_streaming_access(env, event_id, user)
+            || storage::get_virtual_ticket_holdings(env, event_id, user) > 0
+    }
+
+    /// Move a virtual ticket's streaming access between holders. `from` is
+    /// `None` when the ticket is issued and `to` is `None` when it stops
+    /// being valid. Does nothing for in-person tickets.
+    fn move_virtual_streaming_access(
+        env: &Env,
+        ticket_id: u64,
+        event_id: u64,
+        from: Option<&Address>,
+        to: Option<&Address>,
+    ) {
+        if storage::get_virtual_ticket_price(env, ticket_id).is_none() {
+            return;
         }
-        if ticket.revoked {
-            return Err(LumentixError::RevokedTicket);
+        if let Some(from) = from {
+            let held = storage::get_virtual_ticket_holdings(env, event_id, from);
+            storage::set_virtual_ticket_holdings(env, event_id, from, held.saturating_sub(1));
         }
-        if ticket.refunded {
-            return Err(LumentixError::RefundNotAllowed);
+        if let Some(to) = to {
+            let held = storage::get_virtual_ticket_holdings(env, event_id, to);
+            storage::set_virtual_ticket_holdings(env, event_id, to, held + 1);
         }
+    }
 
-        tier.filled_slots += 1;
-        storage::set_vip_tier(&env, event_id, &tier_name, &tier);
+    /// Open a virtual attendance session. Before `end_time` this is live
+    /// viewing and needs streaming access; afterwards it is replay viewing
+    /// and needs a replay entitlement within the replay window.
+    pub fn track_virtual_attendance(
+        env: Env,
+        event_id: u64,
+        user: Address,
+    ) -> Result<(), LumentixError> {
+        user.require_auth();
 
-        ticket.vip_tier = Some(tier_name.clone());
-        storage::set_ticket(&env, ticket_id, &ticket);
+        let event = storage::get_event(&env, event_id)?;
+        let now = env.ledger().timestamp();
+        let replay = now > event.end_time;
+        if replay {
+            Self::check_replay_available(&env, &event)?;
+            match storage::get_replay_entitlement(&env, event_id, &user) {
+                Some(entitlement) if !entitlement.refunded => {}
+                _ => return Err(LumentixError::ReplayEntitlementNotFound),
+            }
+        } else if !Self::has_stream_entitlement(&env, event_id, &user) {
+            return Err(LumentixError::Unauthorized);
+        }
 
-        VipTicketAssigned::emit(&env, ticket_id, event_id, tier_name, ticket.owner);
+        let mut sessions = storage::get_virtual_sessions(&env, event_id, &user);
+        if let Some(last) = sessions.last() {
+            if last.left_at == 0 {
+                return Err(LumentixError::VirtualSessionAlreadyOpen);
+            }
+        }
+        sessions.push_back(VirtualSession {
+            joined_at: now,
+            left_at: 0,
+            replay,
+        });
+        storage::set_virtual_sessions(&env, event_id, &user, &sessions);
 
+        storage::set_virtual_attended(&env, event_id, &user);
         Ok(())
     }
 
-    /// Validate that a ticket has VIP access for a given tier.
-    /// Used at check-in to verify VIP entitlements.
-    pub fn validate_vip_access(
+    /// Close the user's open virtual attendance session.
+    pub fn leave_virtual_session(
         env: Env,
-        ticket_id: u64,
-        tier_name: String,
-    ) -> Result<bool, LumentixError> {
-        let ticket = storage::get_ticket(&env, ticket_id)?;
+        event_id: u64,
+        user: Address,
+    ) -> Result<(), LumentixError> {
+        user.require_auth();
 
-        if ticket.revoked || ticket.refunded || ticket.used {
-            return Ok(false);
+        let mut sessions = storage::get_virtual_sessions(&env, event_id, &user);
+        let index = match sessions.len().checked_sub(1) {
+            Some(index) => index,
+            None => return Err(LumentixError::VirtualSessionNotOpen),
+        };
+        let mut session = sessions.get(index).unwrap();
+        if session.left_at != 0 {
+            return Err(LumentixError::VirtualSessionNotOpen);
         }
 
-        match &ticket.vip_tier {
-            Some(tier) => Ok(tier == &tier_name),
-            None => Ok(false),
-        }
+        session.left_at = env.ledger().timestamp();
+        sessions.set(index, session);
+        storage::set_virtual_sessions(&env, event_id, &user, &sessions);
+        Ok(())
     }
 
-    /// Get VIP tier details for an event.
-    pub fn get_vip_tier(
-        env: Env,
-        event_id: u64,
-        tier_name: String,
-    ) -> Result<VipTier, LumentixError> {
-        storage::get_vip_tier(&env, event_id, &tier_name)
+    /// Get all recorded virtual sessions of a user for an event.
+    pub fn get_virtual_sessions(env: Env, event_id: u64, user: Address) -> Vec<VirtualSession> {
+        storage::get_virtual_sessions(&env, event_id, &user)
     }
 
-    // ═══════════════════════════════════════════════════════════════════════
-    // ACCESSIBILITY FEATURES
-    // ═══════════════════════════════════════════════════════════════════════
-
-    /// Configure accessibility inventory for an event. Only organizer can call.
-    pub fn setup_accessibility_inventory(
+    /// Offer an on-demand replay of a hybrid event for `window_secs` after
+    /// `end_time`. Reconfiguring updates the price and window for future
+    /// buyers; a withdrawn replay cannot be re-offered.
+    pub fn configure_replay(
         env: Env,
         organizer: Address,
         event_id: u64,
-        wheelchair_total: u32,
-        hearing_total: u32,
-        visual_total: u32,
+        price: i128,
+        window_secs: u64,
     ) -> Result<(), LumentixError> {
         organizer.require_auth();
-
-        let mut event = storage::get_event(&env, event_id)?;
+        let event = storage::get_event(&env, event_id)?;
         if event.organizer != organizer {
             return Err(LumentixError::Unauthorized);
         }
+        if !storage::is_hybrid_event(&env, event_id) {
+            return Err(LumentixError::NotHybridEvent);
+        }
+        if price < 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+        if window_secs == 0 {
+            return Err(LumentixError::InvalidTimeRange);
+        }
+        if let Ok(existing) = storage::get_replay_config(&env, event_id) {
+            if existing.withdrawn {
+                return Err(LumentixError::ReplayUnavailable);
+            }
+        }
 
-        let inv = AccessibilityInventory {
-            wheelchair_available: wheelchair_total,
-            wheelchair_total,
-            hearing_available: hearing_total,
-            hearing_total,
-            visual_available: visual_total,
-            visual_total,
-        };
-
-        storage::set_accessibility_inventory(&env, event_id, &inv);
-
-        event.accessibility_wheelchair = wheelchair_total;
-        event.accessibility_hearing = hearing_total;
-        event.accessibility_visual = visual_total;
-        storage::set_event(&env, event_id, &event);
-
-        AccessibilityInventoryUpdated::emit(
-            &env,
+        let config = ReplayConfig {
             event_id,
-            wheelchair_total,
-            hearing_total,
-            visual_total,
-        );
+            price,
+            window_secs,
+            withdrawn: false,
+        };
+        storage::set_replay_config(&env, event_id, &config);
 
+        ReplayConfigured::emit(&env, event_id, price, window_secs);
         Ok(())
     }
 
-    /// Request an accessibility accommodation for a ticket.
-    pub fn request_accessibility_booking(
+    /// Get the replay configuration of an event.
+    pub fn get_replay_config(env: Env, event_id: u64) -> Result<ReplayConfig, LumentixError> {
+        storage::get_replay_config(&env, event_id)
+    }
+
+    /// Buy a replay entitlement. Revenue is split between the platform fee
+    /// and event escrow exactly like `purchase_ticket`.
+    pub fn purchase_replay(
         env: Env,
-        attendee: Address,
+        viewer: Address,
         event_id: u64,
-        ticket_id: u64,
-        accommodation_type: String,
-    ) -> Result<u64, LumentixError> {
-        attendee.require_auth();
+    ) -> Result<ReplayEntitlement, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
+        viewer.require_auth();
 
         let event = storage::get_event(&env, event_id)?;
-        if event.status != EventStatus::Published {
+        if event.status != EventStatus::Published && event.status != EventStatus::Completed {
             return Err(LumentixError::InvalidStatusTransition);
         }
+        let config = Self::check_replay_available(&env, &event)?;
 
-        let ticket = storage::get_ticket(&env, ticket_id)?;
-        if ticket.owner != attendee {
-            return Err(LumentixError::Unauthorized);
-        }
-        if ticket.event_id != event_id {
-            return Err(LumentixError::Unauthorized);
-        }
-
-        let mut inv = storage::get_accessibility_inventory(&env, event_id)?;
-
-        if accommodation_type == String::from_str(&env, "wheelchair") {
-            if inv.wheelchair_available == 0 {
-                return Err(LumentixError::AccommodationUnavailable);
-            }
-            inv.wheelchair_available -= 1;
-        } else if accommodation_type == String::from_str(&env, "hearing") {
-            if inv.hearing_available == 0 {
-                return Err(LumentixError::AccommodationUnavailable);
-            }
-            inv.hearing_available -= 1;
-        } else if accommodation_type == String::from_str(&env, "visual") {
-            if inv.visual_available == 0 {
-                return Err(LumentixError::AccommodationUnavailable);
+        if let Some(existing) = storage::get_replay_entitlement(&env, event_id, &viewer) {
+            if !existing.refunded {
+                return Err(LumentixError::ReplayAlreadyPurchased);
             }
-            inv.visual_available -= 1;
-        } else {
-            return Err(LumentixError::AccommodationUnavailable);
         }
 
-        storage::set_accessibility_inventory(&env, event_id, &inv);
+        if let Ok(token_address) = storage::get_token_result(&env) {
+            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+            token_client.transfer(&viewer, env.current_contract_address(), &config.price);
+        }
 
-        let booking_id = storage::get_next_accessibility_booking_id(&env);
-        storage::increment_accessibility_booking_id(&env);
+        let fee_bps = storage::get_platform_fee_bps(&env);
+        let platform_fee = (config.price * fee_bps as i128) / 10000;
+        if platform_fee > 0 {
+            storage::add_platform_balance(&env, platform_fee);
+        }
+        storage::add_escrow(&env, event_id, config.price - platform_fee);
 
-        let booking = AccessibilityBooking {
-            id: booking_id,
+        let entitlement = ReplayEntitlement {
             event_id,
-            ticket_id,
-            attendee: attendee.clone(),
-            accommodation_type: accommodation_type.clone(),
-            approved: true,
+            viewer: viewer.clone(),
+            amount_paid: config.price,
+            platform_fee,
+            purchased_at: env.ledger().timestamp(),
+            refunded: false,
         };
+        storage::set_replay_entitlement(&env, &entitlement);
 
-        storage::set_accessibility_booking(&env, booking_id, &booking);
+        ReplayPurchased::emit(&env, event_id, viewer, config.price, platform_fee);
 
-        AccessibilityBooked::emit(&env, booking_id, event_id, attendee, accommodation_type);
+        Ok(entitlement)
+    }
 
-        Ok(booking_id)
+    /// Get a viewer's replay entitlement for an event.
+    pub fn get_replay_entitlement(
+        env: Env,
+        event_id: u64,
+        viewer: Address,
+    ) -> Result<ReplayEntitlement, LumentixError> {
+        storage::get_replay_entitlement(&env, event_id, &viewer)
+            .ok_or(LumentixError::ReplayEntitlementNotFound)
     }
 
-    /// Manage (update) accessibility inventory for an event. Only organizer.
-    pub fn manage_accessibility_inventory(
+    /// Withdraw an event's replay. Existing entitlements become refundable.
+    pub fn withdraw_replay(
         env: Env,
         organizer: Address,
         event_id: u64,
-        wheelchair_available: u32,
-        hearing_available: u32,
-        visual_available: u32,
     ) -> Result<(), LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_ESCROW_RELEASES)?;
         organizer.require_auth();
-
         let event = storage::get_event(&env, event_id)?;
         if event.organizer != organizer {
             return Err(LumentixError::Unauthorized);
         }
 
-        let mut inv = storage::get_accessibility_inventory(&env, event_id)?;
+        let mut config = storage::get_replay_config(&env, event_id)?;
+        if config.withdrawn {
+            return Err(LumentixError::ReplayUnavailable);
+        }
+        config.withdrawn = true;
+        storage::set_replay_config(&env, event_id, &config);
 
-        validation::validate_accessibility_counts(wheelchair_available, inv.wheelchair_total)?;
-        validation::validate_accessibility_counts(hearing_available, inv.hearing_total)?;
-        validation::validate_accessibility_counts(visual_available, inv.visual_total)?;
+        ReplayWithdrawn::emit(&env, event_id);
+        Ok(())
+    }
 
-        inv.wheelchair_available = wheelchair_available;
-        inv.hearing_available = hearing_available;
-        inv.visual_available = visual_available;
+    /// Refund a replay entitlement in full, including the platform fee,
+    /// after the organizer has withdrawn the replay. A fee that was already
+    /// withdrawn is owed to the viewer as platform fee debt instead.
+    pub fn refund_replay(env: Env, viewer: Address, event_id: u64) -> Result<i128, LumentixError> {
+        Self::require_feature_active(&env, FEATURE_REFUNDS)?;
+        viewer.require_auth();
 
-        storage::set_accessibility_inventory(&env, event_id, &inv);
+        let config = storage::get_replay_config(&env, event_id)?;
+        if !config.withdrawn {
+            return Err(LumentixError::ReplayNotWithdrawn);
+        }
 
-        AccessibilityInventoryUpdated::emit(
-            &env,
-            event_id,
-            wheelchair_available,
-            hearing_available,
-            visual_available,
-        );
+        let mut entitlement = storage::get_replay_entitlement(&env, event_id, &viewer)
+            .ok_or(LumentixError::ReplayEntitlementNotFound)?;
+        if entitlement.refunded {
+            return Err(LumentixError::RefundNotAllowed);
+        }
 
-        Ok(())
+        let escrowed = entitlement.amount_paid - entitlement.platform_fee;
+        storage::deduct_escrow(&env, event_id, escrowed)?;
+        let refunded =
+            escrowed + storage::return_platform_fee(&env, &None, &viewer, entitlement.platform_fee);
+
+        if let Ok(token_address) = storage::get_token_result(&env) {
+            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+            token_client.transfer(&env.current_contract_address(), &viewer, &refunded);
+        }
+
+        entitlement.refunded = true;
+        storage::set_replay_entitlement(&env, &entitlement);
+
+        ReplayRefunded::emit(&env, event_id, viewer, refunded);
+
+        Ok(refunded)
     }
 
-    /// Validate that an attendee's accessibility needs can be met.
-    pub fn validate_accessibility_needs(
-        env: Env,
-        event_id: u64,
-        accommodation_type: String,
-    ) -> Result<bool, LumentixError> {
-        if let Ok(inv) = storage::get_accessibility_inventory(&env, event_id) {
-            let available = if accommodation_type == String::from_str(&env, "wheelchair") {
-                inv.wheelchair_available
-            } else if accommodation_type == String::from_str(&env, "hearing") {
-                inv.hearing_available
-            } else if accommodation_type == String::from_str(&env, "visual") {
-                inv.visual_available
-            } else {
-                return Err(LumentixError::AccommodationUnavailable);
-            };
-            Ok(available > 0)
-        } else {
-            Ok(false)
+    /// The event's replay config, if it is neither withdrawn nor past its window.
+    fn check_replay_available(env: &Env, event: &Event) -> Result<ReplayConfig, LumentixError> {
+        let config = storage::get_replay_config(env, event.id)?;
+        let closes_at = event.end_time.saturating_add(config.window_secs);
+        if config.withdrawn || env.ledger().timestamp() > closes_at {
+            return Err(LumentixError::ReplayUnavailable);
         }
+        Ok(config)
     }
 
-    /// Get the accessibility booking for a given booking ID.
-    pub fn get_accessibility_booking(
+    /// Open a time-limited access session for the event's configured CDN,
+    /// bound to an ed25519 `session_key` the holder keeps off-chain. Nothing
+    /// stored here grants access on its own: the CDN has the viewer sign a
+    /// challenge with the session key and checks it with
+    /// `verify_stream_access_token`.
+    pub fn issue_stream_access_token(
         env: Env,
-        booking_id: u64,
-    ) -> Result<AccessibilityBooking, LumentixError> {
-        storage::get_accessibility_booking(&env, booking_id)
-    }
-
-    // ═══════════════════════════════════════════════════════════════════════
-    // MULTI-CURRENCY SUPPORT
-    // ═══════════════════════════════════════════════════════════════════════
+        holder: Address,
+        ticket_id: u64,
+        session_key: BytesN<32>,
+    ) -> Result<StreamAccessToken, LumentixError> {
+        holder.require_auth();
 
-    /// Set a currency oracle price feed. Only the admin can register a currency.
-    pub fn set_currency_oracle(
-        env: Env,
-        admin: Address,
-        code: String,
-        decimals: u32,
-        oracle_price: i128,
-    ) -> Result<(), LumentixError> {
-        admin.require_auth();
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        if ticket.owner != holder {
+            return Err(LumentixError::Unauthorized);
+        }
+        if ticket.revoked {
+            return Err(LumentixError::RevokedTicket);
+        }
+        if ticket.refunded {
+            return Err(LumentixError::RefundNotAllowed);
+        }
 
-        let stored_admin = storage::get_admin(&env);
-        if stored_admin != admin {
+        let is_virtual = storage::get_virtual_ticket_price(&env, ticket_id).is_some();
+        if !is_virtual && !Self::has_stream_entitlement(&env, ticket.event_id, &holder) {
             return Err(LumentixError::Unauthorized);
         }
 
-        validation::validate_currency_code(&code)?;
+        let config = storage::get_stream_delivery_config(&env, ticket.event_id)
+            .ok_or(LumentixError::StreamDeliveryNotConfigured)?;
+        if config.cdn_endpoint.is_empty() {
+            return Err(LumentixError::StreamDeliveryNotConfigured);
+        }
 
-        let config = CurrencyConfig {
-            code: code.clone(),
-            decimals,
-            oracle_price,
-            last_updated: env.ledger().timestamp(),
+        let expires_at = env.ledger().timestamp() + STREAM_TOKEN_LIFETIME_SECS;
+
+        let access = StreamAccessToken {
+            session_key,
+            ticket_id,
+            event_id: ticket.event_id,
+            holder: holder.clone(),
+            cdn_endpoint: config.cdn_endpoint,
+            expires_at,
         };
+        storage::set_stream_access_token(&env, &access);
 
-        storage::set_currency_config(&env, &code, &config);
+        StreamAccessTokenIssued::emit(&env, ticket_id, ticket.event_id, holder, expires_at);
 
-        OraclePriceUpdated::emit(&env, code, oracle_price, env.ledger().timestamp());
+        Ok(access)
+    }
 
-        Ok(())
+    /// Check a viewer's answer to a CDN challenge. The ticket must have an
+    /// unexpired session, still be valid and owned by the holder that opened
+    /// it, and `signature` must be the session key's signature over the
+    /// ticket id (big-endian) followed by `challenge`. A bad signature fails
+    /// the call. CDNs should use a fresh challenge per request.
+    pub fn verify_stream_access_token(
+        env: Env,
+        ticket_id: u64,
+        challenge: Bytes,
+        signature: BytesN<64>,
+    ) -> bool {
+        let access = match storage::get_stream_access_token(&env, ticket_id) {
+            Some(access) => access,
+            None => return false,
+        };
+        if env.ledger().timestamp() >= access.expires_at {
+            return false;
+        }
+        let valid = match storage::get_ticket(&env, ticket_id) {
+            Ok(ticket) => ticket.owner == access.holder && !ticket.revoked && !ticket.refunded,
+            Err(_) => false,
+        };
+        if !valid {
+            return false;
+        }
+
+        let mut message = Bytes::from_array(&env, &ticket_id.to_be_bytes());
+        message.append(&challenge);
+        env.crypto()
+            .ed25519_verify(&access.session_key, &message, &signature);
+        true
     }
 
-    /// Set the currency for an event. Only the organizer can set it.
-    /// The currency must have been registered via set_currency_oracle.
-    pub fn set_event_currency(
+    /// Tune adaptive streaming quality for virtual attendees on a hybrid event.
+    pub fn optimize_stream_quality(
         env: Env,
         organizer: Address,
         event_id: u64,
-        currency: String,
-    ) -> Result<(), LumentixError> {
+        target_bitrate_kbps: u32,
+    ) -> Result<StreamDeliveryConfig, LumentixError> {
         organizer.require_auth();
-
-        let mut event = storage::get_event(&env, event_id)?;
+        let event = storage::get_event(&env, event_id)?;
         if event.organizer != organizer {
             return Err(LumentixError::Unauthorized);
         }
 
-        validation::validate_currency_code(&currency)?;
-
-        if event.status == EventStatus::Completed || event.status == EventStatus::Cancelled {
-            return Err(LumentixError::InvalidStatusTransition);
-        }
-
-        if !storage::has_currency(&env, &currency) {
-            return Err(LumentixError::UnsupportedCurrency);
-        }
-
-        event.currency = currency.clone();
-        storage::set_event(&env, event_id, &event);
+        let mut config = storage::get_stream_delivery_config(&env, event_id).unwrap_or(
+            StreamDeliveryConfig {
+                cdn_endpoint: String::from_str(&env, ""),
+                stream_url: String::from_str(&env, ""),
+                quality_profile: String::from_str(&env, "auto"),
+                adaptive_bitrate: true,
+                target_bitrate_kbps: 2_500,
+            },
+        );
 
-        EventCurrencySet::emit(&env, event_id, currency);
+        let profile = if target_bitrate_kbps >= 4_500 {
+            String::from_str(&env, "1080p")
+        } else if target_bitrate_kbps >= 2_500 {
+            String::from_str(&env, "720p")
+        } else {
+            String::from_str(&env, "480p")
+        };
 
-        Ok(())
+        config.target_bitrate_kbps = target_bitrate_kbps;
+        config.quality_profile = profile;
+        config.adaptive_bitrate = true;
+        storage::set_stream_delivery_config(&env, event_id, &config);
+        Ok(config)
     }
 
-    /// Convert a price from one currency to another using oracle price feeds.
-    pub fn convert_price(
+    /// Configure CDN endpoints and playback URLs for hybrid content delivery.
+    pub fn manage_content_delivery(
         env: Env,
-        from_currency: String,
-        to_currency: String,
-        amount: i128,
-    ) -> Result<i128, LumentixError> {
-        if from_currency == to_currency {
-            return Ok(amount);
+        organizer: Address,
+        event_id: u64,
+        cdn_endpoint: String,
+        stream_url: String,
+        quality_profile: String,
+    ) -> Result<StreamDeliveryConfig, LumentixError> {
+        organizer.require_auth();
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
         }
 
-        let from_config = storage::get_currency_config(&env, &from_currency)?;
-        let to_config = storage::get_currency_config(&env, &to_currency)?;
-
-        if from_config.oracle_price <= 0 || to_config.oracle_price <= 0 {
-            return Err(LumentixError::OraclePriceNotFound);
-        }
+        validation::validate_string_not_empty(&cdn_endpoint)?;
+        validation::validate_string_not_empty(&stream_url)?;
+        validation::validate_string_not_empty(&quality_profile)?;
 
-        let converted = amount * from_config.oracle_price / to_config.oracle_price;
+        let config = StreamDeliveryConfig {
+            cdn_endpoint: cdn_endpoint.clone(),
+            stream_url: stream_url.clone(),
+            quality_profile,
+            adaptive_bitrate: true,
+            target_bitrate_kbps: 2_500,
+        };
+        storage::set_stream_delivery_config(&env, event_id, &config);
 
-        if converted <= 0 && amount > 0 {
-            return Err(LumentixError::CurrencyConversionError);
-        }
+        storage::set_stream_url(&env, event_id, &stream_url);
 
-        Ok(converted)
+        Ok(config)
     }
 
-    /// Handle currency fluctuation by updating oracle price and returning new converted amount.
-    pub fn handle_currency_fluctuation(
+    /// Register or remove an independent QoS reporter (CDN edge, probe).
+    /// Only the admin can manage reporters so organizers cannot grade
+    /// their own streams.
+    pub fn set_qos_reporter(
         env: Env,
         admin: Address,
-        currency: String,
-        new_oracle_price: i128,
-        amount: i128,
-        to_currency: String,
-    ) -> Result<i128, LumentixError> {
-        admin.require_auth();
+        reporter: Address,
+        registered: bool,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "set_qos_reporter",
+            vec![&env, reporter.into_val(&env), registered.into_val(&env)],
+        )?;
 
-        let stored_admin = storage::get_admin(&env);
-        if stored_admin != admin {
+        storage::set_qos_reporter(&env, &reporter, registered);
+        QosReporterUpdated::emit(&env, reporter, registered);
+        Ok(())
+    }
+
+    /// Whether an address is a registered QoS reporter.
+    pub fn is_qos_reporter(env: Env, reporter: Address) -> bool {
+        storage::is_qos_reporter(&env, &reporter)
+    }
+
+    /// Submit a signed streaming measurement for the current time bucket.
+    /// Each reporter may submit once per bucket.
+    pub fn submit_qos_report(
+        env: Env,
+        reporter: Address,
+        event_id: u64,
+        avg_bitrate_kbps: u32,
+        rebuffer_ratio_bps: u32,
+    ) -> Result<u64, LumentixError> {
+        reporter.require_auth();
+        if !storage::is_qos_reporter(&env, &reporter) {
             return Err(LumentixError::Unauthorized);
         }
+        if !storage::is_hybrid_event(&env, event_id) {
+            return Err(LumentixError::NotHybridEvent);
+        }
 
-        if new_oracle_price <= 0 {
-            return Err(LumentixError::OraclePriceNotFound);
+        let now = env.ledger().timestamp();
+        let bucket = now / QOS_BUCKET_SECS;
+        let mut reports = storage::get_qos_reports(&env, event_id, bucket);
+        if reports.iter().any(|report| report.reporter == reporter) {
+            return Err(LumentixError::QosReportAlreadySubmitted);
         }
 
-        let mut config = storage::get_currency_config(&env, &currency)?;
-        config.oracle_price = new_oracle_price;
-        config.last_updated = env.ledger().timestamp();
-        storage::set_currency_config(&env, &currency, &config);
+        reports.push_back(QosReport {
+            reporter: reporter.clone(),
+            avg_bitrate_kbps,
+            rebuffer_ratio_bps,
+            submitted_at: now,
+        });
+        storage::set_qos_reports(&env, event_id, bucket, &reports);
 
-        OraclePriceUpdated::emit(
+        QosReportSubmitted::emit(
             &env,
-            currency.clone(),
-            new_oracle_price,
-            env.ledger().timestamp(),
+            event_id,
+            bucket,
+            reporter,
+            avg_bitrate_kbps,
+            rebuffer_ratio_bps,
         );
 
-        Self::convert_price(env, currency, to_currency, amount)
+        Ok(bucket)
     }
 
-    /// Get the currency config for a given code.
-    pub fn get_currency_config(env: Env, code: String) -> Result<CurrencyConfig, LumentixError> {
-        storage::get_currency_config(&env, &code)
-    }
+    /// Median bitrate and rebuffer ratio across all reports for a bucket
+    /// (`timestamp / QOS_BUCKET_SECS`).
+    pub fn get_qos_aggregate(env: Env, event_id: u64, bucket: u64) -> QosAggregate {
+        let reports = storage::get_qos_reports(&env, event_id, bucket);
 
-    /// Get the event currency.
-    pub fn get_event_currency(env: Env, event_id: u64) -> Result<String, LumentixError> {
-        let event = storage::get_event(&env, event_id)?;
-        Ok(event.currency)
-    }
+        let mut bitrates = Vec::new(&env);
+        let mut rebuffers = Vec::new(&env);
+        for report in reports.iter() {
+            bitrates.push_back(report.avg_bitrate_kbps);
+            rebuffers.push_back(report.rebuffer_ratio_bps);
+        }
 
-    // ═══════════════════════════════════════════════════════════════════════
-    // SEAT SELECTION / VENUE MAPPING
-    // ═══════════════════════════════════════════════════════════════════════
+        QosAggregate {
+            bucket,
+            report_count: reports.len(),
+            median_bitrate_kbps: Self::median(&env, &bitrates),
+            median_rebuffer_bps: Self::median(&env, &rebuffers),
+        }
+    }
 
-    /// Create a venue layout for an event. Only the organizer can call this.
-    pub fn create_venue_layout(
+    /// Declare the streaming SLA for a hybrid event. It must be set before
+    /// the event starts and cannot be changed afterwards.
+    pub fn set_streaming_sla(
         env: Env,
         organizer: Address,
         event_id: u64,
-        sections: Vec<VenueSection>,
+        sla: StreamingSla,
     ) -> Result<(), LumentixError> {
         organizer.require_auth();
-
         let event = storage::get_event(&env, event_id)?;
         if event.organizer != organizer {
             return Err(LumentixError::Unauthorized);
         }
-
-        let layout = VenueLayout {
-            sections: sections.clone(),
-        };
-
-        storage::set_venue_layout(&env, event_id, &layout);
-
-        // Generate individual seat entries
-        for section in sections.iter() {
-            for row in 1..=section.rows {
-                for num in 1..=section.seats_per_row {
-                    let seat_id = Self::build_seat_id(&env, &section.name, row, num);
-                    let seat = Seat {
-                        section: section.name.clone(),
-                        row,
-                        number: num,
-                        occupied: false,
-                        held_until: 0,
-                        held_by: None,
-                        x: None,
-                        y: None,
-                    };
-                    storage::set_seat(&env, event_id, &seat_id, &seat);
-                }
-            }
+        if !storage::is_hybrid_event(&env, event_id) {
+            return Err(LumentixError::NotHybridEvent);
+        }
+        if env.ledger().timestamp() >= event.start_time {
+            return Err(LumentixError::InvalidTimeRange);
+        }
+        if sla.refund_bps == 0 || sla.refund_bps > 10_000 || sla.min_reports == 0 {
+            return Err(LumentixError::InvalidAmount);
         }
 
-        VenueLayoutCreated::emit(&env, event_id, sections.len());
-
+        storage::set_streaming_sla(&env, event_id, &sla);
         Ok(())
     }
 
-    /// Select (hold) a seat for a buyer. The hold expires after the given duration.
-    pub fn select_seat(
+    /// Get the streaming SLA declared for an event.
+    pub fn get_streaming_sla(env: Env, event_id: u64) -> Result<StreamingSla, LumentixError> {
+        storage::get_streaming_sla(&env, event_id)
+    }
+
+    /// Claim the SLA's partial refund on a virtual ticket by pointing at a
+    /// closed time bucket whose aggregated quality breached the SLA. Each
+    /// ticket can claim once.
+    pub fn claim_sla_refund(
         env: Env,
-        buyer: Address,
-        event_id: u64,
-        section: String,
-        row: u32,
-        number: u32,
-        hold_duration: u64,
-    ) -> Result<String, LumentixError> {
-        buyer.require_auth();
+        holder: Address,
+        ticket_id: u64,
+        bucket: u64,
+    ) -> Result<i128, LumentixError> {
+        Self::require_feature_active(&env, FEATURE_REFUNDS)?;
+        holder.require_auth();
 
-        let event = storage::get_event(&env, event_id)?;
-        if event.status != EventStatus::Published {
-            return Err(LumentixError::InvalidStatusTransition);
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+        if ticket.owner != holder {
+            return Err(LumentixError::Unauthorized);
         }
-        if event.paused {
-            return Err(LumentixError::EventPaused);
+        if ticket.refunded || ticket.revoked {
+            return Err(LumentixError::RefundNotAllowed);
+        }
+        let price = storage::get_virtual_ticket_price(&env, ticket_id)
+            .ok_or(LumentixError::RefundNotAllowed)?;
+        if storage::get_sla_refund(&env, ticket_id).is_some() {
+            return Err(LumentixError::SlaRefundAlreadyClaimed);
         }
 
-        let seat_id = Self::build_seat_id(&env, &section, row, number);
-        let mut seat = storage::get_seat(&env, event_id, &seat_id)?;
+        let sla = storage::get_streaming_sla(&env, ticket.event_id)?;
+        if env.ledger().timestamp() < (bucket + 1) * QOS_BUCKET_SECS {
+            return Err(LumentixError::QosBucketOpen);
+        }
 
-        if seat.occupied {
-            return Err(LumentixError::SeatAlreadyOccupied);
+        let aggregate = Self::get_qos_aggregate(env.clone(), ticket.event_id, bucket);
+        let breached = aggregate.report_count >= sla.min_reports
+            && (aggregate.median_bitrate_kbps < sla.min_bitrate_kbps
+                || aggregate.median_rebuffer_bps > sla.max_rebuffer_bps);
+        if !breached {
+            return Err(LumentixError::SlaNotBreached);
         }
 
-        let now = env.ledger().timestamp();
-        if seat.held_until > now {
-            return Err(LumentixError::SeatHeld);
+        let amount = (price * sla.refund_bps as i128) / 10000;
+        storage::deduct_escrow(&env, ticket.event_id, amount)?;
+        if let Ok(token_address) = storage::get_token_result(&env) {
+            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+            token_client.transfer(&env.current_contract_address(), &holder, &amount);
         }
+        storage::set_sla_refund(&env, ticket_id, amount);
 
-        seat.held_by = Some(buyer.clone());
-        seat.held_until = now + hold_duration;
-        storage::set_seat(&env, event_id, &seat_id, &seat);
+        SlaRefundClaimed::emit(&env, ticket_id, ticket.event_id, bucket, amount);
 
-        SeatSelected::emit(&env, event_id, seat_id.clone(), buyer, seat.held_until);
+        Ok(amount)
+    }
 
-        Ok(seat_id)
+    fn median(env: &Env, values: &Vec<u32>) -> u32 {
+        let mut sorted: Vec<u32> = Vec::new(env);
+        for value in values.iter() {
+            let mut index = 0;
+            while index < sorted.len() && sorted.get(index).unwrap() <= value {
+                index += 1;
+            }
+            sorted.insert(index, value);
+        }
+
+        let len = sorted.len();
+        if len == 0 {
+            return 0;
+        }
+        if len % 2 == 1 {
+            sorted.get(len / 2).unwrap()
+        } else {
+            let lower = sorted.get(len / 2 - 1).unwrap() as u64;
+            let upper = sorted.get(len / 2).unwrap() as u64;
+            ((lower + upper) / 2) as u32
+        }
     }
 
-    /// Release a held seat. Only the holder or the organizer can release it.
-    pub fn release_seat_hold(
+    /// Record and return streaming performance metrics for live dashboards.
+    pub fn monitor_streaming_performance(
         env: Env,
-        caller: Address,
         event_id: u64,
-        section: String,
-        row: u32,
-        number: u32,
+        avg_bitrate_kbps: u32,
+        rebuffer_ratio_bps: u32,
+        concurrent_viewers: u32,
+    ) -> Result<StreamPerformanceMetrics, LumentixError> {
+        let _ = storage::get_event(&env, event_id)?;
+
+        let quality_score = if rebuffer_ratio_bps <= 200 {
+            95u32
+        } else if rebuffer_ratio_bps <= 500 {
+            80u32
+        } else if rebuffer_ratio_bps <= 1_000 {
+            65u32
+        } else {
+            45u32
+        };
+
+        let metrics = StreamPerformanceMetrics {
+            event_id,
+            avg_bitrate_kbps,
+            rebuffer_ratio_bps,
+            concurrent_viewers,
+            quality_score,
+            last_measured_at: env.ledger().timestamp(),
+        };
+        storage::set_stream_performance_metrics(&env, event_id, &metrics);
+        Ok(metrics)
+    }
+    // ═══════════════════════════════════════════════════════════════════════
+    // VIP TIER SYSTEM
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Create a VIP tier for an event. Only the organizer can call this.
+    pub fn create_vip_tier(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        tier_name: String,
+        price: i128,
+        max_slots: u32,
+        benefits: Vec<String>,
     ) -> Result<(), LumentixError> {
-        caller.require_auth();
+        organizer.require_auth();
 
         let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
 
-        let seat_id = Self::build_seat_id(&env, &section, row, number);
-        let mut seat = storage::get_seat(&env, event_id, &seat_id)?;
+        validation::validate_string_not_empty(&tier_name)?;
+        validation::validate_positive_amount(price)?;
+        validation::validate_positive_slots(max_slots)?;
 
-        let is_holder = match &seat.held_by {
-            Some(addr) => addr == &caller,
-            None => false,
+        if storage::has_vip_tier(&env, event_id, &tier_name) {
+            return Err(LumentixError::VipTierAlreadyExists);
+        }
+
+        let tier = VipTier {
+            name: tier_name.clone(),
+            price,
+            max_slots,
+            filled_slots: 0,
+            benefits,
         };
-        let is_organizer = event.organizer == caller;
 
-        if !is_holder && !is_organizer {
+        storage::set_vip_tier(&env, event_id, &tier_name, &tier);
+
+        VipTierCreated::emit(&env, event_id, tier_name, price, max_slots);
+
+        Ok(())
+    }
+
+    /// Assign VIP benefits to a ticket. Validates the tier has available slots.
+    pub fn assign_vip_benefits(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        ticket_id: u64,
+        tier_name: String,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
             return Err(LumentixError::Unauthorized);
         }
 
-        seat.held_by = None;
-        seat.held_until = 0;
-        storage::set_seat(&env, event_id, &seat_id, &seat);
+        let mut tier = storage::get_vip_tier(&env, event_id, &tier_name)?;
 
-        SeatHoldReleased::emit(&env, event_id, seat_id);
+        if tier.filled_slots >= tier.max_slots {
+            return Err(LumentixError::VipTierFull);
+        }
+
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        if ticket.event_id != event_id {
+            return Err(LumentixError::Unauthorized);
+        }
+        if ticket.revoked {
+            return Err(LumentixError::RevokedTicket);
+        }
+        if ticket.refunded {
+            return Err(LumentixError::RefundNotAllowed);
+        }
+
+        tier.filled_slots += 1;
+        storage::set_vip_tier(&env, event_id, &tier_name, &tier);
+
+        ticket.vip_tier = Some(tier_name.clone());
+        storage::set_ticket(&env, ticket_id, &ticket);
+
+        VipTicketAssigned::emit(&env, ticket_id, event_id, tier_name, ticket.owner);
 
         Ok(())
     }
 
-    /// Validate that a seat is available for booking.
-    pub fn validate_seat_availability(
+    /// Validate that a ticket has VIP access for a given tier.
+    /// Used at check-in to verify VIP entitlements.
+    pub fn validate_vip_access(
         env: Env,
-        event_id: u64,
-        section: String,
-        row: u32,
-        number: u32,
+        ticket_id: u64,
+        tier_name: String,
     ) -> Result<bool, LumentixError> {
-        let seat_id = Self::build_seat_id(&env, &section, row, number);
-        let seat = storage::get_seat(&env, event_id, &seat_id)?;
+        let ticket = storage::get_ticket(&env, ticket_id)?;
 
-        if seat.occupied {
+        if ticket.revoked || ticket.refunded || ticket.used {
             return Ok(false);
         }
 
-        let now = env.ledger().timestamp();
-        if seat.held_until > now {
-            return Ok(false);
+        match &ticket.vip_tier {
+            Some(tier) => Ok(tier == &tier_name),
+            None => Ok(false),
         }
-
-        Ok(true)
     }
 
-    /// Get the venue layout for an event.
-    pub fn get_venue_layout(env: Env, event_id: u64) -> Result<VenueLayout, LumentixError> {
-        storage::get_venue_layout(&env, event_id)
-    }
-
-    /// Get seat information.
-    pub fn get_seat_info(
+    /// Get VIP tier details for an event.
+    pub fn get_vip_tier(
         env: Env,
         event_id: u64,
-        section: String,
-        row: u32,
-        number: u32,
-    ) -> Result<Seat, LumentixError> {
-        let seat_id = Self::build_seat_id(&env, &section, row, number);
-        storage::get_seat(&env, event_id, &seat_id)
+        tier_name: String,
+    ) -> Result<VipTier, LumentixError> {
+        storage::get_vip_tier(&env, event_id, &tier_name)
     }
 
-    // ── Internal helpers ─────────────────────────────────────────────────────
+    /// Buy a VIP tier for a ticket the buyer owns. Charges the tier price, or
+    /// the price difference when upgrading from a cheaper tier, into escrow.
+    /// Returns the amount charged.
+    pub fn purchase_vip_upgrade(
+        env: Env,
+        buyer: Address,
+        ticket_id: u64,
+        tier_name: String,
+    ) -> Result<i128, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
+        buyer.require_auth();
 
-    fn default_pricing_schedule() -> PricingSchedule {
-        PricingSchedule {
-            early_bird_multiplier_bps: 8_000,
-            standard_multiplier_bps: 10_000,
-            late_multiplier_bps: 10_000,
-            last_minute_multiplier_bps: 15_000,
-            early_bird_days: 30,
-            standard_days: 7,
-            last_minute_hours: 24,
+        let mut ticket = storage::get_ticket(&env, ticket_id)?;
+        if ticket.owner != buyer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if ticket.revoked {
+            return Err(LumentixError::RevokedTicket);
+        }
+        if ticket.used {
+            return Err(LumentixError::TicketAlreadyUsed);
+        }
+        if ticket.refunded {
+            return Err(LumentixError::RefundNotAllowed);
         }
-    }
 
-    fn resolve_price_tier(env: &Env, event_id: u64, event: &Event) -> Result<PriceTier, LumentixError> {
-        let schedule = storage::get_pricing_schedule(env, event_id)
-            .unwrap_or_else(|| Self::default_pricing_schedule());
-        let now = env.ledger().timestamp();
-        let time_until_start = event.start_time.saturating_sub(now);
-        let early_cutoff = schedule.early_bird_days as u64 * ONE_DAY_SECONDS;
-        let standard_cutoff = schedule.standard_days as u64 * ONE_DAY_SECONDS;
-        let last_minute_cutoff = schedule.last_minute_hours as u64 * 3_600;
+        let event = storage::get_event(&env, ticket.event_id)?;
+        if event.status != EventStatus::Published {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
 
-        if time_until_start > early_cutoff {
-            Ok(PriceTier::EarlyBird)
-        } else if time_until_start >= standard_cutoff {
-            Ok(PriceTier::Standard)
-        } else if time_until_start <= last_minute_cutoff {
-            Ok(PriceTier::LastMinute)
-        } else {
-            Ok(PriceTier::Late)
+        if ticket.vip_tier.as_ref() == Some(&tier_name) {
+            return Err(LumentixError::VipTierAlreadyOwned);
         }
-    }
 
-    fn estimate_mint_resource_units(quantity: u32) -> u64 {
-        MINT_BASE_RESOURCE_UNITS + (quantity as u64 * MINT_PER_TICKET_RESOURCE_UNITS)
-    }
-    fn add_offer_recipient_if_missing(env: &Env, event_id: u64, buyer: &Address) {
-        let mut recipients = storage::get_waitlist_offer_recipients(env, event_id);
-        for existing in recipients.iter() {
-            if existing == *buyer {
-                return;
-            }
+        let mut tier = storage::get_vip_tier(&env, ticket.event_id, &tier_name)?;
+        if tier.filled_slots >= tier.max_slots {
+            return Err(LumentixError::VipTierFull);
         }
-        recipients.push_back(buyer.clone());
-        storage::set_waitlist_offer_recipients(env, event_id, &recipients);
-    }
 
-    fn cleanup_expired_waitlist_offers(env: &Env, event_id: u64, now: u64) {
-        let recipients = storage::get_waitlist_offer_recipients(env, event_id);
-        let mut active_recipients = Vec::<Address>::new(env);
-        let mut reserved = storage::get_waitlist_reserved(env, event_id);
+        // Upgrading pays only the difference and frees the previous tier's slot
+        let mut amount = tier.price;
+        if let Some(current_name) = ticket.vip_tier.clone() {
+            let mut current = storage::get_vip_tier(&env, ticket.event_id, &current_name)?;
+            amount -= current.price;
+            if amount <= 0 {
+                return Err(LumentixError::VipDowngradeNotAllowed);
+            }
+            current.filled_slots = current.filled_slots.saturating_sub(1);
+            storage::set_vip_tier(&env, ticket.event_id, &current_name, &current);
+        }
 
-        for buyer in recipients.iter() {
-            if let Some(offer) = storage::get_waitlist_offer(env, event_id, &buyer) {
-                if offer.quantity == 0 || offer.expires_at <= now {
-                    reserved = reserved.saturating_sub(offer.quantity);
-                    storage::remove_waitlist_offer(env, event_id, &buyer);
-                } else {
-                    active_recipients.push_back(buyer);
-                }
-            }
-        }
-
-        storage::set_waitlist_offer_recipients(env, event_id, &active_recipients);
-        storage::set_waitlist_reserved(env, event_id, reserved);
-    }
-
-    fn process_waitlist_queue_internal(env: &Env, event_id: u64, mut available: u32) -> u32 {
-        if available == 0 {
-            return 0;
+        if let Ok(token_address) = storage::get_token_result(&env) {
+            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+            token_client.transfer(&buyer, env.current_contract_address(), &amount);
         }
 
-        let event = match storage::get_event(env, event_id) {
-            Ok(event) => event,
-            Err(_) => return 0,
-        };
-        if event.status != EventStatus::Published {
-            return 0;
+        let fee_bps = storage::get_platform_fee_bps(&env);
+        let platform_fee = (amount * fee_bps as i128) / 10000;
+        if platform_fee > 0 {
+            storage::add_platform_balance(&env, platform_fee);
         }
+        storage::add_escrow(&env, ticket.event_id, amount - platform_fee);
 
-        let mut queue = storage::get_waitlist_queue(env, event_id);
-        let mut next_queue = Vec::<Address>::new(env);
-        let mut processed: u32 = 0;
-        let now = env.ledger().timestamp();
-
-        for buyer in queue.iter() {
-            if available == 0 {
-                next_queue.push_back(buyer);
-                continue;
-            }
-
-            let expires_at = now + ONE_DAY_SECONDS;
-            let offer = WaitlistOffer {
-                quantity: 1,
-                expires_at,
-            };
-            storage::set_waitlist_offer(env, event_id, &buyer, &offer);
-            Self::add_offer_recipient_if_missing(env, event_id, &buyer);
+        let mut purchase = storage::get_vip_purchase(&env, ticket_id).unwrap_or(VipPurchase {
+            ticket_id,
+            tier_name: tier_name.clone(),
+            amount_paid: 0,
+            platform_fee: 0,
+        });
+        purchase.tier_name = tier_name.clone();
+        purchase.amount_paid += amount;
+        purchase.platform_fee += platform_fee;
+        storage::set_vip_purchase(&env, ticket_id, &purchase);
 
-            let reserved = storage::get_waitlist_reserved(env, event_id);
-            storage::set_waitlist_reserved(env, event_id, reserved.saturating_add(1));
+        tier.filled_slots += 1;
+        storage::set_vip_tier(&env, ticket.event_id, &tier_name, &tier);
 
-            WaitlistAvailabilityNotified::emit(env, event_id, buyer, 1, expires_at);
-            available -= 1;
-            processed += 1;
-        }
+        ticket.vip_tier = Some(tier_name.clone());
+        storage::set_ticket(&env, ticket_id, &ticket);
 
-        queue = next_queue;
-        storage::set_waitlist_queue(env, event_id, &queue);
-        processed
-    }
+        VipUpgradePurchased::emit(&env, ticket_id, ticket.event_id, tier_name, buyer, amount);
 
-    fn build_seat_id(env: &Env, section: &String, row: u32, number: u32) -> String {
-        let sec = section.to_bytes();
-        let mut buf = [0u8; 64];
-        let mut i = 0;
-        for b in sec.iter() {
-            if i < 62 {
-                buf[i] = b;
-                i += 1;
-            }
-        }
-        buf[i] = b'-';
-        i += 1;
-        let r_str = match row {
-            0 => "0",
-            1 => "1",
-            2 => "2",
-            3 => "3",
-            4 => "4",
-            5 => "5",
-            6 => "6",
-            7 => "7",
-            8 => "8",
-            9 => "9",
-            10 => "10",
-            _ => "0",
-        };
-        for b in r_str.bytes() {
-            if i < 63 {
-                buf[i] = b;
-                i += 1;
-            }
-        }
-        buf[i] = b'-';
-        i += 1;
-        let n_str = match number {
-            0 => "0",
-            1 => "1",
-            2 => "2",
-            3 => "3",
-            4 => "4",
-            5 => "5",
-            6 => "6",
-            7 => "7",
-            8 => "8",
-            9 => "9",
-            10 => "10",
-            _ => "0",
-        };
-        for b in n_str.bytes() {
-            if i < 64 {
-                buf[i] = b;
-                i += 1;
-            }
-        }
-        let valid = core::str::from_utf8(&buf[..i]).unwrap_or("");
-        String::from_str(env, valid)
+        Ok(amount)
     }
 
-    // ── Insurance Functions ─────────────────────────────────────────────────────
-
-    /// Purchase insurance for a ticket.
-    /// Premium is 10% of the ticket price.
-    /// Provides full refund protection if the event is cancelled.
-    pub fn purchase_insurance(
+    /// Redeem one of the benefits of a ticket's VIP tier. Only the organizer or
+    /// an authorized gate validator can redeem, and each benefit only once.
+    pub fn redeem_vip_benefit(
         env: Env,
+        validator: Address,
         ticket_id: u64,
-        buyer: Address,
-    ) -> Result<u64, LumentixError> {
-        buyer.require_auth();
+        benefit: String,
+    ) -> Result<(), LumentixError> {
+        validator.require_auth();
 
-        // Get the ticket
         let ticket = storage::get_ticket(&env, ticket_id)?;
-
-        // Verify the buyer is the ticket owner
-        if ticket.owner != buyer {
-            return Err(LumentixError::Unauthorized);
+        if ticket.revoked {
+            return Err(LumentixError::RevokedTicket);
         }
-
-        // Check if insurance already purchased for this ticket
-        let _ = storage::get_insurance_policy_by_ticket(&env, ticket_id);
-        if storage::get_insurance_policy_by_ticket(&env, ticket_id).is_ok() {
-            return Err(LumentixError::InsuranceAlreadyPurchased);
+        if ticket.refunded {
+            return Err(LumentixError::RefundNotAllowed);
         }
 
-        // Get the event to calculate premium
         let event = storage::get_event(&env, ticket.event_id)?;
-
-        // Calculate premium (10% of ticket price)
-        let premium = (event.ticket_price * 10) / 100;
-        if premium <= 0 {
-            return Err(LumentixError::InvalidInsurancePremium);
+        if !Self::is_gate_validator(&env, &event, &validator) {
+            return Err(LumentixError::Unauthorized);
         }
 
-        // Process token transfer for premium
-        if let Ok(token_address) = storage::get_token_result(&env) {
-            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
-            token_client.transfer(&buyer, &env.current_contract_address(), &premium);
+        let tier_name = ticket.vip_tier.ok_or(LumentixError::VipTierNotFound)?;
+        let tier = storage::get_vip_tier(&env, ticket.event_id, &tier_name)?;
+        if !tier.benefits.contains(&benefit) {
+            return Err(LumentixError::VipBenefitNotFound);
         }
 
-        // Add premium to insurance pool
-        storage::add_to_insurance_pool(&env, premium);
-
-        // Create insurance policy
-        let policy_id = storage::get_next_insurance_policy_id(&env);
-        storage::increment_insurance_policy_id(&env);
-        storage::increment_total_policies(&env);
-
-        let policy = InsurancePolicy {
-            id: policy_id,
-            ticket_id,
-            event_id: ticket.event_id,
-            holder: buyer.clone(),
-            premium_paid: premium,
-            coverage_amount: event.ticket_price, // Full ticket price coverage
-            purchase_time: env.ledger().timestamp(),
-            active: true,
-            claim_processed: false,
-        };
-
-        storage::set_insurance_policy(&env, policy_id, &policy);
+        if storage::get_vip_redemptions(&env, ticket_id)
+            .iter()
+            .any(|redemption| redemption.benefit == benefit)
+        {
+            return Err(LumentixError::VipBenefitAlreadyRedeemed);
+        }
 
-        // Emit InsurancePurchased event
-        InsurancePurchased::emit(
+        storage::add_vip_redemption(
             &env,
-            policy_id,
-            ticket_id,
-            ticket.event_id,
-            buyer,
-            premium,
-            event.ticket_price,
+            &VipBenefitRedemption {
+                ticket_id,
+                benefit: benefit.clone(),
+                redeemed_by: validator.clone(),
+                redeemed_at: env.ledger().timestamp(),
+            },
         );
 
-        // Emit InsurancePoolUpdated event
-        let pool = storage::get_insurance_pool(&env);
-        InsurancePoolUpdated::emit(
-            &env,
-            pool.total_balance,
-            pool.total_policies,
-            pool.total_claims_paid,
-        );
+        VipBenefitRedeemed::emit(&env, ticket_id, benefit, validator);
 
-        Ok(policy_id)
+        Ok(())
     }
 
-    /// Process an insurance claim for a cancelled event.
-    /// Validates the cancellation reason and processes the refund.
-    pub fn process_insurance_claim(
-        env: Env,
-        ticket_id: u64,
-        claimant: Address,
-        cancellation_reason: CancellationReason,
-    ) -> Result<(), LumentixError> {
-        claimant.require_auth();
+    /// Get the VIP benefits redeemed so far for a ticket.
+    pub fn get_vip_redemptions(env: Env, ticket_id: u64) -> Vec<VipBenefitRedemption> {
+        storage::get_vip_redemptions(&env, ticket_id)
+    }
 
-        // Get the insurance policy
-        let mut policy = storage::get_insurance_policy_by_ticket(&env, ticket_id)?;
+    /// Free a refunded or revoked ticket's VIP slot and return what was paid
+    /// for the tier. A platform fee that was already withdrawn is owed to
+    /// the recipient as platform fee debt instead.
+    fn release_vip_on_refund(env: &Env, ticket: &Ticket, recipient: &Address) -> Result<i128, LumentixError> {
+        let tier_name = match &ticket.vip_tier {
+            Some(tier_name) => tier_name,
+            None => return Ok(0),
+        };
 
-        // Verify the claimant is the policy holder
-        if policy.holder != claimant {
-            return Err(LumentixError::Unauthorized);
+        if let Ok(mut tier) = storage::get_vip_tier(env, ticket.event_id, tier_name) {
+            tier.filled_slots = tier.filled_slots.saturating_sub(1);
+            storage::set_vip_tier(env, ticket.event_id, tier_name, &tier);
         }
 
-        // Check if policy is active
-        if !policy.active {
-            return Err(LumentixError::InsurancePolicyNotActive);
-        }
+        let purchase = match storage::get_vip_purchase(env, ticket.id) {
+            Some(purchase) => purchase,
+            None => return Ok(0),
+        };
 
-        // Check if claim already processed
-        if policy.claim_processed {
-            return Err(LumentixError::InsuranceClaimAlreadyProcessed);
-        }
+        let escrowed = purchase.amount_paid - purchase.platform_fee;
+        storage::deduct_escrow(env, ticket.event_id, escrowed)?;
+        let fee_returned =
+            storage::return_platform_fee(env, &None, recipient, purchase.platform_fee);
+        storage::remove_vip_purchase(env, ticket.id);
 
-        // Validate cancellation reason
-        Self::validate_cancellation_reason(&env, ticket_id, &cancellation_reason)?;
+        let refunded = escrowed + fee_returned;
+        if let Ok(token_address) = storage::get_token_result(env) {
+            let token_client = soroban_sdk::token::Client::new(env, &token_address);
+            token_client.transfer(&env.current_contract_address(), recipient, &refunded);
+        }
 
-        // Get the event
-        let event = storage::get_event(&env, policy.event_id)?;
+        Ok(refunded)
+    }
 
-        // Verify event is cancelled
-        if event.status != EventStatus::Cancelled {
-            return Err(LumentixError::EventNotCancelled);
-        }
+    // ═══════════════════════════════════════════════════════════════════════
+    // ACCESSIBILITY FEATURES
+    // ═══════════════════════════════════════════════════════════════════════
 
-        // Process the claim - deduct from insurance pool
-        storage::deduct_from_insurance_pool(&env, policy.coverage_amount)?;
+    /// Configure accessibility inventory for an event. Only organizer can call.
+    pub fn setup_accessibility_inventory(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        wheelchair_total: u32,
+        hearing_total: u32,
+        visual_total: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
 
-        // Transfer coverage amount to claimant
-        if let Ok(token_address) = storage::get_token_result(&env) {
-            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
-            token_client.transfer(
-                &env.current_contract_address(),
-                &claimant,
-                &policy.coverage_amount,
-            );
+        let mut event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
         }
 
-        // Mark policy as claim processed
-        policy.claim_processed = true;
-        policy.active = false;
-        storage::set_insurance_policy(&env, policy.id, &policy);
+        let inv = AccessibilityInventory {
+            wheelchair_available: wheelchair_total,
+            wheelchair_total,
+            hearing_available: hearing_total,
+            hearing_total,
+            visual_available: visual_total,
+            visual_total,
+        };
 
-        // Emit InsuranceClaimProcessed event
-        InsuranceClaimProcessed::emit(
-            &env,
-            policy.id,
-            ticket_id,
-            policy.event_id,
-            claimant,
-            policy.coverage_amount,
-            cancellation_reason,
-        );
+        storage::set_accessibility_inventory(&env, event_id, &inv);
 
-        // Emit InsurancePoolUpdated event
-        let pool = storage::get_insurance_pool(&env);
-        InsurancePoolUpdated::emit(
+        event.accessibility_wheelchair = wheelchair_total;
+        event.accessibility_hearing = hearing_total;
+        event.accessibility_visual = visual_total;
+        storage::set_event(&env, event_id, &event);
+
+        AccessibilityInventoryUpdated::emit(
             &env,
-            pool.total_balance,
-            pool.total_policies,
-            pool.total_claims_paid,
+            event_id,
+            wheelchair_total,
+            hearing_total,
+            visual_total,
         );
 
         Ok(())
     }
 
-    /// Validate that a cancellation reason is valid for an insurance claim.
-    /// Only certain reasons qualify for insurance payouts.
-    pub fn validate_cancellation_reason(
-        env: &Env,
-        ticket_id: u64,
-        reason: &CancellationReason,
+    /// Define or resize an organizer-specific accommodation type (companion
+    /// seat, interpreter, quiet room, ...). Resizing keeps existing bookings:
+    /// `total` may not drop below the number of slots already reserved.
+    pub fn configure_accommodation_type(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        name: String,
+        total: u32,
+        companion_ticket: bool,
+        accessible_seats: Vec<String>,
     ) -> Result<(), LumentixError> {
-        // Get the insurance policy
-        let policy = storage::get_insurance_policy_by_ticket(env, ticket_id)?;
+        organizer.require_auth();
 
-        // Get the event
-        let event = storage::get_event(env, policy.event_id)?;
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        validation::validate_string_not_empty(&name)?;
 
-        // Validate based on cancellation reason
-        match reason {
-            CancellationReason::EventCancelledByOrganizer => {
-                // Always valid - organizer cancelled the event
-                if event.status != EventStatus::Cancelled {
-                    return Err(LumentixError::InvalidCancellationReason);
-                }
-            }
-            CancellationReason::ForceMajeure => {
-                // Valid if event is cancelled (assumes force majeure led to cancellation)
-                if event.status != EventStatus::Cancelled {
-                    return Err(LumentixError::InvalidCancellationReason);
-                }
-            }
-            CancellationReason::VenueUnavailable => {
-                // Valid if event is cancelled
-                if event.status != EventStatus::Cancelled {
-                    return Err(LumentixError::InvalidCancellationReason);
-                }
-            }
-            CancellationReason::ArtistPerformerUnavailable => {
-                // Valid if event is cancelled
-                if event.status != EventStatus::Cancelled {
-                    return Err(LumentixError::InvalidCancellationReason);
-                }
-            }
-            CancellationReason::HealthSafetyConcerns => {
-                // Valid if event is cancelled
-                if event.status != EventStatus::Cancelled {
-                    return Err(LumentixError::InvalidCancellationReason);
-                }
-            }
-            CancellationReason::GovernmentRestriction => {
-                // Valid if event is cancelled
-                if event.status != EventStatus::Cancelled {
-                    return Err(LumentixError::InvalidCancellationReason);
-                }
-            }
-            CancellationReason::Other => {
-                // "Other" is only valid if event is cancelled
-                // In a production system, you might require additional documentation
-                if event.status != EventStatus::Cancelled {
-                    return Err(LumentixError::InvalidCancellationReason);
-                }
-            }
+        let reserved = match storage::get_accommodation_type(&env, event_id, &name) {
+            Some(existing) => existing.total - existing.available,
+            None => 0,
+        };
+        if total < reserved {
+            return Err(LumentixError::AccommodationUnavailable);
         }
 
+        let accommodation = AccommodationType {
+            name: name.clone(),
+            total,
+            available: total - reserved,
+            companion_ticket,
+            accessible_seats,
+        };
+        storage::set_accommodation_type(&env, event_id, &accommodation);
+
+        AccommodationTypeConfigured::emit(&env, event_id, name, total, companion_ticket);
+
         Ok(())
     }
 
-    /// Get insurance policy by ticket ID.
-    pub fn get_insurance_policy_by_ticket(
+    /// Get an organizer-defined accommodation type.
+    pub fn get_accommodation_type(
         env: Env,
-        ticket_id: u64,
-    ) -> Result<InsurancePolicy, LumentixError> {
-        storage::get_insurance_policy_by_ticket(&env, ticket_id)
+        event_id: u64,
+        name: String,
+    ) -> Result<AccommodationType, LumentixError> {
+        storage::get_accommodation_type(&env, event_id, &name)
+            .ok_or(LumentixError::AccommodationUnavailable)
     }
 
-    /// Get insurance pool information.
-    pub fn get_insurance_pool(env: Env) -> Result<crate::types::InsurancePool, LumentixError> {
-        Ok(storage::get_insurance_pool(&env))
+    /// List the names of all accommodation types defined for an event.
+    pub fn get_accommodation_types(env: Env, event_id: u64) -> Vec<String> {
+        storage::get_accommodation_types(&env, event_id)
     }
 
-    // ═══════════════════════════════════════════════════════════════════════
-    // REVIEW & REPUTATION SYSTEM
-    // ═══════════════════════════════════════════════════════════════════════
-
-    /// Submit a verified event review.
-    ///
-    /// Anti-fake-review guarantees enforced on-chain:
-    ///  1. The reviewer must own the ticket (`ticket.owner == reviewer`).
-    ///  2. The ticket must have been used (`ticket.used == true`).
-    ///  3. The ticket must belong to the reviewed event.
-    ///  4. The event must be Completed.
-    ///  5. One review per reviewer per event (duplicate guard).
-    ///  6. Rating must be 1–5.
-    pub fn submit_event_review(
+    /// Request an accessibility accommodation for a ticket. The slot is
+    /// reserved immediately; the booking stays pending until the organizer
+    /// approves or denies it.
+    pub fn request_accessibility_booking(
         env: Env,
-        reviewer: Address,
+        attendee: Address,
         event_id: u64,
         ticket_id: u64,
-        rating: u32,
-        comment: String,
+        accommodation_type: String,
     ) -> Result<u64, LumentixError> {
-        reviewer.require_auth();
-
-        // Validate rating range
-        if rating < 1 || rating > 5 {
-            return Err(LumentixError::InvalidRating);
-        }
+        attendee.require_auth();
 
-        // Load and validate the event
         let event = storage::get_event(&env, event_id)?;
-        if event.status != EventStatus::Completed {
-            return Err(LumentixError::EventNotCompleted);
+        if event.status != EventStatus::Published {
+            return Err(LumentixError::InvalidStatusTransition);
         }
 
-        // Load and validate the ticket
         let ticket = storage::get_ticket(&env, ticket_id)?;
+        if ticket.owner != attendee {
+            return Err(LumentixError::Unauthorized);
+        }
+        if ticket.event_id != event_id {
+            return Err(LumentixError::Unauthorized);
+        }
+        if ticket.revoked {
+            return Err(LumentixError::RevokedTicket);
+        }
+        if ticket.refunded {
+            return Err(LumentixError::RefundNotAllowed);
+        }
+        if ticket.used {
+            return Err(LumentixError::TicketAlreadyUsed);
+        }
 
-        // Reviewer must own the ticket
-        if ticket.owner != reviewer {
-            return Err(LumentixError::ReviewerNotTicketOwner);
+        if let Some(existing_id) = storage::get_ticket_accessibility_booking(&env, ticket_id) {
+            let existing = storage::get_accessibility_booking(&env, existing_id)?;
+            if Self::is_accessibility_booking_active(&existing) {
+                return Err(LumentixError::AccessibilityBookingExists);
+            }
         }
 
-        // Ticket must belong to the reviewed event
-        if ticket.event_id != event_id {
-            return Err(LumentixError::TicketEventMismatch);
+        Self::reserve_accommodation(&env, event_id, &accommodation_type)?;
+
+        let booking_id = storage::get_next_accessibility_booking_id(&env);
+        storage::increment_accessibility_booking_id(&env);
+
+        let booking = AccessibilityBooking {
+            id: booking_id,
+            event_id,
+            ticket_id,
+            attendee: attendee.clone(),
+            accommodation_type: accommodation_type.clone(),
+            status: AccessibilityBookingStatus::Pending,
+            organizer_notes: String::from_str(&env, ""),
+            seat_id: None,
+            companion_ticket_id: None,
+        };
+
+        storage::set_accessibility_booking(&env, booking_id, &booking);
+        storage::set_ticket_accessibility_booking(&env, ticket_id, booking_id);
+
+        AccessibilityBooked::emit(&env, booking_id, event_id, attendee, accommodation_type);
+
+        Ok(booking_id)
+    }
+
+    /// Approve a pending accessibility booking. Optionally links an accessible
+    /// seat to the attendee's ticket, and issues a free companion ticket when
+    /// the accommodation type offers one. Only the organizer can approve.
+    pub fn approve_accessibility_booking(
+        env: Env,
+        organizer: Address,
+        booking_id: u64,
+        notes: String,
+        seat_id: Option<String>,
+    ) -> Result<AccessibilityBooking, LumentixError> {
+        organizer.require_auth();
+
+        let mut booking = storage::get_accessibility_booking(&env, booking_id)?;
+        let mut event = storage::get_event(&env, booking.event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if booking.status != AccessibilityBookingStatus::Pending {
+            return Err(LumentixError::AccessibilityBookingNotPending);
         }
 
-        // Ticket must have been used (checked in) — attendance proof
-        if !ticket.used {
-            return Err(LumentixError::AttendanceNotVerified);
+        let accommodation =
+            storage::get_accommodation_type(&env, booking.event_id, &booking.accommodation_type);
+        let mut ticket = storage::get_ticket(&env, booking.ticket_id)?;
+
+        if let Some(seat_id) = seat_id.clone() {
+            if let Some(accommodation) = &accommodation {
+                if !accommodation.accessible_seats.is_empty()
+                    && !accommodation.accessible_seats.contains(&seat_id)
+                {
+                    return Err(LumentixError::SeatNotAccessible);
+                }
+            }
+
+            let mut seat = storage::get_seat(&env, booking.event_id, &seat_id)?;
+            if seat.occupied {
+                return Err(LumentixError::SeatAlreadyOccupied);
+            }
+            let held_by_other = match &seat.held_by {
+                Some(holder) => holder != &booking.attendee,
+                None => false,
+            };
+            if seat.held_until > env.ledger().timestamp() && held_by_other {
+                return Err(LumentixError::SeatHeld);
+            }
+
+            seat.occupied = true;
+            seat.held_by = None;
+            seat.held_until = 0;
+            storage::set_seat(&env, booking.event_id, &seat_id, &seat);
+
+            // The accessible seat replaces any seat the ticket already held
+            if let Some(previous) = ticket.seat_id.clone() {
+                if previous != seat_id {
+                    if let Ok(mut old_seat) = storage::get_seat(&env, booking.event_id, &previous) {
+                        old_seat.occupied = false;
+                        storage::set_seat(&env, booking.event_id, &previous, &old_seat);
+                    }
+                }
+            }
+            ticket.seat_id = Some(seat_id);
         }
 
-        // Duplicate review guard
-        if storage::has_reviewer_reviewed(&env, &reviewer, event_id) {
-            return Err(LumentixError::ReviewAlreadySubmitted);
+        let issues_companion = match &accommodation {
+            Some(accommodation) => accommodation.companion_ticket,
+            None => false,
+        };
+        if issues_companion {
+            // Companion tickets take up regular capacity
+            if event.tickets_sold >= event.max_tickets {
+                return Err(LumentixError::EventSoldOut);
+            }
+            Self::require_event_credential(&env, booking.event_id, &booking.attendee)?;
+
+            let companion_id = storage::get_next_ticket_id(&env);
+            storage::increment_ticket_id(&env);
+
+            let companion = Ticket {
+                id: companion_id,
+                event_id: booking.event_id,
+                owner: booking.attendee.clone(),
+                purchase_time: env.ledger().timestamp(),
+                used: false,
+                refunded: false,
+                revoked: false,
+                vip_tier: None,
+                seat_id: None,
+                accessibility_type: None,
+            };
+            storage::set_ticket(&env, companion_id, &companion);
+            storage::set_comp_ticket(&env, companion_id);
+
+            event.tickets_sold += 1;
+            storage::set_event(&env, booking.event_id, &event);
+
+            booking.companion_ticket_id = Some(companion_id);
+        }
+
+        ticket.accessibility_type = Some(booking.accommodation_type.clone());
+        storage::set_ticket(&env, booking.ticket_id, &ticket);
+
+        booking.status = AccessibilityBookingStatus::Approved;
+        booking.organizer_notes = notes.clone();
+        booking.seat_id = seat_id;
+        storage::set_accessibility_booking(&env, booking_id, &booking);
+
+        AccessibilityBookingReviewed::emit(&env, booking_id, booking.event_id, true, notes);
+
+        Ok(booking)
+    }
+
+    /// Deny a pending accessibility booking and return its slot to inventory.
+    /// Only the organizer can deny.
+    pub fn deny_accessibility_booking(
+        env: Env,
+        organizer: Address,
+        booking_id: u64,
+        notes: String,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let mut booking = storage::get_accessibility_booking(&env, booking_id)?;
+        let event = storage::get_event(&env, booking.event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if booking.status != AccessibilityBookingStatus::Pending {
+            return Err(LumentixError::AccessibilityBookingNotPending);
+        }
+
+        Self::release_accommodation(&env, booking.event_id, &booking.accommodation_type);
+
+        booking.status = AccessibilityBookingStatus::Denied;
+        booking.organizer_notes = notes.clone();
+        storage::set_accessibility_booking(&env, booking_id, &booking);
+
+        AccessibilityBookingReviewed::emit(&env, booking_id, booking.event_id, false, notes);
+
+        Ok(())
+    }
+
+    /// Take one slot of an accommodation type. Organizer-defined types take
+    /// precedence; "wheelchair", "hearing" and "visual" fall back to the
+    /// fixed `AccessibilityInventory`.
+    fn reserve_accommodation(
+        env: &Env,
+        event_id: u64,
+        accommodation_type: &String,
+    ) -> Result<(), LumentixError> {
+        if let Some(mut accommodation) =
+            storage::get_accommodation_type(env, event_id, accommodation_type)
+        {
+            if accommodation.available == 0 {
+                return Err(LumentixError::AccommodationUnavailable);
+            }
+            accommodation.available -= 1;
+            storage::set_accommodation_type(env, event_id, &accommodation);
+            return Ok(());
+        }
+
+        let mut inv = storage::get_accessibility_inventory(env, event_id)?;
+
+        if *accommodation_type == String::from_str(env, "wheelchair") {
+            if inv.wheelchair_available == 0 {
+                return Err(LumentixError::AccommodationUnavailable);
+            }
+            inv.wheelchair_available -= 1;
+        } else if *accommodation_type == String::from_str(env, "hearing") {
+            if inv.hearing_available == 0 {
+                return Err(LumentixError::AccommodationUnavailable);
+            }
+            inv.hearing_available -= 1;
+        } else if *accommodation_type == String::from_str(env, "visual") {
+            if inv.visual_available == 0 {
+                return Err(LumentixError::AccommodationUnavailable);
+            }
+            inv.visual_available -= 1;
+        } else {
+            return Err(LumentixError::AccommodationUnavailable);
+        }
+
+        storage::set_accessibility_inventory(env, event_id, &inv);
+        Ok(())
+    }
+
+    /// Return one slot of an accommodation type, never exceeding its total.
+    fn release_accommodation(env: &Env, event_id: u64, accommodation_type: &String) {
+        if let Some(mut accommodation) =
+            storage::get_accommodation_type(env, event_id, accommodation_type)
+        {
+            if accommodation.available < accommodation.total {
+                accommodation.available += 1;
+                storage::set_accommodation_type(env, event_id, &accommodation);
+            }
+            return;
+        }
+
+        if let Ok(mut inv) = storage::get_accessibility_inventory(env, event_id) {
+            if *accommodation_type == String::from_str(env, "wheelchair") {
+                inv.wheelchair_available = (inv.wheelchair_available + 1).min(inv.wheelchair_total);
+            } else if *accommodation_type == String::from_str(env, "hearing") {
+                inv.hearing_available = (inv.hearing_available + 1).min(inv.hearing_total);
+            } else if *accommodation_type == String::from_str(env, "visual") {
+                inv.visual_available = (inv.visual_available + 1).min(inv.visual_total);
+            }
+            storage::set_accessibility_inventory(env, event_id, &inv);
+        }
+    }
+
+    /// Manage (update) accessibility inventory for an event. Only organizer.
+    pub fn manage_accessibility_inventory(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        wheelchair_available: u32,
+        hearing_available: u32,
+        visual_available: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let mut inv = storage::get_accessibility_inventory(&env, event_id)?;
+
+        validation::validate_accessibility_counts(wheelchair_available, inv.wheelchair_total)?;
+        validation::validate_accessibility_counts(hearing_available, inv.hearing_total)?;
+        validation::validate_accessibility_counts(visual_available, inv.visual_total)?;
+
+        inv.wheelchair_available = wheelchair_available;
+        inv.hearing_available = hearing_available;
+        inv.visual_available = visual_available;
+
+        storage::set_accessibility_inventory(&env, event_id, &inv);
+
+        AccessibilityInventoryUpdated::emit(
+            &env,
+            event_id,
+            wheelchair_available,
+            hearing_available,
+            visual_available,
+        );
+
+        Ok(())
+    }
+
+    /// Validate that an attendee's accessibility needs can be met.
+    pub fn validate_accessibility_needs(
+        env: Env,
+        event_id: u64,
+        accommodation_type: String,
+    ) -> Result<bool, LumentixError> {
+        if let Some(accommodation) =
+            storage::get_accommodation_type(&env, event_id, &accommodation_type)
+        {
+            return Ok(accommodation.available > 0);
+        }
+        if let Ok(inv) = storage::get_accessibility_inventory(&env, event_id) {
+            let available = if accommodation_type == String::from_str(&env, "wheelchair") {
+                inv.wheelchair_available
+            } else if accommodation_type == String::from_str(&env, "hearing") {
+                inv.hearing_available
+            } else if accommodation_type == String::from_str(&env, "visual") {
+                inv.visual_available
+            } else {
+                return Err(LumentixError::AccommodationUnavailable);
+            };
+            Ok(available > 0)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Get the accessibility booking for a given booking ID.
+    pub fn get_accessibility_booking(
+        env: Env,
+        booking_id: u64,
+    ) -> Result<AccessibilityBooking, LumentixError> {
+        storage::get_accessibility_booking(&env, booking_id)
+    }
+
+    /// Get the most recent accessibility booking made for a ticket.
+    pub fn get_ticket_accessibility_booking(
+        env: Env,
+        ticket_id: u64,
+    ) -> Result<AccessibilityBooking, LumentixError> {
+        let booking_id = storage::get_ticket_accessibility_booking(&env, ticket_id)
+            .ok_or(LumentixError::AccessibilityBookingNotFound)?;
+        storage::get_accessibility_booking(&env, booking_id)
+    }
+
+    /// Cancel a pending or approved accessibility booking. The slot, any
+    /// linked seat and the companion ticket are released. Only the attendee
+    /// who made the booking can cancel it.
+    pub fn cancel_accessibility_booking(
+        env: Env,
+        attendee: Address,
+        booking_id: u64,
+    ) -> Result<(), LumentixError> {
+        attendee.require_auth();
+
+        let booking = storage::get_accessibility_booking(&env, booking_id)?;
+        if booking.attendee != attendee {
+            return Err(LumentixError::Unauthorized);
+        }
+        if !Self::is_accessibility_booking_active(&booking) {
+            return Err(LumentixError::AccessibilityBookingInactive);
+        }
+
+        let mut ticket = storage::get_ticket(&env, booking.ticket_id)?;
+        let mut event = storage::get_event(&env, booking.event_id)?;
+        Self::release_ticket_accessibility(&env, booking.ticket_id, &mut ticket, &mut event)?;
+        storage::set_ticket(&env, booking.ticket_id, &ticket);
+        storage::set_event(&env, booking.event_id, &event);
+
+        Ok(())
+    }
+
+    /// Choose whether accessibility bookings follow a ticket when it is
+    /// transferred (`true`) or are released back to inventory (`false`,
+    /// the default). Only the organizer can call this.
+    pub fn set_accessibility_transfer_rule(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        carry_on_transfer: bool,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        storage::set_accessibility_carry_on_transfer(&env, event_id, carry_on_transfer);
+        Ok(())
+    }
+
+    /// Whether accessibility bookings follow tickets on transfer for an event.
+    pub fn get_accessibility_transfer_rule(env: Env, event_id: u64) -> bool {
+        storage::get_accessibility_carry_on_transfer(&env, event_id)
+    }
+
+    fn is_accessibility_booking_active(booking: &AccessibilityBooking) -> bool {
+        booking.status == AccessibilityBookingStatus::Pending
+            || booking.status == AccessibilityBookingStatus::Approved
+    }
+
+    /// Release a ticket's active accessibility booking: return the slot to
+    /// inventory, free the linked seat and revoke an unused companion ticket.
+    /// The caller persists `ticket` and `event`.
+    fn release_ticket_accessibility(
+        env: &Env,
+        ticket_id: u64,
+        ticket: &mut Ticket,
+        event: &mut Event,
+    ) -> Result<(), LumentixError> {
+        let booking_id = match storage::get_ticket_accessibility_booking(env, ticket_id) {
+            Some(id) => id,
+            None => return Ok(()),
+        };
+        let mut booking = storage::get_accessibility_booking(env, booking_id)?;
+        if !Self::is_accessibility_booking_active(&booking) {
+            return Ok(());
+        }
+
+        Self::release_accommodation(env, booking.event_id, &booking.accommodation_type);
+
+        if let Some(seat_id) = booking.seat_id.clone() {
+            if let Ok(mut seat) = storage::get_seat(env, booking.event_id, &seat_id) {
+                seat.occupied = false;
+                storage::set_seat(env, booking.event_id, &seat_id, &seat);
+            }
+            if ticket.seat_id == Some(seat_id) {
+                ticket.seat_id = None;
+            }
+        }
+        ticket.accessibility_type = None;
+
+        if let Some(companion_id) = booking.companion_ticket_id {
+            let mut companion = storage::get_ticket(env, companion_id)?;
+            if !companion.used && !companion.revoked {
+                companion.revoked = true;
+                storage::set_ticket(env, companion_id, &companion);
+                event.tickets_sold = event.tickets_sold.saturating_sub(1);
+            }
+        }
+
+        booking.status = AccessibilityBookingStatus::Cancelled;
+        storage::set_accessibility_booking(env, booking_id, &booking);
+
+        AccessibilityBookingReleased::emit(env, booking_id, booking.event_id, ticket_id);
+
+        Ok(())
+    }
+
+    /// Carry a ticket's accessibility booking over to its new owner, or
+    /// release it, depending on the event's transfer policy.
+    fn apply_accessibility_transfer_rule(
+        env: &Env,
+        ticket_id: u64,
+        ticket: &mut Ticket,
+        to: &Address,
+    ) {
+        let booking_id = match storage::get_ticket_accessibility_booking(env, ticket_id) {
+            Some(id) => id,
+            None => return,
+        };
+        let mut booking = match storage::get_accessibility_booking(env, booking_id) {
+            Ok(booking) => booking,
+            Err(_) => return,
+        };
+        if !Self::is_accessibility_booking_active(&booking) {
+            return;
+        }
+
+        if storage::get_accessibility_carry_on_transfer(env, ticket.event_id) {
+            booking.attendee = to.clone();
+            storage::set_accessibility_booking(env, booking_id, &booking);
+
+            // The companion ticket travels with the booking, unless it was
+            // already transferred away on its own
+            if let Some(companion_id) = booking.companion_ticket_id {
+                if let Ok(mut companion) = storage::get_ticket(env, companion_id) {
+                    let held = companion.owner == ticket.owner;
+                    if held && !companion.used && !companion.refunded && !companion.revoked {
+                        let from = companion.owner.clone();
+                        companion.owner = to.clone();
+                        storage::set_ticket(env, companion_id, &companion);
+                        storage::append_ticket_transfer_history(
+                            env,
+                            companion_id,
+                            TicketTransferRecord {
+                                from: from.clone(),
+                                to: to.clone(),
+                                timestamp: env.ledger().timestamp(),
+                            },
+                        );
+                        TicketTransferred::emit(
+                            env,
+                            companion_id,
+                            ticket.event_id,
+                            from,
+                            to.clone(),
+                        );
+                    }
+                }
+            }
+        } else if let Ok(mut event) = storage::get_event(env, ticket.event_id) {
+            if Self::release_ticket_accessibility(env, ticket_id, ticket, &mut event).is_ok() {
+                storage::set_event(env, ticket.event_id, &event);
+            }
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // MULTI-CURRENCY SUPPORT
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Set a currency oracle price feed, quoted at `CURRENCY_PRICE_DECIMALS`.
+    /// Only the admin can register a currency.
+    pub fn set_currency_oracle(
+        env: Env,
+        admin: Address,
+        code: String,
+        decimals: u32,
+        oracle_price: i128,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "set_currency_oracle",
+            vec![&env, code.into_val(&env), decimals.into_val(&env), oracle_price.into_val(&env)],
+        )?;
+
+        validation::validate_currency_code(&code)?;
+
+        // Keep any guards configured for an existing currency
+        let (max_staleness, max_deviation_bps) = match storage::get_currency_config(&env, &code) {
+            Ok(existing) => (existing.max_staleness, existing.max_deviation_bps),
+            Err(_) => (0, 0),
+        };
+
+        let config = CurrencyConfig {
+            code: code.clone(),
+            decimals,
+            oracle_price,
+            last_updated: env.ledger().timestamp(),
+            max_staleness,
+            max_deviation_bps,
+        };
+
+        storage::set_currency_config(&env, &code, &config);
+
+        OraclePriceUpdated::emit(&env, code, oracle_price, env.ledger().timestamp());
+
+        Ok(())
+    }
+
+    /// Set the currency for an event. Only the organizer can set it.
+    /// The currency must have been registered via set_currency_oracle.
+    pub fn set_event_currency(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        currency: String,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let mut event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        validation::validate_currency_code(&currency)?;
+
+        if event.status == EventStatus::Completed || event.status == EventStatus::Cancelled {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        if !storage::has_currency(&env, &currency) {
+            return Err(LumentixError::UnsupportedCurrency);
+        }
+
+        event.currency = currency.clone();
+        storage::set_event(&env, event_id, &event);
+
+        EventCurrencySet::emit(&env, event_id, currency);
+
+        Ok(())
+    }
+
+    /// Convert a price from one currency to another using oracle price feeds.
+    /// Rejects the conversion when either price is stale or the feed has jumped
+    /// beyond its configured deviation.
+    pub fn convert_price(
+        env: Env,
+        from_currency: String,
+        to_currency: String,
+        amount: i128,
+    ) -> Result<i128, LumentixError> {
+        if from_currency == to_currency {
+            return Ok(amount);
+        }
+
+        let from_config = storage::get_currency_config(&env, &from_currency)?;
+        let to_config = storage::get_currency_config(&env, &to_currency)?;
+
+        let from_price = Self::current_currency_price(&env, &from_config)?;
+        let to_price = Self::current_currency_price(&env, &to_config)?;
+
+        let converted = amount * from_price / to_price;
+
+        if converted <= 0 && amount > 0 {
+            return Err(LumentixError::CurrencyConversionError);
+        }
+
+        Ok(converted)
+    }
+
+    /// Handle currency fluctuation by updating oracle price and returning new converted amount.
+    /// Price quotes already issued keep their locked amount.
+    pub fn handle_currency_fluctuation(
+        env: Env,
+        admin: Address,
+        currency: String,
+        new_oracle_price: i128,
+        amount: i128,
+        to_currency: String,
+    ) -> Result<i128, LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "handle_currency_fluctuation",
+            vec![
+                &env,
+                currency.into_val(&env),
+                new_oracle_price.into_val(&env),
+                amount.into_val(&env),
+                to_currency.into_val(&env),
+            ],
+        )?;
+
+        if new_oracle_price <= 0 {
+            return Err(LumentixError::OraclePriceNotFound);
+        }
+
+        let mut config = storage::get_currency_config(&env, &currency)?;
+        config.oracle_price = new_oracle_price;
+        config.last_updated = env.ledger().timestamp();
+        storage::set_currency_config(&env, &currency, &config);
+
+        OraclePriceUpdated::emit(
+            &env,
+            currency.clone(),
+            new_oracle_price,
+            env.ledger().timestamp(),
+        );
+
+        Self::convert_price(env, currency, to_currency, amount)
+    }
+
+    /// Get the currency config for a given code.
+    pub fn get_currency_config(env: Env, code: String) -> Result<CurrencyConfig, LumentixError> {
+        storage::get_currency_config(&env, &code)
+    }
+
+    /// Point a registered currency at an external SEP-40 price feed. The admin
+    /// price set via set_currency_oracle remains the fallback. Only the admin can call this.
+    pub fn set_currency_feed(
+        env: Env,
+        admin: Address,
+        code: String,
+        oracle: Address,
+        asset: OracleAsset,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "set_currency_feed",
+            vec![&env, code.into_val(&env), oracle.into_val(&env), asset.into_val(&env)],
+        )?;
+
+        storage::get_currency_config(&env, &code)?;
+        storage::set_currency_feed(
+            &env,
+            &code,
+            &OracleFeed {
+                oracle: oracle.clone(),
+                asset,
+            },
+        );
+
+        OracleFeedConfigured::emit(&env, code, oracle);
+
+        Ok(())
+    }
+
+    /// Configure how old a price may be (seconds) and how far a feed reading may
+    /// move from the last accepted price (basis points). Zero disables a guard.
+    /// Only the admin can call this.
+    pub fn set_currency_price_guards(
+        env: Env,
+        admin: Address,
+        code: String,
+        max_staleness: u64,
+        max_deviation_bps: u32,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "set_currency_price_guards",
+            vec![
+                &env,
+                code.into_val(&env),
+                max_staleness.into_val(&env),
+                max_deviation_bps.into_val(&env),
+            ],
+        )?;
+
+        let mut config = storage::get_currency_config(&env, &code)?;
+        config.max_staleness = max_staleness;
+        config.max_deviation_bps = max_deviation_bps;
+        storage::set_currency_config(&env, &code, &config);
+
+        CurrencyPriceGuardsUpdated::emit(&env, code, max_staleness, max_deviation_bps);
+
+        Ok(())
+    }
+
+    /// Record the current feed price as the last accepted price for a currency.
+    /// Anyone can call this; the same staleness and deviation guards apply.
+    pub fn refresh_currency_price(env: Env, code: String) -> Result<i128, LumentixError> {
+        let mut config = storage::get_currency_config(&env, &code)?;
+        if storage::get_currency_feed(&env, &code).is_none() {
+            return Err(LumentixError::OraclePriceNotFound);
+        }
+
+        let data = Self::read_feed_price(&env, &config)?.ok_or(LumentixError::OraclePriceStale)?;
+        config.oracle_price = data.price;
+        config.last_updated = data.timestamp;
+        storage::set_currency_config(&env, &code, &config);
+
+        OraclePriceUpdated::emit(&env, code, data.price, data.timestamp);
+
+        Ok(data.price)
+    }
+
+    /// Get the price currently used for conversions of a currency.
+    pub fn get_currency_price(env: Env, code: String) -> Result<i128, LumentixError> {
+        let config = storage::get_currency_config(&env, &code)?;
+        Self::current_currency_price(&env, &config)
+    }
+
+    /// Feed price when usable, otherwise the last accepted (admin) price.
+    fn current_currency_price(env: &Env, config: &CurrencyConfig) -> Result<i128, LumentixError> {
+        if let Some(data) = Self::read_feed_price(env, config)? {
+            return Ok(data.price);
+        }
+
+        if config.oracle_price <= 0 {
+            return Err(LumentixError::OraclePriceNotFound);
+        }
+
+        let age = env.ledger().timestamp().saturating_sub(config.last_updated);
+        if config.max_staleness > 0 && age > config.max_staleness {
+            return Err(LumentixError::OraclePriceStale);
+        }
+
+        Ok(config.oracle_price)
+    }
+
+    /// Query the currency's external feed, rescaled from the feed's `decimals()`
+    /// to `CURRENCY_PRICE_DECIMALS`. Returns `None` when there is no feed or it has
+    /// no fresh, positive price, so callers can fall back to the admin price.
+    fn read_feed_price(
+        env: &Env,
+        config: &CurrencyConfig,
+    ) -> Result<Option<OraclePriceData>, LumentixError> {
+        let feed = match storage::get_currency_feed(env, &config.code) {
+            Some(feed) => feed,
+            None => return Ok(None),
+        };
+
+        let oracle = PriceOracleClient::new(env, &feed.oracle);
+        let mut data = match oracle.try_lastprice(&feed.asset) {
+            Ok(Ok(Some(data))) => data,
+            _ => return Ok(None),
+        };
+        let feed_decimals = match oracle.try_decimals() {
+            Ok(Ok(decimals)) => decimals,
+            _ => return Ok(None),
+        };
+
+        data.price = match Self::normalize_feed_price(data.price, feed_decimals) {
+            Some(price) if price > 0 => price,
+            _ => return Ok(None),
+        };
+
+        let age = env.ledger().timestamp().saturating_sub(data.timestamp);
+        if config.max_staleness > 0 && age > config.max_staleness {
+            return Ok(None);
+        }
+
+        if config.max_deviation_bps > 0 && config.oracle_price > 0 {
+            let deviation_bps = (data.price - config.oracle_price).abs() * 10000 / config.oracle_price;
+            if deviation_bps > config.max_deviation_bps as i128 {
+                return Err(LumentixError::OraclePriceDeviation);
+            }
+        }
+
+        Ok(Some(data))
+    }
+
+    fn normalize_feed_price(price: i128, feed_decimals: u32) -> Option<i128> {
+        if feed_decimals >= CURRENCY_PRICE_DECIMALS {
+            let scale = 10i128.checked_pow(feed_decimals - CURRENCY_PRICE_DECIMALS)?;
+            Some(price / scale)
+        } else {
+            let scale = 10i128.checked_pow(CURRENCY_PRICE_DECIMALS - feed_decimals)?;
+            price.checked_mul(scale)
+        }
+    }
+
+    /// Get the event currency.
+    pub fn get_event_currency(env: Env, event_id: u64) -> Result<String, LumentixError> {
+        let event = storage::get_event(&env, event_id)?;
+        Ok(event.currency)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // MULTI-TOKEN PAYMENTS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Accept a SAC token as payment for an event. Only the organizer can call this.
+    /// `currency` must have been registered via set_currency_oracle; ticket prices in
+    /// this token are converted from the event currency through its oracle price.
+    pub fn add_accepted_token(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        token: Address,
+        currency: String,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        if event.status == EventStatus::Completed || event.status == EventStatus::Cancelled {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        if !storage::has_currency(&env, &currency) {
+            return Err(LumentixError::UnsupportedCurrency);
+        }
+
+        let mut accepted = storage::get_accepted_tokens(&env, event_id);
+        if accepted.iter().any(|entry| entry.token == token) {
+            return Err(LumentixError::TokenAlreadyAccepted);
+        }
+
+        accepted.push_back(AcceptedToken {
+            token: token.clone(),
+            currency: currency.clone(),
+        });
+        storage::set_accepted_tokens(&env, event_id, &accepted);
+
+        AcceptedTokenAdded::emit(&env, event_id, token, currency);
+
+        Ok(())
+    }
+
+    /// Stop accepting a token for an event. Escrow already held in the token is
+    /// still refunded and released in it.
+    pub fn remove_accepted_token(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        token: Address,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let accepted = storage::get_accepted_tokens(&env, event_id);
+        let mut remaining = Vec::new(&env);
+        for entry in accepted.iter() {
+            if entry.token != token {
+                remaining.push_back(entry);
+            }
+        }
+
+        if remaining.len() == accepted.len() {
+            return Err(LumentixError::TokenNotAccepted);
+        }
+
+        storage::set_accepted_tokens(&env, event_id, &remaining);
+
+        AcceptedTokenRemoved::emit(&env, event_id, token);
+
+        Ok(())
+    }
+
+    /// Get the tokens accepted as payment for an event.
+    pub fn get_accepted_tokens(env: Env, event_id: u64) -> Result<Vec<AcceptedToken>, LumentixError> {
+        storage::get_event(&env, event_id)?;
+        Ok(storage::get_accepted_tokens(&env, event_id))
+    }
+
+    /// Quote the current ticket price of an event in one of its accepted tokens.
+    /// The quoted amount is locked for the buyer in temporary storage until
+    /// `expires_at`; redeem it by passing its id to `purchase_ticket`. Later oracle
+    /// updates (including `handle_currency_fluctuation`) never change an issued quote.
+    pub fn quote_ticket_price(
+        env: Env,
+        buyer: Address,
+        event_id: u64,
+        token: Address,
+    ) -> Result<PriceQuote, LumentixError> {
+        buyer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.status != EventStatus::Published {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        let accepted = Self::find_accepted_token(&env, event_id, &token)?;
+        let base_price = Self::calculate_dynamic_price(env.clone(), event_id, 0, 0)?;
+        let amount = Self::convert_price(env.clone(), event.currency, accepted.currency, base_price)?;
+
+        let quote_id = storage::get_next_quote_id(&env);
+        storage::increment_quote_id(&env);
+
+        let quote = PriceQuote {
+            quote_id,
+            event_id,
+            buyer: buyer.clone(),
+            token: token.clone(),
+            amount,
+            expires_at: env.ledger().timestamp() + storage::get_quote_lock_seconds(&env),
+        };
+        storage::set_price_quote(&env, &quote);
+
+        PriceQuoteIssued::emit(&env, quote_id, event_id, buyer, token, amount, quote.expires_at);
+
+        Ok(quote)
+    }
+
+    /// Consume a buyer's price quote for `event_id`, returning the quoted
+    /// token and the locked amount.
+    fn redeem_price_quote(
+        env: &Env,
+        buyer: &Address,
+        event_id: u64,
+        quote_id: u64,
+    ) -> Result<(AcceptedToken, i128), LumentixError> {
+        let quote = storage::get_price_quote(env, quote_id)?;
+        if quote.event_id != event_id {
+            return Err(LumentixError::PriceQuoteNotFound);
+        }
+        if quote.buyer != *buyer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        if env.ledger().timestamp() > quote.expires_at {
+            return Err(LumentixError::PriceQuoteExpired);
+        }
+
+        let accepted = Self::find_accepted_token(env, event_id, &quote.token)?;
+        storage::remove_price_quote(env, quote_id);
+
+        Ok((accepted, quote.amount))
+    }
+
+    /// Get a price quote that has not been redeemed yet.
+    pub fn get_price_quote(env: Env, quote_id: u64) -> Result<PriceQuote, LumentixError> {
+        storage::get_price_quote(&env, quote_id)
+    }
+
+    /// Set how long ticket price quotes stay locked, in seconds. Capped at
+    /// MAX_QUOTE_LOCK_SECS so a quote never outlives its temporary entry.
+    /// Only the admin can call this.
+    pub fn set_quote_lock_duration(
+        env: Env,
+        admin: Address,
+        seconds: u64,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "set_quote_lock_duration",
+            vec![&env, seconds.into_val(&env)],
+        )?;
+
+        if seconds == 0 || seconds > MAX_QUOTE_LOCK_SECS {
+            return Err(LumentixError::InvalidAmount);
+        }
+
+        storage::set_quote_lock_seconds(&env, seconds);
+
+        Ok(())
+    }
+
+    /// Purchase a ticket paying in one of the event's accepted tokens.
+    /// The platform fee and escrow are held in that token. A checkout carbon
+    /// surcharge opted into with `offset_opt_in` is still paid in the
+    /// platform token, like the rest of the carbon sub-escrow.
+    pub fn purchase_ticket_with_token(
+        env: Env,
+        buyer: Address,
+        event_id: u64,
+        token: Address,
+        amount: i128,
+        offset_opt_in: bool,
+    ) -> Result<u64, LumentixError> {
+        buyer.require_auth();
+
+        let accepted = Self::find_accepted_token(&env, event_id, &token)?;
+        let ticket_id = Self::purchase_ticket_internal(
+            env.clone(),
+            buyer.clone(),
+            event_id,
+            amount,
+            Some(accepted),
+            None,
+        )?;
+        if offset_opt_in {
+            Self::collect_checkout_offset(&env, &buyer, event_id, ticket_id)?;
+        }
+        Ok(ticket_id)
+    }
+
+    /// Get the escrow balance held for an event in a specific accepted token.
+    pub fn get_token_escrow_balance(
+        env: Env,
+        event_id: u64,
+        token: Address,
+    ) -> Result<i128, LumentixError> {
+        storage::get_event(&env, event_id)?;
+        Ok(storage::get_token_escrow(&env, event_id, &token))
+    }
+
+    /// Get the token and amount a ticket was paid with, if it was bought with an accepted token.
+    pub fn get_ticket_payment(env: Env, ticket_id: u64) -> Option<TicketPayment> {
+        storage::get_ticket_payment(&env, ticket_id)
+    }
+
+    /// Withdraw platform fees collected in an accepted token. Only the admin can call this.
+    pub fn withdraw_token_platform_fees(
+        env: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<i128, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_admin(
+            &env,
+            &admin,
+            "withdraw_token_platform_fees",
+            vec![&env, token.into_val(&env)],
+        )?;
+
+        // Fees owed back to refunded holders stay in the contract
+        let debt = storage::get_total_platform_fee_debt(&env, &Some(token.clone()));
+        let balance = storage::get_token_platform_balance(&env, &token) - debt;
+        if balance <= 0 {
+            return Err(LumentixError::NoPlatformFees);
+        }
+
+        storage::set_token_platform_balance(&env, &token, debt);
+
+        let token_client = soroban_sdk::token::Client::new(&env, &token);
+        token_client.transfer(&env.current_contract_address(), &admin, &balance);
+
+        TokenPlatformFeesWithdrawn::emit(&env, admin, token, balance);
+
+        Ok(balance)
+    }
+
+    fn find_accepted_token(
+        env: &Env,
+        event_id: u64,
+        token: &Address,
+    ) -> Result<AcceptedToken, LumentixError> {
+        storage::get_accepted_tokens(env, event_id)
+            .iter()
+            .find(|entry| entry.token == *token)
+            .ok_or(LumentixError::TokenNotAccepted)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // SEAT SELECTION / VENUE MAPPING
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Create a venue layout for an event. Only the organizer can call this.
+    pub fn create_venue_layout(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        sections: Vec<VenueSection>,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let layout = VenueLayout {
+            sections: sections.clone(),
+        };
+
+        storage::set_venue_layout(&env, event_id, &layout);
+
+        // Generate individual seat entries
+        for section in sections.iter() {
+            for row in 1..=section.rows {
+                for num in 1..=section.seats_per_row {
+                    let seat_id = Self::build_seat_id(&env, &section.name, row, num);
+                    let seat = Seat {
+                        section: section.name.clone(),
+                        row,
+                        number: num,
+                        occupied: false,
+                        held_until: 0,
+                        held_by: None,
+                        x: None,
+                        y: None,
+                    };
+                    storage::set_seat(&env, event_id, &seat_id, &seat);
+                }
+            }
+        }
+
+        VenueLayoutCreated::emit(&env, event_id, sections.len());
+
+        Ok(())
+    }
+
+    /// Select (hold) a seat for a buyer. The hold expires after the given duration.
+    pub fn select_seat(
+        env: Env,
+        buyer: Address,
+        event_id: u64,
+        section: String,
+        row: u32,
+        number: u32,
+        hold_duration: u64,
+    ) -> Result<String, LumentixError> {
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
+        buyer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.status != EventStatus::Published {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        if event.paused {
+            return Err(LumentixError::EventPaused);
+        }
+
+        let seat_id = Self::build_seat_id(&env, &section, row, number);
+        let mut seat = storage::get_seat(&env, event_id, &seat_id)?;
+
+        if seat.occupied {
+            return Err(LumentixError::SeatAlreadyOccupied);
+        }
+
+        let now = env.ledger().timestamp();
+        if seat.held_until > now {
+            return Err(LumentixError::SeatHeld);
+        }
+
+        seat.held_by = Some(buyer.clone());
+        seat.held_until = now + hold_duration;
+        storage::set_seat(&env, event_id, &seat_id, &seat);
+
+        SeatSelected::emit(&env, event_id, seat_id.clone(), buyer, seat.held_until);
+
+        Ok(seat_id)
+    }
+
+    /// Release a held seat. Only the holder or the organizer can release it.
+    pub fn release_seat_hold(
+        env: Env,
+        caller: Address,
+        event_id: u64,
+        section: String,
+        row: u32,
+        number: u32,
+    ) -> Result<(), LumentixError> {
+        caller.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+
+        let seat_id = Self::build_seat_id(&env, &section, row, number);
+        let mut seat = storage::get_seat(&env, event_id, &seat_id)?;
+
+        let is_holder = match &seat.held_by {
+            Some(addr) => addr == &caller,
+            None => false,
+        };
+        let is_organizer = event.organizer == caller;
+
+        if !is_holder && !is_organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        seat.held_by = None;
+        seat.held_until = 0;
+        storage::set_seat(&env, event_id, &seat_id, &seat);
+
+        SeatHoldReleased::emit(&env, event_id, seat_id);
+
+        Ok(())
+    }
+
+    /// Validate that a seat is available for booking.
+    pub fn validate_seat_availability(
+        env: Env,
+        event_id: u64,
+        section: String,
+        row: u32,
+        number: u32,
+    ) -> Result<bool, LumentixError> {
+        let seat_id = Self::build_seat_id(&env, &section, row, number);
+        let seat = storage::get_seat(&env, event_id, &seat_id)?;
+
+        if seat.occupied {
+            return Ok(false);
+        }
+
+        let now = env.ledger().timestamp();
+        if seat.held_until > now {
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Get the venue layout for an event.
+    pub fn get_venue_layout(env: Env, event_id: u64) -> Result<VenueLayout, LumentixError> {
+        storage::get_venue_layout(&env, event_id)
+    }
+
+    /// Get seat information.
+    pub fn get_seat_info(
+        env: Env,
+        event_id: u64,
+        section: String,
+        row: u32,
+        number: u32,
+    ) -> Result<Seat, LumentixError> {
+        let seat_id = Self::build_seat_id(&env, &section, row, number);
+        storage::get_seat(&env, event_id, &seat_id)
+    }
+
+    // ── Internal helpers ─────────────────────────────────────────────────────
+
+    fn default_pricing_schedule() -> PricingSchedule {
+        PricingSchedule {
+            early_bird_multiplier_bps: 8_000,
+            standard_multiplier_bps: 10_000,
+            late_multiplier_bps: 10_000,
+            last_minute_multiplier_bps: 15_000,
+            early_bird_days: 30,
+            standard_days: 7,
+            last_minute_hours: 24,
+        }
+    }
+
+    fn resolve_price_tier(env: &Env, event_id: u64, event: &Event) -> Result<PriceTier, LumentixError> {
+        let schedule = storage::get_pricing_schedule(env, event_id)
+            .unwrap_or_else(|| Self::default_pricing_schedule());
+        let now = env.ledger().timestamp();
+        let time_until_start = event.start_time.saturating_sub(now);
+        let early_cutoff = schedule.early_bird_days as u64 * ONE_DAY_SECONDS;
+        let standard_cutoff = schedule.standard_days as u64 * ONE_DAY_SECONDS;
+        let last_minute_cutoff = schedule.last_minute_hours as u64 * 3_600;
+
+        if time_until_start > early_cutoff {
+            Ok(PriceTier::EarlyBird)
+        } else if time_until_start >= standard_cutoff {
+            Ok(PriceTier::Standard)
+        } else if time_until_start <= last_minute_cutoff {
+            Ok(PriceTier::LastMinute)
+        } else {
+            Ok(PriceTier::Late)
+        }
+    }
+
+    fn estimate_mint_resource_units(quantity: u32) -> u64 {
+        MINT_BASE_RESOURCE_UNITS + (quantity as u64 * MINT_PER_TICKET_RESOURCE_UNITS)
+    }
+    fn add_offer_recipient_if_missing(env: &Env, event_id: u64, buyer: &Address) {
+        let mut recipients = storage::get_waitlist_offer_recipients(env, event_id);
+        for existing in recipients.iter() {
+            if existing == *buyer {
+                return;
+            }
+        }
+        recipients.push_back(buyer.clone());
+        storage::set_waitlist_offer_recipients(env, event_id, &recipients);
+    }
+
+    fn cleanup_expired_waitlist_offers(env: &Env, event_id: u64, now: u64) {
+        let recipients = storage::get_waitlist_offer_recipients(env, event_id);
+        let mut active_recipients = Vec::<Address>::new(env);
+        let mut reserved = storage::get_waitlist_reserved(env, event_id);
+
+        for buyer in recipients.iter() {
+            if let Some(offer) = storage::get_waitlist_offer(env, event_id, &buyer) {
+                if offer.quantity == 0 || offer.expires_at <= now {
+                    reserved = reserved.saturating_sub(offer.quantity);
+                    storage::remove_waitlist_offer(env, event_id, &buyer);
+                } else {
+                    active_recipients.push_back(buyer);
+                }
+            }
+        }
+
+        storage::set_waitlist_offer_recipients(env, event_id, &active_recipients);
+        storage::set_waitlist_reserved(env, event_id, reserved);
+    }
+
+    fn process_waitlist_queue_internal(env: &Env, event_id: u64, mut available: u32) -> u32 {
+        if available == 0 {
+            return 0;
+        }
+
+        let event = match storage::get_event(env, event_id) {
+            Ok(event) => event,
+            Err(_) => return 0,
+        };
+        if event.status != EventStatus::Published {
+            return 0;
+        }
+
+        let mut queue = storage::get_waitlist_queue(env, event_id);
+        let mut next_queue = Vec::<Address>::new(env);
+        let mut processed: u32 = 0;
+        let now = env.ledger().timestamp();
+
+        for buyer in queue.iter() {
+            if available == 0 {
+                next_queue.push_back(buyer);
+                continue;
+            }
+
+            let expires_at = now + ONE_DAY_SECONDS;
+            let offer = WaitlistOffer {
+                quantity: 1,
+                expires_at,
+            };
+            storage::set_waitlist_offer(env, event_id, &buyer, &offer);
+            Self::add_offer_recipient_if_missing(env, event_id, &buyer);
+
+            let reserved = storage::get_waitlist_reserved(env, event_id);
+            storage::set_waitlist_reserved(env, event_id, reserved.saturating_add(1));
+
+            WaitlistAvailabilityNotified::emit(env, event_id, buyer, 1, expires_at);
+            available -= 1;
+            processed += 1;
+        }
+
+        queue = next_queue;
+        storage::set_waitlist_queue(env, event_id, &queue);
+        processed
+    }
+
+    fn build_seat_id(env: &Env, section: &String, row: u32, number: u32) -> String {
+        let sec = section.to_bytes();
+        let mut buf = [0u8; 64];
+        let mut i = 0;
+        for b in sec.iter() {
+            if i < 62 {
+                buf[i] = b;
+                i += 1;
+            }
+        }
+        buf[i] = b'-';
+        i += 1;
+        let r_str = match row {
+            0 => "0",
+            1 => "1",
+            2 => "2",
+            3 => "3",
+            4 => "4",
+            5 => "5",
+            6 => "6",
+            7 => "7",
+            8 => "8",
+            9 => "9",
+            10 => "10",
+            _ => "0",
+        };
+        for b in r_str.bytes() {
+            if i < 63 {
+                buf[i] = b;
+                i += 1;
+            }
+        }
+        buf[i] = b'-';
+        i += 1;
+        let n_str = match number {
+            0 => "0",
+            1 => "1",
+            2 => "2",
+            3 => "3",
+            4 => "4",
+            5 => "5",
+            6 => "6",
+            7 => "7",
+            8 => "8",
+            9 => "9",
+            10 => "10",
+            _ => "0",
+        };
+        for b in n_str.bytes() {
+            if i < 64 {
+                buf[i] = b;
+                i += 1;
+            }
+        }
+        let valid = core::str::from_utf8(&buf[..i]).unwrap_or("");
+        String::from_str(env, valid)
+    }
+
+    // ── Insurance Functions ─────────────────────────────────────────────────────
+
+    /// Create an insurance product. Only the admin can define products.
+    pub fn create_insurance_product(
+        env: Env,
+        admin: Address,
+        name: String,
+        premium_bps: u32,
+        coverage_bps: u32,
+        purchase_cutoff_secs: u64,
+        covered_reasons: Vec<CancellationReason>,
+    ) -> Result<u32, LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "create_insurance_product",
+            vec![
+                &env,
+                name.into_val(&env),
+                premium_bps.into_val(&env),
+                coverage_bps.into_val(&env),
+                purchase_cutoff_secs.into_val(&env),
+                covered_reasons.into_val(&env),
+            ],
+        )?;
+        Self::validate_insurance_product(&name, premium_bps, coverage_bps, &covered_reasons)?;
+
+        let product_id = storage::get_next_insurance_product_id(&env);
+        storage::increment_insurance_product_id(&env);
+
+        let product = InsuranceProduct {
+            id: product_id,
+            name,
+            premium_bps,
+            coverage_bps,
+            purchase_cutoff_secs,
+            covered_reasons,
+            active: true,
+        };
+        storage::set_insurance_product(&env, &product);
+
+        InsuranceProductConfigured::emit(&env, product_id, premium_bps, coverage_bps, true);
+
+        Ok(product_id)
+    }
+
+    /// Update an insurance product's terms for future policies, or retire it.
+    /// Existing policies keep the terms they were sold under.
+    pub fn update_insurance_product(
+        env: Env,
+        admin: Address,
+        product_id: u32,
+        premium_bps: u32,
+        coverage_bps: u32,
+        purchase_cutoff_secs: u64,
+        covered_reasons: Vec<CancellationReason>,
+        active: bool,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "update_insurance_product",
+            vec![
+                &env,
+                product_id.into_val(&env),
+                premium_bps.into_val(&env),
+                coverage_bps.into_val(&env),
+                purchase_cutoff_secs.into_val(&env),
+                covered_reasons.into_val(&env),
+                active.into_val(&env),
+            ],
+        )?;
+
+        let mut product = storage::get_insurance_product(&env, product_id)?;
+        Self::validate_insurance_product(
+            &product.name,
+            premium_bps,
+            coverage_bps,
+            &covered_reasons,
+        )?;
+
+        product.premium_bps = premium_bps;
+        product.coverage_bps = coverage_bps;
+        product.purchase_cutoff_secs = purchase_cutoff_secs;
+        product.covered_reasons = covered_reasons;
+        product.active = active;
+        storage::set_insurance_product(&env, &product);
+
+        InsuranceProductConfigured::emit(&env, product_id, premium_bps, coverage_bps, active);
+
+        Ok(())
+    }
+
+    /// Get an insurance product.
+    pub fn get_insurance_product(
+        env: Env,
+        product_id: u32,
+    ) -> Result<InsuranceProduct, LumentixError> {
+        storage::get_insurance_product(&env, product_id)
+    }
+
+    fn validate_insurance_product(
+        name: &String,
+        premium_bps: u32,
+        coverage_bps: u32,
+        covered_reasons: &Vec<CancellationReason>,
+    ) -> Result<(), LumentixError> {
+        if name.is_empty()
+            || premium_bps == 0
+            || coverage_bps == 0
+            || coverage_bps > 10_000
+            || covered_reasons.is_empty()
+        {
+            return Err(LumentixError::InvalidInsuranceProduct);
+        }
+        Ok(())
+    }
+
+    /// Amount actually paid for a ticket in the settlement token, including
+    /// any VIP upgrade. Tickets paid in another accepted token cannot be
+    /// insured because the pool settles in the contract token.
+    fn insurable_ticket_value(
+        env: &Env,
+        ticket: &Ticket,
+        event: &Event,
+    ) -> Result<i128, LumentixError> {
+        if storage::get_ticket_payment(env, ticket.id).is_some() {
+            return Err(LumentixError::InvalidInsurancePremium);
+        }
+        if storage::is_comp_ticket(env, ticket.id) {
+            return Ok(0);
+        }
+
+        let base = storage::get_ticket_price_paid(env, ticket.id)
+            .or(storage::get_virtual_ticket_price(env, ticket.id))
+            .unwrap_or(event.ticket_price);
+        let vip = match storage::get_vip_purchase(env, ticket.id) {
+            Some(purchase) => purchase.amount_paid,
+            None => 0,
+        };
+        Ok(base + vip)
+    }
+
+    /// Move a ticket's insurance policy to the ticket's new owner.
+    fn transfer_insurance_policy(env: &Env, ticket_id: u64, to: &Address) {
+        if let Ok(mut policy) = storage::get_insurance_policy_by_ticket(env, ticket_id) {
+            if policy.active {
+                policy.holder = to.clone();
+                storage::set_insurance_policy(env, policy.id, &policy);
+            }
+        }
+    }
+
+    /// Purchase insurance for a ticket under an insurance product. Premium
+    /// and coverage are the product's share of what was paid for the ticket.
+    /// Sales close `purchase_cutoff_secs` before the event starts, and
+    /// while the event is paused or no longer published.
+    pub fn purchase_insurance(
+        env: Env,
+        ticket_id: u64,
+        buyer: Address,
+        product_id: u32,
+    ) -> Result<u64, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
+        buyer.require_auth();
+
+        // Get the ticket
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+
+        // Verify the buyer is the ticket owner
+        if ticket.owner != buyer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        // Check if insurance already purchased for this ticket
+        let _ = storage::get_insurance_policy_by_ticket(&env, ticket_id);
+        if storage::get_insurance_policy_by_ticket(&env, ticket_id).is_ok() {
+            return Err(LumentixError::InsuranceAlreadyPurchased);
+        }
+
+        let product = storage::get_insurance_product(&env, product_id)?;
+        if !product.active {
+            return Err(LumentixError::InsuranceProductNotFound);
+        }
+
+        let event = storage::get_event(&env, ticket.event_id)?;
+        if event.status != EventStatus::Published || event.paused {
+            return Err(LumentixError::InsurancePurchaseClosed);
+        }
+        let now = env.ledger().timestamp();
+        if now.saturating_add(product.purchase_cutoff_secs) > event.start_time {
+            return Err(LumentixError::InsurancePurchaseClosed);
+        }
+
+        let ticket_value = Self::insurable_ticket_value(&env, &ticket, &event)?;
+        let premium = (ticket_value * product.premium_bps as i128) / 10000;
+        let coverage = (ticket_value * product.coverage_bps as i128) / 10000;
+        if premium <= 0 {
+            return Err(LumentixError::InvalidInsurancePremium);
+        }
+
+        // New cover may not push the pool below its reserve ratio
+        let pool = storage::get_insurance_pool(&env);
+        if !Self::insurance_reserve_holds(
+            &env,
+            pool.total_balance + premium,
+            pool.outstanding_coverage + coverage,
+        ) {
+            return Err(LumentixError::InsuranceReserveBreached);
+        }
+
+        // Process token transfer for premium
+        if let Ok(token_address) = storage::get_token_result(&env) {
+            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+            token_client.transfer(&buyer, &env.current_contract_address(), &premium);
+        }
+
+        // Add premium to insurance pool; LPs earn it through the share price
+        storage::add_to_insurance_pool(&env, premium);
+        storage::add_insurance_exposure(&env, ticket.event_id, coverage);
+
+        // Create insurance policy
+        let policy_id = storage::get_next_insurance_policy_id(&env);
+        storage::increment_insurance_policy_id(&env);
+        storage::increment_total_policies(&env);
+
+        let policy = InsurancePolicy {
+            id: policy_id,
+            ticket_id,
+            event_id: ticket.event_id,
+            holder: buyer.clone(),
+            premium_paid: premium,
+            coverage_amount: coverage,
+            purchase_time: now,
+            active: true,
+            claim_processed: false,
+            product_id,
+            paid_out: 0,
+        };
+
+        storage::set_insurance_policy(&env, policy_id, &policy);
+        storage::add_event_insurance_policy(&env, ticket.event_id, policy_id);
+
+        // Emit InsurancePurchased event
+        InsurancePurchased::emit(
+            &env,
+            policy_id,
+            ticket_id,
+            ticket.event_id,
+            buyer,
+            premium,
+            coverage,
+        );
+
+        // Emit InsurancePoolUpdated event
+        let pool = storage::get_insurance_pool(&env);
+        InsurancePoolUpdated::emit(
+            &env,
+            pool.total_balance,
+            pool.total_policies,
+            pool.total_claims_paid,
+        );
+
+        Ok(policy_id)
+    }
+
+    /// Process an insurance claim for a cancelled event.
+    /// Validates the cancellation reason and processes the refund.
+    pub fn process_insurance_claim(
+        env: Env,
+        ticket_id: u64,
+        claimant: Address,
+        cancellation_reason: CancellationReason,
+    ) -> Result<(), LumentixError> {
+        claimant.require_auth();
+
+        // Get the insurance policy
+        let mut policy = storage::get_insurance_policy_by_ticket(&env, ticket_id)?;
+
+        // Verify the claimant is the policy holder
+        if policy.holder != claimant {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        // Check if policy is active
+        if !policy.active {
+            return Err(LumentixError::InsurancePolicyNotActive);
+        }
+
+        // Check if claim already processed
+        if policy.claim_processed {
+            return Err(LumentixError::InsuranceClaimAlreadyProcessed);
+        }
+
+        // The reason must match the one recorded when the event was cancelled
+        Self::validate_cancellation_reason(&env, ticket_id, &cancellation_reason)?;
+        if !Self::policy_covers(&env, &policy, &cancellation_reason)? {
+            return Err(LumentixError::InvalidCancellationReason);
+        }
+
+        if storage::get_insurance_pool(&env).total_balance <= 0 {
+            return Err(LumentixError::InsufficientInsurancePool);
+        }
+        Self::pay_insurance_claim(&env, &mut policy, cancellation_reason)?;
+
+        // Emit InsurancePoolUpdated event
+        let pool = storage::get_insurance_pool(&env);
+        InsurancePoolUpdated::emit(
+            &env,
+            pool.total_balance,
+            pool.total_policies,
+            pool.total_claims_paid,
+        );
+
+        Ok(())
+    }
+
+    /// Validate that a cancellation reason is valid for an insurance claim.
+    /// Only certain reasons qualify for insurance payouts.
+    pub fn validate_cancellation_reason(
+        env: &Env,
+        ticket_id: u64,
+        reason: &CancellationReason,
+    ) -> Result<(), LumentixError> {
+        // Get the insurance policy
+        let policy = storage::get_insurance_policy_by_ticket(env, ticket_id)?;
+
+        // Get the event
+        let event = storage::get_event(env, policy.event_id)?;
+
+        // Only the recorded reason is claimable
+        if let Some(cancellation) = storage::get_event_cancellation(env, policy.event_id) {
+            if cancellation.reason != *reason {
+                return Err(LumentixError::InvalidCancellationReason);
+            }
+        }
+
+        // Validate based on cancellation reason
+        match reason {
+            CancellationReason::EventCancelledByOrganizer => {
+                // Always valid - organizer cancelled the event
+                if event.status != EventStatus::Cancelled {
+                    return Err(LumentixError::InvalidCancellationReason);
+                }
+            }
+            CancellationReason::ForceMajeure => {
+                // Valid if event is cancelled (assumes force majeure led to cancellation)
+                if event.status != EventStatus::Cancelled {
+                    return Err(LumentixError::InvalidCancellationReason);
+                }
+            }
+            CancellationReason::VenueUnavailable => {
+                // Valid if event is cancelled
+                if event.status != EventStatus::Cancelled {
+                    return Err(LumentixError::InvalidCancellationReason);
+                }
+            }
+            CancellationReason::ArtistPerformerUnavailable => {
+                // Valid if event is cancelled
+                if event.status != EventStatus::Cancelled {
+                    return Err(LumentixError::InvalidCancellationReason);
+                }
+            }
+            CancellationReason::HealthSafetyConcerns => {
+                // Valid if event is cancelled
+                if event.status != EventStatus::Cancelled {
+                    return Err(LumentixError::InvalidCancellationReason);
+                }
+            }
+            CancellationReason::GovernmentRestriction => {
+                // Valid if event is cancelled
+                if event.status != EventStatus::Cancelled {
+                    return Err(LumentixError::InvalidCancellationReason);
+                }
+            }
+            CancellationReason::Other => {
+                // "Other" is only valid if event is cancelled
+                // In a production system, you might require additional documentation
+                if event.status != EventStatus::Cancelled {
+                    return Err(LumentixError::InvalidCancellationReason);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Settle up to `max_policies` of a cancelled event's insurance policies
+    /// against the recorded cancellation reason, paying holders directly.
+    /// Resumes where the previous call stopped; anyone can call it.
+    pub fn settle_event_insurance(
+        env: Env,
+        event_id: u64,
+        max_policies: u32,
+    ) -> Result<InsuranceSettlement, LumentixError> {
+        if max_policies == 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.status != EventStatus::Cancelled {
+            return Err(LumentixError::EventNotCancelled);
+        }
+        let cancellation = storage::get_event_cancellation(&env, event_id)
+            .ok_or(LumentixError::CancellationReasonNotRecorded)?;
+
+        let mut settlement =
+            storage::get_insurance_settlement(&env, event_id).unwrap_or(InsuranceSettlement {
+                event_id,
+                next_index: 0,
+                policies_settled: 0,
+                total_paid: 0,
+                complete: false,
+            });
+        if settlement.complete {
+            return Ok(settlement);
+        }
+
+        let policy_ids = storage::get_event_insurance_policies(&env, event_id);
+        let end = policy_ids
+            .len()
+            .min(settlement.next_index.saturating_add(max_policies));
+        for index in settlement.next_index..end {
+            let mut policy = storage::get_insurance_policy(&env, policy_ids.get_unchecked(index))?;
+            if !policy.active || policy.claim_processed {
+                continue;
+            }
+
+            if Self::policy_covers(&env, &policy, &cancellation.reason)? {
+                settlement.total_paid +=
+                    Self::pay_insurance_claim(&env, &mut policy, cancellation.reason.clone())?;
+                settlement.policies_settled += 1;
+            } else {
+                // Not covered: the policy lapses and stops counting as exposure
+                policy.active = false;
+                storage::set_insurance_policy(&env, policy.id, &policy);
+                storage::release_insurance_exposure(&env, event_id, policy.coverage_amount);
+            }
+        }
+        settlement.next_index = end;
+        settlement.complete = end == policy_ids.len();
+        storage::set_insurance_settlement(&env, &settlement);
+
+        let pool = storage::get_insurance_pool(&env);
+        InsurancePoolUpdated::emit(
+            &env,
+            pool.total_balance,
+            pool.total_policies,
+            pool.total_claims_paid,
+        );
+
+        Ok(settlement)
+    }
+
+    /// Get the insurance settlement progress for an event.
+    pub fn get_insurance_settlement(
+        env: Env,
+        event_id: u64,
+    ) -> Result<InsuranceSettlement, LumentixError> {
+        storage::get_insurance_settlement(&env, event_id).ok_or(LumentixError::EventNotFound)
+    }
+
+    /// Whether a policy's product covers a cancellation reason. Policies
+    /// sold before insurance products existed cover any reason.
+    fn policy_covers(
+        env: &Env,
+        policy: &InsurancePolicy,
+        reason: &CancellationReason,
+    ) -> Result<bool, LumentixError> {
+        if policy.product_id == crate::types::LEGACY_INSURANCE_PRODUCT_ID {
+            return Ok(true);
+        }
+        let product = storage::get_insurance_product(env, policy.product_id)?;
+        Ok(product.covered_reasons.contains(reason))
+    }
+
+    /// Pay a policy's coverage to its holder, capped at the pool balance,
+    /// and close the policy. Returns the amount paid.
+    fn pay_insurance_claim(
+        env: &Env,
+        policy: &mut InsurancePolicy,
+        reason: CancellationReason,
+    ) -> Result<i128, LumentixError> {
+        let pool = storage::get_insurance_pool(env);
+        let payout = policy.coverage_amount.min(pool.total_balance).max(0);
+        if payout > 0 {
+            storage::deduct_from_insurance_pool(env, payout)?;
+            if let Ok(token_address) = storage::get_token_result(env) {
+                let token_client = soroban_sdk::token::Client::new(env, &token_address);
+                token_client.transfer(&env.current_contract_address(), &policy.holder, &payout);
+            }
+        }
+        storage::release_insurance_exposure(env, policy.event_id, policy.coverage_amount);
+
+        policy.claim_processed = true;
+        policy.active = false;
+        policy.paid_out = payout;
+        storage::set_insurance_policy(env, policy.id, policy);
+
+        InsuranceClaimProcessed::emit(
+            env,
+            policy.id,
+            policy.ticket_id,
+            policy.event_id,
+            policy.holder.clone(),
+            payout,
+            reason,
+        );
+        Ok(payout)
+    }
+
+    /// Get insurance policy by ticket ID.
+    pub fn get_insurance_policy_by_ticket(
+        env: Env,
+        ticket_id: u64,
+    ) -> Result<InsurancePolicy, LumentixError> {
+        storage::get_insurance_policy_by_ticket(&env, ticket_id)
+    }
+
+    /// Get insurance pool information.
+    pub fn get_insurance_pool(env: Env) -> Result<crate::types::InsurancePool, LumentixError> {
+        Ok(storage::get_insurance_pool(&env))
+    }
+
+    /// Set the LP withdrawal cooldown and minimum reserve ratio. Admin only.
+    pub fn set_insurance_pool_params(
+        env: Env,
+        admin: Address,
+        withdrawal_cooldown_secs: u64,
+        min_reserve_ratio_bps: u32,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "set_insurance_pool_params",
+            vec![
+                &env,
+                withdrawal_cooldown_secs.into_val(&env),
+                min_reserve_ratio_bps.into_val(&env),
+            ],
+        )?;
+
+        storage::set_insurance_pool_params(
+            &env,
+            &InsurancePoolParams {
+                withdrawal_cooldown_secs,
+                min_reserve_ratio_bps,
+            },
+        );
+        Ok(())
+    }
+
+    /// Get the insurance pool parameters.
+    pub fn get_insurance_pool_params(env: Env) -> InsurancePoolParams {
+        storage::get_insurance_pool_params(&env)
+    }
+
+    /// Deposit into the insurance pool in exchange for LP shares priced at
+    /// the current share price. Returns the shares minted. Fails with
+    /// InsurancePoolDrained while claims have left shares with no backing.
+    pub fn deposit_insurance_liquidity(
+        env: Env,
+        provider: Address,
+        amount: i128,
+    ) -> Result<i128, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
+        provider.require_auth();
+        if amount <= 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+
+        let mut pool = storage::get_insurance_pool(&env);
+        // Shares left over after claims drained the pool are worthless; minting
+        // 1:1 beside them would hand part of the new deposit to their holders
+        if pool.total_shares > 0 && pool.total_balance <= 0 {
+            return Err(LumentixError::InsurancePoolDrained);
+        }
+        // Premiums collected before any LP joined belong to the protocol;
+        // they get protocol-owned shares so the deposit is priced against them
+        if pool.total_shares == 0 && pool.total_balance > 0 {
+            let protocol = env.current_contract_address();
+            let mut reserve = storage::get_insurance_lp_position(&env, &protocol);
+            reserve.shares += pool.total_balance;
+            storage::set_insurance_lp_position(&env, &reserve);
+            pool.total_shares = pool.total_balance;
+        }
+        let shares = if pool.total_shares == 0 {
+            amount
+        } else {
+            amount * pool.total_shares / pool.total_balance
+        };
+        if shares <= 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+
+        if let Ok(token_address) = storage::get_token_result(&env) {
+            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+            token_client.transfer(&provider, env.current_contract_address(), &amount);
+        }
+
+        pool.total_balance += amount;
+        pool.total_shares += shares;
+        storage::set_insurance_pool(&env, &pool);
+
+        let mut position = storage::get_insurance_lp_position(&env, &provider);
+        position.shares += shares;
+        position.deposited += amount;
+        storage::set_insurance_lp_position(&env, &position);
+
+        InsuranceLiquidityDeposited::emit(&env, provider, amount, shares);
+        InsurancePoolUpdated::emit(
+            &env,
+            pool.total_balance,
+            pool.total_policies,
+            pool.total_claims_paid,
+        );
+
+        Ok(shares)
+    }
+
+    /// Start the withdrawal cooldown for `shares`. Shares keep earning
+    /// premiums and bearing claims until they are withdrawn. A new request
+    /// replaces any pending one and restarts the cooldown.
+    pub fn request_insurance_withdrawal(
+        env: Env,
+        provider: Address,
+        shares: i128,
+    ) -> Result<u64, LumentixError> {
+        provider.require_auth();
+        if shares <= 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+
+        let mut position = storage::get_insurance_lp_position(&env, &provider);
+        if position.shares < shares {
+            return Err(LumentixError::InsufficientInsuranceShares);
+        }
+
+        let params = storage::get_insurance_pool_params(&env);
+        let available_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(params.withdrawal_cooldown_secs);
+        position.pending_withdrawal_shares = shares;
+        position.withdrawal_available_at = available_at;
+        storage::set_insurance_lp_position(&env, &position);
+
+        InsuranceWithdrawalRequested::emit(&env, provider, shares, available_at);
+
+        Ok(available_at)
+    }
+
+    /// Redeem the pending withdrawal once the cooldown has elapsed. Fails if
+    /// the remaining capital would fall below the minimum reserve ratio.
+    pub fn withdraw_insurance_liquidity(
+        env: Env,
+        provider: Address,
+    ) -> Result<i128, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        provider.require_auth();
+
+        let mut position = storage::get_insurance_lp_position(&env, &provider);
+        let shares = position.pending_withdrawal_shares;
+        if shares == 0 {
+            return Err(LumentixError::InsuranceWithdrawalNotRequested);
+        }
+        if env.ledger().timestamp() < position.withdrawal_available_at {
+            return Err(LumentixError::InsuranceWithdrawalCoolingDown);
+        }
+        if position.shares < shares {
+            return Err(LumentixError::InsufficientInsuranceShares);
+        }
+
+        let mut pool = storage::get_insurance_pool(&env);
+        let amount = shares * pool.total_balance / pool.total_shares;
+        if !Self::insurance_reserve_holds(
+            &env,
+            pool.total_balance - amount,
+            pool.outstanding_coverage,
+        ) {
+            return Err(LumentixError::InsuranceReserveBreached);
+        }
+
+        pool.total_balance -= amount;
+        pool.total_shares -= shares;
+        storage::set_insurance_pool(&env, &pool);
+
+        position.shares -= shares;
+        position.pending_withdrawal_shares = 0;
+        position.withdrawal_available_at = 0;
+        storage::set_insurance_lp_position(&env, &position);
+
+        if amount > 0 {
+            if let Ok(token_address) = storage::get_token_result(&env) {
+                let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+                token_client.transfer(&env.current_contract_address(), &provider, &amount);
+            }
+        }
+
+        InsuranceLiquidityWithdrawn::emit(&env, provider, shares, amount);
+        InsurancePoolUpdated::emit(
+            &env,
+            pool.total_balance,
+            pool.total_policies,
+            pool.total_claims_paid,
+        );
+
+        Ok(amount)
+    }
+
+    /// Release the outstanding coverage of an event that ended without being
+    /// cancelled, so its policies no longer count against pool capital.
+    /// Anyone can call this.
+    pub fn release_insurance_exposure(env: Env, event_id: u64) -> Result<i128, LumentixError> {
+        let event = storage::get_event(&env, event_id)?;
+        let ended = event.status == EventStatus::Completed
+            || (event.status != EventStatus::Cancelled
+                && env.ledger().timestamp() > event.end_time);
+        if !ended {
+            return Err(LumentixError::InsuranceExposureNotReleasable);
+        }
+
+        let exposure = storage::get_insurance_event_exposure(&env, event_id);
+        storage::release_insurance_exposure(&env, event_id, exposure);
+        Ok(exposure)
+    }
+
+    /// Get an underwriter's LP position.
+    pub fn get_insurance_lp_position(env: Env, provider: Address) -> InsuranceLpPosition {
+        storage::get_insurance_lp_position(&env, &provider)
+    }
+
+    /// Value of one LP share, scaled by `INSURANCE_SHARE_PRICE_SCALE`.
+    pub fn get_insurance_share_price(env: Env) -> i128 {
+        let pool = storage::get_insurance_pool(&env);
+        if pool.total_shares == 0 {
+            return crate::types::INSURANCE_SHARE_PRICE_SCALE;
+        }
+        pool.total_balance * crate::types::INSURANCE_SHARE_PRICE_SCALE / pool.total_shares
+    }
+
+    /// Outstanding coverage as basis points of pool capital. Returns
+    /// `u32::MAX` when there is exposure but no capital.
+    pub fn get_insurance_utilisation(env: Env) -> u32 {
+        let pool = storage::get_insurance_pool(&env);
+        if pool.outstanding_coverage == 0 {
+            return 0;
+        }
+        if pool.total_balance <= 0 {
+            return u32::MAX;
+        }
+        let bps = pool.outstanding_coverage * 10000 / pool.total_balance;
+        u32::try_from(bps).unwrap_or(u32::MAX)
+    }
+
+    /// Total coverage on policies that can still be claimed.
+    pub fn get_insurance_exposure(env: Env) -> i128 {
+        storage::get_insurance_pool(&env).outstanding_coverage
+    }
+
+    fn insurance_reserve_holds(env: &Env, capital: i128, exposure: i128) -> bool {
+        let params = storage::get_insurance_pool_params(env);
+        capital * 10000 >= exposure * params.min_reserve_ratio_bps as i128
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // REVIEW & REPUTATION SYSTEM
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Submit a verified event review.
+    ///
+    /// Anti-fake-review guarantees enforced on-chain:
+    ///  1. The reviewer must own the ticket (`ticket.owner == reviewer`).
+    ///  2. The ticket must have been used (`ticket.used == true`).
+    ///  3. The ticket must belong to the reviewed event.
+    ///  4. The event must be Completed.
+    ///  5. One review per reviewer per event (duplicate guard).
+    ///  6. Rating must be 1–5.
+    pub fn submit_event_review(
+        env: Env,
+        reviewer: Address,
+        event_id: u64,
+        ticket_id: u64,
+        rating: u32,
+        comment: String,
+    ) -> Result<u64, LumentixError> {
+        reviewer.require_auth();
+
+        // Validate rating range
+        if rating < 1 || rating > 5 {
+            return Err(LumentixError::InvalidRating);
+        }
+
+        // Load and validate the event
+        let event = storage::get_event(&env, event_id)?;
+        if event.status != EventStatus::Completed {
+            return Err(LumentixError::EventNotCompleted);
+        }
+
+        // Load and validate the ticket
+        let ticket = storage::get_ticket(&env, ticket_id)?;
+
+        // Reviewer must own the ticket
+        if ticket.owner != reviewer {
+            return Err(LumentixError::ReviewerNotTicketOwner);
+        }
+
+        // Ticket must belong to the reviewed event
+        if ticket.event_id != event_id {
+            return Err(LumentixError::TicketEventMismatch);
+        }
+
+        // Ticket must have been used (checked in) — attendance proof
+        if !ticket.used {
+            return Err(LumentixError::AttendanceNotVerified);
+        }
+
+        // Duplicate review guard
+        if storage::has_reviewer_reviewed(&env, &reviewer, event_id) {
+            return Err(LumentixError::ReviewAlreadySubmitted);
+        }
+
+        // Create and persist the review
+        let review_id = storage::get_next_review_id(&env);
+        storage::increment_review_id(&env);
+
+        let review = EventReview {
+            id: review_id,
+            event_id,
+            reviewer: reviewer.clone(),
+            organizer: event.organizer.clone(),
+            ticket_id,
+            rating,
+            comment,
+            attendance_verified: true, // ticket.used == true guarantees this
+            timestamp: env.ledger().timestamp(),
+            organizer_response: None,
+            hidden: false,
+        };
+
+        storage::set_review(&env, review_id, &review);
+        storage::set_reviewer_event(&env, &reviewer, event_id, review_id);
+
+        // Update organizer reputation inline
+        Self::update_organizer_reputation_internal(&env, &review, &event, true, false);
+
+        // Emit events
+        ReviewSubmitted::emit(
+            &env,
+            review_id,
+            event_id,
+            reviewer.clone(),
+            event.organizer.clone(),
+            rating,
+            true,
+        );
+        AttendanceVerified::emit(&env, review_id, event_id, reviewer, ticket_id);
+
+        Ok(review_id)
+    }
+
+    /// Validate that a reviewer attended the event.
+    ///
+    /// Checks:
+    ///  a) The review exists.
+    ///  b) The linked ticket exists and belongs to the reviewer.
+    ///  c) The ticket was used (checked in).
+    ///  d) The ticket belongs to the correct event.
+    ///
+    /// Returns `true` if all checks pass, `false` otherwise.
+    /// Emits `AttendanceVerified` on success or `AttendanceVerificationFailed` on failure.
+    pub fn validate_reviewer_attendance(env: Env, review_id: u64) -> Result<bool, LumentixError> {
+        let review = storage::get_review(&env, review_id)?;
+
+        // Already verified — idempotent
+        if review.attendance_verified {
+            return Ok(true);
+        }
+
+        let ticket_result = storage::get_ticket(&env, review.ticket_id);
+
+        let verified = match ticket_result {
+            Err(_) => false,
+            Ok(ticket) => {
+                ticket.owner == review.reviewer && ticket.event_id == review.event_id && ticket.used
+            }
+        };
+
+        if verified {
+            // Update the review record
+            let mut updated = review.clone();
+            updated.attendance_verified = true;
+            Self::save_review(&env, &updated);
+
+            AttendanceVerified::emit(
+                &env,
+                review_id,
+                review.event_id,
+                review.reviewer,
+                review.ticket_id,
+            );
+        } else {
+            AttendanceVerificationFailed::emit(&env, review_id, review.reviewer);
+        }
+
+        Ok(verified)
+    }
+
+    /// Calculate and return the reputation score for an organizer.
+    ///
+    /// Score formula (result stored as integer 0–10000, divide by 100 for display):
+    ///   base        = (weighted_average / 5) × 6000   → up to 6000 (60 pts)
+    ///   volume      = min(total_reviews / 50, 1) × 2000 → up to 2000 (20 pts)
+    ///   consistency = max(0, 1 − std_dev / 2) × 2000   → up to 2000 (20 pts)
+    ///
+    /// Reviews are weighted by the age of their event, halving every
+    /// `REPUTATION_HALF_LIFE_SECS`. The weighted mean and standard deviation
+    /// come from the per-event rating sums and sums of squares. Hidden
+    /// reviews are excluded.
+    ///
+    /// Returns the updated `OrganizerReputation`.
+    pub fn calculate_reputation_score(
+        env: Env,
+        organizer: Address,
+    ) -> Result<OrganizerReputation, LumentixError> {
+        let mut rep = storage::get_organizer_reputation(&env, &organizer);
+
+        if rep.total_reviews == 0 {
+            return Ok(rep);
+        }
+
+        Self::recompute_reputation(&env, &mut rep);
+        storage::set_organizer_reputation(&env, &organizer, &rep);
+
+        ReputationUpdated::emit(
+            &env,
+            organizer,
+            rep.reputation_score,
+            rep.average_rating_x100,
+            rep.total_reviews,
+        );
+
+        Ok(rep)
+    }
+
+    /// Respond publicly to a review. Only the reviewed event's organizer can
+    /// respond; a later response replaces the earlier one.
+    pub fn respond_to_review(
+        env: Env,
+        organizer: Address,
+        review_id: u64,
+        response: String,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let mut review = storage::get_review(&env, review_id)?;
+        if review.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        review.organizer_response = Some(response);
+        Self::save_review(&env, &review);
+
+        ReviewResponded::emit(&env, review_id, organizer);
+
+        Ok(())
+    }
+
+    /// Hide an abusive review. Admin only. The organizer's reputation is
+    /// recomputed without it.
+    pub fn hide_review(
+        env: Env,
+        admin: Address,
+        review_id: u64,
+        reason: String,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "hide_review",
+            vec![&env, review_id.into_val(&env), reason.into_val(&env)],
+        )?;
+
+        let mut review = storage::get_review(&env, review_id)?;
+        if review.hidden {
+            return Err(LumentixError::ReviewAlreadyHidden);
+        }
+
+        review.hidden = true;
+        Self::save_review(&env, &review);
+
+        let event = storage::get_event(&env, review.event_id)?;
+        Self::update_organizer_reputation_internal(&env, &review, &event, false, true);
+
+        ReviewModerated::emit(&env, review_id, admin, true, reason);
+
+        Ok(())
+    }
+
+    /// Restore a hidden review. Admin only.
+    pub fn restore_review(
+        env: Env,
+        admin: Address,
+        review_id: u64,
+        reason: String,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "restore_review",
+            vec![&env, review_id.into_val(&env), reason.into_val(&env)],
+        )?;
+
+        let mut review = storage::get_review(&env, review_id)?;
+        if !review.hidden {
+            return Err(LumentixError::ReviewNotHidden);
+        }
+
+        review.hidden = false;
+        Self::save_review(&env, &review);
+
+        let event = storage::get_event(&env, review.event_id)?;
+        Self::update_organizer_reputation_internal(&env, &review, &event, true, true);
+
+        ReviewModerated::emit(&env, review_id, admin, false, reason);
+
+        Ok(())
+    }
+
+    /// Get a review by ID.
+    pub fn get_review(env: Env, review_id: u64) -> Result<EventReview, LumentixError> {
+        storage::get_review(&env, review_id)
+    }
+
+    /// Get the reputation record for an organizer, including per-event
+    /// breakdowns of visible reviews.
+    pub fn get_organizer_reputation(env: Env, organizer: Address) -> OrganizerReputation {
+        storage::get_organizer_reputation(&env, &organizer)
+    }
+
+    // ── Internal reputation helper ────────────────────────────────────────────
+
+    /// Add (`visible == true`) or remove a review's rating from the
+    /// organizer's totals and the event breakdown, then rescore. Removal
+    /// only happens through moderation; `moderated` marks a restore.
+    fn update_organizer_reputation_internal(
+        env: &Env,
+        review: &EventReview,
+        event: &Event,
+        visible: bool,
+        moderated: bool,
+    ) {
+        let mut rep = storage::get_organizer_reputation(env, &review.organizer);
+        let rating = review.rating;
+        let square = rating * rating;
+        let (mut breakdown, position) =
+            Self::find_event_breakdown(&rep, review.event_id, event.end_time);
+
+        if visible {
+            rep.total_reviews += 1;
+            rep.total_ratings_sum += rating;
+            rep.total_ratings_sq_sum += square;
+            breakdown.review_count += 1;
+            breakdown.ratings_sum += rating;
+            breakdown.ratings_sq_sum += square;
+            if moderated {
+                rep.hidden_reviews -= 1;
+            }
+        } else {
+            rep.hidden_reviews += 1;
+            rep.total_reviews -= 1;
+            rep.total_ratings_sum -= rating;
+            rep.total_ratings_sq_sum -= square;
+            breakdown.review_count -= 1;
+            breakdown.ratings_sum -= rating;
+            breakdown.ratings_sq_sum -= square;
+        }
+        breakdown.average_rating_x100 = (breakdown.ratings_sum * 100)
+            .checked_div(breakdown.review_count)
+            .unwrap_or(0);
+
+        match position {
+            Some(i) => rep.event_breakdowns.set(i, breakdown),
+            None => rep.event_breakdowns.push_back(breakdown),
+        }
+
+        Self::recompute_reputation(env, &mut rep);
+        storage::set_organizer_reputation(env, &review.organizer, &rep);
+
+        ReputationUpdated::emit(
+            env,
+            review.organizer.clone(),
+            rep.reputation_score,
+            rep.average_rating_x100,
+            rep.total_reviews,
+        );
+    }
+
+    /// Persist an existing review. A review still in the version 1 layout is
+    /// folded into its organizer's breakdowns the first time it is rewritten.
+    fn save_review(env: &Env, review: &EventReview) {
+        if storage::is_legacy_review(env, review.id) {
+            Self::fold_legacy_review(env, review);
+        }
+        storage::set_review(env, review.id, review);
+    }
+
+    /// Fold a version 1 review into its organizer's squared sum and event
+    /// breakdown. The legacy totals already count its rating.
+    fn fold_legacy_review(env: &Env, review: &EventReview) {
+        let mut rep = storage::get_organizer_reputation(env, &review.organizer);
+        let end_time = storage::get_event(env, review.event_id)
+            .map(|event| event.end_time)
+            .unwrap_or(review.timestamp);
+        let square = review.rating * review.rating;
+        let (mut breakdown, position) =
+            Self::find_event_breakdown(&rep, review.event_id, end_time);
+
+        rep.total_ratings_sq_sum += square;
+        breakdown.review_count += 1;
+        breakdown.ratings_sum += review.rating;
+        breakdown.ratings_sq_sum += square;
+        breakdown.average_rating_x100 = (breakdown.ratings_sum * 100) / breakdown.review_count;
+        match position {
+            Some(i) => rep.event_breakdowns.set(i, breakdown),
+            None => rep.event_breakdowns.push_back(breakdown),
+        }
+
+        Self::recompute_reputation(env, &mut rep);
+        storage::set_organizer_reputation(env, &review.organizer, &rep);
+    }
+
+    /// An organizer's breakdown for an event and its position, or an empty
+    /// breakdown if the event has none yet.
+    fn find_event_breakdown(
+        rep: &OrganizerReputation,
+        event_id: u64,
+        event_end_time: u64,
+    ) -> (EventReputation, Option<u32>) {
+        for (i, existing) in rep.event_breakdowns.iter().enumerate() {
+            if existing.event_id == event_id {
+                return (existing, Some(i as u32));
+            }
+        }
+        let breakdown = EventReputation {
+            event_id,
+            event_end_time,
+            review_count: 0,
+            ratings_sum: 0,
+            ratings_sq_sum: 0,
+            average_rating_x100: 0,
+        };
+        (breakdown, None)
+    }
+
+    /// Recompute averages, spread and score from the event breakdowns.
+    fn recompute_reputation(env: &Env, rep: &mut OrganizerReputation) {
+        if rep.total_reviews == 0 {
+            rep.average_rating_x100 = 0;
+            rep.weighted_average_x100 = 0;
+            rep.std_dev_x100 = 0;
+            rep.reputation_score = 0;
+            return;
+        }
+
+        // Incremental average: avg_x100 = (sum × 100) / count
+        rep.average_rating_x100 = (rep.total_ratings_sum * 100) / rep.total_reviews;
+
+        let now = env.ledger().timestamp();
+        let mut weight_total: u128 = 0;
+        let mut weighted_sum: u128 = 0;
+        let mut weighted_sq_sum: u128 = 0;
+        for breakdown in rep.event_breakdowns.iter() {
+            if breakdown.review_count == 0 {
+                continue;
+            }
+            let weight = Self::reputation_decay_weight(now, breakdown.event_end_time) as u128;
+            weight_total += weight * breakdown.review_count as u128;
+            weighted_sum += weight * breakdown.ratings_sum as u128;
+            weighted_sq_sum += weight * breakdown.ratings_sq_sum as u128;
+        }
+
+        // variance = E[r²] − E[r]², both scaled by 100²
+        match (weighted_sum * 100).checked_div(weight_total) {
+            Some(mean_x100) => {
+                let mean_sq_x10000 = weighted_sq_sum * 10000 / weight_total;
+                let variance_x10000 = mean_sq_x10000.saturating_sub(mean_x100 * mean_x100);
+                rep.weighted_average_x100 = mean_x100 as u32;
+                rep.std_dev_x100 = Self::isqrt(variance_x10000) as u32;
+            }
+            None => {
+                // Only version 1 reviews, not yet folded into breakdowns
+                rep.weighted_average_x100 = rep.average_rating_x100;
+                rep.std_dev_x100 = 0;
+            }
+        }
+
+        let base = (rep.weighted_average_x100 as u64 * 6000) / 500;
+        let volume = if rep.total_reviews >= 50 {
+            2000u64
+        } else {
+            (rep.total_reviews as u64 * 2000) / 50
+        };
+        let consistency = (2000 * (200 - rep.std_dev_x100.min(200)) as u64) / 200;
+        rep.reputation_score = ((base + volume + consistency) as u32).min(10000);
+    }
+
+    /// Weight (in bps) of reviews for an event that ended at `end_time`,
+    /// halving every `REPUTATION_HALF_LIFE_SECS` and interpolated linearly
+    /// within each half-life. Never drops below 1.
+    fn reputation_decay_weight(now: u64, end_time: u64) -> u64 {
+        let age = now.saturating_sub(end_time);
+        let half_life = crate::types::REPUTATION_HALF_LIFE_SECS;
+        let halvings = age / half_life;
+        if halvings >= 13 {
+            return 1;
+        }
+        let upper = 10000u64 >> halvings;
+        let lower = upper >> 1;
+        let weight = upper - (upper - lower) * (age % half_life) / half_life;
+        weight.max(1)
+    }
+
+    fn isqrt(value: u128) -> u128 {
+        if value < 2 {
+            return value;
+        }
+        let mut x = value;
+        let mut y = x.div_ceil(2);
+        while y < x {
+            x = y;
+            y = (x + value / x) / 2;
+        }
+        x
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // SMART CONTRACT UPGRADE MECHANISM
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Configure the upgrade governance parameters.
+    /// Only the contract admin can set this.
+    /// Sets voting period, required approval percentage, and governance member list.
+    pub fn configure_upgrade_governance(
+        env: Env,
+        admin: Address,
+        voting_period_seconds: u64,
+        required_approval_percentage: u32,
+        governance_members: Vec<Address>,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "configure_upgrade_governance",
+            vec![
+                &env,
+                voting_period_seconds.into_val(&env),
+                required_approval_percentage.into_val(&env),
+                governance_members.into_val(&env),
+            ],
+        )?;
+        Self::require_admin_parameter_control(&env)?;
+
+        Self::apply_upgrade_governance(
+            &env,
+            admin,
+            voting_period_seconds,
+            required_approval_percentage,
+            governance_members,
+        )
+    }
+
+    fn apply_upgrade_governance(
+        env: &Env,
+        actor: Address,
+        voting_period_seconds: u64,
+        required_approval_percentage: u32,
+        governance_members: Vec<Address>,
+    ) -> Result<(), LumentixError> {
+        validation::validate_voting_period(voting_period_seconds)?;
+        validation::validate_approval_percentage(required_approval_percentage)?;
+
+        if governance_members.len() == 0 {
+            return Err(LumentixError::InvalidAmount);
+        }
+
+        let config = UpgradeGovernanceConfig {
+            voting_period_seconds,
+            required_approval_percentage,
+            governance_members,
+        };
+
+        storage::set_upgrade_governance_config(env, &config);
+
+        UpgradeGovernanceConfigUpdated::emit(
+            env,
+            actor,
+            voting_period_seconds,
+            required_approval_percentage,
+            config.governance_members.len(),
+        );
+
+        Ok(())
+    }
+
+    /// Propose a contract upgrade by specifying a new WASM hash.
+    /// Only governance members can propose upgrades.
+    /// Validates that the same WASM hash has not already been proposed.
+    pub fn propose_upgrade(
+        env: Env,
+        proposer: Address,
+        new_wasm_hash: BytesN<32>,
+        description: String,
+    ) -> Result<u64, LumentixError> {
+        proposer.require_auth();
+
+        let config = storage::get_upgrade_governance_config(&env);
+
+        // Verify proposer is a governance member
+        let mut is_member = false;
+        for i in 0..config.governance_members.len() {
+            if config.governance_members.get(i).unwrap() == proposer {
+                is_member = true;
+                break;
+            }
+        }
+        if !is_member {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        validation::validate_wasm_hash(&new_wasm_hash)?;
+        validation::validate_string_not_empty(&description)?;
+
+        // Check duplicate proposal hash
+        if storage::has_upgrade_proposal_by_hash(&env, &new_wasm_hash) {
+            return Err(LumentixError::UpgradeProposalAlreadyExists);
+        }
+
+        let proposal_id = storage::get_next_upgrade_proposal_id(&env);
+        storage::increment_upgrade_proposal_id(&env);
+
+        let created_at = env.ledger().timestamp();
+        let voting_deadline = created_at + config.voting_period_seconds;
+
+        let proposal = UpgradeProposal {
+            proposal_id,
+            proposer: proposer.clone(),
+            new_wasm_hash: new_wasm_hash.clone(),
+            description: description.clone(),
+            created_at,
+            voting_deadline,
+            state: UpgradeState::Pending,
+            yes_votes: 0,
+            no_votes: 0,
+            required_yes_votes: 0, // computed on finalization
+            total_voters: config.governance_members.len(),
+            kind: UpgradeKind::Upgrade,
+            executable_at: 0,
+            previous_wasm_hash: None,
+        };
+
+        storage::set_upgrade_proposal(&env, proposal_id, &proposal);
+        storage::set_upgrade_proposal_hash(&env, &new_wasm_hash, proposal_id);
+
+        UpgradeProposed::emit(
+            &env,
+            proposal_id,
+            proposer,
+            new_wasm_hash,
+            description,
+            voting_deadline,
+        );
+
+        Ok(proposal_id)
+    }
+
+    /// Vote on an upgrade proposal.
+    /// Only governance members can vote.
+    /// Each member can only vote once per proposal.
+    pub fn vote_on_upgrade(
+        env: Env,
+        voter: Address,
+        proposal_id: u64,
+        vote_yes: bool,
+    ) -> Result<(), LumentixError> {
+        voter.require_auth();
+
+        let config = storage::get_upgrade_governance_config(&env);
+
+        // Verify voter is a governance member
+        let mut is_member = false;
+        for i in 0..config.governance_members.len() {
+            if config.governance_members.get(i).unwrap() == voter {
+                is_member = true;
+                break;
+            }
+        }
+        if !is_member {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        let mut proposal = storage::get_upgrade_proposal(&env, proposal_id)?;
+
+        // Check proposal is still in voting state
+        if proposal.state != UpgradeState::Pending {
+            return Err(LumentixError::UpgradeNotInVotingState);
+        }
+
+        // Check voting deadline
+        let now = env.ledger().timestamp();
+        if now > proposal.voting_deadline {
+            return Err(LumentixError::UpgradeVotingPeriodExpired);
+        }
+
+        // Check if already voted
+        if storage::get_upgrade_vote(&env, proposal_id, &voter).is_some() {
+            return Err(LumentixError::UpgradeAlreadyVoted);
         }
 
-        // Create and persist the review
-        let review_id = storage::get_next_review_id(&env);
-        storage::increment_review_id(&env);
-
-        let review = EventReview {
-            id: review_id,
-            event_id,
-            reviewer: reviewer.clone(),
-            organizer: event.organizer.clone(),
-            ticket_id,
-            rating,
-            comment,
-            attendance_verified: true, // ticket.used == true guarantees this
-            timestamp: env.ledger().timestamp(),
+        // Record vote
+        let vote = UpgradeVote {
+            voter: voter.clone(),
+            proposal_id,
+            vote_yes,
+            timestamp: now,
         };
 
-        storage::set_review(&env, review_id, &review);
-        storage::set_reviewer_event(&env, &reviewer, event_id, review_id);
+        storage::set_upgrade_vote(&env, proposal_id, &voter, &vote);
 
-        // Update organizer reputation inline
-        Self::update_organizer_reputation_internal(&env, &event.organizer, rating);
+        if vote_yes {
+            proposal.yes_votes += 1;
+        } else {
+            proposal.no_votes += 1;
+        }
 
-        // Emit events
-        ReviewSubmitted::emit(
+        storage::set_upgrade_proposal(&env, proposal_id, &proposal);
+
+        UpgradeVoteCast::emit(
             &env,
-            review_id,
-            event_id,
-            reviewer.clone(),
-            event.organizer.clone(),
-            rating,
-            true,
+            proposal_id,
+            voter,
+            vote_yes,
+            proposal.yes_votes,
+            proposal.no_votes,
         );
-        AttendanceVerified::emit(&env, review_id, event_id, reviewer, ticket_id);
 
-        Ok(review_id)
+        Ok(())
     }
 
-    /// Validate that a reviewer attended the event.
-    ///
-    /// Checks:
-    ///  a) The review exists.
-    ///  b) The linked ticket exists and belongs to the reviewer.
-    ///  c) The ticket was used (checked in).
-    ///  d) The ticket belongs to the correct event.
-    ///
-    /// Returns `true` if all checks pass, `false` otherwise.
-    /// Emits `AttendanceVerified` on success or `AttendanceVerificationFailed` on failure.
-    pub fn validate_reviewer_attendance(env: Env, review_id: u64) -> Result<bool, LumentixError> {
-        let review = storage::get_review(&env, review_id)?;
+    /// Close voting on a proposal after its deadline. The proposal is
+    /// Approved when yes votes meet the required approval percentage and
+    /// outnumber no votes, and becomes executable once the timelock elapses.
+    /// Otherwise it is Rejected. Anyone can call this.
+    pub fn finalize_upgrade_proposal(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<UpgradeState, LumentixError> {
+        let config = storage::get_upgrade_governance_config(&env);
+        let mut proposal = storage::get_upgrade_proposal(&env, proposal_id)?;
 
-        // Already verified — idempotent
-        if review.attendance_verified {
-            return Ok(true);
+        if proposal.state != UpgradeState::Pending {
+            return Err(LumentixError::UpgradeNotInVotingState);
         }
 
-        let ticket_result = storage::get_ticket(&env, review.ticket_id);
-
-        let verified = match ticket_result {
-            Err(_) => false,
-            Ok(ticket) => {
-                ticket.owner == review.reviewer && ticket.event_id == review.event_id && ticket.used
-            }
-        };
+        let now = env.ledger().timestamp();
+        if now <= proposal.voting_deadline {
+            return Err(LumentixError::UpgradeVotingOpen);
+        }
 
-        if verified {
-            // Update the review record
-            let mut updated = review.clone();
-            updated.attendance_verified = true;
-            storage::set_review(&env, review_id, &updated);
+        // Calculate required votes
+        let required_yes = (proposal.total_voters as u64
+            * config.required_approval_percentage as u64
+            / 100) as u32;
+        proposal.required_yes_votes = required_yes;
 
-            AttendanceVerified::emit(
-                &env,
-                review_id,
-                review.event_id,
-                review.reviewer,
-                review.ticket_id,
-            );
+        if required_yes > 0
+            && proposal.yes_votes >= required_yes
+            && proposal.yes_votes > proposal.no_votes
+        {
+            let safeguards = storage::get_upgrade_safeguards(&env);
+            proposal.state = UpgradeState::Approved;
+            proposal.executable_at = now + safeguards.timelock_seconds;
         } else {
-            AttendanceVerificationFailed::emit(&env, review_id, review.reviewer);
+            proposal.state = UpgradeState::Rejected;
         }
+        storage::set_upgrade_proposal(&env, proposal_id, &proposal);
 
-        Ok(verified)
+        UpgradeFinalized::emit(
+            &env,
+            proposal_id,
+            proposal.state.clone(),
+            proposal.executable_at,
+        );
+
+        Ok(proposal.state)
     }
 
-    /// Calculate and return the reputation score for an organizer.
-    ///
-    /// Score formula (result stored as integer 0–10000, divide by 100 for display):
-    ///   base        = (average_rating / 5) × 6000   → up to 6000 (60 pts)
-    ///   volume      = min(total_reviews / 50, 1) × 2000 → up to 2000 (20 pts)
-    ///   consistency = max(0, 1 − std_dev / 2) × 2000   → up to 2000 (20 pts)
-    ///
-    /// std_dev is approximated as: sqrt(variance) where variance is computed
-    /// from the running sum of squares stored in the reputation record.
-    ///
-    /// Returns the updated `OrganizerReputation`.
-    pub fn calculate_reputation_score(
+    /// Execute an approved upgrade proposal once its timelock has elapsed.
+    /// Any governance member can execute. The WASM hash that was live before
+    /// the upgrade is kept so governance can roll back to it.
+    pub fn execute_upgrade(
         env: Env,
-        organizer: Address,
-    ) -> Result<OrganizerReputation, LumentixError> {
-        let rep = storage::get_organizer_reputation(&env, &organizer);
-
-        if rep.total_reviews == 0 {
-            return Ok(rep);
-        }
+        executor: Address,
+        proposal_id: u64,
+    ) -> Result<(), LumentixError> {
+        executor.require_auth();
+        Self::require_upgrade_member(&env, &executor)?;
 
-        // average_rating_x100 is already maintained incrementally
-        let avg_x100 = rep.average_rating_x100;
+        let proposal = Self::take_executable_proposal(&env, proposal_id, UpgradeKind::Upgrade)?;
+        let wasm_hash = proposal.new_wasm_hash.clone();
+        Self::install_wasm(&env, proposal_id, proposal)?;
 
-        // Base score: (avg / 5) × 6000 — avg_x100 is in [100, 500]
-        let base = (avg_x100 as u64 * 6000) / 500;
+        UpgradeExecuted::emit(&env, proposal_id, executor, wasm_hash);
 
-        // Volume score: min(total / 50, 1) × 2000
-        let volume = if rep.total_reviews >= 50 {
-            2000u64
-        } else {
-            (rep.total_reviews as u64 * 2000) / 50
-        };
+        Ok(())
+    }
 
-        // Consistency: approximate std_dev from variance
-        // variance = (sum_sq / n) - mean^2
-        // We store total_ratings_sum; for a simple approximation we use
-        // the spread between the average and the extremes.
-        // Full std_dev requires sum of squares — use a conservative 2000 pts
-        // when we have fewer than 5 reviews, otherwise scale by volume.
-        let consistency = if rep.total_reviews < 5 {
-            1000u64 // neutral for small samples
-        } else {
-            // Heuristic: more reviews with stable average → higher consistency
-            let stability = if avg_x100 >= 400 { 2000u64 } else { 1500u64 };
-            stability
-        };
+    /// Configure the upgrade timelock and the guardian allowed to veto
+    /// proposals before execution. Admin only. The timelock cannot be set
+    /// below `MIN_UPGRADE_TIMELOCK_SECS`.
+    pub fn configure_upgrade_safeguards(
+        env: Env,
+        admin: Address,
+        timelock_seconds: u64,
+        guardian: Option<Address>,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "configure_upgrade_safeguards",
+            vec![&env, timelock_seconds.into_val(&env), guardian.into_val(&env)],
+        )?;
+        Self::require_admin_parameter_control(&env)?;
 
-        let score = ((base + volume + consistency) as u32).min(10000);
+        Self::apply_upgrade_safeguards(&env, timelock_seconds, guardian)
+    }
 
-        let mut updated = rep;
-        updated.reputation_score = score;
-        storage::set_organizer_reputation(&env, &organizer, &updated);
+    fn apply_upgrade_safeguards(
+        env: &Env,
+        timelock_seconds: u64,
+        guardian: Option<Address>,
+    ) -> Result<(), LumentixError> {
+        if timelock_seconds < crate::types::MIN_UPGRADE_TIMELOCK_SECS {
+            return Err(LumentixError::InvalidUpgradeTimelock);
+        }
 
-        ReputationUpdated::emit(
-            &env,
-            organizer,
-            score,
-            updated.average_rating_x100,
-            updated.total_reviews,
+        storage::set_upgrade_safeguards(
+            env,
+            &UpgradeSafeguards {
+                timelock_seconds,
+                guardian,
+            },
         );
-
-        Ok(updated)
+        Ok(())
     }
 
-    /// Get a review by ID.
-    pub fn get_review(env: Env, review_id: u64) -> Result<EventReview, LumentixError> {
-        storage::get_review(&env, review_id)
+    /// Get the upgrade timelock and guardian.
+    pub fn get_upgrade_safeguards(env: Env) -> UpgradeSafeguards {
+        storage::get_upgrade_safeguards(&env)
     }
 
-    /// Get the reputation record for an organizer.
-    pub fn get_organizer_reputation(env: Env, organizer: Address) -> OrganizerReputation {
-        storage::get_organizer_reputation(&env, &organizer)
-    }
+    /// Veto a pending or approved proposal before it executes. Guardian only.
+    pub fn veto_upgrade(
+        env: Env,
+        guardian: Address,
+        proposal_id: u64,
+        reason: String,
+    ) -> Result<(), LumentixError> {
+        guardian.require_auth();
+        if storage::get_upgrade_safeguards(&env).guardian != Some(guardian.clone()) {
+            return Err(LumentixError::Unauthorized);
+        }
 
-    // ── Internal reputation helper ────────────────────────────────────────────
+        let mut proposal = storage::get_upgrade_proposal(&env, proposal_id)?;
+        if proposal.state != UpgradeState::Pending && proposal.state != UpgradeState::Approved {
+            return Err(LumentixError::UpgradeVetoNotAllowed);
+        }
 
-    fn update_organizer_reputation_internal(env: &Env, organizer: &Address, new_rating: u32) {
-        let mut rep = storage::get_organizer_reputation(env, organizer);
+        proposal.state = UpgradeState::Vetoed;
+        storage::set_upgrade_proposal(&env, proposal_id, &proposal);
 
-        rep.total_reviews += 1;
-        rep.total_ratings_sum += new_rating;
+        UpgradeVetoed::emit(&env, proposal_id, guardian, reason);
 
-        // Incremental average: avg_x100 = (sum × 100) / count
-        rep.average_rating_x100 = (rep.total_ratings_sum * 100) / rep.total_reviews;
+        Ok(())
+    }
 
-        // Quick score update: base only (full recalc available via calculate_reputation_score)
-        let base = (rep.average_rating_x100 as u64 * 6000) / 500;
-        let volume = if rep.total_reviews >= 50 {
-            2000u64
-        } else {
-            (rep.total_reviews as u64 * 2000) / 50
-        };
-        rep.reputation_score = ((base + volume + 1000) as u32).min(10000);
+    /// Record the WASM hash the contract was deployed with, so the first
+    /// upgrade can be rolled back. Admin only, and only once; later hashes
+    /// are tracked by executed upgrades.
+    pub fn record_deployed_wasm_hash(
+        env: Env,
+        admin: Address,
+        wasm_hash: BytesN<32>,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "record_deployed_wasm_hash",
+            vec![&env, wasm_hash.into_val(&env)],
+        )?;
+        if storage::get_current_wasm_hash(&env).is_some() {
+            return Err(LumentixError::WasmHashAlreadyRecorded);
+        }
+        validation::validate_wasm_hash(&wasm_hash)?;
 
-        storage::set_organizer_reputation(env, organizer, &rep);
+        storage::set_current_wasm_hash(&env, &wasm_hash);
+        Ok(())
+    }
 
-        ReputationUpdated::emit(
-            env,
-            organizer.clone(),
-            rep.reputation_score,
-            rep.average_rating_x100,
-            rep.total_reviews,
-        );
+    /// WASM hash the contract is running, if recorded.
+    pub fn get_current_wasm_hash(env: Env) -> Option<BytesN<32>> {
+        storage::get_current_wasm_hash(&env)
     }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    // SMART CONTRACT UPGRADE MECHANISM
-    // ═══════════════════════════════════════════════════════════════════════════
+    /// WASM hash that was live before the last executed upgrade, if known.
+    pub fn get_previous_wasm_hash(env: Env) -> Option<BytesN<32>> {
+        storage::get_previous_wasm_hash(&env)
+    }
 
-    /// Configure the upgrade governance parameters.
-    /// Only the contract admin can set this.
-    /// Sets voting period, required approval percentage, and governance member list.
-    pub fn configure_upgrade_governance(
+    /// Propose restoring the WASM that was live before the last upgrade.
+    /// Goes through the same vote, timelock and veto as an upgrade.
+    pub fn propose_rollback(
         env: Env,
-        admin: Address,
-        voting_period_seconds: u64,
-        required_approval_percentage: u32,
-        governance_members: Vec<Address>,
-    ) -> Result<(), LumentixError> {
-        admin.require_auth();
+        proposer: Address,
+        description: String,
+    ) -> Result<u64, LumentixError> {
+        proposer.require_auth();
+        Self::require_upgrade_member(&env, &proposer)?;
+        validation::validate_string_not_empty(&description)?;
 
-        let stored_admin = storage::get_admin(&env);
-        if admin != stored_admin {
-            return Err(LumentixError::Unauthorized);
-        }
+        let previous_wasm_hash =
+            storage::get_previous_wasm_hash(&env).ok_or(LumentixError::NoPreviousWasmHash)?;
 
-        validation::validate_voting_period(voting_period_seconds)?;
-        validation::validate_approval_percentage(required_approval_percentage)?;
+        let config = storage::get_upgrade_governance_config(&env);
+        let proposal_id = storage::get_next_upgrade_proposal_id(&env);
+        storage::increment_upgrade_proposal_id(&env);
 
-        if governance_members.len() == 0 {
-            return Err(LumentixError::InvalidAmount);
-        }
+        let created_at = env.ledger().timestamp();
+        let voting_deadline = created_at + config.voting_period_seconds;
 
-        let config = UpgradeGovernanceConfig {
-            voting_period_seconds,
-            required_approval_percentage,
-            governance_members,
+        let proposal = UpgradeProposal {
+            proposal_id,
+            proposer: proposer.clone(),
+            new_wasm_hash: previous_wasm_hash.clone(),
+            description: description.clone(),
+            created_at,
+            voting_deadline,
+            state: UpgradeState::Pending,
+            yes_votes: 0,
+            no_votes: 0,
+            required_yes_votes: 0,
+            total_voters: config.governance_members.len(),
+            kind: UpgradeKind::Rollback,
+            executable_at: 0,
+            previous_wasm_hash: None,
         };
+        storage::set_upgrade_proposal(&env, proposal_id, &proposal);
 
-        storage::set_upgrade_governance_config(&env, &config);
-
-        UpgradeGovernanceConfigUpdated::emit(
+        UpgradeProposed::emit(
             &env,
-            admin,
-            voting_period_seconds,
-            required_approval_percentage,
-            config.governance_members.len(),
+            proposal_id,
+            proposer,
+            previous_wasm_hash,
+            description,
+            voting_deadline,
         );
 
+        Ok(proposal_id)
+    }
+
+    /// Execute an approved rollback proposal once its timelock has elapsed.
+    pub fn rollback_upgrade(
+        env: Env,
+        executor: Address,
+        proposal_id: u64,
+    ) -> Result<(), LumentixError> {
+        executor.require_auth();
+        Self::require_upgrade_member(&env, &executor)?;
+
+        let proposal = Self::take_executable_proposal(&env, proposal_id, UpgradeKind::Rollback)?;
+        let wasm_hash = proposal.new_wasm_hash.clone();
+        Self::install_wasm(&env, proposal_id, proposal)?;
+
+        UpgradeRolledBack::emit(&env, proposal_id, executor, wasm_hash);
+
         Ok(())
     }
 
-    /// Propose a contract upgrade by specifying a new WASM hash.
-    /// Only governance members can propose upgrades.
-    /// Validates that the same WASM hash has not already been proposed.
-    pub fn propose_upgrade(
+    /// Propose a platform parameter change. Voted on with `vote_on_upgrade`,
+    /// closed with `finalize_upgrade_proposal` and subject to the same
+    /// timelock and guardian veto as code upgrades.
+    pub fn propose_governance_action(
         env: Env,
         proposer: Address,
-        new_wasm_hash: BytesN<32>,
+        action: GovernanceAction,
         description: String,
     ) -> Result<u64, LumentixError> {
         proposer.require_auth();
-
-        let config = storage::get_upgrade_governance_config(&env);
-
-        // Verify proposer is a governance member
-        let mut is_member = false;
-        for i in 0..config.governance_members.len() {
-            if config.governance_members.get(i).unwrap() == proposer {
-                is_member = true;
-                break;
-            }
-        }
-        if !is_member {
-            return Err(LumentixError::Unauthorized);
-        }
-
-        validation::validate_wasm_hash(&new_wasm_hash)?;
+        Self::require_upgrade_member(&env, &proposer)?;
         validation::validate_string_not_empty(&description)?;
 
-        // Check duplicate proposal hash
-        if storage::has_upgrade_proposal_by_hash(&env, &new_wasm_hash) {
-            return Err(LumentixError::UpgradeProposalAlreadyExists);
-        }
-
+        let config = storage::get_upgrade_governance_config(&env);
         let proposal_id = storage::get_next_upgrade_proposal_id(&env);
         storage::increment_upgrade_proposal_id(&env);
 
         let created_at = env.ledger().timestamp();
         let voting_deadline = created_at + config.voting_period_seconds;
 
+        // The hash field identifies the action being voted on
+        let action_hash: BytesN<32> = env
+            .crypto()
+            .sha256(&action.clone().to_xdr(&env))
+            .to_bytes();
+
         let proposal = UpgradeProposal {
             proposal_id,
             proposer: proposer.clone(),
-            new_wasm_hash: new_wasm_hash.clone(),
-            description: description.clone(),
+            new_wasm_hash: action_hash,
+            description,
             created_at,
             voting_deadline,
             state: UpgradeState::Pending,
             yes_votes: 0,
             no_votes: 0,
-            required_yes_votes: 0, // computed on execution
+            required_yes_votes: 0,
             total_voters: config.governance_members.len(),
+            kind: UpgradeKind::Governance,
+            executable_at: 0,
+            previous_wasm_hash: None,
         };
-
         storage::set_upgrade_proposal(&env, proposal_id, &proposal);
-        storage::set_upgrade_proposal_hash(&env, &new_wasm_hash, proposal_id);
+        storage::set_governance_action(&env, proposal_id, &action);
 
-        UpgradeProposed::emit(
-            &env,
-            proposal_id,
-            proposer,
-            new_wasm_hash,
-            description,
-            voting_deadline,
-        );
+        GovernanceActionProposed::emit(&env, proposal_id, proposer, action);
 
         Ok(proposal_id)
     }
 
-    /// Vote on an upgrade proposal.
-    /// Only governance members can vote.
-    /// Each member can only vote once per proposal.
-    pub fn vote_on_upgrade(
+    /// Apply an approved governance action once its timelock has elapsed.
+    /// Any governance member can execute.
+    pub fn execute_governance_action(
         env: Env,
-        voter: Address,
+        executor: Address,
         proposal_id: u64,
-        vote_yes: bool,
     ) -> Result<(), LumentixError> {
-        voter.require_auth();
+        executor.require_auth();
+        Self::require_upgrade_member(&env, &executor)?;
 
-        let config = storage::get_upgrade_governance_config(&env);
+        let mut proposal =
+            Self::take_executable_proposal(&env, proposal_id, UpgradeKind::Governance)?;
+        let action = storage::get_governance_action(&env, proposal_id)
+            .ok_or(LumentixError::GovernanceActionNotFound)?;
 
-        // Verify voter is a governance member
-        let mut is_member = false;
-        for i in 0..config.governance_members.len() {
-            if config.governance_members.get(i).unwrap() == voter {
-                is_member = true;
-                break;
+        match action.clone() {
+            GovernanceAction::PlatformFee(fee_bps) => {
+                Self::apply_platform_fee(&env, executor.clone(), fee_bps)?;
+            }
+            GovernanceAction::PlatformFeeRecipient(recipient) => {
+                Self::apply_admin_proposal(&env, recipient)?;
+            }
+            GovernanceAction::Token(token) => storage::set_token(&env, &token),
+            GovernanceAction::CertificationStandard(standard, enabled) => {
+                storage::set_certification_standard_enabled(&env, &standard, enabled);
+                CertificationStandardUpdated::emit(&env, standard, enabled);
+            }
+            GovernanceAction::BridgePaused(paused) => storage::set_bridge_paused(&env, paused),
+            GovernanceAction::UpgradeGovernance(voting_period, approval_percentage, members) => {
+                Self::apply_upgrade_governance(
+                    &env,
+                    executor.clone(),
+                    voting_period,
+                    approval_percentage,
+                    members,
+                )?;
+            }
+            GovernanceAction::UpgradeSafeguards(timelock_seconds, guardian) => {
+                Self::apply_upgrade_safeguards(&env, timelock_seconds, guardian)?;
             }
         }
-        if !is_member {
+
+        proposal.state = UpgradeState::Executed;
+        storage::set_upgrade_proposal(&env, proposal_id, &proposal);
+
+        GovernanceActionExecuted::emit(&env, proposal_id, executor, action);
+
+        Ok(())
+    }
+
+    /// Get the action carried by a governance proposal.
+    pub fn get_governance_action(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<GovernanceAction, LumentixError> {
+        storage::get_governance_action(&env, proposal_id)
+            .ok_or(LumentixError::GovernanceActionNotFound)
+    }
+
+    /// Hand platform parameters over to governance. Admin only and
+    /// irreversible; afterwards the admin can no longer change the platform
+    /// fee, fee recipient, token, certification standards, governance members
+    /// or upgrade safeguards, or resume the bridge, and is left with emergency
+    /// actions such as pausing it.
+    pub fn enable_governance_control(env: Env, admin: Address) -> Result<(), LumentixError> {
+        Self::require_admin(&env, &admin, "enable_governance_control", Vec::new(&env))?;
+        if storage::get_upgrade_governance_config(&env)
+            .governance_members
+            .is_empty()
+        {
+            return Err(LumentixError::GovernanceNotConfigured);
+        }
+
+        storage::set_governance_controlled(&env);
+        GovernanceControlEnabled::emit(&env, admin);
+
+        Ok(())
+    }
+
+    /// Whether platform parameters are controlled by governance.
+    pub fn is_governance_controlled(env: Env) -> bool {
+        storage::is_governance_controlled(&env)
+    }
+
+    fn require_admin_parameter_control(env: &Env) -> Result<(), LumentixError> {
+        if storage::is_governance_controlled(env) {
+            return Err(LumentixError::GovernanceControlled);
+        }
+        Ok(())
+    }
+
+    fn require_upgrade_member(env: &Env, member: &Address) -> Result<(), LumentixError> {
+        let config = storage::get_upgrade_governance_config(env);
+        if !config.governance_members.contains(member) {
             return Err(LumentixError::Unauthorized);
         }
+        Ok(())
+    }
 
-        let mut proposal = storage::get_upgrade_proposal(&env, proposal_id)?;
+    /// Load a proposal of `kind` that is approved and past its timelock.
+    fn take_executable_proposal(
+        env: &Env,
+        proposal_id: u64,
+        kind: UpgradeKind,
+    ) -> Result<UpgradeProposal, LumentixError> {
+        let proposal = storage::get_upgrade_proposal(env, proposal_id)?;
+        if proposal.kind != kind {
+            return Err(LumentixError::UpgradeKindMismatch);
+        }
+        match proposal.state {
+            UpgradeState::Approved => {}
+            UpgradeState::Executed => return Err(LumentixError::UpgradeAlreadyExecuted),
+            _ => return Err(LumentixError::UpgradeNotApproved),
+        }
+        if env.ledger().timestamp() < proposal.executable_at {
+            return Err(LumentixError::UpgradeTimelockActive);
+        }
+        Ok(proposal)
+    }
 
-        // Check proposal is still in voting state
-        if proposal.state != UpgradeState::Pending {
-            return Err(LumentixError::UpgradeNotInVotingState);
+    /// Swap in the proposal's WASM, remembering the hash it replaces.
+    fn install_wasm(
+        env: &Env,
+        proposal_id: u64,
+        mut proposal: UpgradeProposal,
+    ) -> Result<(), LumentixError> {
+        let previous = storage::get_current_wasm_hash(env);
+        if let Some(previous_hash) = previous.clone() {
+            storage::set_previous_wasm_hash(env, &previous_hash);
         }
+        storage::set_current_wasm_hash(env, &proposal.new_wasm_hash);
 
-        // Check voting deadline
-        let now = env.ledger().timestamp();
-        if now > proposal.voting_deadline {
-            return Err(LumentixError::UpgradeVotingPeriodExpired);
+        proposal.previous_wasm_hash = previous;
+        proposal.state = UpgradeState::Executed;
+        storage::set_upgrade_proposal(env, proposal_id, &proposal);
+
+        env.deployer()
+            .update_current_contract_wasm(proposal.new_wasm_hash.clone());
+        Ok(())
+    }
+
+    /// Rewrite up to `batch_size` stored records from `from_version` to the
+    /// next schema version. Admin only. Run after an upgrade that changes
+    /// record layouts; call repeatedly until the returned progress is
+    /// complete, at which point the stored schema version is bumped.
+    /// Currency configs are not walked; they upgrade on read and are
+    /// rewritten on their next update.
+    pub fn migrate(
+        env: Env,
+        admin: Address,
+        from_version: u32,
+        batch_size: u32,
+    ) -> Result<MigrationProgress, LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "migrate",
+            vec![&env, from_version.into_val(&env), batch_size.into_val(&env)],
+        )?;
+        if batch_size == 0 {
+            return Err(LumentixError::InvalidAmount);
         }
 
-        // Check if already voted
-        if storage::get_upgrade_vote(&env, proposal_id, &voter).is_some() {
-            return Err(LumentixError::UpgradeAlreadyVoted);
+        let version = storage::get_storage_version(&env);
+        if version >= crate::types::STORAGE_SCHEMA_VERSION {
+            return Err(LumentixError::StorageAlreadyMigrated);
+        }
+        if from_version != version {
+            return Err(LumentixError::StorageVersionMismatch);
         }
 
-        // Record vote
-        let vote = UpgradeVote {
-            voter: voter.clone(),
-            proposal_id,
-            vote_yes,
-            timestamp: now,
+        let mut progress = match storage::get_migration_progress(&env) {
+            Some(progress) if progress.from_version == from_version => progress,
+            _ => MigrationProgress {
+                from_version,
+                to_version: from_version + 1,
+                stage: MigrationStage::AccessibilityBookings,
+                next_id: 1,
+                records_migrated: 0,
+                complete: false,
+            },
         };
 
-        storage::set_upgrade_vote(&env, proposal_id, &voter, &vote);
-
-        if vote_yes {
-            proposal.yes_votes += 1;
-        } else {
-            proposal.no_votes += 1;
+        // Reads decode either layout; writing back stores the current one
+        let mut budget = batch_size;
+        while progress.stage != MigrationStage::Done {
+            if progress.next_id >= Self::migration_stage_end(&env, progress.stage) {
+                progress.stage = Self::next_migration_stage(progress.stage);
+                progress.next_id = 1;
+            } else if budget == 0 {
+                break;
+            } else {
+                if Self::migrate_record(&env, progress.stage, progress.next_id) {
+                    progress.records_migrated += 1;
+                }
+                progress.next_id += 1;
+                budget -= 1;
+            }
         }
 
-        storage::set_upgrade_proposal(&env, proposal_id, &proposal);
+        progress.complete = progress.stage == MigrationStage::Done;
+        if progress.complete {
+            // The pool is a single instance record, rewritten last
+            storage::set_insurance_pool(&env, &storage::get_insurance_pool(&env));
+            storage::set_storage_version(&env, progress.to_version);
+        }
+        storage::set_migration_progress(&env, &progress);
 
-        UpgradeVoteCast::emit(
+        StorageMigrated::emit(
             &env,
-            proposal_id,
-            voter,
-            vote_yes,
-            proposal.yes_votes,
-            proposal.no_votes,
+            progress.from_version,
+            progress.to_version,
+            batch_size - budget,
+            progress.complete,
         );
 
-        Ok(())
+        Ok(progress)
     }
 
-    /// Execute an approved upgrade proposal.
-    /// After voting deadline passes with sufficient approval, any governance member can execute.
-    /// Computes required votes based on approval percentage and total voters.
-    pub fn execute_upgrade(
+    /// First ID past the records of a migration stage
+    fn migration_stage_end(env: &Env, stage: MigrationStage) -> u64 {
+        match stage {
+            MigrationStage::AccessibilityBookings => {
+                storage::get_next_accessibility_booking_id(env)
+            }
+            MigrationStage::InsurancePolicies => storage::get_next_insurance_policy_id(env),
+            MigrationStage::Reviews => storage::get_next_review_id(env),
+            MigrationStage::UpgradeProposals => storage::get_next_upgrade_proposal_id(env),
+            MigrationStage::CarbonOffsetPurchases => {
+                storage::get_next_carbon_offset_purchase_id(env)
+            }
+            MigrationStage::IdentityCredentials => storage::get_next_identity_credential_id(env),
+            MigrationStage::EnvironmentalImpacts => storage::get_next_event_id(env),
+            MigrationStage::Done => 0,
+        }
+    }
+
+    fn next_migration_stage(stage: MigrationStage) -> MigrationStage {
+        match stage {
+            MigrationStage::AccessibilityBookings => MigrationStage::InsurancePolicies,
+            MigrationStage::InsurancePolicies => MigrationStage::Reviews,
+            MigrationStage::Reviews => MigrationStage::UpgradeProposals,
+            MigrationStage::UpgradeProposals => MigrationStage::CarbonOffsetPurchases,
+            MigrationStage::CarbonOffsetPurchases => MigrationStage::IdentityCredentials,
+            MigrationStage::IdentityCredentials => MigrationStage::EnvironmentalImpacts,
+            MigrationStage::EnvironmentalImpacts | MigrationStage::Done => MigrationStage::Done,
+        }
+    }
+
+    /// Rewrite one record of a migration stage in the current layout.
+    /// Returns whether the record existed. Organizer reputations are
+    /// rewritten as their legacy reviews are folded in.
+    fn migrate_record(env: &Env, stage: MigrationStage, id: u64) -> bool {
+        match stage {
+            MigrationStage::AccessibilityBookings => storage::get_accessibility_booking(env, id)
+                .map(|booking| storage::set_accessibility_booking(env, id, &booking))
+                .is_ok(),
+            MigrationStage::InsurancePolicies => storage::get_insurance_policy(env, id)
+                .map(|policy| storage::set_insurance_policy(env, id, &policy))
+                .is_ok(),
+            MigrationStage::Reviews => storage::get_review(env, id)
+                .map(|review| Self::save_review(env, &review))
+                .is_ok(),
+            MigrationStage::UpgradeProposals => storage::get_upgrade_proposal(env, id)
+                .map(|proposal| storage::set_upgrade_proposal(env, id, &proposal))
+                .is_ok(),
+            MigrationStage::CarbonOffsetPurchases => storage::get_carbon_offset_purchase(env, id)
+                .map(|purchase| storage::set_carbon_offset_purchase(env, id, &purchase))
+                .is_ok(),
+            MigrationStage::IdentityCredentials => storage::get_identity_credential(env, id)
+                .map(|credential| storage::set_identity_credential(env, id, &credential))
+                .is_ok(),
+            MigrationStage::EnvironmentalImpacts => {
+                if !storage::has_environmental_impact(env, id) {
+                    return false;
+                }
+                let impact = storage::get_environmental_impact(env, id);
+                storage::set_environmental_impact(env, id, &impact);
+                true
+            }
+            MigrationStage::Done => false,
+        }
+    }
+
+    /// Schema version of persisted records.
+    pub fn get_storage_version(env: Env) -> u32 {
+        storage::get_storage_version(&env)
+    }
+
+    /// Progress of the latest storage migration, if one has run.
+    pub fn get_migration_progress(env: Env) -> Option<MigrationProgress> {
+        storage::get_migration_progress(&env)
+    }
+
+    /// Get an upgrade proposal by ID
+    pub fn get_upgrade_proposal(
         env: Env,
-        executor: Address,
         proposal_id: u64,
-    ) -> Result<(), LumentixError> {
-        executor.require_auth();
+    ) -> Result<UpgradeProposal, LumentixError> {
+        storage::get_upgrade_proposal(&env, proposal_id)
+    }
 
-        let config = storage::get_upgrade_governance_config(&env);
+    /// Get upgrade governance configuration
+    pub fn get_upgrade_governance_config(env: Env) -> UpgradeGovernanceConfig {
+        storage::get_upgrade_governance_config(&env)
+    }
 
-        // Verify executor is a governance member
-        let mut is_member = false;
-        for i in 0..config.governance_members.len() {
-            if config.governance_members.get(i).unwrap() == executor {
-                is_member = true;
-                break;
-            }
-        }
-        if !is_member {
+    /// Get a vote record for a specific proposal and voter
+    pub fn get_upgrade_vote(env: Env, proposal_id: u64, voter: Address) -> Option<UpgradeVote> {
+        storage::get_upgrade_vote(&env, proposal_id, &voter)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // CARBON OFFSET PROGRAM
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Estimate the carbon footprint for an event based on venue size,
+    /// expected attendance, and travel patterns, using the admin-configured
+    /// emission factors for the event's footprint profile.
+    ///
+    /// Formula:
+    ///   venue_footprint = venue_size_sqm × venue_factor(venue_type) × energy_mix_bps / 10_000
+    ///   attendance_footprint = expected_attendance × attendee_kg
+    ///   travel_footprint =
+    ///       expected_attendance × avg_travel_distance_km × transport_g_per_km / 1000
+    ///
+    /// Organizer only. The inputs are saved to the event's footprint profile,
+    /// which is frozen once the event completes or its actual footprint is recorded.
+    pub fn calculate_carbon_footprint(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        venue_size_sqm: u64,
+        expected_attendance: u64,
+        avg_travel_distance_km: u64,
+    ) -> Result<CarbonFootprint, LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
             return Err(LumentixError::Unauthorized);
         }
+        Self::require_footprint_profile_open(&env, &event)?;
 
-        let proposal = storage::get_upgrade_proposal(&env, proposal_id)?;
-
-        // Must be in pending state
-        if proposal.state != UpgradeState::Pending {
-            return Err(LumentixError::UpgradeAlreadyExecuted);
+        if venue_size_sqm == 0 || expected_attendance == 0 {
+            return Err(LumentixError::InvalidCarbonFootprintParams);
         }
 
-        // Voting period must have ended
-        let now = env.ledger().timestamp();
-        if now <= proposal.voting_deadline {
-            return Err(LumentixError::UpgradeVotingPeriodExpired);
+        let mut profile = Self::footprint_profile(&env, event_id);
+        profile.venue_size_sqm = venue_size_sqm;
+        profile.expected_attendance = expected_attendance;
+        profile.avg_travel_distance_km = avg_travel_distance_km;
+        storage::set_footprint_profile(&env, event_id, &profile);
+
+        let footprint =
+            Self::compute_carbon_footprint(&env, event_id, &profile, expected_attendance);
+        storage::set_carbon_footprint(&env, event_id, &footprint);
+
+        // Once measured, the actual footprint drives the impact tracker
+        if storage::get_actual_carbon_footprint(&env, event_id).is_none() {
+            Self::set_impact_footprint(&env, event_id, footprint.total_footprint_kg);
         }
 
-        // Calculate required votes
-        let required_yes = (proposal.total_voters as u64
-            * config.required_approval_percentage as u64
-            / 100) as u32;
-        if proposal.yes_votes < required_yes || required_yes == 0 {
-            return Err(LumentixError::UpgradeInsufficientVotes);
+        CarbonFootprintCalculated::emit(
+            &env,
+            event_id,
+            footprint.total_footprint_kg,
+            footprint.venue_footprint_kg,
+            footprint.attendance_footprint_kg,
+            footprint.travel_footprint_kg,
+        );
+
+        Ok(footprint)
+    }
+
+    /// Replace the emission factors used for footprint calculations. Admin only.
+    pub fn set_emission_factors(
+        env: Env,
+        admin: Address,
+        factors: EmissionFactors,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "set_emission_factors",
+            vec![&env, factors.into_val(&env)],
+        )?;
+
+        let venue_types = [VenueType::Indoor, VenueType::Outdoor, VenueType::Mixed];
+        let energy_mixes = [EnergyMix::Grid, EnergyMix::Mixed, EnergyMix::Renewable];
+        let transport_modes = [
+            TransportMode::Car,
+            TransportMode::PublicTransit,
+            TransportMode::Air,
+            TransportMode::Active,
+        ];
+        let complete = factors.attendee_kg >= 0
+            && venue_types
+                .iter()
+                .all(|t| factors.venue_kg_per_sqm.get(t.clone()).is_some_and(|f| f >= 0))
+            && energy_mixes
+                .iter()
+                .all(|m| factors.energy_mix_bps.contains_key(m.clone()))
+            && transport_modes
+                .iter()
+                .all(|m| factors.transport_g_per_km.get(m.clone()).is_some_and(|f| f >= 0));
+        if !complete {
+            return Err(LumentixError::InvalidEmissionFactors);
+        }
+
+        storage::set_emission_factors(&env, &factors);
+        EmissionFactorsUpdated::emit(&env, admin, factors);
+
+        Ok(())
+    }
+
+    /// Get the emission factors in effect.
+    pub fn get_emission_factors(env: Env) -> EmissionFactors {
+        storage::get_emission_factors(&env)
+    }
+
+    /// Set the venue type, energy mix and main transport mode an event's
+    /// footprint is calculated with. Organizer only. Events without a
+    /// profile are treated as indoor, grid-powered and reached by car.
+    /// Frozen once the event completes or its actual footprint is recorded.
+    pub fn set_event_footprint_profile(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        venue_type: VenueType,
+        energy_mix: EnergyMix,
+        transport_mode: TransportMode,
+    ) -> Result<FootprintProfile, LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
         }
+        Self::require_footprint_profile_open(&env, &event)?;
 
-        // Update proposal state
-        let mut updated = proposal.clone();
-        updated.state = UpgradeState::Approved;
-        updated.required_yes_votes = required_yes;
-        storage::set_upgrade_proposal(&env, proposal_id, &updated);
+        let mut profile = Self::footprint_profile(&env, event_id);
+        profile.venue_type = venue_type;
+        profile.energy_mix = energy_mix;
+        profile.transport_mode = transport_mode;
+        storage::set_footprint_profile(&env, event_id, &profile);
 
-        // Deploy upgrade
-        env.deployer()
-            .update_current_contract_wasm(proposal.new_wasm_hash.clone());
+        Ok(profile)
+    }
 
-        // Mark as executed
-        let mut executed = updated;
-        executed.state = UpgradeState::Executed;
-        storage::set_upgrade_proposal(&env, proposal_id, &executed);
+    /// The actual footprint is measured against the profile, so it may not
+    /// change after the event.
+    fn require_footprint_profile_open(env: &Env, event: &Event) -> Result<(), LumentixError> {
+        if event.status == EventStatus::Completed
+            || storage::get_actual_carbon_footprint(env, event.id).is_some()
+        {
+            return Err(LumentixError::CarbonFootprintFinalized);
+        }
+        Ok(())
+    }
 
-        UpgradeExecuted::emit(&env, proposal_id, executor, proposal.new_wasm_hash);
+    /// Recalculate a completed event's footprint from its real check-in
+    /// count instead of the expected attendance. Uses the venue and travel
+    /// inputs of the latest estimate. Callable by anyone; the actual
+    /// footprint then replaces the estimate in the impact tracker.
+    pub fn record_actual_carbon_footprint(
+        env: Env,
+        event_id: u64,
+    ) -> Result<CarbonFootprint, LumentixError> {
+        let event = storage::get_event(&env, event_id)?;
+        if event.status != EventStatus::Completed {
+            return Err(LumentixError::EventNotCompleted);
+        }
+        let estimate = storage::get_carbon_footprint(&env, event_id)
+            .ok_or(LumentixError::CarbonOffsetNotConfigured)?;
+        let profile = Self::footprint_profile(&env, event_id);
 
-        Ok(())
-    }
+        let checked_in = storage::get_check_in_count(&env, event_id);
+        let actual = Self::compute_carbon_footprint(&env, event_id, &profile, checked_in as u64);
+        storage::set_actual_carbon_footprint(&env, event_id, &actual);
+        Self::set_impact_footprint(&env, event_id, actual.total_footprint_kg);
 
-    /// Get an upgrade proposal by ID
-    pub fn get_upgrade_proposal(
-        env: Env,
-        proposal_id: u64,
-    ) -> Result<UpgradeProposal, LumentixError> {
-        storage::get_upgrade_proposal(&env, proposal_id)
-    }
+        ActualFootprintRecorded::emit(
+            &env,
+            event_id,
+            estimate.total_footprint_kg,
+            actual.total_footprint_kg,
+            checked_in,
+        );
 
-    /// Get upgrade governance configuration
-    pub fn get_upgrade_governance_config(env: Env) -> UpgradeGovernanceConfig {
-        storage::get_upgrade_governance_config(&env)
+        Ok(actual)
     }
 
-    /// Get a vote record for a specific proposal and voter
-    pub fn get_upgrade_vote(env: Env, proposal_id: u64, voter: Address) -> Option<UpgradeVote> {
-        storage::get_upgrade_vote(&env, proposal_id, &voter)
+    /// Get the measured footprint recorded after the event, if any.
+    pub fn get_actual_carbon_footprint(env: Env, event_id: u64) -> Option<CarbonFootprint> {
+        storage::get_actual_carbon_footprint(&env, event_id)
     }
 
-    // ═══════════════════════════════════════════════════════════════════════════
-    // CARBON OFFSET PROGRAM
-    // ═══════════════════════════════════════════════════════════════════════════
-
-    /// Calculate the carbon footprint for an event based on venue size,
-    /// expected attendance, and travel patterns.
-    ///
-    /// Formula:
-    ///   venue_footprint = venue_size_factor × base_emission_per_sqm
-    ///   attendance_footprint = expected_attendance × attendance_emission_factor
-    ///   travel_footprint = expected_attendance × avg_travel_distance_km × travel_emission_per_km
-    pub fn calculate_carbon_footprint(
+    /// Summarise an event's estimated and actual footprint, offsets and
+    /// neutrality.
+    pub fn get_sustainability_report(
         env: Env,
         event_id: u64,
-        venue_size_sqm: u64,
-        expected_attendance: u64,
-        avg_travel_distance_km: u64,
-    ) -> Result<CarbonFootprint, LumentixError> {
-        // Validate event exists
-        let _event = storage::get_event(&env, event_id)?;
+    ) -> Result<SustainabilityReport, LumentixError> {
+        storage::get_event(&env, event_id)?;
 
-        if venue_size_sqm == 0 || expected_attendance == 0 {
-            return Err(LumentixError::InvalidCarbonFootprintParams);
-        }
+        let estimated = storage::get_carbon_footprint(&env, event_id)
+            .map(|footprint| footprint.total_footprint_kg)
+            .unwrap_or(0);
+        let actual = storage::get_actual_carbon_footprint(&env, event_id)
+            .map(|footprint| footprint.total_footprint_kg);
+        let expected_attendance = storage::get_footprint_profile(&env, event_id)
+            .map(|profile| profile.expected_attendance)
+            .unwrap_or(0);
+        let impact = storage::get_environmental_impact(&env, event_id);
+
+        Ok(SustainabilityReport {
+            event_id,
+            estimated_footprint_kg: estimated,
+            actual_footprint_kg: actual,
+            expected_attendance,
+            checked_in_attendance: storage::get_check_in_count(&env, event_id),
+            footprint_delta_kg: actual.map(|kg| kg - estimated).unwrap_or(0),
+            verified_offset_kg: impact.total_offset_kg,
+            pending_offset_kg: impact.pending_offset_kg,
+            net_impact_kg: impact.net_impact_kg,
+            neutral_status: impact.neutral_status,
+        })
+    }
 
-        let now = env.ledger().timestamp();
+    fn footprint_profile(env: &Env, event_id: u64) -> FootprintProfile {
+        storage::get_footprint_profile(env, event_id).unwrap_or(FootprintProfile {
+            venue_size_sqm: 0,
+            avg_travel_distance_km: 0,
+            expected_attendance: 0,
+            venue_type: VenueType::Indoor,
+            energy_mix: EnergyMix::Grid,
+            transport_mode: TransportMode::Car,
+        })
+    }
 
-        // Constants for carbon calculation (kg CO2e)
-        let venue_emission_per_sqm: i128 = 25; // 25 kg CO2e per sqm for event duration
-        let attendance_emission_per_person: i128 = 5; // 5 kg CO2e per attendee
-        let travel_emission_per_km_per_person: i128 = 255; // grams = 0.255 kg per km per person
-
-        let venue_footprint = (venue_size_sqm as i128).saturating_mul(venue_emission_per_sqm);
-        let attendance_footprint =
-            (expected_attendance as i128).saturating_mul(attendance_emission_per_person);
-        let travel_footprint = (expected_attendance as i128)
-            .saturating_mul(avg_travel_distance_km as i128)
-            .saturating_mul(travel_emission_per_km_per_person)
+    /// Apply the emission factors to a profile for the given attendance.
+    fn compute_carbon_footprint(
+        env: &Env,
+        event_id: u64,
+        profile: &FootprintProfile,
+        attendance: u64,
+    ) -> CarbonFootprint {
+        let factors = storage::get_emission_factors(env);
+        let venue_kg_per_sqm = factors
+            .venue_kg_per_sqm
+            .get(profile.venue_type.clone())
+            .unwrap_or(0);
+        let energy_mix_bps = factors
+            .energy_mix_bps
+            .get(profile.energy_mix.clone())
+            .unwrap_or(10_000);
+        let transport_g_per_km = factors
+            .transport_g_per_km
+            .get(profile.transport_mode.clone())
+            .unwrap_or(0);
+
+        let venue_footprint = (profile.venue_size_sqm as i128)
+            .saturating_mul(venue_kg_per_sqm)
+            .saturating_mul(energy_mix_bps as i128)
+            / 10_000;
+        let attendance_footprint = (attendance as i128).saturating_mul(factors.attendee_kg);
+        let travel_footprint = (attendance as i128)
+            .saturating_mul(profile.avg_travel_distance_km as i128)
+            .saturating_mul(transport_g_per_km)
             / 1000; // convert grams to kg
 
-        let total = venue_footprint + attendance_footprint + travel_footprint;
-
-        let footprint = CarbonFootprint {
+        CarbonFootprint {
             event_id,
             venue_footprint_kg: venue_footprint,
             attendance_footprint_kg: attendance_footprint,
             travel_footprint_kg: travel_footprint,
-            total_footprint_kg: total,
-            calculated_at: now,
+            total_footprint_kg: venue_footprint + attendance_footprint + travel_footprint,
+            calculated_at: env.ledger().timestamp(),
+        }
+    }
+
+    fn set_impact_footprint(env: &Env, event_id: u64, total_footprint_kg: i128) {
+        let mut impact = storage::get_environmental_impact(env, event_id);
+        impact.total_footprint_kg = total_footprint_kg;
+        impact.net_impact_kg = total_footprint_kg - impact.total_offset_kg;
+        impact.neutral_status = impact.total_footprint_kg > 0 && impact.net_impact_kg <= 0;
+        storage::set_environmental_impact(env, event_id, &impact);
+    }
+
+    /// Register a carbon project that offsets can be bought from. Admin only.
+    pub fn register_carbon_project(
+        env: Env,
+        admin: Address,
+        project_id: String,
+        name: String,
+        price_per_kg: i128,
+        beneficiary: Address,
+        attestor: Address,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "register_carbon_project",
+            vec![
+                &env,
+                project_id.into_val(&env),
+                name.into_val(&env),
+                price_per_kg.into_val(&env),
+                beneficiary.into_val(&env),
+                attestor.into_val(&env),
+            ],
+        )?;
+        validation::validate_carbon_project_id(&project_id)?;
+        if price_per_kg <= 0 {
+            return Err(LumentixError::InvalidCarbonProject);
+        }
+        if storage::has_carbon_project(&env, &project_id) {
+            return Err(LumentixError::CarbonProjectAlreadyRegistered);
+        }
+
+        let project = CarbonProject {
+            project_id: project_id.clone(),
+            name,
+            price_per_kg,
+            beneficiary: beneficiary.clone(),
+            attestor: attestor.clone(),
+            active: true,
+            total_sold_kg: 0,
+            total_retired_kg: 0,
         };
+        storage::set_carbon_project(&env, &project);
 
-        storage::set_carbon_footprint(&env, event_id, &footprint);
+        CarbonProjectConfigured::emit(&env, project_id, price_per_kg, beneficiary, attestor, true);
 
-        // Update environmental impact
-        let mut impact = storage::get_environmental_impact(&env, event_id);
-        impact.total_footprint_kg = total;
-        impact.net_impact_kg = total - impact.total_offset_kg;
-        impact.neutral_status = impact.total_footprint_kg > 0 && impact.net_impact_kg <= 0;
-        storage::set_environmental_impact(&env, event_id, &impact);
+        Ok(())
+    }
 
-        CarbonFootprintCalculated::emit(
+    /// Change a carbon project's price, beneficiary, attestor or availability.
+    /// Pending offsets are attested by the attestor current at attestation time.
+    pub fn update_carbon_project(
+        env: Env,
+        admin: Address,
+        project_id: String,
+        price_per_kg: i128,
+        beneficiary: Address,
+        attestor: Address,
+        active: bool,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
             &env,
-            event_id,
-            total,
-            venue_footprint,
-            attendance_footprint,
-            travel_footprint,
+            &admin,
+            "update_carbon_project",
+            vec![
+                &env,
+                project_id.into_val(&env),
+                price_per_kg.into_val(&env),
+                beneficiary.into_val(&env),
+                attestor.into_val(&env),
+                active.into_val(&env),
+            ],
+        )?;
+        if price_per_kg <= 0 {
+            return Err(LumentixError::InvalidCarbonProject);
+        }
+
+        let mut project = storage::get_carbon_project(&env, &project_id)?;
+        project.price_per_kg = price_per_kg;
+        project.beneficiary = beneficiary.clone();
+        project.attestor = attestor.clone();
+        project.active = active;
+        storage::set_carbon_project(&env, &project);
+
+        CarbonProjectConfigured::emit(
+            &env,
+            project_id,
+            price_per_kg,
+            beneficiary,
+            attestor,
+            active,
         );
 
-        Ok(footprint)
+        Ok(())
     }
 
-    /// Purchase carbon offset credits to neutralize event environmental impact.
-    /// The offset amount is deducted from the purchaser's balance.
-    /// Records the purchase and updates the environmental impact tracker.
+    /// Get a registered carbon project.
+    pub fn get_carbon_project(
+        env: Env,
+        project_id: String,
+    ) -> Result<CarbonProject, LumentixError> {
+        storage::get_carbon_project(&env, &project_id)
+    }
+
+    /// Purchase carbon offset credits from a registered project to neutralize
+    /// event environmental impact. The cost is the project's price per kg and
+    /// is paid to the project's beneficiary; `max_cost` guards against price
+    /// changes. The offset stays unverified, and outside the event's
+    /// neutrality, until the attestor confirms its retirement.
     pub fn purchase_carbon_offset(
         env: Env,
         purchaser: Address,
         event_id: u64,
         offset_amount_kg: i128,
-        cost: i128,
+        max_cost: i128,
         project_id: String,
     ) -> Result<u64, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
         purchaser.require_auth();
 
+        Self::record_carbon_offset_purchase(
+            &env,
+            purchaser,
+            event_id,
+            offset_amount_kg,
+            max_cost,
+            project_id,
+        )
+    }
+
+    /// Price an offset from its project, pay the beneficiary from `purchaser`
+    /// and record the purchase as pending attestation.
+    fn record_carbon_offset_purchase(
+        env: &Env,
+        purchaser: Address,
+        event_id: u64,
+        offset_amount_kg: i128,
+        max_cost: i128,
+        project_id: String,
+    ) -> Result<u64, LumentixError> {
         // Validate event exists
-        let _event = storage::get_event(&env, event_id)?;
+        let _event = storage::get_event(env, event_id)?;
 
         validation::validate_offset_amount(offset_amount_kg)?;
-        validation::validate_positive_amount(cost)?;
+        validation::validate_positive_amount(max_cost)?;
         validation::validate_carbon_project_id(&project_id)?;
 
-        let purchase_id = storage::get_next_carbon_offset_purchase_id(&env);
-        storage::increment_carbon_offset_purchase_id(&env);
+        let mut project = storage::get_carbon_project(env, &project_id)?;
+        if !project.active {
+            return Err(LumentixError::CarbonProjectInactive);
+        }
+        let cost = offset_amount_kg
+            .checked_mul(project.price_per_kg)
+            .ok_or(LumentixError::InvalidAmount)?;
+        if cost > max_cost {
+            return Err(LumentixError::CarbonOffsetCostExceeded);
+        }
+
+        if let Ok(token_address) = storage::get_token_result(env) {
+            soroban_sdk::token::Client::new(env, &token_address).transfer(
+                &purchaser,
+                &project.beneficiary,
+                &cost,
+            );
+        }
+        project.total_sold_kg += offset_amount_kg;
+        storage::set_carbon_project(env, &project);
+
+        let purchase_id = storage::get_next_carbon_offset_purchase_id(env);
+        storage::increment_carbon_offset_purchase_id(env);
 
         let now = env.ledger().timestamp();
 
@@ -4200,39 +8203,367 @@ impl LumentixContract {
             cost,
             project_id: project_id.clone(),
             timestamp: now,
-            verified: true,
+            verified: false,
+            retirement_serial: None,
         };
 
+        storage::set_carbon_offset_purchase(env, purchase_id, &purchase);
+
+        // Pending until attested; neutrality only counts retired offsets
+        let mut impact = storage::get_environmental_impact(env, event_id);
+        impact.pending_offset_kg += offset_amount_kg;
+        impact.total_purchases += 1;
+        storage::set_environmental_impact(env, event_id, &impact);
+
+        CarbonOffsetPurchased::emit(
+            env,
+            purchase_id,
+            event_id,
+            purchaser,
+            offset_amount_kg,
+            cost,
+            project_id,
+        );
+
+        EnvironmentalImpactUpdated::emit(
+            env,
+            event_id,
+            impact.total_footprint_kg,
+            impact.total_offset_kg,
+            impact.net_impact_kg,
+            impact.neutral_status,
+        );
+
+        Ok(purchase_id)
+    }
+
+    /// Confirm that the credits behind an offset purchase were retired in the
+    /// project's registry. Only the project's attestor may call this, and each
+    /// retirement serial can back a single purchase. Moves the offset from
+    /// pending into the event's verified total.
+    pub fn attest_carbon_retirement(
+        env: Env,
+        attestor: Address,
+        purchase_id: u64,
+        retirement_serial: BytesN<32>,
+    ) -> Result<(), LumentixError> {
+        attestor.require_auth();
+
+        let mut purchase = storage::get_carbon_offset_purchase(&env, purchase_id)?;
+        let mut project = storage::get_carbon_project(&env, &purchase.project_id)?;
+        if project.attestor != attestor {
+            return Err(LumentixError::Unauthorized);
+        }
+        if purchase.verified {
+            return Err(LumentixError::CarbonOffsetAlreadyVerified);
+        }
+        if storage::get_retirement_serial_purchase(&env, &retirement_serial).is_some() {
+            return Err(LumentixError::RetirementSerialAlreadyUsed);
+        }
+
+        purchase.verified = true;
+        purchase.retirement_serial = Some(retirement_serial.clone());
         storage::set_carbon_offset_purchase(&env, purchase_id, &purchase);
+        storage::set_retirement_serial(&env, &retirement_serial, purchase_id);
 
-        // Update environmental impact
+        project.total_retired_kg += purchase.offset_amount_kg;
+        storage::set_carbon_project(&env, &project);
+
+        let event_id = purchase.event_id;
         let mut impact = storage::get_environmental_impact(&env, event_id);
-        impact.total_offset_kg += offset_amount_kg;
-        impact.total_purchases += 1;
+        impact.pending_offset_kg -= purchase.offset_amount_kg;
+        impact.total_offset_kg += purchase.offset_amount_kg;
         impact.net_impact_kg = impact.total_footprint_kg - impact.total_offset_kg;
         impact.neutral_status = impact.net_impact_kg <= 0 && impact.total_footprint_kg > 0;
         storage::set_environmental_impact(&env, event_id, &impact);
 
-        CarbonOffsetPurchased::emit(
+        CarbonRetirementAttested::emit(
             &env,
             purchase_id,
+            purchase.project_id,
+            retirement_serial,
+            purchase.offset_amount_kg,
+        );
+
+        EnvironmentalImpactUpdated::emit(
+            &env,
+            event_id,
+            impact.total_footprint_kg,
+            impact.total_offset_kg,
+            impact.net_impact_kg,
+            impact.neutral_status,
+        );
+
+        Ok(())
+    }
+
+    /// Offer attendees an optional carbon offset surcharge at checkout. The
+    /// per-ticket offset is the event's calculated footprint divided by its
+    /// capacity, priced at the project's current rate. Calling again
+    /// refreshes the terms for future purchases; the project is fixed once
+    /// anyone has opted in.
+    pub fn enable_checkout_carbon_offset(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        project_id: String,
+    ) -> Result<CheckoutCarbonOffset, LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if event.status == EventStatus::Completed || event.status == EventStatus::Cancelled {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+        let footprint = storage::get_carbon_footprint(&env, event_id)
+            .filter(|footprint| footprint.total_footprint_kg > 0)
+            .ok_or(LumentixError::CarbonOffsetNotConfigured)?;
+        let project = storage::get_carbon_project(&env, &project_id)?;
+        if !project.active {
+            return Err(LumentixError::CarbonProjectInactive);
+        }
+
+        let capacity = event.max_tickets.max(1) as i128;
+        let offset_kg_per_ticket = (footprint.total_footprint_kg + capacity - 1) / capacity;
+        let surcharge_per_ticket = offset_kg_per_ticket
+            .checked_mul(project.price_per_kg)
+            .ok_or(LumentixError::InvalidAmount)?;
+
+        let config = match storage::get_checkout_carbon_offset(&env, event_id) {
+            Some(existing) => {
+                if existing.opted_in_tickets > 0 && existing.project_id != project_id {
+                    return Err(LumentixError::CheckoutOffsetProjectLocked);
+                }
+                CheckoutCarbonOffset {
+                    project_id: project_id.clone(),
+                    offset_kg_per_ticket,
+                    surcharge_per_ticket,
+                    enabled: true,
+                    ..existing
+                }
+            }
+            None => CheckoutCarbonOffset {
+                event_id,
+                project_id: project_id.clone(),
+                offset_kg_per_ticket,
+                surcharge_per_ticket,
+                enabled: true,
+                opted_in_tickets: 0,
+                total_offset_kg: 0,
+                settled: false,
+                offset_purchase_id: None,
+            },
+        };
+        storage::set_checkout_carbon_offset(&env, &config);
+
+        CheckoutOffsetConfigured::emit(
+            &env,
             event_id,
-            purchaser,
-            offset_amount_kg,
-            cost,
             project_id,
+            offset_kg_per_ticket,
+            surcharge_per_ticket,
+            true,
+        );
+
+        Ok(config)
+    }
+
+    /// Stop offering the checkout offset. Contributions already made are
+    /// still settled when the event completes.
+    pub fn disable_checkout_carbon_offset(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        let mut config = storage::get_checkout_carbon_offset(&env, event_id)
+            .ok_or(LumentixError::CheckoutOffsetNotEnabled)?;
+        config.enabled = false;
+        storage::set_checkout_carbon_offset(&env, &config);
+
+        CheckoutOffsetConfigured::emit(
+            &env,
+            event_id,
+            config.project_id,
+            config.offset_kg_per_ticket,
+            config.surcharge_per_ticket,
+            false,
+        );
+
+        Ok(())
+    }
+
+    /// Get an event's checkout offset terms and totals.
+    pub fn get_checkout_carbon_offset(env: Env, event_id: u64) -> Option<CheckoutCarbonOffset> {
+        storage::get_checkout_carbon_offset(&env, event_id)
+    }
+
+    /// Balance of checkout surcharges awaiting settlement for an event.
+    pub fn get_carbon_escrow_balance(env: Env, event_id: u64) -> i128 {
+        storage::get_carbon_escrow(&env, event_id)
+    }
+
+    /// Spend an event's carbon sub-escrow on an offset from its checkout
+    /// project once the event has completed. Buys as many kg as the escrow
+    /// covers at the project's current price; the contract is recorded as
+    /// purchaser. The remainder, worth less than one kg, moves to the event
+    /// escrow. Callable by anyone, once per event.
+    pub fn settle_checkout_carbon_offset(env: Env, event_id: u64) -> Result<u64, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_ESCROW_RELEASES)?;
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.status != EventStatus::Completed {
+            return Err(LumentixError::EventNotCompleted);
+        }
+        let mut config = storage::get_checkout_carbon_offset(&env, event_id)
+            .ok_or(LumentixError::CheckoutOffsetNotEnabled)?;
+        if config.settled {
+            return Err(LumentixError::CarbonOffsetAlreadyPurchased);
+        }
+
+        let balance = storage::get_carbon_escrow(&env, event_id);
+        let project = storage::get_carbon_project(&env, &config.project_id)?;
+        let offset_kg = balance / project.price_per_kg;
+        if offset_kg <= 0 {
+            return Err(LumentixError::InsufficientCarbonCredits);
+        }
+        let cost = offset_kg * project.price_per_kg;
+        let remainder = balance - cost;
+        storage::deduct_carbon_escrow(&env, event_id, balance)?;
+        if remainder > 0 {
+            storage::add_escrow(&env, event_id, remainder);
+        }
+
+        let purchase_id = Self::record_carbon_offset_purchase(
+            &env,
+            env.current_contract_address(),
+            event_id,
+            offset_kg,
+            cost,
+            config.project_id.clone(),
+        )?;
+
+        config.settled = true;
+        config.offset_purchase_id = Some(purchase_id);
+        storage::set_checkout_carbon_offset(&env, &config);
+
+        CheckoutOffsetSettled::emit(&env, event_id, purchase_id, offset_kg, cost, remainder);
+
+        Ok(purchase_id)
+    }
+
+    /// Get the offset contributed at checkout for a ticket, if any.
+    pub fn get_ticket_carbon_offset(env: Env, ticket_id: u64) -> Option<AttendeeCarbonOffset> {
+        storage::get_ticket_carbon_offset(&env, ticket_id)
+    }
+
+    /// Get every checkout offset contributed by an attendee.
+    pub fn get_attendee_carbon_offsets(env: Env, attendee: Address) -> Vec<AttendeeCarbonOffset> {
+        let mut offsets = Vec::new(&env);
+        for ticket_id in storage::get_attendee_carbon_offset_tickets(&env, &attendee).iter() {
+            if let Some(offset) = storage::get_ticket_carbon_offset(&env, ticket_id) {
+                offsets.push_back(offset);
+            }
+        }
+        offsets
+    }
+
+    /// Charge the checkout surcharge for a freshly purchased ticket into the
+    /// event's carbon sub-escrow.
+    fn collect_checkout_offset(
+        env: &Env,
+        buyer: &Address,
+        event_id: u64,
+        ticket_id: u64,
+    ) -> Result<(), LumentixError> {
+        let mut config = storage::get_checkout_carbon_offset(env, event_id)
+            .filter(|config| config.enabled)
+            .ok_or(LumentixError::CheckoutOffsetNotEnabled)?;
+
+        if let Ok(token_address) = storage::get_token_result(env) {
+            soroban_sdk::token::Client::new(env, &token_address).transfer(
+                buyer,
+                env.current_contract_address(),
+                &config.surcharge_per_ticket,
+            );
+        }
+        storage::add_carbon_escrow(env, event_id, config.surcharge_per_ticket);
+
+        config.opted_in_tickets += 1;
+        config.total_offset_kg += config.offset_kg_per_ticket;
+        storage::set_checkout_carbon_offset(env, &config);
+
+        storage::set_ticket_carbon_offset(
+            env,
+            &AttendeeCarbonOffset {
+                ticket_id,
+                event_id,
+                attendee: buyer.clone(),
+                offset_kg: config.offset_kg_per_ticket,
+                surcharge: config.surcharge_per_ticket,
+                contributed_at: env.ledger().timestamp(),
+                refunded: false,
+            },
         );
 
-        EnvironmentalImpactUpdated::emit(
-            &env,
+        CheckoutOffsetContributed::emit(
+            env,
+            ticket_id,
             event_id,
-            impact.total_footprint_kg,
-            impact.total_offset_kg,
-            impact.net_impact_kg,
-            impact.neutral_status,
+            buyer.clone(),
+            config.offset_kg_per_ticket,
+            config.surcharge_per_ticket,
         );
 
-        Ok(purchase_id)
+        Ok(())
+    }
+
+    /// Return an unsettled checkout surcharge with a ticket refund. Once the
+    /// event's offset is settled the surcharge has been spent and is kept.
+    fn refund_checkout_offset(
+        env: &Env,
+        ticket_id: u64,
+        recipient: &Address,
+    ) -> Result<i128, LumentixError> {
+        let Some(mut offset) = storage::get_ticket_carbon_offset(env, ticket_id) else {
+            return Ok(0);
+        };
+        if offset.refunded {
+            return Ok(0);
+        }
+        let settled = storage::get_checkout_carbon_offset(env, offset.event_id)
+            .is_some_and(|config| config.settled);
+        if settled {
+            return Ok(0);
+        }
+
+        storage::deduct_carbon_escrow(env, offset.event_id, offset.surcharge)?;
+        if let Ok(token_address) = storage::get_token_result(env) {
+            soroban_sdk::token::Client::new(env, &token_address).transfer(
+                &env.current_contract_address(),
+                recipient,
+                &offset.surcharge,
+            );
+        }
+
+        if let Some(mut config) = storage::get_checkout_carbon_offset(env, offset.event_id) {
+            config.opted_in_tickets = config.opted_in_tickets.saturating_sub(1);
+            config.total_offset_kg -= offset.offset_kg;
+            storage::set_checkout_carbon_offset(env, &config);
+        }
+
+        offset.refunded = true;
+        storage::set_ticket_carbon_offset(env, &offset);
+
+        Ok(offset.surcharge)
     }
 
     /// Track and return the current environmental impact for an event.
@@ -4271,13 +8602,164 @@ impl LumentixContract {
         expires_at: u64,
         metadata_hash: BytesN<32>,
     ) -> Result<u64, LumentixError> {
-        admin.require_auth();
+        Self::require_admin(
+            &env,
+            &admin,
+            "issue_identity_credential",
+            vec![
+                &env,
+                subject.into_val(&env),
+                provider.into_val(&env),
+                provider_id.into_val(&env),
+                level.into_val(&env),
+                expires_at.into_val(&env),
+                metadata_hash.into_val(&env),
+            ],
+        )?;
 
-        let stored_admin = storage::get_admin(&env);
-        if admin != stored_admin {
-            return Err(LumentixError::Unauthorized);
+        Self::store_identity_credential(
+            &env,
+            subject,
+            provider,
+            provider_id,
+            level,
+            expires_at,
+            metadata_hash,
+            None,
+        )
+    }
+
+    /// Register an external issuer whose ed25519 signatures are accepted for
+    /// credentials of `provider`, up to `max_level`. Admin only.
+    pub fn register_credential_issuer(
+        env: Env,
+        admin: Address,
+        public_key: BytesN<32>,
+        provider: IdentityProvider,
+        name: String,
+        max_level: u32,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "register_credential_issuer",
+            vec![
+                &env,
+                public_key.into_val(&env),
+                provider.into_val(&env),
+                name.into_val(&env),
+                max_level.into_val(&env),
+            ],
+        )?;
+        validation::validate_string_not_empty(&name)?;
+        validation::validate_identity_level(max_level)?;
+        if storage::get_trusted_issuer(&env, &public_key).is_some() {
+            return Err(LumentixError::CredentialIssuerAlreadyRegistered);
+        }
+
+        let issuer = TrustedIssuer {
+            public_key: public_key.clone(),
+            provider: provider.clone(),
+            name,
+            max_level,
+            active: true,
+            registered_at: env.ledger().timestamp(),
+        };
+        storage::set_trusted_issuer(&env, &issuer);
+
+        CredentialIssuerUpdated::emit(&env, public_key, provider, max_level, true);
+
+        Ok(())
+    }
+
+    /// Stop or resume accepting an issuer's signatures. Credentials it
+    /// already signed stay valid until revoked. Admin only.
+    pub fn set_credential_issuer_active(
+        env: Env,
+        admin: Address,
+        public_key: BytesN<32>,
+        active: bool,
+    ) -> Result<(), LumentixError> {
+        Self::require_admin(
+            &env,
+            &admin,
+            "set_credential_issuer_active",
+            vec![&env, public_key.into_val(&env), active.into_val(&env)],
+        )?;
+
+        let mut issuer = storage::get_trusted_issuer(&env, &public_key)
+            .ok_or(LumentixError::CredentialIssuerNotTrusted)?;
+        issuer.active = active;
+        storage::set_trusted_issuer(&env, &issuer);
+
+        CredentialIssuerUpdated::emit(&env, public_key, issuer.provider, issuer.max_level, active);
+
+        Ok(())
+    }
+
+    /// Get a registered credential issuer.
+    pub fn get_credential_issuer(env: Env, public_key: BytesN<32>) -> Option<TrustedIssuer> {
+        storage::get_trusted_issuer(&env, &public_key)
+    }
+
+    /// Public keys of the issuers registered for an identity provider.
+    pub fn get_provider_issuers(env: Env, provider: IdentityProvider) -> Vec<BytesN<32>> {
+        storage::get_provider_issuers(&env, &provider)
+    }
+
+    /// Present a credential signed by a trusted issuer. The contract checks
+    /// the issuer's ed25519 signature over the payload's XDR encoding and
+    /// creates the credential without the admin. Must be submitted by the
+    /// subject; an invalid signature aborts the call.
+    pub fn present_signed_credential(
+        env: Env,
+        subject: Address,
+        issuer_key: BytesN<32>,
+        payload: SignedCredentialPayload,
+        signature: BytesN<64>,
+    ) -> Result<u64, LumentixError> {
+        subject.require_auth();
+
+        let issuer = storage::get_trusted_issuer(&env, &issuer_key)
+            .filter(|issuer| issuer.active)
+            .ok_or(LumentixError::CredentialIssuerNotTrusted)?;
+        if payload.subject != subject || payload.provider != issuer.provider {
+            return Err(LumentixError::IdentityVerificationFailed);
+        }
+        if payload.verifier != env.current_contract_address()
+            || payload.issued_at > env.ledger().timestamp()
+        {
+            return Err(LumentixError::InvalidIdentityProof);
         }
+        if payload.level > issuer.max_level {
+            return Err(LumentixError::CredentialLevelNotAllowed);
+        }
+
+        env.crypto()
+            .ed25519_verify(&issuer_key, &payload.clone().to_xdr(&env), &signature);
+
+        Self::store_identity_credential(
+            &env,
+            subject,
+            payload.provider,
+            payload.provider_id,
+            payload.level,
+            payload.expires_at,
+            payload.metadata_hash,
+            Some(issuer_key),
+        )
+    }
 
+    fn store_identity_credential(
+        env: &Env,
+        subject: Address,
+        provider: IdentityProvider,
+        provider_id: String,
+        level: u32,
+        expires_at: u64,
+        metadata_hash: BytesN<32>,
+        issuer: Option<BytesN<32>>,
+    ) -> Result<u64, LumentixError> {
         validation::validate_string_not_empty(&provider_id)?;
         validation::validate_identity_level(level)?;
 
@@ -4288,17 +8770,17 @@ impl LumentixContract {
         }
 
         // Check if credential already exists for this subject+provider
-        if storage::get_identity_credential_by_subject(&env, &subject, &provider).is_some() {
+        if storage::get_identity_credential_by_subject(env, &subject, &provider).is_some() {
             return Err(LumentixError::IdentityCredentialAlreadyExists);
         }
 
         // Register provider if not already
-        if !storage::is_identity_provider_supported(&env, &provider) {
-            storage::register_identity_provider(&env, &provider, true);
+        if !storage::is_identity_provider_supported(env, &provider) {
+            storage::register_identity_provider(env, &provider, true);
         }
 
-        let credential_id = storage::get_next_identity_credential_id(&env);
-        storage::increment_identity_credential_id(&env);
+        let credential_id = storage::get_next_identity_credential_id(env);
+        storage::increment_identity_credential_id(env);
 
         let credential = IdentityCredential {
             credential_id,
@@ -4310,12 +8792,13 @@ impl LumentixContract {
             revoked: false,
             metadata_hash,
             level,
+            issuer,
         };
 
-        storage::set_identity_credential(&env, credential_id, &credential);
-        storage::set_identity_credential_by_subject(&env, &subject, &provider, credential_id);
+        storage::set_identity_credential(env, credential_id, &credential);
+        storage::set_identity_credential_by_subject(env, &subject, &provider, credential_id);
 
-        IdentityCredentialIssued::emit(&env, credential_id, subject, provider, level, expires_at);
+        IdentityCredentialIssued::emit(env, credential_id, subject, provider, level, expires_at);
 
         Ok(credential_id)
     }
@@ -4366,7 +8849,9 @@ impl LumentixContract {
     }
 
     /// Validate the authenticity of an identity credential using a cryptographic proof.
-    /// This checks that the proof signature matches the credential and subject.
+    /// The proof must be signed by the credential's issuer over the XDR of
+    /// `(contract address, credential_id, subject, timestamp)` within the last
+    /// five minutes. Admin-issued credentials have no issuer key and are rejected.
     pub fn validate_credential_authenticity(
         env: Env,
         credential_id: u64,
@@ -4396,25 +8881,24 @@ impl LumentixContract {
             return Err(LumentixError::IdentityCredentialExpired);
         }
 
-        // In production, this would verify a cryptographic signature
-        // For now, we validate the proof structure and timestamp freshness
         // Proof timestamp should be within reasonable window (e.g., 5 minutes)
         let min_valid = if now >= 300 { now - 300 } else { 0 };
         if proof.timestamp > now || proof.timestamp < min_valid {
             return Err(LumentixError::InvalidIdentityProof);
         }
 
-        // Verify signature is non-zero (placeholder for actual signature verification)
-        let mut sig_valid = false;
-        for i in 0..64 {
-            if proof.signature.get(i) != Some(0) {
-                sig_valid = true;
-                break;
-            }
-        }
-        if !sig_valid {
-            return Err(LumentixError::InvalidIdentityProof);
-        }
+        let issuer_key = credential
+            .issuer
+            .ok_or(LumentixError::CredentialIssuerNotTrusted)?;
+        let message = (
+            env.current_contract_address(),
+            proof.credential_id,
+            proof.subject,
+            proof.timestamp,
+        )
+            .to_xdr(&env);
+        env.crypto()
+            .ed25519_verify(&issuer_key, &message, &proof.signature);
 
         Ok(true)
     }
@@ -4425,12 +8909,12 @@ impl LumentixContract {
         admin: Address,
         credential_id: u64,
     ) -> Result<(), LumentixError> {
-        admin.require_auth();
-
-        let stored_admin = storage::get_admin(&env);
-        if admin != stored_admin {
-            return Err(LumentixError::Unauthorized);
-        }
+        Self::require_admin(
+            &env,
+            &admin,
+            "revoke_identity_credential",
+            vec![&env, credential_id.into_val(&env)],
+        )?;
 
         let mut credential = storage::get_identity_credential(&env, credential_id)?;
         credential.revoked = true;
@@ -4469,9 +8953,28 @@ impl LumentixContract {
     ) -> Result<bool, LumentixError> {
         subject.require_auth();
 
-        let credential = storage::get_identity_credential(&env, credential_id)?;
+        let credential = Self::check_did_credential(&env, ticket_id, credential_id, &subject)?;
 
-        if credential.subject != subject {
+        BlockchainIdentityVerified::emit(
+            &env,
+            credential_id,
+            subject,
+            credential.provider,
+            true,
+        );
+
+        Ok(true)
+    }
+
+    fn check_did_credential(
+        env: &Env,
+        ticket_id: u64,
+        credential_id: u64,
+        subject: &Address,
+    ) -> Result<IdentityCredential, LumentixError> {
+        let credential = storage::get_identity_credential(env, credential_id)?;
+
+        if credential.subject != *subject {
             return Err(LumentixError::IdentityVerificationFailed);
         }
 
@@ -4484,7 +8987,7 @@ impl LumentixContract {
             return Err(LumentixError::IdentityCredentialExpired);
         }
 
-        let association = storage::get_ticket_did_association(&env, ticket_id)?;
+        let association = storage::get_ticket_did_association(env, ticket_id)?;
 
         if association.credential_id != credential_id {
             return Err(LumentixError::DidCredentialVerificationFailed);
@@ -4494,24 +8997,17 @@ impl LumentixContract {
             return Err(LumentixError::DidCredentialVerificationFailed);
         }
 
-        if association.subject != subject {
+        if association.subject != *subject {
             return Err(LumentixError::DidCredentialVerificationFailed);
         }
 
-        BlockchainIdentityVerified::emit(
-            &env,
-            credential_id,
-            subject,
-            credential.provider,
-            true,
-        );
-
-        Ok(true)
+        Ok(credential)
     }
 
     /// Link a ticket to a DID credential.
     /// The subject must match the ticket owner.
-    /// Only one DID can be linked to a ticket at a time.
+    /// Only one DID can be linked to a ticket at a time; a revoked link, or
+    /// one left by a previous owner, can be replaced.
     pub fn link_ticket_to_did(
         env: Env,
         subject: Address,
@@ -4541,8 +9037,10 @@ impl LumentixContract {
             return Err(LumentixError::IdentityCredentialExpired);
         }
 
-        if storage::has_ticket_did_association(&env, ticket_id) {
-            return Err(LumentixError::TicketDidAlreadyLinked);
+        if let Ok(existing) = storage::get_ticket_did_association(&env, ticket_id) {
+            if !existing.revoked && existing.subject == subject {
+                return Err(LumentixError::TicketDidAlreadyLinked);
+            }
         }
 
         let association = TicketDidAssociation {
@@ -4586,6 +9084,146 @@ impl LumentixContract {
         Ok(())
     }
 
+    /// Gate an event on identity (e.g. 18+ verified). Buyers and transfer
+    /// recipients must hold a valid credential from one of `providers` at
+    /// `min_level` or above, and check-in requires the holder to have linked
+    /// such a credential to the ticket with `link_ticket_to_did`. Organizer only.
+    pub fn set_event_credential_requirement(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        providers: Vec<IdentityProvider>,
+        min_level: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if providers.is_empty() {
+            return Err(LumentixError::InvalidCredentialRequirement);
+        }
+        validation::validate_identity_level(min_level)?;
+
+        storage::set_event_credential_requirement(
+            &env,
+            &EventCredentialRequirement {
+                event_id,
+                providers: providers.clone(),
+                min_level,
+            },
+        );
+
+        EventCredentialRequirementUpdated::emit(&env, event_id, organizer, providers, min_level);
+
+        Ok(())
+    }
+
+    /// Remove an event's credential requirement. Organizer only.
+    pub fn remove_credential_requirement(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        storage::remove_event_credential_requirement(&env, event_id);
+
+        EventCredentialRequirementUpdated::emit(&env, event_id, organizer, Vec::new(&env), 0);
+
+        Ok(())
+    }
+
+    /// Get an event's credential requirement, if any.
+    pub fn get_event_credential_requirement(
+        env: Env,
+        event_id: u64,
+    ) -> Option<EventCredentialRequirement> {
+        storage::get_event_credential_requirement(&env, event_id)
+    }
+
+    /// Whether `holder` may buy or receive tickets for `event_id`. Always true
+    /// for events without a credential requirement.
+    pub fn is_credential_eligible(
+        env: Env,
+        event_id: u64,
+        holder: Address,
+    ) -> Result<bool, LumentixError> {
+        storage::get_event(&env, event_id)?;
+        Ok(Self::require_event_credential(&env, event_id, &holder).is_ok())
+    }
+
+    fn credential_meets_requirement(
+        env: &Env,
+        requirement: &EventCredentialRequirement,
+        credential: &IdentityCredential,
+    ) -> bool {
+        !credential.revoked
+            && env.ledger().timestamp() <= credential.expires_at
+            && credential.level >= requirement.min_level
+            && requirement.providers.contains(&credential.provider)
+    }
+
+    fn require_event_credential(
+        env: &Env,
+        event_id: u64,
+        holder: &Address,
+    ) -> Result<(), LumentixError> {
+        let Some(requirement) = storage::get_event_credential_requirement(env, event_id) else {
+            return Ok(());
+        };
+
+        for provider in requirement.providers.iter() {
+            let credential = storage::get_identity_credential_by_subject(env, holder, &provider)
+                .and_then(|id| storage::get_identity_credential(env, id).ok());
+            if let Some(credential) = credential {
+                if Self::credential_meets_requirement(env, &requirement, &credential) {
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(LumentixError::CredentialRequirementNotMet)
+    }
+
+    /// Check-in for a gated event: the holder presents the DID credential
+    /// linked to the ticket, which must pass the same checks as
+    /// `verify_did_credential` and satisfy the event's requirement.
+    fn require_check_in_credential(
+        env: &Env,
+        ticket_id: u64,
+        ticket: &Ticket,
+    ) -> Result<(), LumentixError> {
+        let Some(requirement) = storage::get_event_credential_requirement(env, ticket.event_id)
+        else {
+            return Ok(());
+        };
+
+        ticket.owner.require_auth();
+        let association = storage::get_ticket_did_association(env, ticket_id)?;
+        let credential =
+            Self::check_did_credential(env, ticket_id, association.credential_id, &ticket.owner)?;
+        if !Self::credential_meets_requirement(env, &requirement, &credential) {
+            return Err(LumentixError::CredentialRequirementNotMet);
+        }
+
+        BlockchainIdentityVerified::emit(
+            env,
+            association.credential_id,
+            ticket.owner.clone(),
+            credential.provider,
+            true,
+        );
+
+        Ok(())
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // ESCROW MANAGEMENT
     // ═══════════════════════════════════════════════════════════════════════════
@@ -4599,12 +9237,12 @@ impl LumentixContract {
         admin: Address,
         chain: String,
     ) -> Result<(), LumentixError> {
-        admin.require_auth();
-
-        let stored_admin = storage::get_admin(&env);
-        if admin != stored_admin {
-            return Err(LumentixError::Unauthorized);
-        }
+        Self::require_admin(
+            &env,
+            &admin,
+            "register_supported_chain",
+            vec![&env, chain.into_val(&env)],
+        )?;
 
         validation::validate_chain_name(&chain)?;
         storage::register_supported_chain(&env, &chain, true);
@@ -4614,11 +9252,10 @@ impl LumentixContract {
 
     /// Pause or unpause the bridge functionality (admin only)
     pub fn set_bridge_paused(env: Env, admin: Address, paused: bool) -> Result<(), LumentixError> {
-        admin.require_auth();
-
-        let stored_admin = storage::get_admin(&env);
-        if admin != stored_admin {
-            return Err(LumentixError::Unauthorized);
+        Self::require_admin(&env, &admin, "set_bridge_paused", vec![&env, paused.into_val(&env)])?;
+        // Pausing is an emergency action; resuming goes through governance
+        if !paused {
+            Self::require_admin_parameter_control(&env)?;
         }
 
         storage::set_bridge_paused(&env, paused);
@@ -4637,6 +9274,8 @@ impl LumentixContract {
         target_chain: String,
         recipient: Address,
     ) -> Result<u64, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_TRANSFERS)?;
         sender.require_auth();
 
         // Check bridge is not paused
@@ -4774,6 +9413,8 @@ impl LumentixContract {
         caller: Address,
         transfer_id: u64,
     ) -> Result<(), LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_TRANSFERS)?;
         caller.require_auth();
 
         let mut transfer = storage::get_cross_chain_transfer(&env, transfer_id)?;
@@ -4804,8 +9445,15 @@ impl LumentixContract {
         // Update ticket ownership if on same chain (for Stellar-to-Stellar simulated)
         // In production, the ticket would be minted/burned across chains
         let mut ticket = storage::get_ticket(&env, transfer.ticket_id)?;
-        ticket.owner = transfer.recipient.clone();
-        storage::set_ticket(&env, transfer.ticket_id, &ticket);
+        Self::require_event_credential(&env, ticket.event_id, &transfer.recipient)?;
+        let from = ticket.owner.clone();
+        Self::persist_ticket_transfer(
+            &env,
+            transfer.ticket_id,
+            &mut ticket,
+            from,
+            transfer.recipient.clone(),
+        );
 
         // Finalize transfer
         transfer.status = CrossChainTransferStatus::Completed;
@@ -4919,6 +9567,8 @@ impl LumentixContract {
         buyer: Address,
         merchandise_id: u64,
     ) -> Result<(), LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_MERCHANDISE)?;
         buyer.require_auth();
 
         let mut item = storage::get_merchandise(&env, merchandise_id)?;
@@ -4975,6 +9625,7 @@ impl LumentixContract {
         ticket_id: u64,
         merchandise_id: u64,
     ) -> Result<(), LumentixError> {
+        Self::require_feature_active(&env, FEATURE_MERCHANDISE)?;
         buyer.require_auth();
         let ticket = storage::get_ticket(&env, ticket_id)?;
         if ticket.owner != buyer {
@@ -4996,6 +9647,8 @@ impl LumentixContract {
         ticket_id: u64,
         merchandise_id: u64,
     ) -> Result<u64, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_MERCHANDISE)?;
         buyer.require_auth();
         let ticket = storage::get_ticket(&env, ticket_id)?;
         if ticket.owner != buyer {
@@ -5045,6 +9698,7 @@ impl LumentixContract {
         ticket_id: u64,
         merchandise_id: u64,
     ) -> Result<u64, LumentixError> {
+        Self::require_feature_active(&env, FEATURE_MERCHANDISE)?;
         buyer.require_auth();
         let ticket = storage::get_ticket(&env, ticket_id)?;
         if ticket.owner != buyer {
@@ -5087,6 +9741,7 @@ impl LumentixContract {
         transferable: bool,
         metadata_hash: soroban_sdk::BytesN<32>,
     ) -> Result<u64, LumentixError> {
+        Self::require_feature_active(&env, FEATURE_NFT_MINTING)?;
         organizer.require_auth();
 
         let event = storage::get_event(&env, event_id)?;
@@ -5187,6 +9842,8 @@ impl LumentixContract {
         to: Address,
         nft_id: u64,
     ) -> Result<(), LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_RESALE)?;
         from.require_auth();
 
         let mut nft = storage::get_nft(&env, nft_id)?;
@@ -5294,6 +9951,7 @@ impl LumentixContract {
         transferable: bool,
         metadata_hash: BytesN<32>,
     ) -> Result<u64, LumentixError> {
+        Self::require_feature_active(&env, FEATURE_NFT_MINTING)?;
         organizer.require_auth();
 
         let event = storage::get_event(&env, event_id)?;
@@ -5445,6 +10103,7 @@ impl LumentixContract {
         attendee: Address,
         ticket_id: u64,
     ) -> Result<MemorabiliaClaim, LumentixError> {
+        Self::require_feature_active(&env, FEATURE_NFT_MINTING)?;
         attendee.require_auth();
 
         let claim = storage::get_memorabilia_claim(&env, ticket_id)?;
@@ -5481,14 +10140,26 @@ impl LumentixContract {
         venue_id: String,
         space_id: String,
         capacity: u32,
-    ) -> Result<(), LumentixError> {
-        organizer.require_auth();
-
-        let event = storage::get_event(&env, event_id)?;
-        if event.organizer != organizer {
-            return Err(LumentixError::Unauthorized);
-        }
-
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        Self::record_venue_space_allocation(&env, event_id, venue_id, space_id, capacity);
+
+        Ok(())
+    }
+
+    fn record_venue_space_allocation(
+        env: &Env,
+        event_id: u64,
+        venue_id: String,
+        space_id: String,
+        capacity: u32,
+    ) {
         let alloc = VenueSpaceAllocation {
             event_id,
             venue_id: venue_id.clone(),
@@ -5498,11 +10169,9 @@ impl LumentixContract {
             is_optimized: false,
         };
 
-        storage::set_venue_space_allocation(&env, event_id, &venue_id, &space_id, &alloc);
-
-        VenueSpaceAllocated::emit(&env, event_id, venue_id, space_id, capacity);
+        storage::set_venue_space_allocation(env, event_id, &venue_id, &space_id, &alloc);
 
-        Ok(())
+        VenueSpaceAllocated::emit(env, event_id, venue_id, space_id, capacity);
     }
 
     pub fn optimize_space_utilization(
@@ -5905,6 +10574,7 @@ impl LumentixContract {
         event_id: u64,
         proposed_price: i128,
     ) -> Result<i128, LumentixError> {
+        Self::require_feature_active(&env, FEATURE_RESALE)?;
         enforcer.require_auth();
 
         let event = storage::get_event(&env, event_id)?;
@@ -5987,6 +10657,9 @@ impl LumentixContract {
         to: Address,
     ) {
         let event_id = ticket.event_id;
+        Self::apply_accessibility_transfer_rule(env, ticket_id, ticket, &to);
+        Self::transfer_insurance_policy(env, ticket_id, &to);
+        Self::move_virtual_streaming_access(env, ticket_id, event_id, Some(&from), Some(&to));
         ticket.owner = to.clone();
         storage::set_ticket(env, ticket_id, ticket);
         storage::append_ticket_transfer_history(
@@ -6025,14 +10698,15 @@ impl LumentixContract {
     }
 
     pub fn register_zkp_params(env: Env, admin: Address, params: String) -> Result<(), LumentixError> {
-        admin.require_auth();
         if !storage::is_initialized(&env) {
             return Err(LumentixError::NotInitialized);
         }
-        let current_admin = storage::get_admin(&env);
-        if current_admin != admin {
-            return Err(LumentixError::Unauthorized);
-        }
+        Self::require_admin(
+            &env,
+            &admin,
+            "register_zkp_params",
+            vec![&env, params.into_val(&env)],
+        )?;
         storage::set_zkp_params(&env, &params);
         Ok(())
     }
@@ -6049,14 +10723,15 @@ impl LumentixContract {
     }
 
     pub fn update_compliance_rules(env: Env, admin: Address, rules: String) -> Result<(), LumentixError> {
-        admin.require_auth();
         if !storage::is_initialized(&env) {
             return Err(LumentixError::NotInitialized);
         }
-        let current_admin = storage::get_admin(&env);
-        if current_admin != admin {
-            return Err(LumentixError::Unauthorized);
-        }
+        Self::require_admin(
+            &env,
+            &admin,
+            "update_compliance_rules",
+            vec![&env, rules.into_val(&env)],
+        )?;
         storage::set_compliance_rules(&env, &rules);
         Ok(())
     }
@@ -6591,4 +11266,447 @@ impl LumentixContract {
 
         Ok(discounted_amount)
     }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // GATE VALIDATORS & MULTI-SIG ESCROW
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Authorize a gate validator (e.g. door staff) to check tickets in for an event.
+    /// Only the event organizer can add validators.
+    pub fn add_validator(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        validator: Address,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        storage::set_event_validator(&env, event_id, &validator);
+        ValidatorAdded::emit(&env, event_id, organizer, validator);
+        Ok(())
+    }
+
+    /// Revoke a gate validator for an event. Only the event organizer can remove validators.
+    pub fn remove_validator(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        validator: Address,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        storage::remove_event_validator(&env, event_id, &validator);
+        ValidatorRemoved::emit(&env, event_id, organizer, validator);
+        Ok(())
+    }
+
+    /// Check whether an address may check tickets in for an event.
+    /// The organizer is always authorized.
+    pub fn is_authorized_validator(
+        env: Env,
+        event_id: u64,
+        validator: Address,
+    ) -> Result<bool, LumentixError> {
+        let event = storage::get_event(&env, event_id)?;
+        Ok(Self::is_gate_validator(&env, &event, &validator))
+    }
+
+    /// Configure the multi-sig signers and approval threshold guarding an event's
+    /// escrow release. Only the event organizer can configure signers, and only
+    /// once; later changes go through `rotate_escrow_signers`.
+    pub fn set_escrow_signers(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        if storage::get_escrow_config(&env, event_id).is_some() {
+            return Err(LumentixError::EscrowSignersLocked);
+        }
+
+        Self::store_escrow_signers(&env, event_id, None, signers, threshold)
+    }
+
+    /// Replace an event's escrow signers. Requires the organizer plus a
+    /// threshold of the current signers; pending release approvals are cleared.
+    pub fn rotate_escrow_signers(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        approvers: Vec<Address>,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+        let current = storage::get_escrow_config(&env, event_id)
+            .ok_or(LumentixError::EscrowConfigNotFound)?;
+
+        let mut approved: Vec<Address> = Vec::new(&env);
+        for approver in approvers.iter() {
+            if !current.signers.contains(&approver) {
+                return Err(LumentixError::Unauthorized);
+            }
+            if approved.contains(&approver) {
+                continue;
+            }
+            approver.require_auth();
+            approved.push_back(approver);
+        }
+        if approved.len() < current.threshold {
+            return Err(LumentixError::EscrowThresholdNotMet);
+        }
+
+        Self::store_escrow_signers(&env, event_id, Some(current), signers, threshold)
+    }
+
+    fn store_escrow_signers(
+        env: &Env,
+        event_id: u64,
+        previous: Option<EscrowConfig>,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), LumentixError> {
+        if threshold == 0 || threshold > signers.len() {
+            return Err(LumentixError::InvalidEscrowThreshold);
+        }
+        // A repeated signer would count twice towards the threshold
+        let mut unique: Vec<Address> = Vec::new(env);
+        for signer in signers.iter() {
+            if unique.contains(&signer) {
+                return Err(LumentixError::InvalidEscrowThreshold);
+            }
+            unique.push_back(signer);
+        }
+
+        // Approvals given under the old signer set do not carry over
+        if let Some(previous) = previous {
+            for signer in previous.signers.iter() {
+                storage::remove_escrow_approval(env, event_id, &signer);
+            }
+        }
+
+        let signer_count = signers.len();
+        storage::set_escrow_config(
+            env,
+            event_id,
+            &EscrowConfig {
+                event_id,
+                signers,
+                threshold,
+            },
+        );
+
+        EscrowSignersSet::emit(env, event_id, signer_count, threshold);
+        Ok(())
+    }
+
+    /// Approve the release of an event's escrow. Only configured signers may approve.
+    pub fn approve_release(env: Env, event_id: u64, signer: Address) -> Result<(), LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_ESCROW_RELEASES)?;
+        signer.require_auth();
+
+        let config = storage::get_escrow_config(&env, event_id)
+            .ok_or(LumentixError::EscrowConfigNotFound)?;
+        if !config.signers.contains(&signer) {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        storage::set_escrow_approval(&env, event_id, &signer);
+        EscrowReleaseApproval::emit(&env, event_id, signer, true);
+        Ok(())
+    }
+
+    /// Withdraw a previously given escrow release approval.
+    pub fn revoke_approval(env: Env, event_id: u64, signer: Address) -> Result<(), LumentixError> {
+        signer.require_auth();
+
+        storage::get_escrow_config(&env, event_id).ok_or(LumentixError::EscrowConfigNotFound)?;
+        storage::remove_escrow_approval(&env, event_id, &signer);
+        EscrowReleaseApproval::emit(&env, event_id, signer, false);
+        Ok(())
+    }
+
+    /// Get the multi-sig escrow configuration for an event.
+    pub fn get_escrow_config(env: Env, event_id: u64) -> Result<EscrowConfig, LumentixError> {
+        storage::get_escrow_config(&env, event_id).ok_or(LumentixError::EscrowConfigNotFound)
+    }
+
+    /// Number of signers currently approving the release of an event's escrow.
+    pub fn get_escrow_approval_count(env: Env, event_id: u64) -> Result<u32, LumentixError> {
+        let config = storage::get_escrow_config(&env, event_id)
+            .ok_or(LumentixError::EscrowConfigNotFound)?;
+        Ok(Self::count_escrow_approvals(&env, &config))
+    }
+
+    // ── Internal validator / escrow helpers ──────────────────────────────────
+
+    fn is_gate_validator(env: &Env, event: &Event, caller: &Address) -> bool {
+        event.organizer == *caller || storage::is_event_validator(env, event.id, caller)
+    }
+
+    fn count_escrow_approvals(env: &Env, config: &EscrowConfig) -> u32 {
+        let mut approvals = 0u32;
+        for signer in config.signers.iter() {
+            if storage::has_escrow_approval(env, config.event_id, &signer) {
+                approvals += 1;
+            }
+        }
+        approvals
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // SPONSORSHIPS
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Register a sponsor tier for an event. Only the event organizer can register tiers.
+    /// `booth_spaces` lists the booth space IDs (within `booth_venue_id`) handed out
+    /// in contribution order; leave it empty for tiers without a booth.
+    pub fn register_sponsor_tier(
+        env: Env,
+        organizer: Address,
+        event_id: u64,
+        name: String,
+        price: i128,
+        max_sponsors: u32,
+        comp_tickets: u32,
+        booth_venue_id: String,
+        booth_spaces: Vec<String>,
+        booth_capacity: u32,
+    ) -> Result<u32, LumentixError> {
+        organizer.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.organizer != organizer {
+            return Err(LumentixError::Unauthorized);
+        }
+
+        if event.status == EventStatus::Cancelled || event.status == EventStatus::Completed {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        if price <= 0
+            || max_sponsors == 0
+            || (!booth_spaces.is_empty() && booth_spaces.len() < max_sponsors)
+        {
+            return Err(LumentixError::InvalidSponsorTier);
+        }
+
+        let tier_id = storage::get_next_sponsor_tier_id(&env, event_id);
+        storage::increment_sponsor_tier_id(&env, event_id);
+
+        let tier = SponsorTier {
+            event_id,
+            tier_id,
+            name,
+            price,
+            max_sponsors,
+            sponsor_count: 0,
+            comp_tickets,
+            booth_venue_id,
+            booth_spaces,
+            booth_capacity,
+        };
+        storage::set_sponsor_tier(&env, event_id, tier_id, &tier);
+
+        SponsorTierRegistered::emit(&env, event_id, tier_id, price, max_sponsors);
+
+        Ok(tier_id)
+    }
+
+    /// Sponsor an event tier. The full tier price is paid into the event escrow
+    /// (no platform fee), and the tier's complimentary tickets and booth are
+    /// issued to the sponsor immediately.
+    pub fn contribute(
+        env: Env,
+        sponsor: Address,
+        event_id: u64,
+        tier_id: u32,
+        amount: i128,
+    ) -> Result<SponsorContribution, LumentixError> {
+        Self::require_not_globally_paused(&env)?;
+        Self::require_feature_active(&env, FEATURE_PURCHASES)?;
+        sponsor.require_auth();
+
+        let mut event = storage::get_event(&env, event_id)?;
+        if event.status != EventStatus::Published {
+            return Err(LumentixError::InvalidStatusTransition);
+        }
+
+        let mut tier = storage::get_sponsor_tier(&env, event_id, tier_id)?;
+        if tier.sponsor_count >= tier.max_sponsors {
+            return Err(LumentixError::SponsorTierFull);
+        }
+
+        if amount != tier.price {
+            return Err(LumentixError::InvalidAmount);
+        }
+
+        if storage::get_sponsor_contribution(&env, event_id, tier_id, &sponsor).is_some() {
+            return Err(LumentixError::AlreadySponsored);
+        }
+
+        // Complimentary tickets take up regular capacity
+        if event.tickets_sold.saturating_add(tier.comp_tickets) > event.max_tickets {
+            return Err(LumentixError::EventSoldOut);
+        }
+        if tier.comp_tickets > 0 {
+            Self::require_event_credential(&env, event_id, &sponsor)?;
+        }
+
+        if let Ok(token_address) = storage::get_token_result(&env) {
+            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+            token_client.transfer(&sponsor, env.current_contract_address(), &amount);
+        }
+        storage::add_escrow(&env, event_id, amount);
+
+        let now = env.ledger().timestamp();
+        let mut comp_ticket_ids = Vec::new(&env);
+        for _ in 0..tier.comp_tickets {
+            let ticket_id = storage::get_next_ticket_id(&env);
+            storage::increment_ticket_id(&env);
+
+            let ticket = Ticket {
+                id: ticket_id,
+                event_id,
+                owner: sponsor.clone(),
+                purchase_time: now,
+                used: false,
+                refunded: false,
+                revoked: false,
+                vip_tier: None,
+                seat_id: None,
+                accessibility_type: None,
+            };
+            storage::set_ticket(&env, ticket_id, &ticket);
+            storage::set_comp_ticket(&env, ticket_id);
+            comp_ticket_ids.push_back(ticket_id);
+        }
+        event.tickets_sold += tier.comp_tickets;
+        storage::set_event(&env, event_id, &event);
+
+        let booth_space_id = tier.booth_spaces.get(tier.sponsor_count);
+        if let Some(space_id) = booth_space_id.clone() {
+            Self::record_venue_space_allocation(
+                &env,
+                event_id,
+                tier.booth_venue_id.clone(),
+                space_id,
+                tier.booth_capacity,
+            );
+        }
+
+        tier.sponsor_count += 1;
+        storage::set_sponsor_tier(&env, event_id, tier_id, &tier);
+        storage::add_tier_sponsor(&env, event_id, tier_id, &sponsor);
+
+        let contribution = SponsorContribution {
+            event_id,
+            tier_id,
+            sponsor: sponsor.clone(),
+            amount,
+            contributed_at: now,
+            refunded: false,
+            comp_ticket_ids,
+            booth_space_id,
+        };
+        storage::set_sponsor_contribution(&env, &contribution);
+
+        SponsorContributed::emit(&env, event_id, tier_id, sponsor, amount, tier.comp_tickets);
+
+        Ok(contribution)
+    }
+
+    /// Refund a sponsor's contribution after the event is cancelled.
+    /// Complimentary tickets issued for the sponsorship are revoked.
+    pub fn refund_sponsor(
+        env: Env,
+        sponsor: Address,
+        event_id: u64,
+        tier_id: u32,
+    ) -> Result<i128, LumentixError> {
+        Self::require_feature_active(&env, FEATURE_REFUNDS)?;
+        sponsor.require_auth();
+
+        let event = storage::get_event(&env, event_id)?;
+        if event.status != EventStatus::Cancelled {
+            return Err(LumentixError::EventNotCancelled);
+        }
+
+        let mut contribution = storage::get_sponsor_contribution(&env, event_id, tier_id, &sponsor)
+            .ok_or(LumentixError::SponsorContributionNotFound)?;
+        if contribution.refunded {
+            return Err(LumentixError::SponsorAlreadyRefunded);
+        }
+
+        storage::deduct_escrow(&env, event_id, contribution.amount)?;
+
+        if let Ok(token_address) = storage::get_token_result(&env) {
+            let token_client = soroban_sdk::token::Client::new(&env, &token_address);
+            token_client.transfer(&env.current_contract_address(), &sponsor, &contribution.amount);
+        }
+
+        for ticket_id in contribution.comp_ticket_ids.iter() {
+            if let Ok(mut ticket) = storage::get_ticket(&env, ticket_id) {
+                ticket.revoked = true;
+                storage::set_ticket(&env, ticket_id, &ticket);
+            }
+        }
+
+        contribution.refunded = true;
+        storage::set_sponsor_contribution(&env, &contribution);
+
+        SponsorRefunded::emit(&env, event_id, tier_id, sponsor, contribution.amount);
+
+        Ok(contribution.amount)
+    }
+
+    /// Get a sponsor tier
+    pub fn get_sponsor_tier(env: Env, event_id: u64, tier_id: u32) -> Result<SponsorTier, LumentixError> {
+        storage::get_sponsor_tier(&env, event_id, tier_id)
+    }
+
+    /// Get a sponsor's contribution to a tier
+    pub fn get_sponsor_contribution(
+        env: Env,
+        event_id: u64,
+        tier_id: u32,
+        sponsor: Address,
+    ) -> Result<SponsorContribution, LumentixError> {
+        storage::get_sponsor_contribution(&env, event_id, tier_id, &sponsor)
+            .ok_or(LumentixError::SponsorContributionNotFound)
+    }
+
+    /// Get the number of sponsors and their addresses for a tier
+    pub fn get_tier_contributions(
+        env: Env,
+        event_id: u64,
+        tier_id: u32,
+    ) -> Result<(u32, Vec<Address>), LumentixError> {
+        storage::get_sponsor_tier(&env, event_id, tier_id)?;
+        let sponsors = storage::get_tier_sponsors(&env, event_id, tier_id);
+        Ok((sponsors.len(), sponsors))
+    }
 }
@@ -2,7 +2,7 @@
 
 use crate::error::LumentixError;
 use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
-use crate::types::{EventStatus, RarityTier};
+use crate::types::{CancellationReason, EventStatus, RarityTier};
 use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
 
 fn setup(env: &Env) -> (Address, LumentixContractClient<'_>) {
@@ -88,7 +88,7 @@ fn test_create_merchandise_cancelled_event_fails() {
     let (_admin, client) = setup(&env);
     let organizer = Address::generate(&env);
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     let result = client.try_create_event_merchandise(
         &organizer,
@@ -0,0 +1,9 @@
+use crate::types::{OracleAsset, OraclePriceData};
+use soroban_sdk::{contractclient, Env};
+
+/// Subset of the SEP-40 price feed interface used for currency conversion.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn lastprice(env: Env, asset: OracleAsset) -> Option<OraclePriceData>;
+    fn decimals(env: Env) -> u32;
+}
@@ -0,0 +1,158 @@
+#![allow(warnings)]
+
+use crate::error::LumentixError;
+use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
+use crate::types::{EventStatus, REPUTATION_HALF_LIFE_SECS};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+fn setup(env: &Env) -> (Address, LumentixContractClient<'_>) {
+    let contract_id = env.register(LumentixContract, ());
+    let client = LumentixContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    (admin, client)
+}
+
+fn create_published_event(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+    start_time: u64,
+) -> u64 {
+    let event_id = client.create_event(
+        organizer,
+        &String::from_str(env, "Reviewed Event"),
+        &String::from_str(env, "Description"),
+        &String::from_str(env, "Venue"),
+        &start_time,
+        &(start_time + 1000),
+        &100i128,
+        &10u32,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, organizer);
+    event_id
+}
+
+/// Buy and check in a ticket per rating, complete the event, then submit
+/// one review per rating. Returns the review IDs.
+fn review_event(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+    event_id: u64,
+    ratings: &[u32],
+) -> soroban_sdk::Vec<u64> {
+    let mut attendees = soroban_sdk::Vec::new(env);
+    for _ in ratings {
+        let attendee = Address::generate(env);
+        let ticket_id = client.purchase_ticket(&attendee, &event_id, &100i128, &false, &None);
+        client.use_ticket(&ticket_id, organizer);
+        attendees.push_back((attendee, ticket_id));
+    }
+
+    let end_time = client.get_event(&event_id).end_time;
+    env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+    client.complete_event(organizer, &event_id);
+
+    let mut review_ids = soroban_sdk::Vec::new(env);
+    for (i, rating) in ratings.iter().enumerate() {
+        let (attendee, ticket_id) = attendees.get(i as u32).unwrap();
+        review_ids.push_back(client.submit_event_review(
+            &attendee,
+            &event_id,
+            &ticket_id,
+            rating,
+            &String::from_str(env, "Review"),
+        ));
+    }
+    review_ids
+}
+
+#[test]
+fn test_reputation_uses_real_standard_deviation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer, 1000);
+    review_event(&env, &client, &organizer, event_id, &[5, 5, 1]);
+
+    let rep = client.get_organizer_reputation(&organizer);
+    assert_eq!(rep.total_reviews, 3);
+    assert_eq!(rep.total_ratings_sq_sum, 51);
+    assert_eq!(rep.average_rating_x100, 366);
+    assert_eq!(rep.std_dev_x100, 189);
+    // base 4392 + volume 120 + consistency 110
+    assert_eq!(rep.reputation_score, 4622);
+
+    let breakdown = rep.event_breakdowns.get(0).unwrap();
+    assert_eq!(breakdown.event_id, event_id);
+    assert_eq!(breakdown.review_count, 3);
+    assert_eq!(breakdown.average_rating_x100, 366);
+}
+
+#[test]
+fn test_old_reviews_decay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup(&env);
+    let organizer = Address::generate(&env);
+
+    let old_event = create_published_event(&env, &client, &organizer, 1000);
+    review_event(&env, &client, &organizer, old_event, &[1]);
+
+    let start = 2001 + REPUTATION_HALF_LIFE_SECS;
+    let new_event = create_published_event(&env, &client, &organizer, start);
+    review_event(&env, &client, &organizer, new_event, &[5]);
+
+    let rep = client.calculate_reputation_score(&organizer);
+    assert_eq!(rep.average_rating_x100, 300);
+    assert!(rep.weighted_average_x100 > 360);
+    assert_eq!(rep.event_breakdowns.len(), 2);
+}
+
+#[test]
+fn test_organizer_response_and_moderation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer, 1000);
+    let review_ids = review_event(&env, &client, &organizer, event_id, &[5, 5, 1]);
+    let abusive = review_ids.get(2).unwrap();
+
+    let outsider = Address::generate(&env);
+    let result =
+        client.try_respond_to_review(&outsider, &abusive, &String::from_str(&env, "Sorry"));
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    client.respond_to_review(&organizer, &abusive, &String::from_str(&env, "Sorry"));
+    assert_eq!(
+        client.get_review(&abusive).organizer_response,
+        Some(String::from_str(&env, "Sorry"))
+    );
+
+    let reason = String::from_str(&env, "Abusive language");
+    let result = client.try_hide_review(&outsider, &abusive, &reason);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    client.hide_review(&admin, &abusive, &reason);
+    assert!(client.get_review(&abusive).hidden);
+    let rep = client.get_organizer_reputation(&organizer);
+    assert_eq!(rep.total_reviews, 2);
+    assert_eq!(rep.hidden_reviews, 1);
+    assert_eq!(rep.std_dev_x100, 0);
+    assert_eq!(rep.reputation_score, 8080);
+    assert_eq!(rep.event_breakdowns.get(0).unwrap().review_count, 2);
+
+    let result = client.try_hide_review(&admin, &abusive, &reason);
+    assert_eq!(result, Err(Ok(LumentixError::ReviewAlreadyHidden)));
+
+    client.restore_review(&admin, &abusive, &String::from_str(&env, "Appeal"));
+    let rep = client.get_organizer_reputation(&organizer);
+    assert_eq!(rep.total_reviews, 3);
+    assert_eq!(rep.hidden_reviews, 0);
+    assert_eq!(rep.reputation_score, 4622);
+}
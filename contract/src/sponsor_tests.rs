@@ -0,0 +1,305 @@
+#![allow(warnings)]
+
+use crate::error::LumentixError;
+use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
+use crate::types::{CancellationReason, EventStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, String, Vec,
+};
+
+fn setup(env: &Env) -> (Address, Address, LumentixContractClient<'_>) {
+    let contract_id = env.register(LumentixContract, ());
+    let client = LumentixContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    (admin, contract_id, client)
+}
+
+fn create_and_publish_event(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+) -> u64 {
+    let event_id = client.create_event(
+        organizer,
+        &String::from_str(env, "Sponsored Event"),
+        &String::from_str(env, "Description"),
+        &String::from_str(env, "Venue"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &10u32,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, organizer);
+    event_id
+}
+
+fn register_tier(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+    event_id: u64,
+    price: i128,
+    max_sponsors: u32,
+    comp_tickets: u32,
+    booth_spaces: &Vec<String>,
+) -> u32 {
+    client.register_sponsor_tier(
+        organizer,
+        &event_id,
+        &String::from_str(env, "Gold"),
+        &price,
+        &max_sponsors,
+        &comp_tickets,
+        &String::from_str(env, "expo_hall"),
+        booth_spaces,
+        &4u32,
+    )
+}
+
+#[test]
+fn test_register_sponsor_tier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let tier_id = register_tier(&env, &client, &organizer, event_id, 500, 2, 0, &Vec::new(&env));
+
+    let tier = client.get_sponsor_tier(&event_id, &tier_id);
+    assert_eq!(tier.price, 500);
+    assert_eq!(tier.max_sponsors, 2);
+    assert_eq!(tier.sponsor_count, 0);
+}
+
+#[test]
+fn test_register_sponsor_tier_non_organizer_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let result = client.try_register_sponsor_tier(
+        &attacker,
+        &event_id,
+        &String::from_str(&env, "Gold"),
+        &500i128,
+        &2u32,
+        &0u32,
+        &String::from_str(&env, "expo_hall"),
+        &Vec::new(&env),
+        &0u32,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+}
+
+#[test]
+fn test_register_sponsor_tier_too_few_booths_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let mut booths = Vec::new(&env);
+    booths.push_back(String::from_str(&env, "booth_1"));
+
+    let result = client.try_register_sponsor_tier(
+        &organizer,
+        &event_id,
+        &String::from_str(&env, "Gold"),
+        &500i128,
+        &2u32,
+        &0u32,
+        &String::from_str(&env, "expo_hall"),
+        &booths,
+        &4u32,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::InvalidSponsorTier)));
+}
+
+#[test]
+fn test_contribute_transfers_tokens_into_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_client = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&sponsor, &1_000i128);
+    client.set_token(&admin, &token_address);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let tier_id = register_tier(&env, &client, &organizer, event_id, 500, 2, 0, &Vec::new(&env));
+
+    client.contribute(&sponsor, &event_id, &tier_id, &500i128);
+
+    assert_eq!(token_client.balance(&sponsor), 500);
+    assert_eq!(token_client.balance(&contract_id), 500);
+    assert_eq!(client.get_escrow_balance(&event_id), 500);
+
+    let (count, sponsors) = client.get_tier_contributions(&event_id, &tier_id);
+    assert_eq!(count, 1);
+    assert_eq!(sponsors.get(0).unwrap(), sponsor);
+}
+
+#[test]
+fn test_contribute_issues_comp_tickets_and_booth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let sponsor1 = Address::generate(&env);
+    let sponsor2 = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let mut booths = Vec::new(&env);
+    booths.push_back(String::from_str(&env, "booth_1"));
+    booths.push_back(String::from_str(&env, "booth_2"));
+    let tier_id = register_tier(&env, &client, &organizer, event_id, 500, 2, 2, &booths);
+
+    let first = client.contribute(&sponsor1, &event_id, &tier_id, &500i128);
+    let second = client.contribute(&sponsor2, &event_id, &tier_id, &500i128);
+
+    assert_eq!(first.comp_ticket_ids.len(), 2);
+    for ticket_id in first.comp_ticket_ids.iter() {
+        assert_eq!(client.get_ticket_info(&ticket_id).owner, sponsor1);
+    }
+    assert_eq!(first.booth_space_id, Some(String::from_str(&env, "booth_1")));
+    assert_eq!(second.booth_space_id, Some(String::from_str(&env, "booth_2")));
+    assert_eq!(client.get_event(&event_id).tickets_sold, 4);
+}
+
+#[test]
+fn test_contribute_beyond_capacity_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let tier_id = register_tier(&env, &client, &organizer, event_id, 50, 1, 0, &Vec::new(&env));
+
+    client.contribute(&Address::generate(&env), &event_id, &tier_id, &50i128);
+
+    let result = client.try_contribute(&Address::generate(&env), &event_id, &tier_id, &50i128);
+    assert_eq!(result, Err(Ok(LumentixError::SponsorTierFull)));
+}
+
+#[test]
+fn test_contribute_incorrect_amount_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let tier_id = register_tier(&env, &client, &organizer, event_id, 123, 2, 0, &Vec::new(&env));
+
+    let result = client.try_contribute(&Address::generate(&env), &event_id, &tier_id, &1i128);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidAmount)));
+}
+
+#[test]
+fn test_contribute_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let tier_id = register_tier(&env, &client, &organizer, event_id, 50, 2, 0, &Vec::new(&env));
+
+    client.contribute(&sponsor, &event_id, &tier_id, &50i128);
+
+    let result = client.try_contribute(&sponsor, &event_id, &tier_id, &50i128);
+    assert_eq!(result, Err(Ok(LumentixError::AlreadySponsored)));
+}
+
+#[test]
+fn test_contribute_emits_sponsor_contributed_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let tier_id = register_tier(&env, &client, &organizer, event_id, 50, 2, 0, &Vec::new(&env));
+
+    client.contribute(&Address::generate(&env), &event_id, &tier_id, &50i128);
+
+    assert!(!env.events().all().events().is_empty());
+}
+
+#[test]
+fn test_refund_sponsor_after_cancellation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+
+    let token_admin = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_client = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&sponsor, &500i128);
+    client.set_token(&admin, &token_address);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let tier_id = register_tier(&env, &client, &organizer, event_id, 500, 1, 1, &Vec::new(&env));
+    let contribution = client.contribute(&sponsor, &event_id, &tier_id, &500i128);
+
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+
+    let refunded = client.refund_sponsor(&sponsor, &event_id, &tier_id);
+    assert_eq!(refunded, 500);
+    assert_eq!(token_client.balance(&sponsor), 500);
+    assert_eq!(client.get_escrow_balance(&event_id), 0);
+
+    let comp_ticket = contribution.comp_ticket_ids.get(0).unwrap();
+    assert!(client.get_ticket_info(&comp_ticket).revoked);
+
+    let result = client.try_refund_sponsor(&sponsor, &event_id, &tier_id);
+    assert_eq!(result, Err(Ok(LumentixError::SponsorAlreadyRefunded)));
+}
+
+#[test]
+fn test_refund_sponsor_requires_cancelled_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let tier_id = register_tier(&env, &client, &organizer, event_id, 50, 1, 0, &Vec::new(&env));
+    client.contribute(&sponsor, &event_id, &tier_id, &50i128);
+
+    let result = client.try_refund_sponsor(&sponsor, &event_id, &tier_id);
+    assert_eq!(result, Err(Ok(LumentixError::EventNotCancelled)));
+}
+
+#[test]
+fn test_comp_ticket_cannot_be_refunded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, _contract_id, client) = setup(&env);
+    let organizer = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let tier_id = register_tier(&env, &client, &organizer, event_id, 50, 1, 1, &Vec::new(&env));
+    let contribution = client.contribute(&sponsor, &event_id, &tier_id, &50i128);
+
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+
+    let comp_ticket = contribution.comp_ticket_ids.get(0).unwrap();
+    let result = client.try_refund_ticket(&comp_ticket, &sponsor);
+    assert_eq!(result, Err(Ok(LumentixError::RefundNotAllowed)));
+}
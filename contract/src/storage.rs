@@ -1,23 +1,44 @@
 use crate::error::LumentixError;
 use crate::types::{
-    AccessibilityBooking, AccessibilityInventory, AnonymousSurveyResponse, BridgeTransaction,
-    CarbonFootprint,
+    AcceptedToken, AccessibilityBooking, AccessibilityInventory, AccommodationType,
+    AnonymousSurveyResponse, BridgeTransaction,
+    AttendeeCarbonOffset, CarbonFootprint, CarbonProject, CheckoutCarbonOffset, EmissionFactors,
+    EnergyMix, FootprintProfile, TransportMode, VenueType,
     CarbonOffsetPurchase, CollectibleInventory, CrossChainLock, CrossChainTransfer, CurrencyConfig,
-    EnvironmentalImpact, Event, EventMerchandise, EventReview, IdentityCredential,
-    IdentityProvider, InsurancePolicy, InsurancePool, MemorabiliaClaim, MerchVoucher, NftCollectible,
+    EnvironmentalImpact, EscrowConfig, Event, EventCancellation, EventMerchandise, EventReview,
+    EventCredentialRequirement, IdentityCredential, TrustedIssuer,
+    IdentityProvider, InsuranceLpPosition, InsurancePolicy, InsurancePool, InsurancePoolParams,
+    InsuranceProduct, InsuranceSettlement, MemorabiliaClaim,
+    MerchVoucher, NftCollectible,
+    OracleFeed, PriceQuote,
     OrganizerReputation, PromoCode, ResalePriceCeiling, ScheduleVote, ScheduleVoteCastRecord, Seat, SeatUpgradeBid,
-    Ticket, TicketDidAssociation, TicketTransferRecord, TransferBlackout, ReferralLinkRecord,
-    UpgradeGovernanceConfig, UpgradeProposal, UpgradeVote,
-    VenueLayout, VipTier, WaitlistOffer, PricingSchedule, MintGasUsage, StreamDeliveryConfig,
-    StreamPerformanceMetrics, INSTANCE_LIFETIME, PERSISTENT_LIFETIME,
+    SponsorContribution, SponsorTier,
+    Ticket, TicketDidAssociation, TicketPayment, TicketTransferRecord, TransferBlackout, ReferralLinkRecord,
+    AdminCouncil, GovernanceAction, PendingAdmin,
+    UpgradeGovernanceConfig, UpgradeProposal, UpgradeSafeguards, UpgradeVote,
+    VenueLayout, VipBenefitRedemption, VipPurchase, VipTier, WaitlistOffer, PricingSchedule, MintGasUsage, StreamDeliveryConfig,
+    StreamPerformanceMetrics, DEFAULT_INSURANCE_WITHDRAWAL_COOLDOWN_SECS,
+    DEFAULT_UPGRADE_TIMELOCK_SECS, INSTANCE_LIFETIME,
+    PERSISTENT_LIFETIME, STORAGE_SCHEMA_VERSION, TEMPORARY_LIFETIME,
+    AccessibilityBookingStatus, AccessibilityBookingV1, CarbonOffsetPurchaseV1, CurrencyConfigV1,
+    EnvironmentalImpactV1, EventReviewV1, IdentityCredentialV1, InsurancePolicyV1,
+    InsurancePoolV1, MigrationProgress, OrganizerReputationV1, UpgradeKind, UpgradeProposalV1,
+    LEGACY_INSURANCE_PRODUCT_ID,
     VenueSpaceAllocation, SubscriptionPlan, SubscriptionStatus, SecurityIncident, UserPreferences,
+    ReplayConfig, ReplayEntitlement, StreamAccessToken, VirtualSession, VirtualTicketClass,
+    QosReport, StreamingSla,
 
   CertificationStandard, EventCertificate,
 };
-use soroban_sdk::{Address, BytesN, Env, String, Vec};
+use soroban_sdk::{
+    panic_with_error, symbol_short, Address, BytesN, Env, IntoVal, Map, String, Symbol,
+    TryFromVal, Val, Vec,
+};
 
 // Storage keys
 const INITIALIZED: &str = "INIT";
+const STORAGE_VERSION: &str = "STORE_VER";
+const MIGRATION_PROGRESS: &str = "MIGRATION";
 const ADMIN: &str = "ADMIN";
 const TOKEN: &str = "TOKEN";
 const EVENT_ID_COUNTER: &str = "EVENT_CTR";
@@ -33,11 +54,18 @@ const REFERRAL_LINK_PREFIX: &str = "REFLINK_";
 const REFERRAL_CODE_PREFIX: &str = "REFCODE_";
 const REFERRAL_PURCHASE_PREFIX: &str = "REFBUY_";
 const VIP_TIER_PREFIX: &str = "VIP_";
+const VIP_PURCHASE_PREFIX: &str = "VIPBUY_";
+const VIP_REDEMPTION_PREFIX: &str = "VIPREDM_";
 const ACCESSIBILITY_INV_PREFIX: &str = "ACCINV_";
 const ACCESSIBILITY_BOOKING_PREFIX: &str = "ACCBOOK_";
+const ACCOMMODATION_TYPE_PREFIX: &str = "ACCTYPE_";
+const ACCOMMODATION_TYPE_LIST_PREFIX: &str = "ACCTYPES_";
+const ACCESSIBILITY_TICKET_PREFIX: &str = "ACCTKT_";
+const ACCESSIBILITY_TRANSFER_PREFIX: &str = "ACCXFER_";
 const VENUE_LAYOUT_PREFIX: &str = "VENUE_";
 const SEAT_PREFIX: &str = "SEAT_";
 const CURRENCY_CONFIG_PREFIX: &str = "CURCFG_";
+const CURRENCY_FEED_PREFIX: &str = "CURFEED_";
 const ACC_BOOKING_COUNTER: &str = "ACC_CTR";
 const WAITLIST_QUEUE_PREFIX: &str = "WQUEUE_";
 const WAITLIST_OFFER_PREFIX: &str = "WOFFER_";
@@ -47,9 +75,29 @@ const PRICING_SCHEDULE_PREFIX: &str = "PRSCHED_";
 const MINT_GAS_PREFIX: &str = "MINTGAS_";
 const STREAM_DELIVERY_PREFIX: &str = "STRMDEL_";
 const STREAM_PERF_PREFIX: &str = "STRMPERF_";
+const VIRTUAL_CLASS_PREFIX: &str = "VIRTCLS_";
+const VIRTUAL_TICKET_PREFIX: &str = "VIRTTKT_";
+const VIRTUAL_SESSION_PREFIX: &str = "VIRTSESS_";
+const STREAM_TOKEN_PREFIX: &str = "STRMTOK_";
+const REPLAY_CONFIG_PREFIX: &str = "REPLAYCFG_";
+const REPLAY_ENTITLEMENT_PREFIX: &str = "REPLAYENT_";
+const QOS_REPORTER_PREFIX: &str = "QOSRPTR_";
+const QOS_REPORTS_PREFIX: &str = "QOSRPT_";
+const STREAMING_SLA_PREFIX: &str = "SLA_";
+const SLA_REFUND_PREFIX: &str = "SLAREF_";
 const INSURANCE_POLICY_PREFIX: &str = "INSPOL_";
 const INSURANCE_POLICY_ID_COUNTER: &str = "INSPOL_CTR";
 const INSURANCE_POOL: &str = "INSPOOL";
+const INSURANCE_TICKET_PREFIX: &str = "INSTKT_";
+const INSURANCE_PRODUCT_PREFIX: &str = "INSPROD_";
+const INSURANCE_PRODUCT_COUNTER: &str = "INSPROD_CTR";
+const TICKET_PRICE_PAID_PREFIX: &str = "TKTPAID_";
+const INSURANCE_POOL_PARAMS: &str = "INSPARAMS";
+const INSURANCE_LP_PREFIX: &str = "INSLP_";
+const INSURANCE_EVENT_EXPOSURE_PREFIX: &str = "INSEXP_";
+const INSURANCE_EVENT_POLICIES_PREFIX: &str = "INSEVT_";
+const INSURANCE_SETTLEMENT_PREFIX: &str = "INSSETL_";
+const EVENT_CANCELLATION_PREFIX: &str = "EVCANCEL_";
 const REVIEW_PREFIX: &str = "REVIEW_";
 const REVIEW_ID_COUNTER: &str = "REVIEW_CTR";
 const REVIEWER_EVENT_PREFIX: &str = "REVEVT_";
@@ -78,6 +126,27 @@ const SCHEDULE_VOTE_PREFIX: &str = "SCHVOTE_";
 const SCHEDULE_VOTE_CAST_PREFIX: &str = "SCHCAST_";
 const PROMO_CODE_PREFIX: &str = "PROMO_";
 const PROMO_USER_USAGE_PREFIX: &str = "PROMOUSR_";
+const EVENT_VALIDATOR_PREFIX: &str = "EVVALID_";
+const ESCROW_CONFIG_PREFIX: &str = "ESCCFG_";
+const ESCROW_APPROVAL_PREFIX: &str = "ESCAPPR_";
+const SPONSOR_TIER_CTR_PREFIX: &str = "SPTIERCTR_";
+const SPONSOR_TIER_PREFIX: &str = "SPTIER_";
+const SPONSOR_LIST_PREFIX: &str = "SPLIST_";
+const SPONSOR_CONTRIB_PREFIX: &str = "SPCONTR_";
+const COMP_TICKET_PREFIX: &str = "COMPTKT_";
+const ACCEPTED_TOKENS_PREFIX: &str = "ACCTOKENS_";
+const TOKEN_ESCROW_PREFIX: &str = "TKESCROW_";
+const ESCROW_TOKENS_PREFIX: &str = "ESCTOKENS_";
+const TOKEN_PLATFORM_BAL_PREFIX: &str = "TKPLATBAL_";
+const PLATFORM_FEE_DEBT_PREFIX: &str = "PFDEBT_";
+const PLATFORM_FEE_DEBT_TOTAL_PREFIX: &str = "PFDEBTTOT_";
+const TICKET_PAYMENT_PREFIX: &str = "TKTPAY_";
+const QUOTE_ID_COUNTER: &str = "QUOTE_CTR";
+const QUOTE_LOCK_SECONDS: &str = "QUOTE_LOCK";
+const PRICE_QUOTE_PREFIX: &str = "QUOTE_";
+
+/// Default lock window for ticket price quotes
+const DEFAULT_QUOTE_LOCK_SECONDS: u64 = 300;
 
 /// Check if contract is initialized
 pub fn is_initialized(env: &Env) -> bool {
@@ -106,6 +175,93 @@ pub fn set_admin(env: &Env, admin: &Address) {
         .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
 }
 
+pub fn set_pending_admin(env: &Env, pending: &PendingAdmin) {
+    env.storage().instance().set(&PENDING_ADMIN, pending);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+pub fn get_pending_admin(env: &Env) -> Option<PendingAdmin> {
+    env.storage().instance().get(&PENDING_ADMIN)
+}
+
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&PENDING_ADMIN);
+}
+
+pub fn set_admin_council(env: &Env, council: &AdminCouncil) {
+    env.storage().instance().set(&ADMIN_COUNCIL, council);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+/// Get the admin council; an empty signer list means no council is configured
+pub fn get_admin_council(env: &Env) -> AdminCouncil {
+    env.storage()
+        .instance()
+        .get(&ADMIN_COUNCIL)
+        .unwrap_or(AdminCouncil {
+            signers: Vec::new(env),
+            threshold: 0,
+        })
+}
+
+/// Record a council signer's sign-off on an admin call, identified by the
+/// hash of its entry point and arguments, together with when it expires.
+/// Like escrow approvals these live in temporary storage.
+pub fn set_admin_action_approval(
+    env: &Env,
+    action_hash: &BytesN<32>,
+    signer: &Address,
+    expires_at: u64,
+) {
+    let key = (ADMIN_ACTION_APPROVAL_PREFIX, action_hash.clone(), signer.clone());
+    env.storage().temporary().set(&key, &expires_at);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_LIFETIME, TEMPORARY_LIFETIME);
+}
+
+/// When a signer's sign-off on an admin call expires, if they gave one.
+pub fn get_admin_action_approval(
+    env: &Env,
+    action_hash: &BytesN<32>,
+    signer: &Address,
+) -> Option<u64> {
+    let key = (ADMIN_ACTION_APPROVAL_PREFIX, action_hash.clone(), signer.clone());
+    env.storage().temporary().get(&key)
+}
+
+pub fn remove_admin_action_approval(env: &Env, action_hash: &BytesN<32>, signer: &Address) {
+    let key = (ADMIN_ACTION_APPROVAL_PREFIX, action_hash.clone(), signer.clone());
+    env.storage().temporary().remove(&key);
+}
+
+pub fn set_globally_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&GLOBAL_PAUSE, &paused);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+pub fn is_globally_paused(env: &Env) -> bool {
+    env.storage().instance().get(&GLOBAL_PAUSE).unwrap_or(false)
+}
+
+/// Set the bitmap of paused feature flags (FEATURE_* constants)
+pub fn set_paused_features(env: &Env, features: u32) {
+    env.storage().instance().set(&PAUSED_FEATURES, &features);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+pub fn get_paused_features(env: &Env) -> u32 {
+    env.storage().instance().get(&PAUSED_FEATURES).unwrap_or(0)
+}
+
 /// Get admin address
 pub fn get_admin(env: &Env) -> Address {
     let admin: Address = env.storage().instance().get(&ADMIN).unwrap();
@@ -334,6 +490,253 @@ pub fn get_ticket(env: &Env, ticket_id: u64) -> Result<Ticket, LumentixError> {
     Ok(ticket)
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// Storage schema versioning
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Schema version of persisted records. Contracts initialized before
+/// versioning existed have no stored version and are at version 1.
+pub fn get_storage_version(env: &Env) -> u32 {
+    env.storage().instance().get(&STORAGE_VERSION).unwrap_or(1)
+}
+
+pub fn set_storage_version(env: &Env, version: u32) {
+    env.storage().instance().set(&STORAGE_VERSION, &version);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+pub fn get_migration_progress(env: &Env) -> Option<MigrationProgress> {
+    env.storage().instance().get(&MIGRATION_PROGRESS)
+}
+
+pub fn set_migration_progress(env: &Env, progress: &MigrationProgress) {
+    env.storage().instance().set(&MIGRATION_PROGRESS, progress);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+/// Decode a record stored in either its current or its version 1 layout.
+/// `current_field` exists only in the current layout; records without it
+/// are decoded as `V1` and upgraded.
+fn decode_versioned<T, V1>(
+    env: &Env,
+    raw: Map<Symbol, Val>,
+    current_field: &str,
+    upgrade: fn(&Env, V1) -> T,
+) -> Result<T, LumentixError>
+where
+    T: TryFromVal<Env, Val>,
+    V1: TryFromVal<Env, Val>,
+{
+    if raw.contains_key(Symbol::new(env, current_field)) {
+        return T::try_from_val(env, &raw.to_val())
+            .map_err(|_| LumentixError::StorageRecordUnreadable);
+    }
+    let v1 = V1::try_from_val(env, &raw.to_val())
+        .map_err(|_| LumentixError::StorageRecordUnreadable)?;
+    Ok(upgrade(env, v1))
+}
+
+/// Read a persistent record. Until storage is migrated to the current
+/// schema, records still in the version 1 layout are upgraded on read.
+fn get_persistent_versioned<K, T, V1>(
+    env: &Env,
+    key: &K,
+    current_field: &str,
+    upgrade: fn(&Env, V1) -> T,
+) -> Result<Option<T>, LumentixError>
+where
+    K: IntoVal<Env, Val>,
+    T: TryFromVal<Env, Val>,
+    V1: TryFromVal<Env, Val>,
+{
+    if get_storage_version(env) >= STORAGE_SCHEMA_VERSION {
+        return Ok(env.storage().persistent().get(key));
+    }
+    match env.storage().persistent().get::<K, Map<Symbol, Val>>(key) {
+        Some(raw) => decode_versioned(env, raw, current_field, upgrade).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Read an instance record, upgrading the version 1 layout on read
+fn get_instance_versioned<K, T, V1>(
+    env: &Env,
+    key: &K,
+    current_field: &str,
+    upgrade: fn(&Env, V1) -> T,
+) -> Result<Option<T>, LumentixError>
+where
+    K: IntoVal<Env, Val>,
+    T: TryFromVal<Env, Val>,
+    V1: TryFromVal<Env, Val>,
+{
+    match env.storage().instance().get::<K, Map<Symbol, Val>>(key) {
+        Some(raw) => decode_versioned(env, raw, current_field, upgrade).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Whether a persistent record is still stored in its version 1 layout
+fn is_persistent_v1<K: IntoVal<Env, Val>>(env: &Env, key: &K, current_field: &str) -> bool {
+    match env.storage().persistent().get::<K, Map<Symbol, Val>>(key) {
+        Some(raw) => !raw.contains_key(Symbol::new(env, current_field)),
+        None => false,
+    }
+}
+
+fn upgrade_accessibility_booking(env: &Env, v1: AccessibilityBookingV1) -> AccessibilityBooking {
+    AccessibilityBooking {
+        id: v1.id,
+        event_id: v1.event_id,
+        ticket_id: v1.ticket_id,
+        attendee: v1.attendee,
+        accommodation_type: v1.accommodation_type,
+        status: if v1.approved {
+            AccessibilityBookingStatus::Approved
+        } else {
+            AccessibilityBookingStatus::Pending
+        },
+        organizer_notes: String::from_str(env, ""),
+        seat_id: None,
+        companion_ticket_id: None,
+    }
+}
+
+fn upgrade_currency_config(_env: &Env, v1: CurrencyConfigV1) -> CurrencyConfig {
+    CurrencyConfig {
+        code: v1.code,
+        decimals: v1.decimals,
+        oracle_price: v1.oracle_price,
+        last_updated: v1.last_updated,
+        max_staleness: 0,
+        max_deviation_bps: 0,
+    }
+}
+
+/// Version 1 policies paid their full coverage on a claim
+fn upgrade_insurance_policy(_env: &Env, v1: InsurancePolicyV1) -> InsurancePolicy {
+    InsurancePolicy {
+        id: v1.id,
+        ticket_id: v1.ticket_id,
+        event_id: v1.event_id,
+        holder: v1.holder,
+        premium_paid: v1.premium_paid,
+        coverage_amount: v1.coverage_amount,
+        purchase_time: v1.purchase_time,
+        active: v1.active,
+        claim_processed: v1.claim_processed,
+        product_id: LEGACY_INSURANCE_PRODUCT_ID,
+        paid_out: if v1.claim_processed { v1.coverage_amount } else { 0 },
+    }
+}
+
+fn upgrade_insurance_pool(_env: &Env, v1: InsurancePoolV1) -> InsurancePool {
+    InsurancePool {
+        total_balance: v1.total_balance,
+        total_policies: v1.total_policies,
+        total_claims_paid: v1.total_claims_paid,
+        total_shares: 0,
+        outstanding_coverage: 0,
+    }
+}
+
+fn upgrade_review(_env: &Env, v1: EventReviewV1) -> EventReview {
+    EventReview {
+        id: v1.id,
+        event_id: v1.event_id,
+        reviewer: v1.reviewer,
+        organizer: v1.organizer,
+        ticket_id: v1.ticket_id,
+        rating: v1.rating,
+        comment: v1.comment,
+        attendance_verified: v1.attendance_verified,
+        timestamp: v1.timestamp,
+        organizer_response: None,
+        hidden: false,
+    }
+}
+
+/// Totals carry over; the squared sum and event breakdowns are rebuilt from
+/// the organizer's reviews when they are migrated.
+fn upgrade_organizer_reputation(env: &Env, v1: OrganizerReputationV1) -> OrganizerReputation {
+    OrganizerReputation {
+        organizer: v1.organizer,
+        reputation_score: v1.reputation_score,
+        average_rating_x100: v1.average_rating_x100,
+        total_reviews: v1.total_reviews,
+        total_ratings_sum: v1.total_ratings_sum,
+        total_ratings_sq_sum: 0,
+        weighted_average_x100: v1.average_rating_x100,
+        std_dev_x100: 0,
+        hidden_reviews: 0,
+        event_breakdowns: Vec::new(env),
+    }
+}
+
+fn upgrade_upgrade_proposal(_env: &Env, v1: UpgradeProposalV1) -> UpgradeProposal {
+    UpgradeProposal {
+        proposal_id: v1.proposal_id,
+        proposer: v1.proposer,
+        new_wasm_hash: v1.new_wasm_hash,
+        description: v1.description,
+        created_at: v1.created_at,
+        voting_deadline: v1.voting_deadline,
+        state: v1.state,
+        yes_votes: v1.yes_votes,
+        no_votes: v1.no_votes,
+        required_yes_votes: v1.required_yes_votes,
+        total_voters: v1.total_voters,
+        kind: UpgradeKind::Upgrade,
+        executable_at: 0,
+        previous_wasm_hash: None,
+    }
+}
+
+fn upgrade_carbon_offset_purchase(_env: &Env, v1: CarbonOffsetPurchaseV1) -> CarbonOffsetPurchase {
+    CarbonOffsetPurchase {
+        purchase_id: v1.purchase_id,
+        event_id: v1.event_id,
+        purchaser: v1.purchaser,
+        offset_amount_kg: v1.offset_amount_kg,
+        cost: v1.cost,
+        project_id: v1.project_id,
+        timestamp: v1.timestamp,
+        verified: v1.verified,
+        retirement_serial: None,
+    }
+}
+
+fn upgrade_environmental_impact(_env: &Env, v1: EnvironmentalImpactV1) -> EnvironmentalImpact {
+    EnvironmentalImpact {
+        event_id: v1.event_id,
+        total_footprint_kg: v1.total_footprint_kg,
+        total_offset_kg: v1.total_offset_kg,
+        pending_offset_kg: 0,
+        net_impact_kg: v1.net_impact_kg,
+        total_purchases: v1.total_purchases,
+        neutral_status: v1.neutral_status,
+    }
+}
+
+fn upgrade_identity_credential(_env: &Env, v1: IdentityCredentialV1) -> IdentityCredential {
+    IdentityCredential {
+        credential_id: v1.credential_id,
+        subject: v1.subject,
+        provider: v1.provider,
+        provider_id: v1.provider_id,
+        issued_at: v1.issued_at,
+        expires_at: v1.expires_at,
+        revoked: v1.revoked,
+        metadata_hash: v1.metadata_hash,
+        level: v1.level,
+        issuer: None,
+    }
+}
+
 /// Add amount to escrow for an event
 pub fn add_escrow(env: &Env, event_id: u64, amount: i128) {
     let key = (ESCROW_PREFIX, event_id);
@@ -383,6 +786,260 @@ pub fn clear_escrow(env: &Env, event_id: u64) {
         .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// MULTI-TOKEN PAYMENT STORAGE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Set the tokens accepted as payment for an event
+pub fn set_accepted_tokens(env: &Env, event_id: u64, tokens: &Vec<AcceptedToken>) {
+    let key = (ACCEPTED_TOKENS_PREFIX, event_id);
+    env.storage().persistent().set(&key, tokens);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get the tokens accepted as payment for an event
+pub fn get_accepted_tokens(env: &Env, event_id: u64) -> Vec<AcceptedToken> {
+    let key = (ACCEPTED_TOKENS_PREFIX, event_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Get the tokens that have ever held escrow for an event
+pub fn get_escrow_tokens(env: &Env, event_id: u64) -> Vec<Address> {
+    let key = (ESCROW_TOKENS_PREFIX, event_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Add amount to an event's escrow held in a specific token
+pub fn add_token_escrow(env: &Env, event_id: u64, token: &Address, amount: i128) {
+    let tokens_key = (ESCROW_TOKENS_PREFIX, event_id);
+    let mut tokens = get_escrow_tokens(env, event_id);
+    if !tokens.contains(token) {
+        tokens.push_back(token.clone());
+        env.storage().persistent().set(&tokens_key, &tokens);
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(&tokens_key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+
+    let key = (TOKEN_ESCROW_PREFIX, event_id, token.clone());
+    let current = get_token_escrow(env, event_id, token);
+    env.storage().persistent().set(&key, &(current + amount));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get an event's escrow balance held in a specific token
+pub fn get_token_escrow(env: &Env, event_id: u64, token: &Address) -> i128 {
+    let key = (TOKEN_ESCROW_PREFIX, event_id, token.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Deduct amount from an event's escrow held in a specific token
+pub fn deduct_token_escrow(
+    env: &Env,
+    event_id: u64,
+    token: &Address,
+    amount: i128,
+) -> Result<(), LumentixError> {
+    let key = (TOKEN_ESCROW_PREFIX, event_id, token.clone());
+    let current = get_token_escrow(env, event_id, token);
+
+    if current < amount {
+        return Err(LumentixError::InsufficientEscrow);
+    }
+
+    env.storage().persistent().set(&key, &(current - amount));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    Ok(())
+}
+
+/// Clear an event's escrow held in a specific token
+pub fn clear_token_escrow(env: &Env, event_id: u64, token: &Address) {
+    let key = (TOKEN_ESCROW_PREFIX, event_id, token.clone());
+    env.storage().persistent().set(&key, &0i128);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Add amount to the platform fees collected in a specific token
+pub fn add_token_platform_balance(env: &Env, token: &Address, amount: i128) {
+    let key = (TOKEN_PLATFORM_BAL_PREFIX, token.clone());
+    let current = get_token_platform_balance(env, token);
+    env.storage().persistent().set(&key, &(current + amount));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get the platform fees collected in a specific token
+pub fn get_token_platform_balance(env: &Env, token: &Address) -> i128 {
+    let key = (TOKEN_PLATFORM_BAL_PREFIX, token.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the platform fees collected in a specific token (after a
+/// withdrawal or a returned fee)
+pub fn set_token_platform_balance(env: &Env, token: &Address, amount: i128) {
+    let key = (TOKEN_PLATFORM_BAL_PREFIX, token.clone());
+    env.storage().persistent().set(&key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Record how a ticket was paid when bought with an accepted token
+pub fn set_ticket_payment(env: &Env, ticket_id: u64, payment: &TicketPayment) {
+    let key = (TICKET_PAYMENT_PREFIX, ticket_id);
+    env.storage().persistent().set(&key, payment);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get how a ticket was paid, if it was bought with an accepted token
+pub fn get_ticket_payment(env: &Env, ticket_id: u64) -> Option<TicketPayment> {
+    let key = (TICKET_PAYMENT_PREFIX, ticket_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Get next price quote ID
+pub fn get_next_quote_id(env: &Env) -> u64 {
+    let id = env.storage().instance().get(&QUOTE_ID_COUNTER).unwrap_or(1);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    id
+}
+
+/// Increment price quote ID counter
+pub fn increment_quote_id(env: &Env) {
+    let next_id = get_next_quote_id(env) + 1;
+    env.storage().instance().set(&QUOTE_ID_COUNTER, &next_id);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+/// Set how long price quotes stay locked, in seconds
+pub fn set_quote_lock_seconds(env: &Env, seconds: u64) {
+    env.storage().instance().set(&QUOTE_LOCK_SECONDS, &seconds);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+/// Get how long price quotes stay locked, in seconds
+pub fn get_quote_lock_seconds(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&QUOTE_LOCK_SECONDS)
+        .unwrap_or(DEFAULT_QUOTE_LOCK_SECONDS)
+}
+
+/// Store a price quote. Quotes live in temporary storage since they are short-lived.
+pub fn set_price_quote(env: &Env, quote: &PriceQuote) {
+    let key = (PRICE_QUOTE_PREFIX, quote.quote_id);
+    env.storage().temporary().set(&key, quote);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_LIFETIME, TEMPORARY_LIFETIME);
+}
+
+/// Get a price quote
+pub fn get_price_quote(env: &Env, quote_id: u64) -> Result<PriceQuote, LumentixError> {
+    let key = (PRICE_QUOTE_PREFIX, quote_id);
+    env.storage()
+        .temporary()
+        .get(&key)
+        .ok_or(LumentixError::PriceQuoteNotFound)
+}
+
+/// Remove a redeemed price quote
+pub fn remove_price_quote(env: &Env, quote_id: u64) {
+    let key = (PRICE_QUOTE_PREFIX, quote_id);
+    env.storage().temporary().remove(&key);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// GATE VALIDATOR & MULTI-SIG ESCROW STORAGE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Authorize a gate validator for an event
+pub fn set_event_validator(env: &Env, event_id: u64, validator: &Address) {
+    let key = (EVENT_VALIDATOR_PREFIX, event_id, validator.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Revoke a gate validator for an event
+pub fn remove_event_validator(env: &Env, event_id: u64, validator: &Address) {
+    let key = (EVENT_VALIDATOR_PREFIX, event_id, validator.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Check whether an address is a registered gate validator for an event
+pub fn is_event_validator(env: &Env, event_id: u64, validator: &Address) -> bool {
+    let key = (EVENT_VALIDATOR_PREFIX, event_id, validator.clone());
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Set the multi-sig escrow configuration for an event
+pub fn set_escrow_config(env: &Env, event_id: u64, config: &EscrowConfig) {
+    let key = (ESCROW_CONFIG_PREFIX, event_id);
+    env.storage().persistent().set(&key, config);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get the multi-sig escrow configuration for an event, if any
+pub fn get_escrow_config(env: &Env, event_id: u64) -> Option<EscrowConfig> {
+    let key = (ESCROW_CONFIG_PREFIX, event_id);
+    let config: Option<EscrowConfig> = env.storage().persistent().get(&key);
+    if config.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    }
+    config
+}
+
+/// Record an escrow release approval. Approvals live in temporary storage so
+/// stale sign-offs lapse on their own.
+pub fn set_escrow_approval(env: &Env, event_id: u64, signer: &Address) {
+    let key = (ESCROW_APPROVAL_PREFIX, event_id, signer.clone());
+    env.storage().temporary().set(&key, &true);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_LIFETIME, TEMPORARY_LIFETIME);
+}
+
+/// Check whether a signer currently approves an escrow release
+pub fn has_escrow_approval(env: &Env, event_id: u64, signer: &Address) -> bool {
+    let key = (ESCROW_APPROVAL_PREFIX, event_id, signer.clone());
+    env.storage().temporary().has(&key)
+}
+
+/// Remove a signer's escrow release approval
+pub fn remove_escrow_approval(env: &Env, event_id: u64, signer: &Address) {
+    let key = (ESCROW_APPROVAL_PREFIX, event_id, signer.clone());
+    env.storage().temporary().remove(&key);
+}
+
 /// Set platform fee in basis points (e.g., 250 = 2.5%)
 pub fn set_platform_fee_bps(env: &Env, fee_bps: u32) {
     env.storage().instance().set(&PLATFORM_FEE_BPS, &fee_bps);
@@ -420,14 +1077,96 @@ pub fn get_platform_balance(env: &Env) -> i128 {
     bal
 }
 
-/// Clear platform balance (after withdrawal)
-pub fn clear_platform_balance(env: &Env) {
-    env.storage().instance().set(&PLATFORM_BALANCE, &0i128);
+/// Set platform balance (after a withdrawal or a returned fee)
+pub fn set_platform_balance(env: &Env, amount: i128) {
+    env.storage().instance().set(&PLATFORM_BALANCE, &amount);
     env.storage()
         .instance()
         .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
 }
 
+/// Platform fees held in an accepted token, or in the platform token for `None`
+pub fn get_platform_fees_held(env: &Env, token: &Option<Address>) -> i128 {
+    match token {
+        Some(token) => get_token_platform_balance(env, token),
+        None => get_platform_balance(env),
+    }
+}
+
+fn set_platform_fees_held(env: &Env, token: &Option<Address>, amount: i128) {
+    match token {
+        Some(token) => set_token_platform_balance(env, token, amount),
+        None => set_platform_balance(env, amount),
+    }
+}
+
+/// Platform fees owed back to `holder` in `token` after they were withdrawn
+pub fn get_platform_fee_debt(env: &Env, token: &Option<Address>, holder: &Address) -> i128 {
+    let key = (PLATFORM_FEE_DEBT_PREFIX, token.clone(), holder.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Platform fees owed back to all holders in `token`; withdrawals leave
+/// this much of the balance in place
+pub fn get_total_platform_fee_debt(env: &Env, token: &Option<Address>) -> i128 {
+    let key = (PLATFORM_FEE_DEBT_TOTAL_PREFIX, token.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+fn add_platform_fee_debt(env: &Env, token: &Option<Address>, holder: &Address, amount: i128) {
+    let key = (PLATFORM_FEE_DEBT_PREFIX, token.clone(), holder.clone());
+    let owed = get_platform_fee_debt(env, token, holder) + amount;
+    env.storage().persistent().set(&key, &owed);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+
+    let total_key = (PLATFORM_FEE_DEBT_TOTAL_PREFIX, token.clone());
+    let total = get_total_platform_fee_debt(env, token) + amount;
+    env.storage().persistent().set(&total_key, &total);
+    env.storage()
+        .persistent()
+        .extend_ttl(&total_key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Take a refunded fee back out of the platform fees held in `token`. The
+/// part already withdrawn is booked as a debt owed to `holder`, claimable
+/// once new fees are collected. Returns the part taken back now.
+pub fn return_platform_fee(
+    env: &Env,
+    token: &Option<Address>,
+    holder: &Address,
+    fee: i128,
+) -> i128 {
+    let held = get_platform_fees_held(env, token);
+    let available = held - get_total_platform_fee_debt(env, token);
+    let returned = fee.min(available).max(0);
+    set_platform_fees_held(env, token, held - returned);
+    if fee > returned {
+        add_platform_fee_debt(env, token, holder, fee - returned);
+    }
+    returned
+}
+
+/// Pay down a holder's platform fee debt from the fees held in `token`.
+/// Returns the amount settled.
+pub fn settle_platform_fee_debt(env: &Env, token: &Option<Address>, holder: &Address) -> i128 {
+    let held = get_platform_fees_held(env, token);
+    let owed = get_platform_fee_debt(env, token, holder);
+    let settled = owed.min(held).max(0);
+    if settled == 0 {
+        return 0;
+    }
+    set_platform_fees_held(env, token, held - settled);
+
+    let key = (PLATFORM_FEE_DEBT_PREFIX, token.clone(), holder.clone());
+    env.storage().persistent().set(&key, &(owed - settled));
+    let total_key = (PLATFORM_FEE_DEBT_TOTAL_PREFIX, token.clone());
+    let total = get_total_platform_fee_debt(env, token) - settled;
+    env.storage().persistent().set(&total_key, &total);
+    settled
+}
+
 /// Append a transfer record to a ticket's transfer history
 pub fn append_ticket_transfer_history(env: &Env, ticket_id: u64, record: TicketTransferRecord) {
     let key = (TRANSFER_HISTORY_PREFIX, ticket_id);
@@ -586,6 +1325,47 @@ pub fn has_vip_tier(env: &Env, event_id: u64, tier_name: &String) -> bool {
     env.storage().persistent().has(&key)
 }
 
+/// Store what a ticket holder paid for their VIP tier
+pub fn set_vip_purchase(env: &Env, ticket_id: u64, purchase: &VipPurchase) {
+    let key = (VIP_PURCHASE_PREFIX, ticket_id);
+    env.storage().persistent().set(&key, purchase);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get what a ticket holder paid for their VIP tier, if anything
+pub fn get_vip_purchase(env: &Env, ticket_id: u64) -> Option<VipPurchase> {
+    let key = (VIP_PURCHASE_PREFIX, ticket_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Remove a ticket's VIP payment record (after refund)
+pub fn remove_vip_purchase(env: &Env, ticket_id: u64) {
+    let key = (VIP_PURCHASE_PREFIX, ticket_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Get the VIP benefits redeemed for a ticket
+pub fn get_vip_redemptions(env: &Env, ticket_id: u64) -> Vec<VipBenefitRedemption> {
+    let key = (VIP_REDEMPTION_PREFIX, ticket_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Record a redeemed VIP benefit for a ticket
+pub fn add_vip_redemption(env: &Env, redemption: &VipBenefitRedemption) {
+    let key = (VIP_REDEMPTION_PREFIX, redemption.ticket_id);
+    let mut redemptions = get_vip_redemptions(env, redemption.ticket_id);
+    redemptions.push_back(redemption.clone());
+    env.storage().persistent().set(&key, &redemptions);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // ACCESSIBILITY STORAGE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -647,15 +1427,80 @@ pub fn get_accessibility_booking(
     booking_id: u64,
 ) -> Result<AccessibilityBooking, LumentixError> {
     let key = (ACCESSIBILITY_BOOKING_PREFIX, booking_id);
-    let booking = env
+    let booking =
+        get_persistent_versioned(env, &key, "status", upgrade_accessibility_booking)?
+            .ok_or(LumentixError::AccessibilityBookingNotFound)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    Ok(booking)
+}
+
+/// Index the latest accessibility booking made for a ticket.
+pub fn set_ticket_accessibility_booking(env: &Env, ticket_id: u64, booking_id: u64) {
+    let key = (ACCESSIBILITY_TICKET_PREFIX, ticket_id);
+    env.storage().persistent().set(&key, &booking_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_ticket_accessibility_booking(env: &Env, ticket_id: u64) -> Option<u64> {
+    let key = (ACCESSIBILITY_TICKET_PREFIX, ticket_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Whether accessibility bookings follow a ticket on transfer (default: released).
+pub fn set_accessibility_carry_on_transfer(env: &Env, event_id: u64, carry: bool) {
+    let key = (ACCESSIBILITY_TRANSFER_PREFIX, event_id);
+    env.storage().persistent().set(&key, &carry);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_accessibility_carry_on_transfer(env: &Env, event_id: u64) -> bool {
+    let key = (ACCESSIBILITY_TRANSFER_PREFIX, event_id);
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+pub fn set_accommodation_type(env: &Env, event_id: u64, accommodation: &AccommodationType) {
+    let key = (ACCOMMODATION_TYPE_PREFIX, event_id, accommodation.name.clone());
+    env.storage().persistent().set(&key, accommodation);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+
+    let list_key = (ACCOMMODATION_TYPE_LIST_PREFIX, event_id);
+    let mut names: Vec<String> = env
         .storage()
         .persistent()
-        .get(&key)
-        .ok_or(LumentixError::AccessibilityBookingNotFound)?;
+        .get(&list_key)
+        .unwrap_or(Vec::new(env));
+    if !names.contains(&accommodation.name) {
+        names.push_back(accommodation.name.clone());
+        env.storage().persistent().set(&list_key, &names);
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(&list_key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_accommodation_type(
+    env: &Env,
+    event_id: u64,
+    name: &String,
+) -> Option<AccommodationType> {
+    let key = (ACCOMMODATION_TYPE_PREFIX, event_id, name.clone());
+    env.storage().persistent().get(&key)
+}
+
+pub fn get_accommodation_types(env: &Env, event_id: u64) -> Vec<String> {
+    let key = (ACCOMMODATION_TYPE_LIST_PREFIX, event_id);
     env.storage()
         .persistent()
-        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
-    Ok(booking)
+        .get(&key)
+        .unwrap_or(Vec::new(env))
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -716,12 +1561,11 @@ pub fn set_currency_config(env: &Env, code: &String, config: &CurrencyConfig) {
         .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
 }
 
+/// Currency configs are keyed by code and cannot be walked by a migration,
+/// so the version 1 layout is upgraded on every read until it is rewritten.
 pub fn get_currency_config(env: &Env, code: &String) -> Result<CurrencyConfig, LumentixError> {
     let key = (CURRENCY_CONFIG_PREFIX, code.clone());
-    let config = env
-        .storage()
-        .instance()
-        .get(&key)
+    let config = get_instance_versioned(env, &key, "max_staleness", upgrade_currency_config)?
         .ok_or(LumentixError::UnsupportedCurrency)?;
     env.storage()
         .instance()
@@ -734,6 +1578,21 @@ pub fn has_currency(env: &Env, code: &String) -> bool {
     env.storage().instance().has(&key)
 }
 
+/// Set the external price feed for a currency
+pub fn set_currency_feed(env: &Env, code: &String, feed: &OracleFeed) {
+    let key = (CURRENCY_FEED_PREFIX, code.clone());
+    env.storage().instance().set(&key, feed);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+/// Get the external price feed for a currency, if any
+pub fn get_currency_feed(env: &Env, code: &String) -> Option<OracleFeed> {
+    let key = (CURRENCY_FEED_PREFIX, code.clone());
+    env.storage().instance().get(&key)
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // WAITLIST STORAGE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -915,6 +1774,219 @@ pub fn get_stream_delivery_config(env: &Env, event_id: u64) -> Option<StreamDeli
     config
 }
 
+// Hybrid-event keys predate the prefix constants and stay symbol-keyed.
+pub fn set_stream_url(env: &Env, event_id: u64, url: &String) {
+    let key = (symbol_short!("STRM_URL"), event_id);
+    env.storage().persistent().set(&key, url);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn is_hybrid_event(env: &Env, event_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&(symbol_short!("STRM_URL"), event_id))
+}
+
+pub fn set_streaming_access(env: &Env, event_id: u64, user: &Address, has_access: bool) {
+    let key = (symbol_short!("STRM_ACC"), event_id, user.clone());
+    env.storage().persistent().set(&key, &has_access);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn has_streaming_access(env: &Env, event_id: u64, user: &Address) -> bool {
+    let key = (symbol_short!("STRM_ACC"), event_id, user.clone());
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Number of live virtual tickets a user holds for an event. Streaming access
+/// from virtual tickets follows these tickets through transfers and refunds.
+pub fn set_virtual_ticket_holdings(env: &Env, event_id: u64, user: &Address, count: u32) {
+    let key = (symbol_short!("STRM_VTK"), event_id, user.clone());
+    env.storage().persistent().set(&key, &count);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_virtual_ticket_holdings(env: &Env, event_id: u64, user: &Address) -> u32 {
+    let key = (symbol_short!("STRM_VTK"), event_id, user.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub fn set_virtual_attended(env: &Env, event_id: u64, user: &Address) {
+    let key = (symbol_short!("VIRT_ATT"), event_id, user.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn set_virtual_ticket_class(env: &Env, event_id: u64, class: &VirtualTicketClass) {
+    let key = (VIRTUAL_CLASS_PREFIX, event_id);
+    env.storage().persistent().set(&key, class);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_virtual_ticket_class(
+    env: &Env,
+    event_id: u64,
+) -> Result<VirtualTicketClass, LumentixError> {
+    let key = (VIRTUAL_CLASS_PREFIX, event_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(LumentixError::VirtualTicketClassNotFound)
+}
+
+/// Record the price paid for a virtual ticket; also marks the ticket as virtual.
+pub fn set_virtual_ticket_price(env: &Env, ticket_id: u64, price: i128) {
+    let key = (VIRTUAL_TICKET_PREFIX, ticket_id);
+    env.storage().persistent().set(&key, &price);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_virtual_ticket_price(env: &Env, ticket_id: u64) -> Option<i128> {
+    let key = (VIRTUAL_TICKET_PREFIX, ticket_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn set_virtual_sessions(env: &Env, event_id: u64, user: &Address, sessions: &Vec<VirtualSession>) {
+    let key = (VIRTUAL_SESSION_PREFIX, event_id, user.clone());
+    env.storage().persistent().set(&key, sessions);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_virtual_sessions(env: &Env, event_id: u64, user: &Address) -> Vec<VirtualSession> {
+    let key = (VIRTUAL_SESSION_PREFIX, event_id, user.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Store a ticket's stream access session; a new session replaces the last.
+pub fn set_stream_access_token(env: &Env, token: &StreamAccessToken) {
+    let key = (STREAM_TOKEN_PREFIX, token.ticket_id);
+    env.storage().temporary().set(&key, token);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, TEMPORARY_LIFETIME, TEMPORARY_LIFETIME);
+}
+
+pub fn get_stream_access_token(env: &Env, ticket_id: u64) -> Option<StreamAccessToken> {
+    let key = (STREAM_TOKEN_PREFIX, ticket_id);
+    env.storage().temporary().get(&key)
+}
+
+pub fn set_replay_config(env: &Env, event_id: u64, config: &ReplayConfig) {
+    let key = (REPLAY_CONFIG_PREFIX, event_id);
+    env.storage().persistent().set(&key, config);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_replay_config(env: &Env, event_id: u64) -> Result<ReplayConfig, LumentixError> {
+    let key = (REPLAY_CONFIG_PREFIX, event_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(LumentixError::ReplayNotConfigured)
+}
+
+pub fn set_replay_entitlement(env: &Env, entitlement: &ReplayEntitlement) {
+    let key = (
+        REPLAY_ENTITLEMENT_PREFIX,
+        entitlement.event_id,
+        entitlement.viewer.clone(),
+    );
+    env.storage().persistent().set(&key, entitlement);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_replay_entitlement(
+    env: &Env,
+    event_id: u64,
+    viewer: &Address,
+) -> Option<ReplayEntitlement> {
+    let key = (REPLAY_ENTITLEMENT_PREFIX, event_id, viewer.clone());
+    env.storage().persistent().get(&key)
+}
+
+pub fn set_qos_reporter(env: &Env, reporter: &Address, registered: bool) {
+    let key = (QOS_REPORTER_PREFIX, reporter.clone());
+    if registered {
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_qos_reporter(env: &Env, reporter: &Address) -> bool {
+    let key = (QOS_REPORTER_PREFIX, reporter.clone());
+    env.storage().persistent().has(&key)
+}
+
+pub fn set_qos_reports(env: &Env, event_id: u64, bucket: u64, reports: &Vec<QosReport>) {
+    let key = (QOS_REPORTS_PREFIX, event_id, bucket);
+    env.storage().persistent().set(&key, reports);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_qos_reports(env: &Env, event_id: u64, bucket: u64) -> Vec<QosReport> {
+    let key = (QOS_REPORTS_PREFIX, event_id, bucket);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_streaming_sla(env: &Env, event_id: u64, sla: &StreamingSla) {
+    let key = (STREAMING_SLA_PREFIX, event_id);
+    env.storage().persistent().set(&key, sla);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_streaming_sla(env: &Env, event_id: u64) -> Result<StreamingSla, LumentixError> {
+    let key = (STREAMING_SLA_PREFIX, event_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(LumentixError::StreamingSlaNotConfigured)
+}
+
+pub fn set_sla_refund(env: &Env, ticket_id: u64, amount: i128) {
+    let key = (SLA_REFUND_PREFIX, ticket_id);
+    env.storage().persistent().set(&key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_sla_refund(env: &Env, ticket_id: u64) -> Option<i128> {
+    let key = (SLA_REFUND_PREFIX, ticket_id);
+    env.storage().persistent().get(&key)
+}
+
 pub fn set_stream_performance_metrics(env: &Env, event_id: u64, metrics: &StreamPerformanceMetrics) {
     let key = (STREAM_PERF_PREFIX, event_id);
     env.storage().persistent().set(&key, metrics);
@@ -969,15 +2041,18 @@ pub fn set_insurance_policy(env: &Env, policy_id: u64, policy: &InsurancePolicy)
     env.storage()
         .persistent()
         .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+
+    let ticket_key = (INSURANCE_TICKET_PREFIX, policy.ticket_id);
+    env.storage().persistent().set(&ticket_key, &policy_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&ticket_key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
 }
 
 /// Get insurance policy
 pub fn get_insurance_policy(env: &Env, policy_id: u64) -> Result<InsurancePolicy, LumentixError> {
     let key = (INSURANCE_POLICY_PREFIX, policy_id);
-    let policy = env
-        .storage()
-        .persistent()
-        .get(&key)
+    let policy = get_persistent_versioned(env, &key, "product_id", upgrade_insurance_policy)?
         .ok_or(LumentixError::InsurancePolicyNotFound)?;
     env.storage()
         .persistent()
@@ -990,22 +2065,16 @@ pub fn get_insurance_policy_by_ticket(
     env: &Env,
     ticket_id: u64,
 ) -> Result<InsurancePolicy, LumentixError> {
-    // Note: In a production system, you'd want a more efficient lookup
-    // For now, we'll iterate through policies (this is simplified)
+    let ticket_key = (INSURANCE_TICKET_PREFIX, ticket_id);
+    if let Some(policy_id) = env.storage().persistent().get::<_, u64>(&ticket_key) {
+        return get_insurance_policy(env, policy_id);
+    }
+
+    // Policies written before the ticket index existed are found by scanning
     let policy_id = get_next_insurance_policy_id(env);
     for i in 1..policy_id {
-        let key = (INSURANCE_POLICY_PREFIX, i);
-        if let Some(policy) = env
-            .storage()
-            .persistent()
-            .get::<(&str, u64), InsurancePolicy>(&key)
-        {
+        if let Ok(policy) = get_insurance_policy(env, i) {
             if policy.ticket_id == ticket_id {
-                env.storage().persistent().extend_ttl(
-                    &key,
-                    PERSISTENT_LIFETIME,
-                    PERSISTENT_LIFETIME,
-                );
                 return Ok(policy);
             }
         }
@@ -1015,14 +2084,14 @@ pub fn get_insurance_policy_by_ticket(
 
 /// Get insurance pool
 pub fn get_insurance_pool(env: &Env) -> InsurancePool {
-    let pool: InsurancePool =
-        env.storage()
-            .instance()
-            .get(&INSURANCE_POOL)
-            .unwrap_or(InsurancePool {
+    let pool = get_instance_versioned(env, &INSURANCE_POOL, "total_shares", upgrade_insurance_pool)
+        .unwrap_or_else(|error| panic_with_error!(env, error))
+        .unwrap_or(InsurancePool {
                 total_balance: 0,
                 total_policies: 0,
                 total_claims_paid: 0,
+                total_shares: 0,
+                outstanding_coverage: 0,
             });
     env.storage()
         .instance()
@@ -1057,6 +2126,198 @@ pub fn deduct_from_insurance_pool(env: &Env, amount: i128) -> Result<(), Lumenti
     Ok(())
 }
 
+/// Get insurance pool parameters
+pub fn get_insurance_pool_params(env: &Env) -> InsurancePoolParams {
+    let params = env
+        .storage()
+        .instance()
+        .get(&INSURANCE_POOL_PARAMS)
+        .unwrap_or(InsurancePoolParams {
+            withdrawal_cooldown_secs: DEFAULT_INSURANCE_WITHDRAWAL_COOLDOWN_SECS,
+            min_reserve_ratio_bps: 0,
+        });
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    params
+}
+
+/// Set insurance pool parameters
+pub fn set_insurance_pool_params(env: &Env, params: &InsurancePoolParams) {
+    env.storage().instance().set(&INSURANCE_POOL_PARAMS, params);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+/// Get an underwriter's insurance pool position
+pub fn get_insurance_lp_position(env: &Env, provider: &Address) -> InsuranceLpPosition {
+    let key = (INSURANCE_LP_PREFIX, provider.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(InsuranceLpPosition {
+            provider: provider.clone(),
+            shares: 0,
+            deposited: 0,
+            pending_withdrawal_shares: 0,
+            withdrawal_available_at: 0,
+        })
+}
+
+/// Set an underwriter's insurance pool position
+pub fn set_insurance_lp_position(env: &Env, position: &InsuranceLpPosition) {
+    let key = (INSURANCE_LP_PREFIX, position.provider.clone());
+    env.storage().persistent().set(&key, position);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get outstanding insurance coverage for an event
+pub fn get_insurance_event_exposure(env: &Env, event_id: u64) -> i128 {
+    let key = (INSURANCE_EVENT_EXPOSURE_PREFIX, event_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set outstanding insurance coverage for an event
+pub fn set_insurance_event_exposure(env: &Env, event_id: u64, exposure: i128) {
+    let key = (INSURANCE_EVENT_EXPOSURE_PREFIX, event_id);
+    env.storage().persistent().set(&key, &exposure);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Add coverage to the pool's outstanding exposure
+pub fn add_insurance_exposure(env: &Env, event_id: u64, coverage: i128) {
+    let mut pool = get_insurance_pool(env);
+    pool.outstanding_coverage += coverage;
+    set_insurance_pool(env, &pool);
+    let exposure = get_insurance_event_exposure(env, event_id) + coverage;
+    set_insurance_event_exposure(env, event_id, exposure);
+}
+
+/// Remove coverage from the pool's outstanding exposure
+pub fn release_insurance_exposure(env: &Env, event_id: u64, coverage: i128) {
+    let event_exposure = get_insurance_event_exposure(env, event_id);
+    let released = coverage.min(event_exposure);
+    let mut pool = get_insurance_pool(env);
+    pool.outstanding_coverage -= released.min(pool.outstanding_coverage);
+    set_insurance_pool(env, &pool);
+    set_insurance_event_exposure(env, event_id, event_exposure - released);
+}
+
+/// Append a policy to the event's policy list
+pub fn add_event_insurance_policy(env: &Env, event_id: u64, policy_id: u64) {
+    let key = (INSURANCE_EVENT_POLICIES_PREFIX, event_id);
+    let mut policies = get_event_insurance_policies(env, event_id);
+    policies.push_back(policy_id);
+    env.storage().persistent().set(&key, &policies);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get the policies sold for an event, in purchase order
+pub fn get_event_insurance_policies(env: &Env, event_id: u64) -> Vec<u64> {
+    let key = (INSURANCE_EVENT_POLICIES_PREFIX, event_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Get the insurance settlement progress for an event
+pub fn get_insurance_settlement(env: &Env, event_id: u64) -> Option<InsuranceSettlement> {
+    let key = (INSURANCE_SETTLEMENT_PREFIX, event_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Set the insurance settlement progress for an event
+pub fn set_insurance_settlement(env: &Env, settlement: &InsuranceSettlement) {
+    let key = (INSURANCE_SETTLEMENT_PREFIX, settlement.event_id);
+    env.storage().persistent().set(&key, settlement);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get the recorded cancellation of an event
+pub fn get_event_cancellation(env: &Env, event_id: u64) -> Option<EventCancellation> {
+    let key = (EVENT_CANCELLATION_PREFIX, event_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Record the cancellation of an event
+pub fn set_event_cancellation(env: &Env, cancellation: &EventCancellation) {
+    let key = (EVENT_CANCELLATION_PREFIX, cancellation.event_id);
+    env.storage().persistent().set(&key, cancellation);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get next insurance product ID
+pub fn get_next_insurance_product_id(env: &Env) -> u32 {
+    let id = env
+        .storage()
+        .instance()
+        .get(&INSURANCE_PRODUCT_COUNTER)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    id
+}
+
+/// Increment insurance product ID counter
+pub fn increment_insurance_product_id(env: &Env) {
+    let next_id = get_next_insurance_product_id(env) + 1;
+    env.storage()
+        .instance()
+        .set(&INSURANCE_PRODUCT_COUNTER, &next_id);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+/// Set insurance product
+pub fn set_insurance_product(env: &Env, product: &InsuranceProduct) {
+    let key = (INSURANCE_PRODUCT_PREFIX, product.id);
+    env.storage().persistent().set(&key, product);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get insurance product
+pub fn get_insurance_product(
+    env: &Env,
+    product_id: u32,
+) -> Result<InsuranceProduct, LumentixError> {
+    let key = (INSURANCE_PRODUCT_PREFIX, product_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(LumentixError::InsuranceProductNotFound)
+}
+
+/// Record the settlement-token amount paid for a ticket
+pub fn set_ticket_price_paid(env: &Env, ticket_id: u64, amount: i128) {
+    let key = (TICKET_PRICE_PAID_PREFIX, ticket_id);
+    env.storage().persistent().set(&key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get the settlement-token amount paid for a ticket, if recorded
+pub fn get_ticket_price_paid(env: &Env, ticket_id: u64) -> Option<i128> {
+    let key = (TICKET_PRICE_PAID_PREFIX, ticket_id);
+    env.storage().persistent().get(&key)
+}
+
 /// Increment total policies count in insurance pool
 pub fn increment_total_policies(env: &Env) {
     let mut pool = get_insurance_pool(env);
@@ -1102,10 +2363,7 @@ pub fn set_review(env: &Env, review_id: u64, review: &EventReview) {
 /// Fetch a review by ID
 pub fn get_review(env: &Env, review_id: u64) -> Result<EventReview, LumentixError> {
     let key = (REVIEW_PREFIX, review_id);
-    let review = env
-        .storage()
-        .persistent()
-        .get(&key)
+    let review = get_persistent_versioned(env, &key, "hidden", upgrade_review)?
         .ok_or(LumentixError::ReviewNotFound)?;
     env.storage()
         .persistent()
@@ -1113,6 +2371,12 @@ pub fn get_review(env: &Env, review_id: u64) -> Result<EventReview, LumentixErro
     Ok(review)
 }
 
+/// Whether a review is still stored in the version 1 layout, and so is not
+/// yet counted in its organizer's reputation breakdowns
+pub fn is_legacy_review(env: &Env, review_id: u64) -> bool {
+    is_persistent_v1(env, &(REVIEW_PREFIX, review_id), "hidden")
+}
+
 /// Record that a reviewer has already reviewed an event (duplicate guard).
 /// Key: (reviewer, event_id) → review_id
 pub fn set_reviewer_event(env: &Env, reviewer: &Address, event_id: u64, review_id: u64) {
@@ -1141,16 +2405,19 @@ pub fn set_organizer_reputation(env: &Env, organizer: &Address, rep: &OrganizerR
 /// Fetch an organizer's reputation record
 pub fn get_organizer_reputation(env: &Env, organizer: &Address) -> OrganizerReputation {
     let key = (ORGANIZER_REPUTATION_PREFIX, organizer.clone());
-    let rep: OrganizerReputation =
-        env.storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(OrganizerReputation {
+    let rep = get_persistent_versioned(env, &key, "event_breakdowns", upgrade_organizer_reputation)
+        .unwrap_or_else(|error| panic_with_error!(env, error))
+        .unwrap_or(OrganizerReputation {
                 organizer: organizer.clone(),
                 reputation_score: 0,
                 average_rating_x100: 0,
                 total_reviews: 0,
                 total_ratings_sum: 0,
+                total_ratings_sq_sum: 0,
+                weighted_average_x100: 0,
+                std_dev_x100: 0,
+                hidden_reviews: 0,
+                event_breakdowns: Vec::new(env),
             });
     if env.storage().persistent().has(&key) {
         env.storage()
@@ -1169,6 +2436,16 @@ const UPGRADE_PROPOSAL_PREFIX: &str = "UPROP_";
 const UPGRADE_VOTE_PREFIX: &str = "UVOTE_";
 const UPGRADE_GOVERNANCE_CONFIG: &str = "UPGRADE_GOV";
 const UPGRADE_PROPOSAL_HASH_PREFIX: &str = "UPROPHASH_";
+const UPGRADE_SAFEGUARDS: &str = "UPGRADE_SAFE";
+const UPGRADE_CURRENT_WASM: &str = "UPGRADE_CURWASM";
+const UPGRADE_PREVIOUS_WASM: &str = "UPGRADE_PREVWASM";
+const GOVERNANCE_ACTION_PREFIX: &str = "GOVACT_";
+const GOVERNANCE_CONTROLLED: &str = "GOV_CTRL";
+const PENDING_ADMIN: &str = "ADM_PEND";
+const ADMIN_COUNCIL: &str = "ADM_CNCL";
+const ADMIN_ACTION_APPROVAL_PREFIX: &str = "ADM_APPR_";
+const GLOBAL_PAUSE: &str = "GPAUSE";
+const PAUSED_FEATURES: &str = "FEAT_PAUSE";
 
 pub fn get_next_upgrade_proposal_id(env: &Env) -> u64 {
     let id = env
@@ -1202,10 +2479,7 @@ pub fn set_upgrade_proposal(env: &Env, proposal_id: u64, proposal: &UpgradePropo
 
 pub fn get_upgrade_proposal(env: &Env, proposal_id: u64) -> Result<UpgradeProposal, LumentixError> {
     let key = (UPGRADE_PROPOSAL_PREFIX, proposal_id);
-    let proposal = env
-        .storage()
-        .persistent()
-        .get(&key)
+    let proposal = get_persistent_versioned(env, &key, "kind", upgrade_upgrade_proposal)?
         .ok_or(LumentixError::UpgradeProposalNotFound)?;
     env.storage()
         .persistent()
@@ -1251,6 +2525,80 @@ pub fn get_upgrade_governance_config(env: &Env) -> UpgradeGovernanceConfig {
     config
 }
 
+pub fn set_upgrade_safeguards(env: &Env, safeguards: &UpgradeSafeguards) {
+    env.storage().instance().set(&UPGRADE_SAFEGUARDS, safeguards);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+pub fn get_upgrade_safeguards(env: &Env) -> UpgradeSafeguards {
+    let safeguards = env
+        .storage()
+        .instance()
+        .get(&UPGRADE_SAFEGUARDS)
+        .unwrap_or(UpgradeSafeguards {
+            timelock_seconds: DEFAULT_UPGRADE_TIMELOCK_SECS,
+            guardian: None,
+        });
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+    safeguards
+}
+
+/// WASM hash the contract is currently running, as far as the contract knows
+pub fn get_current_wasm_hash(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&UPGRADE_CURRENT_WASM)
+}
+
+pub fn set_current_wasm_hash(env: &Env, wasm_hash: &BytesN<32>) {
+    env.storage().instance().set(&UPGRADE_CURRENT_WASM, wasm_hash);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+/// WASM hash that was live before the last executed upgrade
+pub fn get_previous_wasm_hash(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&UPGRADE_PREVIOUS_WASM)
+}
+
+pub fn set_previous_wasm_hash(env: &Env, wasm_hash: &BytesN<32>) {
+    env.storage().instance().set(&UPGRADE_PREVIOUS_WASM, wasm_hash);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+pub fn set_governance_action(env: &Env, proposal_id: u64, action: &GovernanceAction) {
+    let key = (GOVERNANCE_ACTION_PREFIX, proposal_id);
+    env.storage().persistent().set(&key, action);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_governance_action(env: &Env, proposal_id: u64) -> Option<GovernanceAction> {
+    let key = (GOVERNANCE_ACTION_PREFIX, proposal_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Whether platform parameters have been handed over to governance
+pub fn is_governance_controlled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&GOVERNANCE_CONTROLLED)
+        .unwrap_or(false)
+}
+
+pub fn set_governance_controlled(env: &Env) {
+    env.storage().instance().set(&GOVERNANCE_CONTROLLED, &true);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
 pub fn set_upgrade_vote(env: &Env, proposal_id: u64, voter: &Address, vote: &UpgradeVote) {
     let key = (UPGRADE_VOTE_PREFIX, proposal_id, voter.clone());
     env.storage().persistent().set(&key, vote);
@@ -1278,6 +2626,16 @@ const CARBON_FOOTPRINT_PREFIX: &str = "CFOOT_";
 const CARBON_OFFSET_PURCHASE_PREFIX: &str = "COFFSET_";
 const CARBON_OFFSET_PURCHASE_COUNTER: &str = "COFFSET_CTR";
 const ENVIRONMENTAL_IMPACT_PREFIX: &str = "ENVIMP_";
+const CARBON_PROJECT_PREFIX: &str = "CPROJ_";
+const CARBON_RETIREMENT_PREFIX: &str = "CRETIRE_";
+const CHECKOUT_OFFSET_PREFIX: &str = "CKOFFSET_";
+const EMISSION_FACTORS: &str = "EMFACTORS";
+const FOOTPRINT_PROFILE_PREFIX: &str = "FPROFILE_";
+const ACTUAL_FOOTPRINT_PREFIX: &str = "CFOOTACT_";
+const CHECK_IN_COUNT_PREFIX: &str = "CHECKINS_";
+const CARBON_ESCROW_PREFIX: &str = "CARBESC_";
+const TICKET_CARBON_OFFSET_PREFIX: &str = "TKTCO2_";
+const ATTENDEE_CARBON_OFFSETS_PREFIX: &str = "ATTCO2_";
 
 pub fn get_next_carbon_offset_purchase_id(env: &Env) -> u64 {
     let id = env
@@ -1320,6 +2678,83 @@ pub fn get_carbon_footprint(env: &Env, event_id: u64) -> Option<CarbonFootprint>
     footprint
 }
 
+pub fn set_actual_carbon_footprint(env: &Env, event_id: u64, footprint: &CarbonFootprint) {
+    let key = (ACTUAL_FOOTPRINT_PREFIX, event_id);
+    env.storage().persistent().set(&key, footprint);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_actual_carbon_footprint(env: &Env, event_id: u64) -> Option<CarbonFootprint> {
+    let key = (ACTUAL_FOOTPRINT_PREFIX, event_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn set_emission_factors(env: &Env, factors: &EmissionFactors) {
+    env.storage().instance().set(&EMISSION_FACTORS, factors);
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME, INSTANCE_LIFETIME);
+}
+
+/// Get emission factors; defaults reproduce the original fixed constants
+/// for indoor, grid-powered, car-travel events
+pub fn get_emission_factors(env: &Env) -> EmissionFactors {
+    env.storage()
+        .instance()
+        .get(&EMISSION_FACTORS)
+        .unwrap_or_else(|| {
+            let mut venue_kg_per_sqm = Map::new(env);
+            venue_kg_per_sqm.set(VenueType::Indoor, 25);
+            venue_kg_per_sqm.set(VenueType::Outdoor, 10);
+            venue_kg_per_sqm.set(VenueType::Mixed, 18);
+            let mut energy_mix_bps = Map::new(env);
+            energy_mix_bps.set(EnergyMix::Grid, 10_000);
+            energy_mix_bps.set(EnergyMix::Mixed, 6_000);
+            energy_mix_bps.set(EnergyMix::Renewable, 2_000);
+            let mut transport_g_per_km = Map::new(env);
+            transport_g_per_km.set(TransportMode::Car, 255);
+            transport_g_per_km.set(TransportMode::PublicTransit, 90);
+            transport_g_per_km.set(TransportMode::Air, 200);
+            transport_g_per_km.set(TransportMode::Active, 0);
+            EmissionFactors {
+                venue_kg_per_sqm,
+                energy_mix_bps,
+                transport_g_per_km,
+                attendee_kg: 5,
+            }
+        })
+}
+
+pub fn set_footprint_profile(env: &Env, event_id: u64, profile: &FootprintProfile) {
+    let key = (FOOTPRINT_PROFILE_PREFIX, event_id);
+    env.storage().persistent().set(&key, profile);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_footprint_profile(env: &Env, event_id: u64) -> Option<FootprintProfile> {
+    let key = (FOOTPRINT_PROFILE_PREFIX, event_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Count a checked-in ticket towards an event's measured attendance
+pub fn increment_check_in_count(env: &Env, event_id: u64) {
+    let key = (CHECK_IN_COUNT_PREFIX, event_id);
+    let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(count + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_check_in_count(env: &Env, event_id: u64) -> u32 {
+    let key = (CHECK_IN_COUNT_PREFIX, event_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
 pub fn set_carbon_offset_purchase(env: &Env, purchase_id: u64, purchase: &CarbonOffsetPurchase) {
     let key = (CARBON_OFFSET_PURCHASE_PREFIX, purchase_id);
     env.storage().persistent().set(&key, purchase);
@@ -1333,15 +2768,136 @@ pub fn get_carbon_offset_purchase(
     purchase_id: u64,
 ) -> Result<CarbonOffsetPurchase, LumentixError> {
     let key = (CARBON_OFFSET_PURCHASE_PREFIX, purchase_id);
-    let purchase = env
+    let purchase =
+        get_persistent_versioned(env, &key, "retirement_serial", upgrade_carbon_offset_purchase)?
+            .ok_or(LumentixError::CarbonOffsetNotFound)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    Ok(purchase)
+}
+
+pub fn set_carbon_project(env: &Env, project: &CarbonProject) {
+    let key = (CARBON_PROJECT_PREFIX, project.project_id.clone());
+    env.storage().persistent().set(&key, project);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_carbon_project(
+    env: &Env,
+    project_id: &String,
+) -> Result<CarbonProject, LumentixError> {
+    let key = (CARBON_PROJECT_PREFIX, project_id.clone());
+    let project = env
         .storage()
         .persistent()
         .get(&key)
-        .ok_or(LumentixError::CarbonOffsetNotFound)?;
+        .ok_or(LumentixError::CarbonOffsetProjectNotFound)?;
     env.storage()
         .persistent()
         .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
-    Ok(purchase)
+    Ok(project)
+}
+
+pub fn has_carbon_project(env: &Env, project_id: &String) -> bool {
+    let key = (CARBON_PROJECT_PREFIX, project_id.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Record which offset purchase a retirement serial was attested for
+pub fn set_retirement_serial(env: &Env, serial: &BytesN<32>, purchase_id: u64) {
+    let key = (CARBON_RETIREMENT_PREFIX, serial.clone());
+    env.storage().persistent().set(&key, &purchase_id);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_retirement_serial_purchase(env: &Env, serial: &BytesN<32>) -> Option<u64> {
+    let key = (CARBON_RETIREMENT_PREFIX, serial.clone());
+    env.storage().persistent().get(&key)
+}
+
+pub fn set_checkout_carbon_offset(env: &Env, config: &CheckoutCarbonOffset) {
+    let key = (CHECKOUT_OFFSET_PREFIX, config.event_id);
+    env.storage().persistent().set(&key, config);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_checkout_carbon_offset(env: &Env, event_id: u64) -> Option<CheckoutCarbonOffset> {
+    let key = (CHECKOUT_OFFSET_PREFIX, event_id);
+    let config: Option<CheckoutCarbonOffset> = env.storage().persistent().get(&key);
+    if config.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    }
+    config
+}
+
+/// Add checkout surcharges to an event's carbon sub-escrow
+pub fn add_carbon_escrow(env: &Env, event_id: u64, amount: i128) {
+    let key = (CARBON_ESCROW_PREFIX, event_id);
+    let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(current + amount));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_carbon_escrow(env: &Env, event_id: u64) -> i128 {
+    let key = (CARBON_ESCROW_PREFIX, event_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub fn deduct_carbon_escrow(env: &Env, event_id: u64, amount: i128) -> Result<(), LumentixError> {
+    let key = (CARBON_ESCROW_PREFIX, event_id);
+    let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    if current < amount {
+        return Err(LumentixError::InsufficientEscrow);
+    }
+    env.storage().persistent().set(&key, &(current - amount));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    Ok(())
+}
+
+/// Store a ticket's checkout offset and index it under the attendee
+pub fn set_ticket_carbon_offset(env: &Env, offset: &AttendeeCarbonOffset) {
+    let key = (TICKET_CARBON_OFFSET_PREFIX, offset.ticket_id);
+    let is_new = !env.storage().persistent().has(&key);
+    env.storage().persistent().set(&key, offset);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+
+    if is_new {
+        let index_key = (ATTENDEE_CARBON_OFFSETS_PREFIX, offset.attendee.clone());
+        let mut tickets = get_attendee_carbon_offset_tickets(env, &offset.attendee);
+        tickets.push_back(offset.ticket_id);
+        env.storage().persistent().set(&index_key, &tickets);
+        env.storage()
+            .persistent()
+            .extend_ttl(&index_key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    }
+}
+
+pub fn get_ticket_carbon_offset(env: &Env, ticket_id: u64) -> Option<AttendeeCarbonOffset> {
+    let key = (TICKET_CARBON_OFFSET_PREFIX, ticket_id);
+    env.storage().persistent().get(&key)
+}
+
+pub fn get_attendee_carbon_offset_tickets(env: &Env, attendee: &Address) -> Vec<u64> {
+    let key = (ATTENDEE_CARBON_OFFSETS_PREFIX, attendee.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
 }
 
 pub fn set_environmental_impact(env: &Env, event_id: u64, impact: &EnvironmentalImpact) {
@@ -1352,16 +2908,21 @@ pub fn set_environmental_impact(env: &Env, event_id: u64, impact: &Environmental
         .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
 }
 
+pub fn has_environmental_impact(env: &Env, event_id: u64) -> bool {
+    let key = (ENVIRONMENTAL_IMPACT_PREFIX, event_id);
+    env.storage().persistent().has(&key)
+}
+
 pub fn get_environmental_impact(env: &Env, event_id: u64) -> EnvironmentalImpact {
     let key = (ENVIRONMENTAL_IMPACT_PREFIX, event_id);
-    let impact: EnvironmentalImpact =
-        env.storage()
-            .persistent()
-            .get(&key)
+    let impact =
+        get_persistent_versioned(env, &key, "pending_offset_kg", upgrade_environmental_impact)
+            .unwrap_or_else(|error| panic_with_error!(env, error))
             .unwrap_or(EnvironmentalImpact {
                 event_id,
                 total_footprint_kg: 0,
                 total_offset_kg: 0,
+                pending_offset_kg: 0,
                 net_impact_kg: 0,
                 total_purchases: 0,
                 neutral_status: false,
@@ -1382,6 +2943,8 @@ const IDENTITY_CREDENTIAL_PREFIX: &str = "IDCRED_";
 const IDENTITY_CREDENTIAL_COUNTER: &str = "IDCRED_CTR";
 const IDENTITY_CREDENTIAL_BY_SUBJECT_PREFIX: &str = "IDSUB_";
 const IDENTITY_PROVIDER_PREFIX: &str = "IDPROV_";
+const CREDENTIAL_ISSUER_PREFIX: &str = "CREDISSR_";
+const PROVIDER_ISSUERS_PREFIX: &str = "PROVISSR_";
 
 pub fn get_next_identity_credential_id(env: &Env) -> u64 {
     let id = env
@@ -1418,10 +2981,7 @@ pub fn get_identity_credential(
     credential_id: u64,
 ) -> Result<IdentityCredential, LumentixError> {
     let key = (IDENTITY_CREDENTIAL_PREFIX, credential_id);
-    let credential = env
-        .storage()
-        .persistent()
-        .get(&key)
+    let credential = get_persistent_versioned(env, &key, "issuer", upgrade_identity_credential)?
         .ok_or(LumentixError::IdentityCredentialNotFound)?;
     env.storage()
         .persistent()
@@ -1478,6 +3038,43 @@ pub fn is_identity_provider_supported(env: &Env, provider: &IdentityProvider) ->
     env.storage().instance().get(&key).unwrap_or(false)
 }
 
+/// Store a trusted issuer, indexing new keys under their provider
+pub fn set_trusted_issuer(env: &Env, issuer: &TrustedIssuer) {
+    let key = (CREDENTIAL_ISSUER_PREFIX, issuer.public_key.clone());
+    if !env.storage().persistent().has(&key) {
+        let index_key = (PROVIDER_ISSUERS_PREFIX, issuer.provider.clone());
+        let mut keys = get_provider_issuers(env, &issuer.provider);
+        keys.push_back(issuer.public_key.clone());
+        env.storage().persistent().set(&index_key, &keys);
+        env.storage()
+            .persistent()
+            .extend_ttl(&index_key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    }
+    env.storage().persistent().set(&key, issuer);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_trusted_issuer(env: &Env, public_key: &BytesN<32>) -> Option<TrustedIssuer> {
+    let key = (CREDENTIAL_ISSUER_PREFIX, public_key.clone());
+    let issuer: Option<TrustedIssuer> = env.storage().persistent().get(&key);
+    if issuer.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    }
+    issuer
+}
+
+pub fn get_provider_issuers(env: &Env, provider: &IdentityProvider) -> Vec<BytesN<32>> {
+    let key = (PROVIDER_ISSUERS_PREFIX, provider.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CROSS-CHAIN TICKET PORTABILITY STORAGE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1862,6 +3459,38 @@ pub fn has_ticket_did_association(env: &Env, ticket_id: u64) -> bool {
     env.storage().persistent().has(&key)
 }
 
+const EVENT_CREDENTIAL_REQUIREMENT_PREFIX: &str = "EVCREDREQ_";
+
+pub fn set_event_credential_requirement(
+    env: &Env,
+    requirement: &EventCredentialRequirement,
+) {
+    let key = (EVENT_CREDENTIAL_REQUIREMENT_PREFIX, requirement.event_id);
+    env.storage().persistent().set(&key, requirement);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+pub fn get_event_credential_requirement(
+    env: &Env,
+    event_id: u64,
+) -> Option<EventCredentialRequirement> {
+    let key = (EVENT_CREDENTIAL_REQUIREMENT_PREFIX, event_id);
+    let requirement: Option<EventCredentialRequirement> = env.storage().persistent().get(&key);
+    if requirement.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    }
+    requirement
+}
+
+pub fn remove_event_credential_requirement(env: &Env, event_id: u64) {
+    let key = (EVENT_CREDENTIAL_REQUIREMENT_PREFIX, event_id);
+    env.storage().persistent().remove(&key);
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // RESALE PRICE CEILING STORAGE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -2219,3 +3848,111 @@ pub fn set_promo_user_usage(env: &Env, event_id: u64, code: &String, user: &Addr
         .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// SPONSORSHIP STORAGE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Get the next sponsor tier ID for an event
+pub fn get_next_sponsor_tier_id(env: &Env, event_id: u64) -> u32 {
+    let key = (SPONSOR_TIER_CTR_PREFIX, event_id);
+    env.storage().persistent().get(&key).unwrap_or(1)
+}
+
+/// Increment the sponsor tier ID counter for an event
+pub fn increment_sponsor_tier_id(env: &Env, event_id: u64) {
+    let key = (SPONSOR_TIER_CTR_PREFIX, event_id);
+    let current = get_next_sponsor_tier_id(env, event_id);
+    env.storage().persistent().set(&key, &(current + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Store a sponsor tier
+pub fn set_sponsor_tier(env: &Env, event_id: u64, tier_id: u32, tier: &SponsorTier) {
+    let key = (SPONSOR_TIER_PREFIX, event_id, tier_id);
+    env.storage().persistent().set(&key, tier);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get a sponsor tier
+pub fn get_sponsor_tier(env: &Env, event_id: u64, tier_id: u32) -> Result<SponsorTier, LumentixError> {
+    let key = (SPONSOR_TIER_PREFIX, event_id, tier_id);
+    let tier = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(LumentixError::SponsorTierNotFound)?;
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    Ok(tier)
+}
+
+/// Get the sponsors that contributed to a tier, in contribution order
+pub fn get_tier_sponsors(env: &Env, event_id: u64, tier_id: u32) -> Vec<Address> {
+    let key = (SPONSOR_LIST_PREFIX, event_id, tier_id);
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append a sponsor to a tier's contribution list
+pub fn add_tier_sponsor(env: &Env, event_id: u64, tier_id: u32, sponsor: &Address) {
+    let key = (SPONSOR_LIST_PREFIX, event_id, tier_id);
+    let mut sponsors = get_tier_sponsors(env, event_id, tier_id);
+    sponsors.push_back(sponsor.clone());
+    env.storage().persistent().set(&key, &sponsors);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Store a sponsor's contribution to a tier
+pub fn set_sponsor_contribution(env: &Env, contribution: &SponsorContribution) {
+    let key = (
+        SPONSOR_CONTRIB_PREFIX,
+        contribution.event_id,
+        contribution.tier_id,
+        contribution.sponsor.clone(),
+    );
+    env.storage().persistent().set(&key, contribution);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Get a sponsor's contribution to a tier, if any
+pub fn get_sponsor_contribution(
+    env: &Env,
+    event_id: u64,
+    tier_id: u32,
+    sponsor: &Address,
+) -> Option<SponsorContribution> {
+    let key = (SPONSOR_CONTRIB_PREFIX, event_id, tier_id, sponsor.clone());
+    let contribution: Option<SponsorContribution> = env.storage().persistent().get(&key);
+    if contribution.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+    }
+    contribution
+}
+
+/// Flag a ticket as complimentary (issued as a sponsor benefit, not purchased)
+pub fn set_comp_ticket(env: &Env, ticket_id: u64) {
+    let key = (COMP_TICKET_PREFIX, ticket_id);
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_LIFETIME, PERSISTENT_LIFETIME);
+}
+
+/// Check whether a ticket is complimentary
+pub fn is_comp_ticket(env: &Env, ticket_id: u64) -> bool {
+    let key = (COMP_TICKET_PREFIX, ticket_id);
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
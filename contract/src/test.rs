@@ -4,10 +4,15 @@
 use crate::error::LumentixError;
 use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
 use crate::storage;
-use crate::types::{EventStatus, Ticket};
+use crate::types::{
+    CancellationReason, EventStatus, StreamingSla, Ticket, ADMIN_ACTION_APPROVAL_WINDOW_SECS,
+    FEATURE_ALL, FEATURE_PURCHASES, FEATURE_REFUNDS, FEATURE_TRANSFERS,
+};
+use ed25519_dalek::{Signer, SigningKey};
 use soroban_sdk::xdr;
 use soroban_sdk::{
-    testutils::Address as _, testutils::Events, testutils::Ledger, token, Address, Env, String,
+    testutils::Address as _, testutils::Events, testutils::Ledger, token, vec, Address, Bytes,
+    BytesN, Env, IntoVal, String, Symbol, Val, Vec,
 };
 
 fn create_test_contract(env: &Env) -> (Address, LumentixContractClient<'_>) {
@@ -215,7 +220,7 @@ fn test_purchase_ticket_success() {
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(ticket_id, 1);
 }
 
@@ -230,7 +235,7 @@ fn test_purchase_ticket_insufficient_funds() {
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
-    let result = client.try_purchase_ticket(&buyer, &event_id, &50i128);
+    let result = client.try_purchase_ticket(&buyer, &event_id, &50i128, &false, &None);
     assert_eq!(result, Err(Ok(LumentixError::InsufficientFunds)));
 }
 
@@ -256,10 +261,10 @@ fn test_purchase_ticket_sold_out() {
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
     let buyer1 = Address::generate(&env);
-    client.purchase_ticket(&buyer1, &event_id, &100i128);
+    client.purchase_ticket(&buyer1, &event_id, &100i128, &false, &None);
 
     let buyer2 = Address::generate(&env);
-    let result = client.try_purchase_ticket(&buyer2, &event_id, &100i128);
+    let result = client.try_purchase_ticket(&buyer2, &event_id, &100i128, &false, &None);
     assert_eq!(result, Err(Ok(LumentixError::EventSoldOut)));
 }
 
@@ -284,7 +289,7 @@ fn test_purchase_ticket_draft_status_fails() {
     );
 
     // Try to purchase ticket for draft event
-    let result = client.try_purchase_ticket(&buyer, &event_id, &100i128);
+    let result = client.try_purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(result, Err(Ok(LumentixError::InvalidStatusTransition)));
 }
 
@@ -300,7 +305,7 @@ fn test_batch_purchase_tickets_success_validates_ticket_properties() {
     env.ledger().with_mut(|li| li.timestamp = 7777);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_ids = client.batch_purchase_tickets(&event_id, &3u32, &buyer);
+    let ticket_ids = client.batch_purchase_tickets(&event_id, &3u32, &buyer, &false);
 
     assert_eq!(ticket_ids.len(), 3);
     assert_eq!(ticket_ids.get(0).unwrap(), 1);
@@ -333,7 +338,7 @@ fn test_batch_purchase_tickets_collects_fee_and_escrow() {
     client.set_platform_fee(&admin, &500u32);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_ids = client.batch_purchase_tickets(&event_id, &4u32, &buyer);
+    let ticket_ids = client.batch_purchase_tickets(&event_id, &4u32, &buyer, &false);
 
     assert_eq!(ticket_ids.len(), 4);
     assert_eq!(client.get_platform_balance(), 20i128);
@@ -351,10 +356,10 @@ fn test_batch_purchase_tickets_rejects_invalid_quantity_limits() {
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
-    let zero_quantity = client.try_batch_purchase_tickets(&event_id, &0u32, &buyer);
+    let zero_quantity = client.try_batch_purchase_tickets(&event_id, &0u32, &buyer, &false);
     assert_eq!(zero_quantity, Err(Ok(LumentixError::InvalidAmount)));
 
-    let over_batch_limit = client.try_batch_purchase_tickets(&event_id, &11u32, &buyer);
+    let over_batch_limit = client.try_batch_purchase_tickets(&event_id, &11u32, &buyer, &false);
     assert_eq!(over_batch_limit, Err(Ok(LumentixError::CapacityExceeded)));
 }
 
@@ -379,7 +384,7 @@ fn test_batch_purchase_tickets_rejects_when_capacity_exceeded() {
     );
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
-    let result = client.try_batch_purchase_tickets(&event_id, &3u32, &buyer);
+    let result = client.try_batch_purchase_tickets(&event_id, &3u32, &buyer, &false);
     assert_eq!(result, Err(Ok(LumentixError::EventSoldOut)));
 }
 
@@ -394,7 +399,7 @@ fn test_batch_purchase_tickets_charges_list_price_per_ticket() {
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
-    let tids = client.batch_purchase_tickets(&event_id, &2u32, &buyer);
+    let tids = client.batch_purchase_tickets(&event_id, &2u32, &buyer, &false);
     assert_eq!(tids.len(), 2);
     assert_eq!(client.get_escrow_balance(&event_id), 200i128);
 }
@@ -420,7 +425,7 @@ fn test_batch_purchase_ten_tickets_reduces_availability_charges_tokens_and_maps_
     let event_id = create_and_publish_event(&env, &client, &organizer);
     let before_buyer_balance = token_client.balance(&buyer);
     let before_contract_balance = token_client.balance(&contract_id);
-    let ticket_ids = client.batch_purchase_tickets(&event_id, &10u32, &buyer);
+    let ticket_ids = client.batch_purchase_tickets(&event_id, &10u32, &buyer, &false);
     let event = client.get_event(&event_id);
     let total_price = 10i128 * event.ticket_price;
 
@@ -465,7 +470,7 @@ fn test_batch_purchase_exceeding_venue_capacity_fails_without_partial_mints() {
     );
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
-    let result = client.try_batch_purchase_tickets(&event_id, &10u32, &buyer);
+    let result = client.try_batch_purchase_tickets(&event_id, &10u32, &buyer, &false);
     assert_eq!(result, Err(Ok(LumentixError::EventSoldOut)));
     assert_eq!(client.get_event(&event_id).tickets_sold, 0);
     assert_eq!(
@@ -488,7 +493,7 @@ fn test_use_ticket_success() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let result = client.try_use_ticket(&ticket_id, &organizer);
     assert!(result.is_ok());
@@ -505,7 +510,7 @@ fn test_use_ticket_unauthorized() {
     let unauthorized = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let result = client.try_use_ticket(&ticket_id, &unauthorized);
     assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
@@ -521,7 +526,7 @@ fn test_use_ticket_already_used() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     client.use_ticket(&ticket_id, &organizer);
 
     let result = client.try_use_ticket(&ticket_id, &organizer);
@@ -544,7 +549,7 @@ fn test_revoke_ticket_non_admin_returns_unauthorized() {
     let attacker = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let result = client.try_revoke_ticket(&attacker, &ticket_id);
     assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
@@ -562,7 +567,7 @@ fn test_revoke_ticket_organizer_not_admin_returns_unauthorized() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let result = client.try_revoke_ticket(&organizer, &ticket_id);
     assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
@@ -579,7 +584,7 @@ fn test_revoke_ticket_admin_succeeds_for_valid_ticket_id() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let result = client.try_revoke_ticket(&admin, &ticket_id);
     assert!(result.is_ok());
@@ -596,7 +601,7 @@ fn test_revoke_ticket_marks_ticket_invalid_for_validity_query() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     assert!(client.get_ticket_validity(&ticket_id));
 
@@ -618,7 +623,7 @@ fn test_use_ticket_on_revoked_ticket_returns_revoked_error() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     client.revoke_ticket(&admin, &ticket_id);
 
     let result = client.try_use_ticket(&ticket_id, &organizer);
@@ -637,7 +642,7 @@ fn test_transfer_ticket_revoked_returns_revoked_error() {
     let recipient = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &false, &None);
     client.revoke_ticket(&admin, &ticket_id);
 
     let result = client.try_transfer_ticket(&ticket_id, &owner, &recipient);
@@ -657,7 +662,7 @@ fn test_revoke_ticket_non_admin_panics_on_non_try_client() {
     let attacker = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     let _ = organizer;
 
     client.revoke_ticket(&attacker, &ticket_id);
@@ -677,9 +682,9 @@ fn test_cancel_event_and_refund() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     let result = client.try_refund_ticket(&ticket_id, &buyer);
     assert!(result.is_ok());
@@ -695,7 +700,7 @@ fn test_refund_event_not_cancelled() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let result = client.try_refund_ticket(&ticket_id, &buyer);
     assert_eq!(result, Err(Ok(LumentixError::EventNotCancelled)));
@@ -712,11 +717,11 @@ fn test_refund_multiple_tickets() {
     let buyer2 = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id_1 = client.purchase_ticket(&buyer1, &event_id, &100i128);
-    let ticket_id_2 = client.purchase_ticket(&buyer2, &event_id, &100i128);
+    let ticket_id_1 = client.purchase_ticket(&buyer1, &event_id, &100i128, &false, &None);
+    let ticket_id_2 = client.purchase_ticket(&buyer2, &event_id, &100i128, &false, &None);
 
     // Cancel event
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     // Both buyers can get refund
     let result1 = client.try_refund_ticket(&ticket_id_1, &buyer1);
@@ -759,16 +764,16 @@ fn test_full_event_cancellation_with_multiple_buyer_refunds() {
     );
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
-    let ticket_id_1 = client.purchase_ticket(&buyer1, &event_id, &100i128);
-    let ticket_id_2 = client.purchase_ticket(&buyer2, &event_id, &100i128);
-    let ticket_id_3 = client.purchase_ticket(&buyer3, &event_id, &100i128);
-    let ticket_id_4 = client.purchase_ticket(&buyer4, &event_id, &100i128);
+    let ticket_id_1 = client.purchase_ticket(&buyer1, &event_id, &100i128, &false, &None);
+    let ticket_id_2 = client.purchase_ticket(&buyer2, &event_id, &100i128, &false, &None);
+    let ticket_id_3 = client.purchase_ticket(&buyer3, &event_id, &100i128, &false, &None);
+    let ticket_id_4 = client.purchase_ticket(&buyer4, &event_id, &100i128, &false, &None);
 
     assert_eq!(client.get_availability(&event_id), 0);
     assert_eq!(client.get_escrow_balance(&event_id), 380i128);
 
     client.use_ticket(&ticket_id_1, &organizer);
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     assert!(client.try_refund_ticket(&ticket_id_2, &buyer2).is_ok());
     assert!(client.try_refund_ticket(&ticket_id_3, &buyer3).is_ok());
@@ -793,7 +798,13 @@ fn test_full_event_cancellation_with_multiple_buyer_refunds() {
     assert_eq!(client.get_availability(&event_id), 3);
 
     let late_buyer = Address::generate(&env);
-    let purchase_result = client.try_purchase_ticket(&late_buyer, &event_id, &100i128);
+    let purchase_result = client.try_purchase_ticket(
+        &late_buyer,
+        &event_id,
+        &100i128,
+        &false,
+        &None,
+    );
     assert_eq!(
         purchase_result,
         Err(Ok(LumentixError::InvalidStatusTransition))
@@ -810,13 +821,13 @@ fn test_refund_used_ticket_fails() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Use ticket first
     client.use_ticket(&ticket_id, &organizer);
 
     // Cancel event
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     // Try to refund used ticket
     let result = client.try_refund_ticket(&ticket_id, &buyer);
@@ -833,9 +844,9 @@ fn test_refund_twice_fails() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
     client.refund_ticket(&ticket_id, &buyer);
 
     // Try to refund again
@@ -854,9 +865,9 @@ fn test_refund_wrong_owner_fails() {
     let wrong_buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     // Try to refund with wrong owner
     let result = client.try_refund_ticket(&ticket_id, &wrong_buyer);
@@ -1083,7 +1094,7 @@ fn test_purchase_ticket_with_platform_fee() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase ticket for 100
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(ticket_id, 1);
 
     // Check platform balance: 5% of 100 = 5
@@ -1108,7 +1119,7 @@ fn test_purchase_ticket_zero_platform_fee() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase ticket for 100
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Check platform balance: 0% of 100 = 0
     let platform_balance = client.get_platform_balance();
@@ -1130,9 +1141,9 @@ fn test_withdraw_platform_fees_success() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase 3 tickets for 100 each
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Platform should have collected 30 (10% of 300)
     let platform_balance = client.get_platform_balance();
@@ -1270,7 +1281,7 @@ fn test_platform_fee_calculation_precision() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase ticket for 1000
-    client.purchase_ticket(&buyer, &event_id, &1000i128);
+    client.purchase_ticket(&buyer, &event_id, &1000i128, &false, &None);
 
     // Platform fee should be 25 (2.5% of 1000)
     let platform_balance = client.get_platform_balance();
@@ -1294,8 +1305,8 @@ fn test_multiple_events_platform_fee_accumulation() {
     let event_id_2 = create_and_publish_event(&env, &client, &organizer2);
 
     // Purchase tickets from both events
-    client.purchase_ticket(&buyer, &event_id_1, &200i128); // Fee: 10
-    client.purchase_ticket(&buyer, &event_id_2, &300i128); // Fee: 15
+    client.purchase_ticket(&buyer, &event_id_1, &200i128, &false, &None); // Fee: 10
+    client.purchase_ticket(&buyer, &event_id_2, &300i128, &false, &None); // Fee: 15
 
     // Platform should have accumulated 25 total
     let platform_balance = client.get_platform_balance();
@@ -1372,7 +1383,7 @@ fn test_release_escrow_after_completion() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase ticket to add funds to escrow
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Complete event
     env.ledger().with_mut(|li| li.timestamp = 2001);
@@ -1393,7 +1404,7 @@ fn test_release_escrow_before_completion_fails() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Try to release escrow without completing event
     let result = client.try_release_escrow(&organizer, &event_id);
@@ -1411,7 +1422,7 @@ fn test_release_escrow_only_organizer() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     env.ledger().with_mut(|li| li.timestamp = 2001);
     client.complete_event(&organizer, &event_id);
@@ -1431,7 +1442,7 @@ fn test_release_escrow_twice_fails() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     env.ledger().with_mut(|li| li.timestamp = 2001);
     client.complete_event(&organizer, &event_id);
@@ -1545,7 +1556,7 @@ fn test_status_transition_cancelled_is_final() {
     let organizer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     // Try to transition from Cancelled to any other status
     let result = client.try_update_event_status(&event_id, &EventStatus::Published, &organizer);
@@ -1575,15 +1586,15 @@ fn test_event_tickets_sold_counter() {
     assert_eq!(event.tickets_sold, 0);
 
     // Purchase 3 tickets
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     let event = client.get_event(&event_id);
     assert_eq!(event.tickets_sold, 1);
 
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     let event = client.get_event(&event_id);
     assert_eq!(event.tickets_sold, 2);
 
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     let event = client.get_event(&event_id);
     assert_eq!(event.tickets_sold, 3);
 }
@@ -1612,11 +1623,11 @@ fn test_event_capacity_enforcement() {
     let buyer = Address::generate(&env);
 
     // First two tickets succeed
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Third ticket fails
-    let result = client.try_purchase_ticket(&buyer, &event_id, &100i128);
+    let result = client.try_purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(result, Err(Ok(LumentixError::EventSoldOut)));
 }
 
@@ -1646,14 +1657,14 @@ fn test_get_availability() {
     assert_eq!(client.get_availability(&event_id), 5);
 
     // Purchase 2 tickets -> 3 remaining
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(client.get_availability(&event_id), 3);
 
     // Purchase 3 more -> 0 remaining
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(client.get_availability(&event_id), 0);
 }
 
@@ -1680,12 +1691,12 @@ fn test_refund_frees_capacity() {
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
     // Buy 2 tickets (sold out)
-    let ticket_id_1 = client.purchase_ticket(&buyer, &event_id, &100i128);
-    let _ticket_id_2 = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id_1 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let _ticket_id_2 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(client.get_availability(&event_id), 0);
 
     // Cancel and refund 1 ticket -> 1 available
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
     client.refund_ticket(&ticket_id_1, &buyer);
 
     let event = client.get_event(&event_id);
@@ -1743,9 +1754,9 @@ fn test_ticket_id_increments() {
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
-    let ticket_id_1 = client.purchase_ticket(&buyer, &event_id, &100i128);
-    let ticket_id_2 = client.purchase_ticket(&buyer, &event_id, &100i128);
-    let ticket_id_3 = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id_1 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let ticket_id_2 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let ticket_id_3 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     assert_eq!(ticket_id_1, 1);
     assert_eq!(ticket_id_2, 2);
@@ -1766,7 +1777,7 @@ fn test_ticket_ownership_verification() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Verify ticket ownership
     let ticket = client.get_ticket_info(&ticket_id);
@@ -1787,7 +1798,7 @@ fn test_ticket_double_check_in_prevention() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // First check-in succeeds
     client.use_ticket(&ticket_id, &organizer);
@@ -1811,9 +1822,9 @@ fn test_multiple_tickets_same_buyer() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Buy 3 tickets
-    let ticket_id_1 = client.purchase_ticket(&buyer, &event_id, &100i128);
-    let ticket_id_2 = client.purchase_ticket(&buyer, &event_id, &100i128);
-    let ticket_id_3 = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id_1 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let ticket_id_2 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let ticket_id_3 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Verify all tickets are owned by same buyer
     assert_eq!(client.get_ticket_info(&ticket_id_1).owner, buyer);
@@ -1858,8 +1869,8 @@ fn test_full_event_lifecycle_happy_path() {
     assert_eq!(client.get_event(&event_id).status, EventStatus::Published);
 
     // 3. Sell tickets
-    let ticket1 = client.purchase_ticket(&buyer1, &event_id, &100i128);
-    let ticket2 = client.purchase_ticket(&buyer2, &event_id, &100i128);
+    let ticket1 = client.purchase_ticket(&buyer1, &event_id, &100i128, &false, &None);
+    let ticket2 = client.purchase_ticket(&buyer2, &event_id, &100i128, &false, &None);
     assert_eq!(client.get_event(&event_id).tickets_sold, 2);
 
     // 4. Validate tickets at event
@@ -1892,11 +1903,11 @@ fn test_full_event_cancellation_with_refunds() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Sell tickets
-    let ticket1 = client.purchase_ticket(&buyer1, &event_id, &100i128);
-    let ticket2 = client.purchase_ticket(&buyer2, &event_id, &100i128);
+    let ticket1 = client.purchase_ticket(&buyer1, &event_id, &100i128, &false, &None);
+    let ticket2 = client.purchase_ticket(&buyer2, &event_id, &100i128, &false, &None);
 
     // Cancel event
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
     assert_eq!(client.get_event(&event_id).status, EventStatus::Cancelled);
 
     // Process refunds
@@ -1923,8 +1934,8 @@ fn test_event_with_platform_fee_end_to_end() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Sell tickets
-    client.purchase_ticket(&buyer, &event_id, &100i128); // Fee: 10, Escrow: 90
-    client.purchase_ticket(&buyer, &event_id, &100i128); // Fee: 10, Escrow: 90
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None); // Fee: 10, Escrow: 90
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None); // Fee: 10, Escrow: 90
 
     // Verify platform collected fees
     assert_eq!(client.get_platform_balance(), 20);
@@ -2073,7 +2084,11 @@ fn test_get_events_by_status_mixed_statuses_filters_correctly() {
         &30u32,
     );
     client.update_event_status(&cancelled_event, &EventStatus::Published, &organizer);
-    client.cancel_event(&organizer, &cancelled_event);
+    client.cancel_event(
+        &organizer,
+        &cancelled_event,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
 
     let draft_events = client.get_events_by_status(&EventStatus::Draft);
     assert_eq!(draft_events.len(), 1);
@@ -2399,7 +2414,11 @@ fn test_get_events_by_organizer_includes_cancelled_events() {
     );
 
     let cancelled_event_id = create_and_publish_event(&env, &client, &organizer);
-    client.cancel_event(&organizer, &cancelled_event_id);
+    client.cancel_event(
+        &organizer,
+        &cancelled_event_id,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
 
     let events = client.get_events_by_organizer(&organizer);
     assert_eq!(events.len(), 2);
@@ -2469,7 +2488,7 @@ fn test_ticket_purchased_emitted_with_correct_amounts() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase ticket
-    let _ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let _ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Get all events - should have EventCreated, EventStatusChanged, TicketPurchased
     let events = env.events().all();
@@ -2511,7 +2530,7 @@ fn test_ticket_purchased_with_platform_fee_emitted_correctly() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase ticket for 200
-    client.purchase_ticket(&buyer, &event_id, &200i128);
+    client.purchase_ticket(&buyer, &event_id, &200i128, &false, &None);
 
     // Find TicketPurchased event
     let events = env.events().all();
@@ -2544,12 +2563,12 @@ fn test_event_cancelled_emitted_with_correct_tickets_sold() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase 3 tickets
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Cancel event
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     // Find EventCancelled event
     let events = env.events().all();
@@ -2583,11 +2602,11 @@ fn test_event_completed_emitted_with_correct_tickets_sold() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase 5 tickets
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Set timestamp after end time and complete event
     env.ledger().with_mut(|li| li.timestamp = 2001);
@@ -2625,9 +2644,9 @@ fn test_escrow_released_emitted_with_correct_amount() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase tickets totaling 300
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Complete event
     env.ledger().with_mut(|li| li.timestamp = 2001);
@@ -2718,10 +2737,10 @@ fn test_platform_fees_withdrawn_emitted_with_correct_amount() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase 4 tickets for 100 each = 400 total, 40 fees
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Withdraw platform fees
     let withdrawn = client.withdraw_platform_fees(&admin);
@@ -2801,7 +2820,7 @@ fn test_event_status_changed_published_to_cancelled() {
 
     // Cancel event (Published -> Cancelled)
     // Note: cancel_event emits EventCancelled, not EventStatusChanged
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     // Find EventCancelled event
     let events = env.events().all();
@@ -2900,7 +2919,7 @@ fn test_generic_event_state_transition_emitted_on_cancel_event() {
     let organizer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     let events = env.events().all();
     let mut found = false;
@@ -2966,9 +2985,15 @@ fn test_update_platform_fee_recipient_success() {
     let (admin, client) = create_test_contract(&env);
     let new_admin = Address::generate(&env);
 
-    // Change admin
+    // Propose the new admin; nothing changes until they accept
     let result = client.try_update_platform_fee_recipient(&admin, &new_admin);
     assert!(result.is_ok());
+    assert_eq!(client.get_pending_admin().unwrap().candidate, new_admin);
+    assert_eq!(client.get_admin(), admin);
+    let result = client.try_set_platform_fee(&new_admin, &250u32);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    client.accept_admin(&new_admin);
 
     // Verify new admin can call admin functions
     let set_fee_result = client.try_set_platform_fee(&new_admin, &250u32);
@@ -3026,6 +3051,7 @@ fn test_update_platform_fee_recipient_get_admin_returns_new_address() {
 
     // Change admin
     client.update_platform_fee_recipient(&admin, &new_admin);
+    client.accept_admin(&new_admin);
 
     // Verify get_admin returns the new admin
     let current_admin = client.get_admin();
@@ -3042,6 +3068,7 @@ fn test_update_platform_fee_recipient_set_platform_fee_with_new_admin() {
 
     // Change admin
     client.update_platform_fee_recipient(&admin, &new_admin);
+    client.accept_admin(&new_admin);
 
     // New admin should be able to set platform fee
     let result = client.try_set_platform_fee(&new_admin, &500u32);
@@ -3065,10 +3092,11 @@ fn test_update_platform_fee_recipient_withdraw_platform_fees_with_new_admin() {
     // Set platform fee and collect some fees
     client.set_platform_fee(&admin, &1000u32);
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Change admin
     client.update_platform_fee_recipient(&admin, &new_admin);
+    client.accept_admin(&new_admin);
 
     // New admin should be able to withdraw fees
     let withdrawn = client.withdraw_platform_fees(&new_admin);
@@ -3090,6 +3118,7 @@ fn test_update_platform_fee_recipient_chain_a_to_b_to_c() {
 
     // A -> B
     client.update_platform_fee_recipient(&admin_a, &admin_b);
+    client.accept_admin(&admin_b);
 
     // Verify B is now admin
     let current_admin = client.get_admin();
@@ -3097,6 +3126,7 @@ fn test_update_platform_fee_recipient_chain_a_to_b_to_c() {
 
     // B -> C
     client.update_platform_fee_recipient(&admin_b, &admin_c);
+    client.accept_admin(&admin_c);
 
     // Verify C is now admin
     let current_admin = client.get_admin();
@@ -3125,6 +3155,7 @@ fn test_update_platform_fee_recipient_emits_event() {
 
     // Change admin
     client.update_platform_fee_recipient(&admin, &new_admin);
+    client.accept_admin(&new_admin);
 
     // Verify AdminChanged event was emitted
     let events = env.events().all();
@@ -3146,6 +3177,318 @@ fn test_update_platform_fee_recipient_emits_event() {
     assert!(found, "AdminChanged event not found");
 }
 
+// ============================================================================
+// ADMIN HANDOVER & GLOBAL PAUSE TESTS
+// ============================================================================
+
+#[test]
+fn test_admin_handover_requires_acceptance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let new_admin = Address::generate(&env);
+    let approvers = Vec::from_array(&env, [admin.clone()]);
+
+    let expires_at = client.propose_admin(&approvers, &new_admin);
+    let pending = client.get_pending_admin().unwrap();
+    assert_eq!(pending.candidate, new_admin);
+    assert_eq!(pending.expires_at, expires_at);
+
+    // Nothing changes until the candidate accepts
+    assert_eq!(client.get_admin(), admin);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_accept_admin(&stranger);
+    assert_eq!(result, Err(Ok(LumentixError::AdminHandoverNotFound)));
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+    assert!(client.get_pending_admin().is_none());
+
+    let result = client.try_set_platform_fee(&admin, &250u32);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    client.set_platform_fee(&new_admin, &250u32);
+}
+
+#[test]
+fn test_admin_handover_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let new_admin = Address::generate(&env);
+    let approvers = Vec::from_array(&env, [admin.clone()]);
+
+    let expires_at = client.propose_admin(&approvers, &new_admin);
+    env.ledger().set_timestamp(expires_at + 1);
+
+    let result = client.try_accept_admin(&new_admin);
+    assert_eq!(result, Err(Ok(LumentixError::AdminHandoverExpired)));
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_admin_handover_cancel_and_non_admin_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let new_admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    let result = client.try_propose_admin(&Vec::from_array(&env, [outsider]), &new_admin);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    let approvers = Vec::from_array(&env, [admin.clone()]);
+    let result = client.try_propose_admin(&approvers, &admin);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidAddress)));
+
+    client.propose_admin(&approvers, &new_admin);
+    client.cancel_admin_proposal(&approvers);
+    assert!(client.get_pending_admin().is_none());
+
+    let result = client.try_accept_admin(&new_admin);
+    assert_eq!(result, Err(Ok(LumentixError::AdminHandoverNotFound)));
+}
+
+#[test]
+fn test_admin_council_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let s3 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [s1.clone(), s2.clone(), s3.clone()]);
+    let admin_only = Vec::from_array(&env, [admin.clone()]);
+
+    let result = client.try_configure_admin_council(&admin_only, &signers, &4u32);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidAdminCouncil)));
+    let duplicated = Vec::from_array(&env, [s1.clone(), s1.clone()]);
+    let result = client.try_configure_admin_council(&admin_only, &duplicated, &1u32);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidAdminCouncil)));
+
+    client.configure_admin_council(&admin_only, &signers, &2u32);
+    assert_eq!(client.get_admin_council().threshold, 2);
+
+    // The admin key alone no longer carries handover authority
+    let new_admin = Address::generate(&env);
+    let result = client.try_propose_admin(&admin_only, &new_admin);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    let result = client.try_propose_admin(&Vec::from_array(&env, [s1.clone()]), &new_admin);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientAdminApprovals)));
+    let repeated = Vec::from_array(&env, [s1.clone(), s1.clone()]);
+    let result = client.try_propose_admin(&repeated, &new_admin);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientAdminApprovals)));
+
+    client.propose_admin(&Vec::from_array(&env, [s1.clone(), s3.clone()]), &new_admin);
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+}
+
+#[test]
+fn test_admin_council_signs_off_single_key_admin_actions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [s1.clone(), s2.clone()]);
+    client.configure_admin_council(&Vec::from_array(&env, [admin.clone()]), &signers, &2u32);
+
+    let result = client.try_set_platform_fee(&admin, &250u32);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientAdminApprovals)));
+    let result = client.try_update_platform_fee_recipient(&admin, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientAdminApprovals)));
+    let result = client.try_set_features_paused(&admin, &FEATURE_PURCHASES, &true);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientAdminApprovals)));
+
+    let action = Symbol::new(&env, "set_platform_fee");
+    let args: Vec<Val> = vec![&env, 250u32.into_val(&env)];
+    let result = client.try_approve_admin_action(&Address::generate(&env), &action, &args);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    client.approve_admin_action(&s1, &action, &args);
+    let result = client.try_set_platform_fee(&admin, &250u32);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientAdminApprovals)));
+
+    client.approve_admin_action(&s2, &action, &args);
+    assert_eq!(client.get_admin_action_approvals(&action, &args), 2);
+    client.set_platform_fee(&admin, &250u32);
+    assert_eq!(client.get_platform_fee(), 250);
+
+    // Sign-offs are spent by the call they authorised
+    assert_eq!(client.get_admin_action_approvals(&action, &args), 0);
+    let result = client.try_set_platform_fee(&admin, &250u32);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientAdminApprovals)));
+}
+
+#[test]
+fn test_admin_council_sign_offs_bind_arguments_and_expire() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let s1 = Address::generate(&env);
+    let s2 = Address::generate(&env);
+    let signers = Vec::from_array(&env, [s1.clone(), s2.clone()]);
+    client.configure_admin_council(&Vec::from_array(&env, [admin.clone()]), &signers, &2u32);
+
+    // A sign-off on one fee does not authorise another
+    let action = Symbol::new(&env, "set_platform_fee");
+    let args: Vec<Val> = vec![&env, 250u32.into_val(&env)];
+    client.approve_admin_action(&s1, &action, &args);
+    client.approve_admin_action(&s2, &action, &args);
+    let result = client.try_set_platform_fee(&admin, &1_000u32);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientAdminApprovals)));
+    let other_args: Vec<Val> = vec![&env, 1_000u32.into_val(&env)];
+    assert_eq!(client.get_admin_action_approvals(&action, &other_args), 0);
+
+    // Nor does it carry over to another entry point
+    let result = client.try_set_quote_lock_duration(&admin, &250u64);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientAdminApprovals)));
+
+    // Sign-offs lapse once the approval window has passed
+    let start = env.ledger().timestamp();
+    env.ledger()
+        .with_mut(|li| li.timestamp = start + ADMIN_ACTION_APPROVAL_WINDOW_SECS + 1);
+    assert_eq!(client.get_admin_action_approvals(&action, &args), 0);
+    let result = client.try_set_platform_fee(&admin, &250u32);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientAdminApprovals)));
+
+    client.approve_admin_action(&s1, &action, &args);
+    client.approve_admin_action(&s2, &action, &args);
+    client.set_platform_fee(&admin, &250u32);
+    assert_eq!(client.get_platform_fee(), 250);
+}
+
+#[test]
+fn test_global_pause_freezes_activity_but_allows_refunds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let approvers = Vec::from_array(&env, [admin.clone()]);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+
+    client.set_global_pause(&approvers, &true);
+    assert!(client.is_globally_paused());
+
+    let result = client.try_purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    assert_eq!(result, Err(Ok(LumentixError::ContractPaused)));
+    let result = client.try_batch_purchase_tickets(&event_id, &2u32, &buyer, &false);
+    assert_eq!(result, Err(Ok(LumentixError::ContractPaused)));
+    let result = client.try_transfer_ticket(&ticket_id, &buyer, &recipient);
+    assert_eq!(result, Err(Ok(LumentixError::ContractPaused)));
+    let result = client.try_withdraw_funds(&organizer, &event_id, &50i128);
+    assert_eq!(result, Err(Ok(LumentixError::ContractPaused)));
+    let result = client.try_withdraw_platform_fees(&admin);
+    assert_eq!(result, Err(Ok(LumentixError::ContractPaused)));
+    let result = client.try_contribute(&recipient, &event_id, &1u32, &500i128);
+    assert_eq!(result, Err(Ok(LumentixError::ContractPaused)));
+    let result = client.try_deposit_insurance_liquidity(&recipient, &500i128);
+    assert_eq!(result, Err(Ok(LumentixError::ContractPaused)));
+    let result = client.try_process_preorder_payment(&buyer, &ticket_id, &1u64);
+    assert_eq!(result, Err(Ok(LumentixError::ContractPaused)));
+
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+    client.refund_ticket(&ticket_id, &buyer);
+    assert!(client.get_ticket_info(&ticket_id).refunded);
+
+    client.set_global_pause(&approvers, &false);
+    let other_event = create_and_publish_event(&env, &client, &organizer);
+    client.purchase_ticket(&buyer, &other_event, &100i128, &false, &None);
+}
+
+#[test]
+fn test_feature_kill_switches_block_only_their_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+
+    let paused = client.set_features_paused(&admin, &FEATURE_PURCHASES, &true);
+    assert_eq!(paused, FEATURE_PURCHASES);
+    assert!(client.is_feature_paused(&FEATURE_PURCHASES));
+    assert!(!client.is_feature_paused(&FEATURE_TRANSFERS));
+
+    let result = client.try_purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    assert_eq!(result, Err(Ok(LumentixError::FeaturePaused)));
+    let result = client.try_batch_purchase_tickets(&event_id, &2u32, &buyer, &false);
+    assert_eq!(result, Err(Ok(LumentixError::FeaturePaused)));
+    let result = client.try_contribute(&recipient, &event_id, &1u32, &500i128);
+    assert_eq!(result, Err(Ok(LumentixError::FeaturePaused)));
+    let result = client.try_deposit_insurance_liquidity(&recipient, &500i128);
+    assert_eq!(result, Err(Ok(LumentixError::FeaturePaused)));
+    let section = String::from_str(&env, "A");
+    let result = client.try_select_seat(&buyer, &event_id, &section, &1u32, &1u32, &60u64);
+    assert_eq!(result, Err(Ok(LumentixError::FeaturePaused)));
+
+    // Other categories keep working
+    client.transfer_ticket(&ticket_id, &buyer, &recipient);
+
+    client.set_features_paused(&admin, &FEATURE_REFUNDS, &true);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+    let result = client.try_refund_ticket(&ticket_id, &recipient);
+    assert_eq!(result, Err(Ok(LumentixError::FeaturePaused)));
+
+    let paused = client.set_features_paused(&admin, &FEATURE_REFUNDS, &false);
+    assert_eq!(paused, FEATURE_PURCHASES);
+    client.refund_ticket(&ticket_id, &recipient);
+}
+
+#[test]
+fn test_feature_circuit_breaker_and_guardian() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let guardian = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    client.configure_upgrade_safeguards(&admin, &86_400u64, &Some(guardian.clone()));
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+
+    let result = client.try_set_features_paused(&outsider, &FEATURE_ALL, &true);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    let result = client.try_set_features_paused(&admin, &0u32, &true);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidFeatureFlags)));
+    let result = client.try_set_features_paused(&admin, &(FEATURE_ALL + 1), &true);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidFeatureFlags)));
+
+    // The guardian can trip the breaker but not reset it
+    client.set_features_paused(&guardian, &FEATURE_ALL, &true);
+    assert_eq!(client.get_paused_features(), FEATURE_ALL);
+    let result = client.try_set_features_paused(&guardian, &FEATURE_ALL, &false);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    let result = client.try_transfer_ticket(&ticket_id, &buyer, &outsider);
+    assert_eq!(result, Err(Ok(LumentixError::FeaturePaused)));
+    let result = client.try_withdraw_funds(&organizer, &event_id, &50i128);
+    assert_eq!(result, Err(Ok(LumentixError::FeaturePaused)));
+
+    client.set_features_paused(&admin, &FEATURE_ALL, &false);
+    assert_eq!(client.get_paused_features(), 0);
+    client.transfer_ticket(&ticket_id, &buyer, &outsider);
+}
+
 // ============================================================================
 // UPDATE EVENT TESTS
 // ============================================================================
@@ -3221,7 +3564,7 @@ fn test_update_event_cancelled_fails() {
 
     // Create, publish, and cancel event
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     // Try to update cancelled event
     let result = client.try_update_event(
@@ -3430,11 +3773,11 @@ fn test_update_event_reduce_max_tickets_below_sold_fails() {
 
     // Publish and sell 5 tickets
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Try to update the event - this should fail because event is Published
     // (only Draft events can be updated)
@@ -3558,7 +3901,7 @@ fn test_get_ticket_validity_true_for_unused_ticket_on_published_event() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let is_valid = client.get_ticket_validity(&ticket_id);
     assert!(is_valid);
@@ -3574,7 +3917,7 @@ fn test_get_ticket_validity_false_for_used_ticket() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     client.use_ticket(&ticket_id, &organizer);
 
     let is_valid = client.get_ticket_validity(&ticket_id);
@@ -3591,8 +3934,8 @@ fn test_get_ticket_validity_false_for_refunded_ticket() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.cancel_event(&organizer, &event_id);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
     client.refund_ticket(&ticket_id, &buyer);
 
     let is_valid = client.get_ticket_validity(&ticket_id);
@@ -3635,7 +3978,7 @@ fn test_escrow_balance_after_one_ticket_purchase() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase 1 ticket for 100
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Escrow should equal ticket_price - platform_fee (0% fee by default)
     let escrow_balance = client.get_escrow_balance(&event_id);
@@ -3658,9 +4001,9 @@ fn test_escrow_balance_after_multiple_ticket_purchases() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase 3 tickets for 100 each
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Escrow should equal 3 * (ticket_price - platform_fee) = 3 * 100 = 300
     let escrow_balance = client.get_escrow_balance(&event_id);
@@ -3684,14 +4027,14 @@ fn test_escrow_balance_decreases_after_refund() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase 2 tickets
-    let ticket_id_1 = client.purchase_ticket(&buyer1, &event_id, &100i128);
-    client.purchase_ticket(&buyer2, &event_id, &100i128);
+    let ticket_id_1 = client.purchase_ticket(&buyer1, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer2, &event_id, &100i128, &false, &None);
 
     // Verify escrow is 200
     assert_eq!(client.get_escrow_balance(&event_id), 200i128);
 
     // Cancel event and refund one ticket
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
     client.refund_ticket(&ticket_id_1, &buyer1);
 
     // Escrow should decrease by the refund amount (100)
@@ -3715,8 +4058,8 @@ fn test_escrow_balance_zero_after_escrow_release() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase tickets to build up escrow
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(client.get_escrow_balance(&event_id), 200i128);
 
     // Complete event and release escrow
@@ -3762,7 +4105,7 @@ fn test_escrow_balance_with_zero_percent_platform_fee() {
 
     // Purchase 5 tickets for 100 each
     for _ in 0..5 {
-        client.purchase_ticket(&buyer, &event_id, &100i128);
+        client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     }
 
     // With 0% platform fee: escrow = tickets_sold * ticket_price = 5 * 100 = 500
@@ -3790,7 +4133,7 @@ fn test_escrow_balance_with_ten_percent_platform_fee() {
 
     // Purchase 4 tickets for 100 each = 400 total
     for _ in 0..4 {
-        client.purchase_ticket(&buyer, &event_id, &100i128);
+        client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     }
 
     // With 10% platform fee:
@@ -3838,13 +4181,13 @@ fn test_escrow_balance_multiple_events_independent() {
     client.update_event_status(&event_id_2, &EventStatus::Published, &organizer);
 
     // Purchase tickets for event 1 (3 tickets at 100 = 300 total, 15 fee, 285 escrow)
-    client.purchase_ticket(&buyer, &event_id_1, &100i128);
-    client.purchase_ticket(&buyer, &event_id_1, &100i128);
-    client.purchase_ticket(&buyer, &event_id_1, &100i128);
+    client.purchase_ticket(&buyer, &event_id_1, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id_1, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id_1, &100i128, &false, &None);
 
     // Purchase tickets for event 2 (2 tickets at 200 = 400 total, 20 fee, 380 escrow)
-    client.purchase_ticket(&buyer, &event_id_2, &200i128);
-    client.purchase_ticket(&buyer, &event_id_2, &200i128);
+    client.purchase_ticket(&buyer, &event_id_2, &200i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id_2, &200i128, &false, &None);
 
     // Verify each event has independent escrow balance
     let escrow_1 = client.get_escrow_balance(&event_id_1);
@@ -3942,7 +4285,7 @@ fn test_transfer_ticket_success_updates_owner() {
     let to = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&from, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&from, &event_id, &100i128, &false, &None);
 
     client.transfer_ticket(&ticket_id, &from, &to);
 
@@ -3962,7 +4305,7 @@ fn test_transfer_ticket_unauthorized_when_caller_is_not_owner() {
     let recipient = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &false, &None);
 
     let result = client.try_transfer_ticket(&ticket_id, &caller, &recipient);
     assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
@@ -3979,7 +4322,7 @@ fn test_transfer_ticket_used_ticket_fails() {
     let recipient = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &false, &None);
     client.use_ticket(&ticket_id, &organizer);
 
     let result = client.try_transfer_ticket(&ticket_id, &owner, &recipient);
@@ -3997,8 +4340,8 @@ fn test_transfer_ticket_refunded_ticket_fails() {
     let recipient = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
-    client.cancel_event(&organizer, &event_id);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &false, &None);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
     client.refund_ticket(&ticket_id, &owner);
 
     let result = client.try_transfer_ticket(&ticket_id, &owner, &recipient);
@@ -4016,8 +4359,8 @@ fn test_transfer_ticket_cancelled_event_fails() {
     let recipient = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
-    client.cancel_event(&organizer, &event_id);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &false, &None);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     let result = client.try_transfer_ticket(&ticket_id, &owner, &recipient);
     assert_eq!(result, Err(Ok(LumentixError::InvalidStatusTransition)));
@@ -4034,7 +4377,7 @@ fn test_transfer_ticket_completed_event_fails() {
     let recipient = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &false, &None);
     env.ledger().with_mut(|li| li.timestamp = 2001);
     client.complete_event(&organizer, &event_id);
 
@@ -4100,13 +4443,13 @@ fn test_transfer_ticket_original_owner_cannot_use_or_refund_after_transfer() {
     let new_owner = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&original_owner, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&original_owner, &event_id, &100i128, &false, &None);
     client.transfer_ticket(&ticket_id, &original_owner, &new_owner);
 
     let use_result = client.try_use_ticket(&ticket_id, &original_owner);
     assert_eq!(use_result, Err(Ok(LumentixError::Unauthorized)));
 
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
     let refund_result = client.try_refund_ticket(&ticket_id, &original_owner);
     assert_eq!(refund_result, Err(Ok(LumentixError::Unauthorized)));
 }
@@ -4122,7 +4465,7 @@ fn test_transfer_ticket_new_owner_ticket_can_be_used() {
     let new_owner = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&original_owner, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&original_owner, &event_id, &100i128, &false, &None);
     client.transfer_ticket(&ticket_id, &original_owner, &new_owner);
 
     client.use_ticket(&ticket_id, &organizer);
@@ -4157,7 +4500,7 @@ fn test_transfer_ticket_double_transfer_succeeds() {
     let third_owner = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&first_owner, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&first_owner, &event_id, &100i128, &false, &None);
 
     client.transfer_ticket(&ticket_id, &first_owner, &second_owner);
     client.transfer_ticket(&ticket_id, &second_owner, &third_owner);
@@ -4177,7 +4520,7 @@ fn test_batch_transfer_tickets_transfers_ownership_for_all_ids_together() {
     let buyer_b = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_ids = client.batch_purchase_tickets(&event_id, &5u32, &buyer_a);
+    let ticket_ids = client.batch_purchase_tickets(&event_id, &5u32, &buyer_a, &false);
     assert_eq!(ticket_ids.len(), 5);
 
     let mut ids = soroban_sdk::Vec::new(&env);
@@ -4204,8 +4547,8 @@ fn test_batch_transfer_tickets_unowned_in_batch_fails_atomically() {
     let buyer_b = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let a_tickets = client.batch_purchase_tickets(&event_id, &4u32, &buyer_a);
-    let b_ticket = client.purchase_ticket(&buyer_b, &event_id, &100i128);
+    let a_tickets = client.batch_purchase_tickets(&event_id, &4u32, &buyer_a, &false);
+    let b_ticket = client.purchase_ticket(&buyer_b, &event_id, &100i128, &false, &None);
 
     let mut ids = soroban_sdk::Vec::new(&env);
     ids.push_back(a_tickets.get(0).unwrap());
@@ -4234,7 +4577,7 @@ fn test_batch_transfer_tickets_require_auth_once_for_sender() {
     let buyer_b = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_ids = client.batch_purchase_tickets(&event_id, &5u32, &buyer_a);
+    let ticket_ids = client.batch_purchase_tickets(&event_id, &5u32, &buyer_a, &false);
 
     let mut ids = soroban_sdk::Vec::new(&env);
     for id in ticket_ids.iter() {
@@ -4262,7 +4605,7 @@ fn test_transfer_ticket_blocked_during_blackout_window() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
     client.set_transfer_blackout(&organizer, &event_id, &0u64, &100u64);
 
-    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &false, &None);
     assert!(client.is_transfer_blackout_active(&event_id));
 
     let result = client.try_transfer_ticket(&ticket_id, &owner, &recipient);
@@ -4282,7 +4625,7 @@ fn test_bypass_transfer_lock_allows_override_during_blackout() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
     client.set_transfer_blackout(&organizer, &event_id, &0u64, &100u64);
 
-    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &false, &None);
     client.bypass_transfer_lock(&organizer, &ticket_id, &owner, &recipient);
 
     let ticket = client.get_ticket_info(&ticket_id);
@@ -4424,7 +4767,7 @@ fn test_get_tickets_by_buyer_returns_single_ticket_with_correct_data() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let tickets = client.get_tickets_by_buyer(&buyer);
     assert_eq!(tickets.len(), 1);
@@ -4459,8 +4802,8 @@ fn test_get_tickets_by_buyer_returns_multiple_tickets_across_events() {
     );
     client.update_event_status(&second_event, &EventStatus::Published, &organizer);
 
-    let first_ticket = client.purchase_ticket(&buyer, &first_event, &100i128);
-    let second_ticket = client.purchase_ticket(&buyer, &second_event, &150i128);
+    let first_ticket = client.purchase_ticket(&buyer, &first_event, &100i128, &false, &None);
+    let second_ticket = client.purchase_ticket(&buyer, &second_event, &150i128, &false, &None);
 
     let tickets = client.get_tickets_by_buyer(&buyer);
     assert_eq!(tickets.len(), 2);
@@ -4479,8 +4822,8 @@ fn test_get_tickets_by_buyer_separates_different_buyers() {
     let buyer_two = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_one = client.purchase_ticket(&buyer_one, &event_id, &100i128);
-    let ticket_two = client.purchase_ticket(&buyer_two, &event_id, &100i128);
+    let ticket_one = client.purchase_ticket(&buyer_one, &event_id, &100i128, &false, &None);
+    let ticket_two = client.purchase_ticket(&buyer_two, &event_id, &100i128, &false, &None);
 
     let buyer_one_tickets = client.get_tickets_by_buyer(&buyer_one);
     let buyer_two_tickets = client.get_tickets_by_buyer(&buyer_two);
@@ -4501,8 +4844,8 @@ fn test_get_tickets_by_buyer_includes_refunded_ticket() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.cancel_event(&organizer, &event_id);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
     client.refund_ticket(&ticket_id, &buyer);
 
     let tickets = client.get_tickets_by_buyer(&buyer);
@@ -4520,7 +4863,7 @@ fn test_get_tickets_by_buyer_includes_used_ticket() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     client.use_ticket(&ticket_id, &organizer);
 
     let tickets = client.get_tickets_by_buyer(&buyer);
@@ -4538,7 +4881,7 @@ fn test_get_tickets_by_buyer_populates_all_ticket_fields() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let ticket_info = client.get_ticket_info(&ticket_id);
     let tickets = client.get_tickets_by_buyer(&buyer);
@@ -4593,8 +4936,8 @@ fn test_concurrent_event_operations_multiple_organizers() {
     client.update_event_status(&event_b_id, &EventStatus::Published, &organizer_b);
 
     // 2. Buyer purchases tickets for both events
-    let ticket_a_id = client.purchase_ticket(&buyer, &event_a_id, &100i128);
-    let ticket_b_id = client.purchase_ticket(&buyer, &event_b_id, &200i128);
+    let ticket_a_id = client.purchase_ticket(&buyer, &event_a_id, &100i128, &false, &None);
+    let ticket_b_id = client.purchase_ticket(&buyer, &event_b_id, &200i128, &false, &None);
 
     // 3. Verify get_tickets_by_buyer returns tickets from both events
     let buyer_tickets = client.get_tickets_by_buyer(&buyer);
@@ -4613,7 +4956,7 @@ fn test_concurrent_event_operations_multiple_organizers() {
     assert!(has_a && has_b);
 
     // 4. Organizer A cancels their event - organizer B's event is unaffected
-    client.cancel_event(&organizer_a, &event_a_id);
+    client.cancel_event(&organizer_a, &event_a_id, &CancellationReason::EventCancelledByOrganizer);
     assert_eq!(client.get_event_status(&event_a_id), EventStatus::Cancelled);
     assert_eq!(client.get_event_status(&event_b_id), EventStatus::Published);
 
@@ -4808,7 +5151,7 @@ fn test_withdraw_funds_cancelled_event() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Cancel the event
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
 
     // Try to withdraw from cancelled event
     client.withdraw_funds(&organizer, &event_id, &200i128);
@@ -4893,7 +5236,7 @@ fn test_bump_ticket_ttl_single_extends_without_error() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Single TTL bump must succeed and ticket must still be readable
     let result = client.try_bump_ticket_ttl(&ticket_id);
@@ -4935,7 +5278,7 @@ fn test_bump_ticket_ttl_batch_extends_all_tickets_systematically() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase a batch of tickets
-    let ticket_ids = client.batch_purchase_tickets(&event_id, &5u32, &buyer);
+    let ticket_ids = client.batch_purchase_tickets(&event_id, &5u32, &buyer, &false);
     assert_eq!(ticket_ids.len(), 5);
 
     // Bump TTL for every ticket in the batch — each must succeed independently
@@ -4970,7 +5313,7 @@ fn test_bump_ticket_ttl_used_ticket_still_extends() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     client.use_ticket(&ticket_id, &organizer);
 
     let result = client.try_bump_ticket_ttl(&ticket_id);
@@ -5208,6 +5551,8 @@ fn test_treasury_rotation_admin_rotates_recipient_a_to_b() {
 
     let result = client.try_update_platform_fee_recipient(&addr_a, &addr_b);
     assert!(result.is_ok(), "Admin must be able to rotate recipient");
+    assert_eq!(client.get_admin(), addr_a, "Rotation waits for Address_B to accept");
+    client.accept_admin(&addr_b);
 
     assert_eq!(
         client.get_admin(),
@@ -5233,14 +5578,15 @@ fn test_treasury_rotation_subsequent_withdrawal_resolves_to_new_recipient() {
 
     // Create and publish event, sell tickets to accumulate fees
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Platform balance: 20 (10% of 200)
     assert_eq!(client.get_platform_balance(), 20i128);
 
     // Rotate recipient from A to B
     client.update_platform_fee_recipient(&addr_a, &addr_b);
+    client.accept_admin(&addr_b);
 
     // Address_A must no longer be able to withdraw
     let old_withdraw = client.try_withdraw_platform_fees(&addr_a);
@@ -5269,10 +5615,11 @@ fn test_treasury_rotation_escrow_release_after_rotation_goes_to_organizer_not_ad
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Rotate admin
     client.update_platform_fee_recipient(&addr_a, &addr_b);
+    client.accept_admin(&addr_b);
 
     // Complete event and release escrow — must go to organizer
     env.ledger().with_mut(|li| li.timestamp = 2001);
@@ -5395,18 +5742,18 @@ fn test_purchase_blocked_while_paused_and_allowed_after_resume() {
     // Pause sales
     client.pause_ticket_sales(&event_id, &organizer);
 
-    let result = client.try_purchase_ticket(&buyer, &event_id, &100i128);
+    let result = client.try_purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(result, Err(Ok(LumentixError::EventPaused)));
 
     // Resume sales
     client.resume_ticket_sales(&event_id);
 
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(ticket_id, 1);
 
     // Refund works even if paused
     client.pause_ticket_sales(&event_id, &organizer);
-    client.cancel_event(&organizer, &event_id);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
     let refund_result = client.try_refund_ticket(&ticket_id, &buyer);
     assert!(refund_result.is_ok());
 }
@@ -5432,7 +5779,7 @@ fn test_batch_purchase_tickets_capacity_balances() {
     );
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
-    let tids = client.batch_purchase_tickets(&event_id, &10u32, &buyer);
+    let tids = client.batch_purchase_tickets(&event_id, &10u32, &buyer, &false);
     assert_eq!(tids.len(), 10);
 
     let event = client.get_event(&event_id);
@@ -5449,7 +5796,7 @@ fn test_batch_purchase_tickets_capacity_balances() {
     assert_eq!(map.len(), 10);
 
     // Over capacity limit (11 per batch)
-    let fail_res = client.try_batch_purchase_tickets(&event_id, &11u32, &buyer);
+    let fail_res = client.try_batch_purchase_tickets(&event_id, &11u32, &buyer, &false);
     assert_eq!(fail_res, Err(Ok(LumentixError::CapacityExceeded)));
 }
 
@@ -5464,8 +5811,8 @@ fn test_batch_use_tickets() {
     let buyer2 = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let tids1 = client.batch_purchase_tickets(&event_id, &4u32, &buyer1);
-    let tid2 = client.purchase_ticket(&buyer2, &event_id, &100i128);
+    let tids1 = client.batch_purchase_tickets(&event_id, &4u32, &buyer1, &false);
+    let tid2 = client.purchase_ticket(&buyer2, &event_id, &100i128, &false, &None);
 
     // Use 4 valid tickets — one consolidated BatchTicketsUsed per event (topic "batchuse")
     assert!(client.try_batch_use_tickets(&tids1, &organizer).is_ok());
@@ -5537,7 +5884,7 @@ fn test_set_event_capacity() {
 
     // Buy 50
     for _ in 0..5 {
-        client.batch_purchase_tickets(&event_id, &10u32, &buyer);
+        client.batch_purchase_tickets(&event_id, &10u32, &buyer, &false);
     }
 
     // Decrease below 50 should fail
@@ -5620,7 +5967,7 @@ fn test_waitlist_fifo_offer_and_reserved_capacity() {
     );
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
-    client.purchase_ticket(&buyer_a, &event_id, &100i128);
+    client.purchase_ticket(&buyer_a, &event_id, &100i128, &false, &None);
     let position = client.join_waitlist(&event_id, &buyer_b);
     assert_eq!(position, 1u32);
 
@@ -5630,12 +5977,12 @@ fn test_waitlist_fifo_offer_and_reserved_capacity() {
         .is_ok());
 
     // Public buyer cannot consume the reserved waitlist slot.
-    let c_result = client.try_purchase_ticket(&buyer_c, &event_id, &100i128);
+    let c_result = client.try_purchase_ticket(&buyer_c, &event_id, &100i128, &false, &None);
     assert_eq!(c_result, Err(Ok(LumentixError::EventSoldOut)));
 
     // Waitlisted buyer can purchase during their reservation window.
     assert!(client
-        .try_purchase_ticket(&buyer_b, &event_id, &100i128)
+        .try_purchase_ticket(&buyer_b, &event_id, &100i128, &false, &None)
         .is_ok());
 }
 
@@ -5692,7 +6039,7 @@ fn test_mint_batch_tickets_tracks_gas_usage() {
     let optimal = client.optimize_mint_gas(&event_id, &3u32);
     assert!(optimal >= 1);
 
-    let ticket_ids = client.mint_batch_tickets(&event_id, &3u32, &buyer);
+    let ticket_ids = client.mint_batch_tickets(&event_id, &3u32, &buyer, &false);
     assert_eq!(ticket_ids.len(), 3);
 
     let usage = client.track_mint_gas_usage(&event_id);
@@ -5735,6 +6082,453 @@ fn test_hybrid_streaming_delivery_and_performance() {
     assert_eq!(metrics.concurrent_viewers, 42);
     assert!(metrics.quality_score >= 60);
 }
+
+fn create_published_hybrid_event(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+) -> u64 {
+    let event_id = client.create_hybrid_event(
+        organizer,
+        &String::from_str(env, "Hybrid Conf"),
+        &100i128,
+        &1u32,
+        &1_000_000u64,
+        &2_000_000u64,
+        &String::from_str(env, "https://cdn.example/live/master.m3u8"),
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, organizer);
+    client.set_virtual_ticket_class(organizer, &event_id, &40i128, &2u32);
+    event_id
+}
+
+#[test]
+fn test_purchase_virtual_ticket_grants_streaming_access() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+
+    // In-person capacity is full but virtual tickets have their own class
+    client.purchase_ticket(&Address::generate(&env), &event_id, &100i128, &false, &None);
+    let ticket_id = client.purchase_virtual_ticket(&viewer, &event_id);
+
+    assert!(client.is_virtual_ticket(&ticket_id));
+    assert_eq!(client.get_virtual_ticket_class(&event_id).sold, 1);
+    assert_eq!(client.get_escrow_balance(&event_id), 140);
+    client.track_virtual_attendance(&event_id, &viewer);
+
+    client.purchase_virtual_ticket(&Address::generate(&env), &event_id);
+    let result = client.try_purchase_virtual_ticket(&Address::generate(&env), &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::VirtualTicketsSoldOut)));
+}
+
+#[test]
+fn test_set_virtual_ticket_class_requires_hybrid_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let result = client.try_set_virtual_ticket_class(&organizer, &event_id, &40i128, &10u32);
+    assert_eq!(result, Err(Ok(LumentixError::NotHybridEvent)));
+}
+
+#[test]
+fn test_virtual_attendance_sessions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    client.purchase_virtual_ticket(&viewer, &event_id);
+
+    let result = client.try_leave_virtual_session(&event_id, &viewer);
+    assert_eq!(result, Err(Ok(LumentixError::VirtualSessionNotOpen)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000_100);
+    client.track_virtual_attendance(&event_id, &viewer);
+    let result = client.try_track_virtual_attendance(&event_id, &viewer);
+    assert_eq!(result, Err(Ok(LumentixError::VirtualSessionAlreadyOpen)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000_700);
+    client.leave_virtual_session(&event_id, &viewer);
+    env.ledger().with_mut(|li| li.timestamp = 1_001_000);
+    client.track_virtual_attendance(&event_id, &viewer);
+
+    let sessions = client.get_virtual_sessions(&event_id, &viewer);
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions.get(0).unwrap().joined_at, 1_000_100);
+    assert_eq!(sessions.get(0).unwrap().left_at, 1_000_700);
+    assert_eq!(sessions.get(1).unwrap().left_at, 0);
+}
+
+fn sign_stream_challenge(
+    env: &Env,
+    session_key: &SigningKey,
+    ticket_id: u64,
+    challenge: &[u8; 8],
+) -> BytesN<64> {
+    let mut message = [0u8; 16];
+    message[..8].copy_from_slice(&ticket_id.to_be_bytes());
+    message[8..].copy_from_slice(challenge);
+    BytesN::from_array(env, &session_key.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_stream_access_token_issue_and_verify() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_virtual_ticket(&viewer, &event_id);
+    let session_key = SigningKey::from_bytes(&[5u8; 32]);
+    let public_key = BytesN::from_array(&env, &session_key.verifying_key().to_bytes());
+
+    let result = client.try_issue_stream_access_token(&viewer, &ticket_id, &public_key);
+    assert_eq!(result, Err(Ok(LumentixError::StreamDeliveryNotConfigured)));
+
+    client.manage_content_delivery(
+        &organizer,
+        &event_id,
+        &String::from_str(&env, "https://cdn.lumentix.example"),
+        &String::from_str(&env, "https://stream.lumentix.example/live.m3u8"),
+        &String::from_str(&env, "auto"),
+    );
+
+    let result =
+        client.try_issue_stream_access_token(&Address::generate(&env), &ticket_id, &public_key);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    let access = client.issue_stream_access_token(&viewer, &ticket_id, &public_key);
+    assert_eq!(access.cdn_endpoint, String::from_str(&env, "https://cdn.lumentix.example"));
+
+    // Only the holder of the session key can answer the CDN's challenge
+    let challenge = Bytes::from_array(&env, b"nonce-01");
+    let signature = sign_stream_challenge(&env, &session_key, ticket_id, b"nonce-01");
+    assert!(client.verify_stream_access_token(&ticket_id, &challenge, &signature));
+    let forged = sign_stream_challenge(
+        &env,
+        &SigningKey::from_bytes(&[6u8; 32]),
+        ticket_id,
+        b"nonce-01",
+    );
+    assert!(client
+        .try_verify_stream_access_token(&ticket_id, &challenge, &forged)
+        .is_err());
+
+    env.ledger().with_mut(|li| li.timestamp = access.expires_at);
+    assert!(!client.verify_stream_access_token(&ticket_id, &challenge, &signature));
+}
+
+#[test]
+fn test_virtual_streaming_access_follows_the_ticket() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    let first = client.purchase_virtual_ticket(&seller, &event_id);
+    let second = client.purchase_virtual_ticket(&seller, &event_id);
+    env.ledger().with_mut(|li| li.timestamp = 1_000_100);
+
+    client.manage_content_delivery(
+        &organizer,
+        &event_id,
+        &String::from_str(&env, "https://cdn.lumentix.example"),
+        &String::from_str(&env, "https://stream.lumentix.example/live.m3u8"),
+        &String::from_str(&env, "auto"),
+    );
+    let session_key = SigningKey::from_bytes(&[5u8; 32]);
+    let public_key = BytesN::from_array(&env, &session_key.verifying_key().to_bytes());
+    client.issue_stream_access_token(&seller, &first, &public_key);
+
+    // The seller's session dies with the transfer and the buyer can stream
+    client.transfer_ticket(&first, &seller, &buyer);
+    let challenge = Bytes::from_array(&env, b"nonce-02");
+    let signature = sign_stream_challenge(&env, &session_key, first, b"nonce-02");
+    assert!(!client.verify_stream_access_token(&first, &challenge, &signature));
+    client.track_virtual_attendance(&event_id, &buyer);
+    client.track_virtual_attendance(&event_id, &seller);
+    client.leave_virtual_session(&event_id, &seller);
+
+    // Refunding one of two tickets keeps access through the other
+    client.transfer_ticket(&first, &buyer, &seller);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+    client.refund_ticket(&first, &seller);
+    client.track_virtual_attendance(&event_id, &seller);
+    client.leave_virtual_session(&event_id, &seller);
+    client.refund_ticket(&second, &seller);
+    let result = client.try_track_virtual_attendance(&event_id, &seller);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    let result = client.try_track_virtual_attendance(&event_id, &buyer);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+}
+
+#[test]
+fn test_refund_virtual_ticket_frees_virtual_capacity() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_virtual_ticket(&viewer, &event_id);
+
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+    client.refund_ticket(&ticket_id, &viewer);
+
+    assert_eq!(client.get_virtual_ticket_class(&event_id).sold, 0);
+    assert_eq!(client.get_escrow_balance(&event_id), 0);
+    assert_eq!(client.get_event(&event_id).tickets_sold, 0);
+    let result = client.try_track_virtual_attendance(&event_id, &viewer);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+}
+
+#[test]
+fn test_replay_purchase_and_replay_session() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    client.configure_replay(&organizer, &event_id, &25i128, &86_400u64);
+
+    let entitlement = client.purchase_replay(&viewer, &event_id);
+    assert_eq!(entitlement.amount_paid, 25);
+    assert_eq!(client.get_escrow_balance(&event_id), 25);
+
+    let result = client.try_purchase_replay(&viewer, &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::ReplayAlreadyPurchased)));
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000_500);
+    let result = client.try_track_virtual_attendance(&event_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(LumentixError::ReplayEntitlementNotFound)));
+
+    client.track_virtual_attendance(&event_id, &viewer);
+    let sessions = client.get_virtual_sessions(&event_id, &viewer);
+    assert!(sessions.get(0).unwrap().replay);
+}
+
+#[test]
+fn test_replay_window_closes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    client.configure_replay(&organizer, &event_id, &25i128, &1_000u64);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_001_001);
+    let result = client.try_purchase_replay(&Address::generate(&env), &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::ReplayUnavailable)));
+}
+
+#[test]
+fn test_refund_replay_after_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let viewer = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_client = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&viewer, &25i128);
+    client.set_token(&admin, &token_address);
+
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    client.configure_replay(&organizer, &event_id, &25i128, &86_400u64);
+    client.purchase_replay(&viewer, &event_id);
+    assert_eq!(token_client.balance(&viewer), 0);
+
+    let result = client.try_refund_replay(&viewer, &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::ReplayNotWithdrawn)));
+
+    client.withdraw_replay(&organizer, &event_id);
+    assert_eq!(client.refund_replay(&viewer, &event_id), 25);
+    assert_eq!(token_client.balance(&viewer), 25);
+    assert_eq!(client.get_escrow_balance(&event_id), 0);
+    assert!(client.get_replay_entitlement(&event_id, &viewer).refunded);
+
+    let result = client.try_refund_replay(&viewer, &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::RefundNotAllowed)));
+}
+
+#[test]
+fn test_refund_replay_after_fee_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let viewer = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_client = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&viewer, &100i128);
+    client.set_token(&admin, &token_address);
+    client.set_platform_fee(&admin, &1000u32);
+
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    client.configure_replay(&organizer, &event_id, &100i128, &86_400u64);
+    client.purchase_replay(&viewer, &event_id);
+    assert_eq!(client.withdraw_platform_fees(&admin), 10);
+
+    client.withdraw_replay(&organizer, &event_id);
+    assert_eq!(client.refund_replay(&viewer, &event_id), 90);
+    assert_eq!(token_client.balance(&viewer), 90);
+    assert_eq!(client.get_escrow_balance(&event_id), 0);
+    assert_eq!(client.get_platform_fee_debt(&viewer, &None), 10);
+}
+
+fn register_qos_reporters(
+    env: &Env,
+    client: &LumentixContractClient,
+    admin: &Address,
+    count: u32,
+) -> Vec<Address> {
+    let mut reporters = Vec::new(env);
+    for _ in 0..count {
+        let reporter = Address::generate(env);
+        client.set_qos_reporter(admin, &reporter, &true);
+        reporters.push_back(reporter);
+    }
+    reporters
+}
+
+fn declare_sla(env: &Env, client: &LumentixContractClient, organizer: &Address, event_id: u64) {
+    client.set_streaming_sla(
+        organizer,
+        &event_id,
+        &StreamingSla {
+            min_bitrate_kbps: 2_500,
+            max_rebuffer_bps: 500,
+            refund_bps: 5_000,
+            min_reports: 2,
+        },
+    );
+}
+
+#[test]
+fn test_qos_reports_require_registered_reporter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+
+    let result = client.try_set_qos_reporter(&organizer, &organizer, &true);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    let result = client.try_submit_qos_report(&organizer, &event_id, &100u32, &5_000u32);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    let reporter = register_qos_reporters(&env, &client, &admin, 1).get(0).unwrap();
+    client.submit_qos_report(&reporter, &event_id, &3_000u32, &100u32);
+    let result = client.try_submit_qos_report(&reporter, &event_id, &3_000u32, &100u32);
+    assert_eq!(result, Err(Ok(LumentixError::QosReportAlreadySubmitted)));
+
+    client.set_qos_reporter(&admin, &reporter, &false);
+    assert!(!client.is_qos_reporter(&reporter));
+}
+
+#[test]
+fn test_qos_aggregate_uses_median() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    let reporters = register_qos_reporters(&env, &client, &admin, 3);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+    client.submit_qos_report(&reporters.get(0).unwrap(), &event_id, &3_000u32, &100u32);
+    client.submit_qos_report(&reporters.get(1).unwrap(), &event_id, &1_000u32, &900u32);
+    let bucket =
+        client.submit_qos_report(&reporters.get(2).unwrap(), &event_id, &2_000u32, &300u32);
+
+    let aggregate = client.get_qos_aggregate(&event_id, &bucket);
+    assert_eq!(aggregate.report_count, 3);
+    assert_eq!(aggregate.median_bitrate_kbps, 2_000);
+    assert_eq!(aggregate.median_rebuffer_bps, 300);
+}
+
+#[test]
+fn test_claim_sla_refund_after_breach() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    declare_sla(&env, &client, &organizer, event_id);
+    let reporters = register_qos_reporters(&env, &client, &admin, 2);
+    let ticket_id = client.purchase_virtual_ticket(&viewer, &event_id);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+    client.submit_qos_report(&reporters.get(0).unwrap(), &event_id, &1_200u32, &100u32);
+    let bucket =
+        client.submit_qos_report(&reporters.get(1).unwrap(), &event_id, &1_500u32, &200u32);
+
+    let result = client.try_claim_sla_refund(&viewer, &ticket_id, &bucket);
+    assert_eq!(result, Err(Ok(LumentixError::QosBucketOpen)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000_300);
+    assert_eq!(client.claim_sla_refund(&viewer, &ticket_id, &bucket), 20);
+    assert_eq!(client.get_escrow_balance(&event_id), 20);
+
+    let result = client.try_claim_sla_refund(&viewer, &ticket_id, &bucket);
+    assert_eq!(result, Err(Ok(LumentixError::SlaRefundAlreadyClaimed)));
+
+    // A later full refund only returns what is left of the ticket price
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+    client.refund_ticket(&ticket_id, &viewer);
+    assert_eq!(client.get_escrow_balance(&event_id), 0);
+}
+
+#[test]
+fn test_claim_sla_refund_requires_breach() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let viewer = Address::generate(&env);
+    let event_id = create_published_hybrid_event(&env, &client, &organizer);
+    declare_sla(&env, &client, &organizer, event_id);
+    let reporters = register_qos_reporters(&env, &client, &admin, 2);
+    let ticket_id = client.purchase_virtual_ticket(&viewer, &event_id);
+
+    // A single low report is below the SLA's minimum report count
+    env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+    let bucket = client.submit_qos_report(&reporters.get(0).unwrap(), &event_id, &500u32, &100u32);
+    env.ledger().with_mut(|li| li.timestamp = 1_000_300);
+    let result = client.try_claim_sla_refund(&viewer, &ticket_id, &bucket);
+    assert_eq!(result, Err(Ok(LumentixError::SlaNotBreached)));
+
+    let result = client.try_set_streaming_sla(
+        &organizer,
+        &event_id,
+        &StreamingSla {
+            min_bitrate_kbps: 0,
+            max_rebuffer_bps: 10_000,
+            refund_bps: 1,
+            min_reports: 1,
+        },
+    );
+    assert_eq!(result, Err(Ok(LumentixError::InvalidTimeRange)));
+}
 // ============================================================================
 // ADMINISTRATIVE OVERSIGHT OPERATIONS TESTS
 // ============================================================================
@@ -5750,7 +6544,7 @@ fn test_normal_user_revoke_fails_auth_panic() {
     let normal_user = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let result = client.try_revoke_ticket(&normal_user, &ticket_id);
     assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
@@ -5766,7 +6560,7 @@ fn test_admin_executes_revoke_on_valid_id() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let result = client.try_revoke_ticket(&admin, &ticket_id);
     assert!(result.is_ok());
@@ -5782,7 +6576,7 @@ fn test_valid_ticket_gets_flagged_invalid_after_revoke() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Verify ticket is initially valid
     assert!(client.get_ticket_validity(&ticket_id));
@@ -5804,7 +6598,7 @@ fn test_regular_check_in_use_ticket_flags_revoked_ticket_panic() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Revoke the ticket
     client.revoke_ticket(&admin, &ticket_id);
@@ -5825,7 +6619,7 @@ fn test_transfer_attempts_on_revoked_tickets_crash() {
     let recipient = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&owner, &event_id, &100i128, &false, &None);
 
     // Revoke the ticket
     client.revoke_ticket(&admin, &ticket_id);
@@ -5849,7 +6643,7 @@ fn test_extending_single_ttl_logic_executes_properly() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Extend TTL for the ticket - this should execute without error
     let result = client.try_bump_ticket_ttl(&ticket_id);
@@ -5872,7 +6666,7 @@ fn test_batch_operations_extend_ttls_dynamically() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase multiple tickets
-    let ticket_ids = client.batch_purchase_tickets(&event_id, &4u32, &buyer);
+    let ticket_ids = client.batch_purchase_tickets(&event_id, &4u32, &buyer, &false);
 
     // Extend TTL for multiple tickets to prevent accidental expiration during deep modifications
     for ticket_id in ticket_ids.iter() {
@@ -5895,7 +6689,7 @@ fn test_correct_state_of_minimum_and_max_ttl_allocations() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Test that TTL extension operations work correctly
     // The PERSISTENT_LIFETIME constant provides the TTL allocation
@@ -5929,7 +6723,7 @@ fn test_batch_check_in_4_valid_tickets_changes_all_state_to_used() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase 4 tickets for the same user
-    let ticket_ids = client.batch_purchase_tickets(&event_id, &4u32, &buyer);
+    let ticket_ids = client.batch_purchase_tickets(&event_id, &4u32, &buyer, &false);
 
     // Batch check-in all 4 tickets
     let result = client.try_batch_use_tickets(&ticket_ids, &organizer);
@@ -5955,8 +6749,8 @@ fn test_batch_check_in_different_user_succeeds_if_same_organizer() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase 3 tickets for buyer1 and 1 ticket for buyer2
-    let mut ticket_ids = client.batch_purchase_tickets(&event_id, &3u32, &buyer1);
-    let buyer2_ticket = client.purchase_ticket(&buyer2, &event_id, &100i128);
+    let mut ticket_ids = client.batch_purchase_tickets(&event_id, &3u32, &buyer1, &false);
+    let buyer2_ticket = client.purchase_ticket(&buyer2, &event_id, &100i128, &false, &None);
     ticket_ids.push_back(buyer2_ticket);
 
     // Batch check-in should succeed since all tickets belong to the same event with same organizer
@@ -5988,7 +6782,7 @@ fn test_already_used_ids_in_batch_gracefully_reject_tx() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     // Purchase 4 tickets
-    let ticket_ids = client.batch_purchase_tickets(&event_id, &4u32, &buyer);
+    let ticket_ids = client.batch_purchase_tickets(&event_id, &4u32, &buyer, &false);
 
     // Use one ticket individually first
     let first_ticket = ticket_ids.get(0).unwrap();
@@ -6796,9 +7590,9 @@ fn test_event_capacity_changed_not_emitted_when_capacity_below_tickets_sold() {
     client.update_event_status(&event_id, &EventStatus::Published, &organizer);
 
     // Sell 3 tickets
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     // Try to reduce capacity to 2 (below 3 sold) — must fail
     let result = client.try_set_event_capacity(&organizer, &event_id, &2u32);
@@ -7148,7 +7942,7 @@ fn test_merchandise_preorder_and_voucher_flow() {
     let buyer = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = purchase_ticket(&env, &client, &buyer, event_id);
+    let ticket_id = purchase_ticket(&env, &client, &buyer, event_id, &None);
 
     let merch_id = client.create_event_merchandise(
         &organizer,
@@ -7200,7 +7994,7 @@ fn test_waitlist_system_and_spot_release_flow() {
     client.publish_event(&organizer, &event_id);
 
     // Buy out the 1 available ticket
-    purchase_ticket(&env, &client, &buyer1, event_id);
+    purchase_ticket(&env, &client, &buyer1, event_id, &None);
 
     // Buyer2 joins waitlist via join_event_waitlist
     let pos = client.join_event_waitlist(&event_id, &buyer2);
@@ -7242,7 +8036,7 @@ fn test_seat_upgrade_bidding_marketplace() {
     let bidder = Address::generate(&env);
 
     let event_id = create_and_publish_event(&env, &client, &organizer);
-    let ticket_id = purchase_ticket(&env, &client, &bidder, event_id);
+    let ticket_id = purchase_ticket(&env, &client, &bidder, event_id, &None);
 
     // Place upgrade bid
     let bid_id = client.place_upgrade_bid(
@@ -1,349 +1,363 @@
 
 pub mod email_campaign_tests;
-use crate::contract::{TicketContract, TicketContractClient};
-use soroban_sdk::{symbol_short, testutils, Address, Env, Vec};
-
-fn setup() -> (Env, Address) {
-    let env = Env::default();
+use crate::error::LumentixError;
+use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
+use crate::types::EventStatus;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, String, Vec,
+};
+
+fn setup(env: &Env) -> LumentixContractClient<'_> {
     env.mock_all_auths();
 
-    let contract_id = env.register(TicketContract, ());
+    let contract_id = env.register(LumentixContract, ());
+    let client = LumentixContractClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env));
 
-    (env, contract_id)
+    client
 }
 
-#[test]
-fn test_issue_ticket() {
-    let (env, contract_id) = setup();
-    let client = TicketContractClient::new(&env, &contract_id);
-
-    let ticket_id = symbol_short!("TICKET1");
-    let event_id = symbol_short!("EVENT1");
-    let owner = <Address as testutils::Address>::generate(&env);
-
-    let ticket = client.issue_ticket(&ticket_id, &event_id, &owner);
-
-    assert_eq!(ticket.id, ticket_id);
-    assert_eq!(ticket.event_id, event_id);
-    assert_eq!(ticket.owner, owner);
-    assert!(!ticket.is_used);
+fn create_published_event(env: &Env, client: &LumentixContractClient, organizer: &Address) -> u64 {
+    let event_id = client.create_event(
+        organizer,
+        &String::from_str(env, "Gate Event"),
+        &String::from_str(env, "Description"),
+        &String::from_str(env, "Location"),
+        &1_000u64,
+        &2_000u64,
+        &100i128,
+        &50u32,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, organizer);
+    event_id
 }
 
-#[test]
-fn test_get_ticket_existing() {
-    let (env, contract_id) = setup();
-    let client = TicketContractClient::new(&env, &contract_id);
-
-    let ticket_id = symbol_short!("TICKET2");
-    let event_id = symbol_short!("EVENT2");
-    let owner = <Address as testutils::Address>::generate(&env);
-
-    client.issue_ticket(&ticket_id, &event_id, &owner);
-    let retrieved = client.get_ticket(&ticket_id);
-
-    assert!(retrieved.is_some());
+fn complete_event_with_sale(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+) -> u64 {
+    let event_id = create_published_event(env, client, organizer);
+    client.purchase_ticket(&Address::generate(env), &event_id, &100i128, &false, &None);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    client.complete_event(organizer, &event_id);
+    event_id
 }
 
-#[test]
-#[should_panic(expected = "Unauthorized")]
-fn test_transfer_unauthorized() {
-    let (env, contract_id) = setup();
-    let client = TicketContractClient::new(&env, &contract_id);
-
-    let ticket_id = symbol_short!("TICKETX");
-    let event_id = symbol_short!("EVENTX");
-    let owner = <Address as testutils::Address>::generate(&env);
-    let attacker = <Address as testutils::Address>::generate(&env);
-
-    client.issue_ticket(&ticket_id, &event_id, &owner);
-
-    // This should panic because attacker is not the owner
-    client.transfer_ticket(&ticket_id, &attacker, &owner);
-}
+// ── Multi-sig escrow ────────────────────────────────────────────────────────
 
 #[test]
 fn test_multisig_escrow_success() {
-    let (env, contract_id) = setup();
-    let client = TicketContractClient::new(&env, &contract_id);
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
 
-    let event_id = symbol_short!("E1");
-    let signer1 = <Address as testutils::Address>::generate(&env);
-    let signer2 = <Address as testutils::Address>::generate(&env);
-    let destination = <Address as testutils::Address>::generate(&env);
+    let event_id = complete_event_with_sale(&env, &client, &organizer);
 
     let mut signers = Vec::new(&env);
     signers.push_back(signer1.clone());
     signers.push_back(signer2.clone());
 
-    client.set_escrow_signers(&event_id, &signers, &2);
+    client.set_escrow_signers(&organizer, &event_id, &signers, &2);
     client.approve_release(&event_id, &signer1);
     client.approve_release(&event_id, &signer2);
+    assert_eq!(client.get_escrow_approval_count(&event_id), 2);
 
-    client.distribute_escrow(&event_id, &destination);
+    let released = client.release_escrow(&organizer, &event_id);
+    assert_eq!(released, 100);
+    assert_eq!(client.get_escrow_approval_count(&event_id), 0);
 }
 
 #[test]
-#[should_panic(expected = "Threshold not met")]
 fn test_multisig_escrow_threshold_not_met() {
-    let (env, contract_id) = setup();
-    let client = TicketContractClient::new(&env, &contract_id);
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
 
-    let event_id = symbol_short!("E2");
-    let signer1 = <Address as testutils::Address>::generate(&env);
-    let signer2 = <Address as testutils::Address>::generate(&env);
-    let destination = <Address as testutils::Address>::generate(&env);
+    let event_id = complete_event_with_sale(&env, &client, &organizer);
 
     let mut signers = Vec::new(&env);
     signers.push_back(signer1.clone());
     signers.push_back(signer2.clone());
 
-    client.set_escrow_signers(&event_id, &signers, &2);
+    client.set_escrow_signers(&organizer, &event_id, &signers, &2);
     client.approve_release(&event_id, &signer1);
 
-    client.distribute_escrow(&event_id, &destination);
+    let result = client.try_release_escrow(&organizer, &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::EscrowThresholdNotMet)));
 }
 
-// ========================================
-// TESTS FOR TICKET VALIDATION FEATURE
-// ========================================
-
 #[test]
-fn test_init_event() {
-    let (env, contract_id) = setup();
-
-    let event_id = symbol_short!("EVENT99");
-    let organizer = <Address as testutils::Address>::generate(&env);
-
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer.clone());
-
-        // Verify organizer is automatically authorized
-        let is_auth = TicketContract::is_authorized_validator(
-            env.clone(),
-            event_id.clone(),
-            organizer.clone(),
-        );
-        assert!(is_auth);
-    });
-}
+fn test_multisig_escrow_revoked_approval_not_counted() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let signer = Address::generate(&env);
 
-#[test]
-fn test_add_validator() {
-    let (env, contract_id) = setup();
+    let event_id = complete_event_with_sale(&env, &client, &organizer);
 
-    let event_id = symbol_short!("EVENT10");
-    let organizer = <Address as testutils::Address>::generate(&env);
-    let validator = <Address as testutils::Address>::generate(&env);
+    let mut signers = Vec::new(&env);
+    signers.push_back(signer.clone());
 
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer.clone());
-        TicketContract::add_validator(env.clone(), event_id.clone(), validator.clone());
+    client.set_escrow_signers(&organizer, &event_id, &signers, &1);
+    client.approve_release(&event_id, &signer);
+    client.revoke_approval(&event_id, &signer);
 
-        let is_auth =
-            TicketContract::is_authorized_validator(env.clone(), event_id, validator.clone());
-        assert!(is_auth);
-    });
+    let result = client.try_release_escrow(&organizer, &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::EscrowThresholdNotMet)));
 }
 
 #[test]
-fn test_remove_validator() {
-    let (env, contract_id) = setup();
+fn test_set_escrow_signers_invalid_threshold() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
 
-    let event_id = symbol_short!("EVENT11");
-    let organizer = <Address as testutils::Address>::generate(&env);
-    let validator = <Address as testutils::Address>::generate(&env);
+    let mut signers = Vec::new(&env);
+    signers.push_back(Address::generate(&env));
+
+    let result = client.try_set_escrow_signers(&organizer, &event_id, &signers, &0);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidEscrowThreshold)));
 
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer.clone());
-        TicketContract::add_validator(env.clone(), event_id.clone(), validator.clone());
-        TicketContract::remove_validator(env.clone(), event_id.clone(), validator.clone());
+    let result = client.try_set_escrow_signers(&organizer, &event_id, &signers, &2);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidEscrowThreshold)));
 
-        let is_auth = TicketContract::is_authorized_validator(env.clone(), event_id, validator);
-        assert!(!is_auth);
-    });
+    signers.push_back(signers.get(0).unwrap());
+    let result = client.try_set_escrow_signers(&organizer, &event_id, &signers, &2);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidEscrowThreshold)));
 }
 
 #[test]
-fn test_is_authorized_validator_organizer() {
-    let (env, contract_id) = setup();
+fn test_escrow_signers_locked_and_rotated_by_current_signers() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let signer1 = Address::generate(&env);
+    let signer2 = Address::generate(&env);
 
-    let event_id = symbol_short!("EVENT12");
-    let organizer = <Address as testutils::Address>::generate(&env);
+    let event_id = complete_event_with_sale(&env, &client, &organizer);
 
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer.clone());
+    let mut signers = Vec::new(&env);
+    signers.push_back(signer1.clone());
+    signers.push_back(signer2.clone());
+    client.set_escrow_signers(&organizer, &event_id, &signers, &2);
+    client.approve_release(&event_id, &signer1);
 
-        let is_auth = TicketContract::is_authorized_validator(env.clone(), event_id, organizer);
-        assert!(is_auth);
-    });
+    // The organizer cannot swap in itself as sole signer
+    let mut takeover = Vec::new(&env);
+    takeover.push_back(organizer.clone());
+    let result = client.try_set_escrow_signers(&organizer, &event_id, &takeover, &1);
+    assert_eq!(result, Err(Ok(LumentixError::EscrowSignersLocked)));
+    let mut approvers = Vec::new(&env);
+    approvers.push_back(signer1.clone());
+    let result =
+        client.try_rotate_escrow_signers(&organizer, &event_id, &approvers, &takeover, &1);
+    assert_eq!(result, Err(Ok(LumentixError::EscrowThresholdNotMet)));
+
+    // With both current signers it goes through and old approvals are dropped
+    approvers.push_back(signer2.clone());
+    let mut rotated = Vec::new(&env);
+    rotated.push_back(signer1.clone());
+    rotated.push_back(Address::generate(&env));
+    client.rotate_escrow_signers(&organizer, &event_id, &approvers, &rotated, &1);
+    assert_eq!(client.get_escrow_approval_count(&event_id), 0);
+
+    let result = client.try_release_escrow(&organizer, &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::EscrowThresholdNotMet)));
+    client.approve_release(&event_id, &signer1);
+    assert_eq!(client.release_escrow(&organizer, &event_id), 100);
 }
 
 #[test]
-fn test_is_authorized_validator_unauthorized() {
-    let (env, contract_id) = setup();
+fn test_approve_release_non_signer_fails() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
 
-    let event_id = symbol_short!("EVENT13");
-    let organizer = <Address as testutils::Address>::generate(&env);
-    let random_address = <Address as testutils::Address>::generate(&env);
+    let result = client.try_approve_release(&event_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(LumentixError::EscrowConfigNotFound)));
 
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer);
+    let mut signers = Vec::new(&env);
+    signers.push_back(Address::generate(&env));
+    client.set_escrow_signers(&organizer, &event_id, &signers, &1);
 
-        let is_auth =
-            TicketContract::is_authorized_validator(env.clone(), event_id, random_address);
-        assert!(!is_auth);
-    });
+    let result = client.try_approve_release(&event_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
 }
 
-#[test]
-fn test_validate_ticket_success() {
-    let (env, contract_id) = setup();
-
-    let event_id = symbol_short!("EVENT14");
-    let ticket_id = symbol_short!("TICKET14");
-    let organizer = <Address as testutils::Address>::generate(&env);
-    let owner = <Address as testutils::Address>::generate(&env);
-
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer.clone());
-        TicketContract::issue_ticket(env.clone(), ticket_id.clone(), event_id, owner);
+// ── Gate validators ─────────────────────────────────────────────────────────
 
-        let validated = TicketContract::validate_ticket(env.clone(), ticket_id.clone(), organizer);
+#[test]
+fn test_add_validator() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let validator = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
 
-        assert!(validated.is_used);
-        assert_eq!(validated.id, ticket_id);
+    client.add_validator(&organizer, &event_id, &validator);
 
-        let retrieved = TicketContract::get_ticket(env.clone(), ticket_id);
-        assert!(retrieved.is_some());
-        assert!(retrieved.unwrap().is_used);
-    });
+    assert!(client.is_authorized_validator(&event_id, &validator));
 }
 
 #[test]
-fn test_validate_ticket_with_gate_agent() {
-    let (env, contract_id) = setup();
-
-    let event_id = symbol_short!("EVENT15");
-    let ticket_id = symbol_short!("TICKET15");
-    let organizer = <Address as testutils::Address>::generate(&env);
-    let gate_agent = <Address as testutils::Address>::generate(&env);
-    let owner = <Address as testutils::Address>::generate(&env);
-
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer.clone());
-        TicketContract::add_validator(env.clone(), event_id.clone(), gate_agent.clone());
-        TicketContract::issue_ticket(env.clone(), ticket_id.clone(), event_id, owner);
-
-        let validated = TicketContract::validate_ticket(env.clone(), ticket_id.clone(), gate_agent);
+fn test_add_validator_non_organizer_fails() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
 
-        assert!(validated.is_used);
-    });
+    let result = client.try_add_validator(&attacker, &event_id, &attacker);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
 }
 
 #[test]
-#[should_panic(expected = "Ticket not found")]
-fn test_validate_nonexistent_ticket() {
-    let (env, contract_id) = setup();
-
-    let event_id = symbol_short!("EVENT16");
-    let ticket_id = symbol_short!("NOEXIST");
-    let organizer = <Address as testutils::Address>::generate(&env);
+fn test_remove_validator() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let validator = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
 
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id, organizer.clone());
+    client.add_validator(&organizer, &event_id, &validator);
+    client.remove_validator(&organizer, &event_id, &validator);
 
-        TicketContract::validate_ticket(env.clone(), ticket_id, organizer);
-    });
+    assert!(!client.is_authorized_validator(&event_id, &validator));
 }
 
 #[test]
-#[should_panic(expected = "already been used")]
-fn test_validate_ticket_already_used() {
-    let (env, contract_id) = setup();
+fn test_is_authorized_validator_organizer() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
 
-    let event_id = symbol_short!("EVENT17");
-    let ticket_id = symbol_short!("TICKET17");
-    let organizer = <Address as testutils::Address>::generate(&env);
-    let owner = <Address as testutils::Address>::generate(&env);
+    assert!(client.is_authorized_validator(&event_id, &organizer));
+    assert!(!client.is_authorized_validator(&event_id, &Address::generate(&env)));
+}
 
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer.clone());
-        TicketContract::issue_ticket(env.clone(), ticket_id.clone(), event_id, owner);
+#[test]
+fn test_use_ticket_with_gate_agent() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let gate_agent = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(
+        &Address::generate(&env),
+        &event_id,
+        &100i128,
+        &false,
+        &None,
+    );
+
+    client.add_validator(&organizer, &event_id, &gate_agent);
+    client.use_ticket(&ticket_id, &gate_agent);
+
+    assert!(client.get_ticket_info(&ticket_id).used);
+}
 
-        // First validation - should succeed
-        TicketContract::validate_ticket(env.clone(), ticket_id.clone(), organizer.clone());
+#[test]
+fn test_use_ticket_unauthorized_validator() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(
+        &Address::generate(&env),
+        &event_id,
+        &100i128,
+        &false,
+        &None,
+    );
+
+    let result = client.try_use_ticket(&ticket_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+}
 
-        // Second validation - should panic
-        TicketContract::validate_ticket(env.clone(), ticket_id, organizer);
-    });
+#[test]
+fn test_use_ticket_removed_validator_fails() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let gate_agent = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(
+        &Address::generate(&env),
+        &event_id,
+        &100i128,
+        &false,
+        &None,
+    );
+
+    client.add_validator(&organizer, &event_id, &gate_agent);
+    client.remove_validator(&organizer, &event_id, &gate_agent);
+
+    let result = client.try_use_ticket(&ticket_id, &gate_agent);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
 }
 
 #[test]
-#[should_panic(expected = "not authorized")]
-fn test_validate_ticket_unauthorized_validator() {
-    let (env, contract_id) = setup();
-
-    let event_id = symbol_short!("EVENT18");
-    let ticket_id = symbol_short!("TICKET18");
-    let organizer = <Address as testutils::Address>::generate(&env);
-    let unauthorized = <Address as testutils::Address>::generate(&env);
-    let owner = <Address as testutils::Address>::generate(&env);
-
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer);
-        TicketContract::issue_ticket(env.clone(), ticket_id.clone(), event_id, owner);
-
-        TicketContract::validate_ticket(env.clone(), ticket_id, unauthorized);
-    });
+fn test_use_ticket_already_used_by_validator() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let gate_agent = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
+    let ticket_id = client.purchase_ticket(
+        &Address::generate(&env),
+        &event_id,
+        &100i128,
+        &false,
+        &None,
+    );
+
+    client.add_validator(&organizer, &event_id, &gate_agent);
+    client.use_ticket(&ticket_id, &gate_agent);
+
+    let result = client.try_use_ticket(&ticket_id, &organizer);
+    assert_eq!(result, Err(Ok(LumentixError::TicketAlreadyUsed)));
 }
 
 #[test]
-fn test_validate_ticket_emits_event() {
-    let (env, contract_id) = setup();
-
-    let event_id = symbol_short!("EVENT19");
-    let ticket_id = symbol_short!("TICKET19");
-    let organizer = <Address as testutils::Address>::generate(&env);
-    let owner = <Address as testutils::Address>::generate(&env);
-
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer.clone());
-        TicketContract::issue_ticket(env.clone(), ticket_id.clone(), event_id.clone(), owner);
-
-        // Validate ticket - this emits a CheckInEvent internally
-        let validated =
-            TicketContract::validate_ticket(env.clone(), ticket_id.clone(), organizer.clone());
-
-        // Verify validation succeeded (event emission is a side-effect)
-        assert!(validated.is_used);
-        assert_eq!(validated.id, ticket_id);
-    });
+fn test_validator_scoped_to_event() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let gate_agent = Address::generate(&env);
+    let event_a = create_published_event(&env, &client, &organizer);
+    let event_b = create_published_event(&env, &client, &organizer);
+    let ticket_b = client.purchase_ticket(
+        &Address::generate(&env),
+        &event_b,
+        &100i128,
+        &false,
+        &None,
+    );
+
+    client.add_validator(&organizer, &event_a, &gate_agent);
+
+    let result = client.try_use_ticket(&ticket_b, &gate_agent);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
 }
 
 #[test]
-fn test_multiple_validators_for_event() {
-    let (env, contract_id) = setup();
-
-    let event_id = symbol_short!("EVENT20");
-    let organizer = <Address as testutils::Address>::generate(&env);
-    let validator1 = <Address as testutils::Address>::generate(&env);
-    let validator2 = <Address as testutils::Address>::generate(&env);
-
-    env.as_contract(&contract_id, || {
-        TicketContract::init_event(env.clone(), event_id.clone(), organizer.clone());
-
-        TicketContract::add_validator(env.clone(), event_id.clone(), validator1.clone());
-        TicketContract::add_validator(env.clone(), event_id.clone(), validator2.clone());
-
-        assert!(TicketContract::is_authorized_validator(
-            env.clone(),
-            event_id.clone(),
-            validator1
-        ));
-        assert!(TicketContract::is_authorized_validator(
-            env.clone(),
-            event_id,
-            validator2
-        ));
-    });
+fn test_add_validator_emits_event() {
+    let env = Env::default();
+    let client = setup(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_published_event(&env, &client, &organizer);
+
+    client.add_validator(&organizer, &event_id, &Address::generate(&env));
+
+    assert!(!env.events().all().events().is_empty());
 }
@@ -1,8 +1,29 @@
-use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Symbol, Vec};
 
 pub const INSTANCE_LIFETIME: u32 = 535_680; // ~30 days
 pub const PERSISTENT_LIFETIME: u32 = 535_680; // ~30 days
 pub const TEMPORARY_LIFETIME: u32 = 17_280; // ~1 day
+pub const MAX_QUOTE_LOCK_SECS: u64 = TEMPORARY_LIFETIME as u64 * 5; // temporary TTL at 5s/ledger
+pub const STREAM_TOKEN_LIFETIME_SECS: u64 = 3_600; // 1 hour
+pub const QOS_BUCKET_SECS: u64 = 300; // 5 minutes
+pub const INSURANCE_SHARE_PRICE_SCALE: i128 = 10_000_000; // share price fixed-point scale
+pub const LEGACY_INSURANCE_PRODUCT_ID: u32 = 0; // policies sold before products; cover any reason
+pub const CURRENCY_PRICE_DECIMALS: u32 = 14; // fixed precision of accepted currency prices
+pub const DEFAULT_INSURANCE_WITHDRAWAL_COOLDOWN_SECS: u64 = 604_800; // 7 days
+pub const REPUTATION_HALF_LIFE_SECS: u64 = 15_552_000; // 180 days
+pub const MIN_UPGRADE_TIMELOCK_SECS: u64 = 86_400; // 1 day
+pub const DEFAULT_UPGRADE_TIMELOCK_SECS: u64 = 172_800; // 2 days
+pub const STORAGE_SCHEMA_VERSION: u32 = 2; // layouts of records changed since version 1
+pub const ADMIN_HANDOVER_WINDOW_SECS: u64 = 604_800; // 7 days to accept an admin proposal
+pub const ADMIN_ACTION_APPROVAL_WINDOW_SECS: u64 = 86_400; // 1 day to use a council sign-off
+pub const FEATURE_PURCHASES: u32 = 1 << 0; // tickets, seats, add-ons, sponsorships, insurance, offsets
+pub const FEATURE_TRANSFERS: u32 = 1 << 1; // ticket transfers, including cross-chain
+pub const FEATURE_REFUNDS: u32 = 1 << 2; // ticket, replay, SLA and sponsor refunds
+pub const FEATURE_ESCROW_RELEASES: u32 = 1 << 3; // escrow releases and organizer withdrawals, incl. replay
+pub const FEATURE_NFT_MINTING: u32 = 1 << 4; // commemorative and memorabilia NFTs
+pub const FEATURE_RESALE: u32 = 1 << 5; // secondary-market trades
+pub const FEATURE_MERCHANDISE: u32 = 1 << 6; // merchandise sales and redemption
+pub const FEATURE_ALL: u32 = (1 << 7) - 1; // every feature; trips the whole circuit breaker
 
 /// Event status enum mirroring backend statuses
 #[contracttype]
@@ -65,6 +86,175 @@ pub struct TicketTransferRecord {
     pub timestamp: u64,
 }
 
+// ── Storage schema versions ────────────────────────────────────────────────
+
+/// `AccessibilityBooking` layout of storage schema version 1, before
+/// bookings could be denied, cancelled or bound to a seat
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessibilityBookingV1 {
+    pub id: u64,
+    pub event_id: u64,
+    pub ticket_id: u64,
+    pub attendee: Address,
+    pub accommodation_type: String,
+    pub approved: bool,
+}
+
+/// `CurrencyConfig` layout of storage schema version 1, before price guards
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CurrencyConfigV1 {
+    pub code: String,
+    pub decimals: u32,
+    pub oracle_price: i128,
+    pub last_updated: u64,
+}
+
+/// `InsurancePolicy` layout of storage schema version 1, before insurance
+/// products and partial payouts
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InsurancePolicyV1 {
+    pub id: u64,
+    pub ticket_id: u64,
+    pub event_id: u64,
+    pub holder: Address,
+    pub premium_paid: i128,
+    pub coverage_amount: i128,
+    pub purchase_time: u64,
+    pub active: bool,
+    pub claim_processed: bool,
+}
+
+/// `InsurancePool` layout of storage schema version 1, before liquidity
+/// shares and exposure tracking
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InsurancePoolV1 {
+    pub total_balance: i128,
+    pub total_policies: u32,
+    pub total_claims_paid: i128,
+}
+
+/// `EventReview` layout of storage schema version 1, before organizer
+/// responses and moderation
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventReviewV1 {
+    pub id: u64,
+    pub event_id: u64,
+    pub reviewer: Address,
+    pub organizer: Address,
+    pub ticket_id: u64,
+    pub rating: u32,
+    pub comment: String,
+    pub attendance_verified: bool,
+    pub timestamp: u64,
+}
+
+/// `OrganizerReputation` layout of storage schema version 1, before
+/// time-weighted scoring and per-event breakdowns
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrganizerReputationV1 {
+    pub organizer: Address,
+    pub reputation_score: u32,
+    pub average_rating_x100: u32,
+    pub total_reviews: u32,
+    pub total_ratings_sum: u32,
+}
+
+/// `UpgradeProposal` layout of storage schema version 1, before rollbacks
+/// and the execution timelock
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpgradeProposalV1 {
+    pub proposal_id: u64,
+    pub proposer: Address,
+    pub new_wasm_hash: BytesN<32>,
+    pub description: String,
+    pub created_at: u64,
+    pub voting_deadline: u64,
+    pub state: UpgradeState,
+    pub yes_votes: u32,
+    pub no_votes: u32,
+    pub required_yes_votes: u32,
+    pub total_voters: u32,
+}
+
+/// `CarbonOffsetPurchase` layout of storage schema version 1, before
+/// retirement attestations
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CarbonOffsetPurchaseV1 {
+    pub purchase_id: u64,
+    pub event_id: u64,
+    pub purchaser: Address,
+    pub offset_amount_kg: i128,
+    pub cost: i128,
+    pub project_id: String,
+    pub timestamp: u64,
+    pub verified: bool,
+}
+
+/// `EnvironmentalImpact` layout of storage schema version 1, before
+/// unretired offsets were tracked separately
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnvironmentalImpactV1 {
+    pub event_id: u64,
+    pub total_footprint_kg: i128,
+    pub total_offset_kg: i128,
+    pub net_impact_kg: i128,
+    pub total_purchases: u32,
+    pub neutral_status: bool,
+}
+
+/// `IdentityCredential` layout of storage schema version 1, before signed
+/// credentials recorded their issuer
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentityCredentialV1 {
+    pub credential_id: u64,
+    pub subject: Address,
+    pub provider: IdentityProvider,
+    pub provider_id: String,
+    pub issued_at: u64,
+    pub expires_at: u64,
+    pub revoked: bool,
+    pub metadata_hash: BytesN<32>,
+    pub level: u32,
+}
+
+/// Record family a storage migration is currently rewriting, in the order
+/// they are walked
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MigrationStage {
+    AccessibilityBookings,
+    InsurancePolicies,
+    Reviews,
+    UpgradeProposals,
+    CarbonOffsetPurchases,
+    IdentityCredentials,
+    EnvironmentalImpacts,
+    Done,
+}
+
+/// Progress of a storage migration. `next_id` is the next record ID to
+/// rewrite within `stage`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationProgress {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub stage: MigrationStage,
+    pub next_id: u64,
+    pub records_migrated: u32,
+    pub complete: bool,
+}
+
 /// Organizer-defined transfer lock window for an event.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -118,6 +308,39 @@ pub struct VipTier {
     pub benefits: Vec<String>,
 }
 
+/// What a ticket holder paid for their VIP tier, kept so refunds can return it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VipPurchase {
+    pub ticket_id: u64,
+    pub tier_name: String,
+    pub amount_paid: i128,
+    pub platform_fee: i128,
+}
+
+/// On-chain record of a VIP benefit redeemed at the venue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VipBenefitRedemption {
+    pub ticket_id: u64,
+    pub benefit: String,
+    pub redeemed_by: Address,
+    pub redeemed_at: u64,
+}
+
+// ── Gate Validators & Multi-Sig Escrow ─────────────────────────────────────
+
+/// Multi-signature approval policy guarding an event's escrow release.
+/// When present, `release_escrow` requires at least `threshold` of `signers`
+/// to have approved.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowConfig {
+    pub event_id: u64,
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
 // ── Accessibility Features ─────────────────────────────────────────────────
 
 #[contracttype]
@@ -139,7 +362,33 @@ pub struct AccessibilityBooking {
     pub ticket_id: u64,
     pub attendee: Address,
     pub accommodation_type: String,
-    pub approved: bool,
+    pub status: AccessibilityBookingStatus,
+    pub organizer_notes: String,
+    pub seat_id: Option<String>,
+    pub companion_ticket_id: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccessibilityBookingStatus {
+    Pending,
+    Approved,
+    Denied,
+    /// Released by attendee cancellation, refund, revocation or transfer
+    Cancelled,
+}
+
+/// Organizer-defined accommodation (e.g. "companion_seat", "interpreter").
+/// `accessible_seats` restricts which seat IDs may be linked on approval;
+/// an empty list allows any free seat.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccommodationType {
+    pub name: String,
+    pub total: u32,
+    pub available: u32,
+    pub companion_ticket: bool,
+    pub accessible_seats: Vec<String>,
 }
 
 // ── Seat Selection / Venue Mapping ─────────────────────────────────────────
@@ -185,6 +434,11 @@ pub struct Seat {
 
 // ── Multi-Currency ─────────────────────────────────────────────────────────
 
+/// Price configuration for a currency. `oracle_price` is the last accepted
+/// price (set by the admin or refreshed from the currency's [`OracleFeed`]) and
+/// is the fallback when the external feed is unavailable. Prices are held at
+/// `CURRENCY_PRICE_DECIMALS`; feed readings are rescaled to it. A zero
+/// `max_staleness` or `max_deviation_bps` disables that guard.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CurrencyConfig {
@@ -192,6 +446,63 @@ pub struct CurrencyConfig {
     pub decimals: u32,
     pub oracle_price: i128,
     pub last_updated: u64,
+    pub max_staleness: u64,
+    pub max_deviation_bps: u32,
+}
+
+/// An external SEP-40 price feed and the asset to query it for.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleFeed {
+    pub oracle: Address,
+    pub asset: OracleAsset,
+}
+
+/// SEP-40 asset identifier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OracleAsset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// SEP-40 price record returned by `lastprice`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OraclePriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// A SAC token an event accepts as payment, priced in `currency` via its
+/// [`CurrencyConfig`] oracle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptedToken {
+    pub token: Address,
+    pub currency: String,
+}
+
+/// How a ticket bought with an accepted token was paid, so refunds are
+/// settled in the same token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketPayment {
+    pub token: Address,
+    pub amount: i128,
+    pub platform_fee: i128,
+}
+
+/// A ticket price in an accepted token, locked for one buyer until `expires_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceQuote {
+    pub quote_id: u64,
+    pub event_id: u64,
+    pub buyer: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub expires_at: u64,
 }
 
 // ── Waitlist ───────────────────────────────────────────────────────────────
@@ -261,6 +572,96 @@ pub struct StreamPerformanceMetrics {
     pub quality_score: u32,
     pub last_measured_at: u64,
 }
+
+/// Online-only ticket class for a hybrid event, sold separately from the
+/// in-person capacity.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VirtualTicketClass {
+    pub event_id: u64,
+    pub price: i128,
+    pub capacity: u32,
+    pub sold: u32,
+}
+
+/// One viewing session of a virtual attendee. `left_at` is 0 while the
+/// session is still open; `replay` marks sessions watched after `end_time`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VirtualSession {
+    pub joined_at: u64,
+    pub left_at: u64,
+    pub replay: bool,
+}
+
+/// On-demand replay offered after a hybrid event ends. Replays can be
+/// bought and watched until `end_time + window_secs`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReplayConfig {
+    pub event_id: u64,
+    pub price: i128,
+    pub window_secs: u64,
+    pub withdrawn: bool,
+}
+
+/// A single streaming measurement submitted by a registered QoS reporter.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QosReport {
+    pub reporter: Address,
+    pub avg_bitrate_kbps: u32,
+    pub rebuffer_ratio_bps: u32,
+    pub submitted_at: u64,
+}
+
+/// Median of all reports submitted for one time bucket.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QosAggregate {
+    pub bucket: u64,
+    pub report_count: u32,
+    pub median_bitrate_kbps: u32,
+    pub median_rebuffer_bps: u32,
+}
+
+/// Organizer-declared streaming quality floor. A bucket with at least
+/// `min_reports` reports whose medians fall outside these bounds makes
+/// `refund_bps` of each virtual ticket's price claimable.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamingSla {
+    pub min_bitrate_kbps: u32,
+    pub max_rebuffer_bps: u32,
+    pub refund_bps: u32,
+    pub min_reports: u32,
+}
+
+/// A viewer's paid right to watch an event's replay.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReplayEntitlement {
+    pub event_id: u64,
+    pub viewer: Address,
+    pub amount_paid: i128,
+    pub platform_fee: i128,
+    pub purchased_at: u64,
+    pub refunded: bool,
+}
+
+/// Short-lived CDN access session for a ticket. `session_key` is an ed25519
+/// key chosen by the holder; the CDN has the viewer sign a fresh challenge
+/// with it and checks the signature with `verify_stream_access_token`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamAccessToken {
+    pub session_key: BytesN<32>,
+    pub ticket_id: u64,
+    pub event_id: u64,
+    pub holder: Address,
+    pub cdn_endpoint: String,
+    pub expires_at: u64,
+}
 // ── Insurance System ───────────────────────────────────────────────────────
 
 /// Cancellation reason enum for insurance claims
@@ -276,6 +677,30 @@ pub enum CancellationReason {
     Other,
 }
 
+/// Authoritative record of why an event was cancelled. The organizer
+/// declares the reason; the admin may attest or correct it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventCancellation {
+    pub event_id: u64,
+    pub reason: CancellationReason,
+    pub declared_by: Address,
+    pub declared_at: u64,
+    pub attested: bool,
+}
+
+/// Progress of the batch insurance settlement for a cancelled event.
+/// `next_index` is the cursor into the event's policy list.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceSettlement {
+    pub event_id: u64,
+    pub next_index: u32,
+    pub policies_settled: u32,
+    pub total_paid: i128,
+    pub complete: bool,
+}
+
 /// Insurance policy for a ticket
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -289,6 +714,23 @@ pub struct InsurancePolicy {
     pub purchase_time: u64,
     pub active: bool,
     pub claim_processed: bool,
+    pub product_id: u32,
+    pub paid_out: i128,
+}
+
+/// Admin-defined insurance product. Premium and coverage are basis points
+/// of the price actually paid for the ticket; policies can only be bought
+/// until `purchase_cutoff_secs` before the event starts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceProduct {
+    pub id: u32,
+    pub name: String,
+    pub premium_bps: u32,
+    pub coverage_bps: u32,
+    pub purchase_cutoff_secs: u64,
+    pub covered_reasons: Vec<CancellationReason>,
+    pub active: bool,
 }
 
 /// Insurance pool balance managed by smart contract
@@ -298,6 +740,31 @@ pub struct InsurancePool {
     pub total_balance: i128,
     pub total_policies: u32,
     pub total_claims_paid: i128,
+    /// LP shares outstanding against `total_balance`
+    pub total_shares: i128,
+    /// Sum of coverage on policies that can still be claimed
+    pub outstanding_coverage: i128,
+}
+
+/// Admin-controlled insurance pool parameters. `min_reserve_ratio_bps` is
+/// the minimum pool capital as a share of outstanding coverage; 0 disables
+/// the check.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsurancePoolParams {
+    pub withdrawal_cooldown_secs: u64,
+    pub min_reserve_ratio_bps: u32,
+}
+
+/// An underwriter's stake in the insurance pool
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceLpPosition {
+    pub provider: Address,
+    pub shares: i128,
+    pub deposited: i128,
+    pub pending_withdrawal_shares: i128,
+    pub withdrawal_available_at: u64,
 }
 
 // ── Review & Reputation System ─────────────────────────────────────────────
@@ -316,6 +783,9 @@ pub struct EventReview {
     pub comment: String,
     pub attendance_verified: bool,
     pub timestamp: u64,
+    pub organizer_response: Option<String>,
+    /// Hidden by moderation; hidden reviews do not count towards reputation
+    pub hidden: bool,
 }
 
 /// Aggregated reputation score for an organizer
@@ -329,6 +799,48 @@ pub struct OrganizerReputation {
     pub average_rating_x100: u32,
     pub total_reviews: u32,
     pub total_ratings_sum: u32,
+    pub total_ratings_sq_sum: u32,
+    /// Time-decayed average rating × 100 used for the score
+    pub weighted_average_x100: u32,
+    /// Time-decayed standard deviation of ratings × 100
+    pub std_dev_x100: u32,
+    pub hidden_reviews: u32,
+    pub event_breakdowns: Vec<EventReputation>,
+}
+
+/// Visible review totals for one of an organizer's events. Reviews decay
+/// by the age of the event they belong to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventReputation {
+    pub event_id: u64,
+    pub event_end_time: u64,
+    pub review_count: u32,
+    pub ratings_sum: u32,
+    pub ratings_sq_sum: u32,
+    pub average_rating_x100: u32,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Platform Administration
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// An admin handover awaiting acceptance by the candidate
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    pub candidate: Address,
+    pub proposed_at: u64,
+    pub expires_at: u64,
+}
+
+/// Signers that jointly authorise admin handovers and the global pause.
+/// An empty signer list means the single admin key acts alone.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminCouncil {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -343,6 +855,32 @@ pub enum UpgradeState {
     Approved,
     Executed,
     Rejected,
+    Vetoed,
+}
+
+/// Whether a proposal installs new code or restores the previous code
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UpgradeKind {
+    Upgrade,
+    Rollback,
+    /// Platform parameter change; the action is stored alongside the proposal
+    Governance,
+}
+
+/// Platform parameter change carried by a governance proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GovernanceAction {
+    PlatformFee(u32),
+    PlatformFeeRecipient(Address),
+    Token(Address),
+    CertificationStandard(CertificationStandard, bool),
+    BridgePaused(bool),
+    /// Voting period in seconds, required approval percentage and members
+    UpgradeGovernance(u64, u32, Vec<Address>),
+    /// Upgrade timelock in seconds and the guardian, if any
+    UpgradeSafeguards(u64, Option<Address>),
 }
 
 /// An upgrade proposal to replace the contract WASM
@@ -360,6 +898,11 @@ pub struct UpgradeProposal {
     pub no_votes: u32,
     pub required_yes_votes: u32,
     pub total_voters: u32,
+    pub kind: UpgradeKind,
+    /// Earliest execution time once approved (end of the timelock)
+    pub executable_at: u64,
+    /// WASM hash that was live before this proposal executed, if known
+    pub previous_wasm_hash: Option<BytesN<32>>,
 }
 
 /// A record of a single vote on an upgrade proposal
@@ -381,6 +924,15 @@ pub struct UpgradeGovernanceConfig {
     pub governance_members: Vec<Address>,
 }
 
+/// Execution safeguards for approved upgrades: the delay between approval
+/// and execution, and the guardian allowed to veto before execution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeSafeguards {
+    pub timelock_seconds: u64,
+    pub guardian: Option<Address>,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Carbon Offset Program
 // ═══════════════════════════════════════════════════════════════════════════
@@ -397,6 +949,77 @@ pub struct CarbonFootprint {
     pub calculated_at: u64,
 }
 
+/// Venue category, selecting the per-sqm emission factor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VenueType {
+    Indoor,
+    Outdoor,
+    Mixed,
+}
+
+/// Energy supply for the venue, scaling venue emissions
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EnergyMix {
+    Grid,
+    Mixed,
+    Renewable,
+}
+
+/// Predominant way attendees travel to the event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TransportMode {
+    Car,
+    PublicTransit,
+    Air,
+    Active,
+}
+
+/// Admin-configured emission factors. Every variant of each category must
+/// have an entry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmissionFactors {
+    pub venue_kg_per_sqm: Map<VenueType, i128>,
+    /// Multiplier applied to venue emissions, in basis points
+    pub energy_mix_bps: Map<EnergyMix, u32>,
+    pub transport_g_per_km: Map<TransportMode, i128>,
+    pub attendee_kg: i128,
+}
+
+/// Inputs an event's footprint is calculated from. Sizes come from the
+/// latest estimate; categories are set by the organizer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FootprintProfile {
+    pub venue_size_sqm: u64,
+    pub avg_travel_distance_km: u64,
+    pub expected_attendance: u64,
+    pub venue_type: VenueType,
+    pub energy_mix: EnergyMix,
+    pub transport_mode: TransportMode,
+}
+
+/// Estimated against measured footprint, with offsets, for an event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SustainabilityReport {
+    pub event_id: u64,
+    pub estimated_footprint_kg: i128,
+    /// Recalculated from check-ins after the event; None until recorded
+    pub actual_footprint_kg: Option<i128>,
+    pub expected_attendance: u64,
+    pub checked_in_attendance: u32,
+    /// Actual minus estimated footprint; zero until the actual is recorded
+    pub footprint_delta_kg: i128,
+    pub verified_offset_kg: i128,
+    pub pending_offset_kg: i128,
+    pub net_impact_kg: i128,
+    pub neutral_status: bool,
+}
+
 /// A carbon offset purchase record
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -408,7 +1031,54 @@ pub struct CarbonOffsetPurchase {
     pub cost: i128,
     pub project_id: String,
     pub timestamp: u64,
+    /// Set once the project's attestor confirms the credits were retired
     pub verified: bool,
+    pub retirement_serial: Option<BytesN<32>>,
+}
+
+/// A carbon project registered by the admin. Offsets are priced per kg,
+/// paid to the beneficiary and verified by the attestor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CarbonProject {
+    pub project_id: String,
+    pub name: String,
+    pub price_per_kg: i128,
+    pub beneficiary: Address,
+    pub attestor: Address,
+    pub active: bool,
+    pub total_sold_kg: i128,
+    pub total_retired_kg: i128,
+}
+
+/// Organizer-enabled carbon offset surcharge offered at ticket checkout.
+/// Per-ticket terms are the event footprint split across capacity, priced
+/// at the project's rate when enabled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CheckoutCarbonOffset {
+    pub event_id: u64,
+    pub project_id: String,
+    pub offset_kg_per_ticket: i128,
+    pub surcharge_per_ticket: i128,
+    pub enabled: bool,
+    pub opted_in_tickets: u32,
+    pub total_offset_kg: i128,
+    pub settled: bool,
+    pub offset_purchase_id: Option<u64>,
+}
+
+/// An attendee's offset contribution made at checkout
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttendeeCarbonOffset {
+    pub ticket_id: u64,
+    pub event_id: u64,
+    pub attendee: Address,
+    pub offset_kg: i128,
+    pub surcharge: i128,
+    pub contributed_at: u64,
+    pub refunded: bool,
 }
 
 /// Aggregated environmental impact tracking for an event
@@ -417,7 +1087,10 @@ pub struct CarbonOffsetPurchase {
 pub struct EnvironmentalImpact {
     pub event_id: u64,
     pub total_footprint_kg: i128,
+    /// Offsets with an attested retirement; only these count towards neutrality
     pub total_offset_kg: i128,
+    /// Offsets paid for but not yet attested
+    pub pending_offset_kg: i128,
     pub net_impact_kg: i128,
     pub total_purchases: u32,
     pub neutral_status: bool,
@@ -451,6 +1124,37 @@ pub struct IdentityCredential {
     pub revoked: bool,
     pub metadata_hash: BytesN<32>,
     pub level: u32,
+    /// Public key of the trusted issuer that signed it; None when admin-issued
+    pub issuer: Option<BytesN<32>>,
+}
+
+/// An external credential issuer (e.g. a KYC provider) trusted to sign
+/// credentials for one identity provider
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrustedIssuer {
+    pub public_key: BytesN<32>,
+    pub provider: IdentityProvider,
+    pub name: String,
+    pub max_level: u32,
+    pub active: bool,
+    pub registered_at: u64,
+}
+
+/// Credential claims signed by a trusted issuer. The issuer signs the XDR
+/// encoding of this struct with its ed25519 key; `verifier` is the contract
+/// the credential is meant for, so signatures cannot be replayed elsewhere.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedCredentialPayload {
+    pub verifier: Address,
+    pub subject: Address,
+    pub provider: IdentityProvider,
+    pub provider_id: String,
+    pub level: u32,
+    pub issued_at: u64,
+    pub expires_at: u64,
+    pub metadata_hash: BytesN<32>,
 }
 
 /// Verification proof for an identity credential
@@ -665,6 +1369,16 @@ pub struct TicketDidAssociation {
     pub revoked: bool,
 }
 
+/// Identity required to hold or attend a gated event: an unrevoked,
+/// unexpired credential from one of `providers` at `min_level` or above
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventCredentialRequirement {
+    pub event_id: u64,
+    pub providers: Vec<IdentityProvider>,
+    pub min_level: u32,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Resale Price Ceiling for Secondary Marketplace
 // ═══════════════════════════════════════════════════════════════════════════
@@ -913,3 +1627,39 @@ pub struct PromoCode {
     pub active: bool,
     pub created_by: Address,
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Sponsorships
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// A sponsorship tier offered by an event organizer. Each sponsor pays
+/// `price` into the event escrow and receives `comp_tickets` complimentary
+/// tickets plus, when `booth_spaces` is non-empty, the next unclaimed booth.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsorTier {
+    pub event_id: u64,
+    pub tier_id: u32,
+    pub name: String,
+    pub price: i128,
+    pub max_sponsors: u32,
+    pub sponsor_count: u32,
+    pub comp_tickets: u32,
+    pub booth_venue_id: String,
+    pub booth_spaces: Vec<String>,
+    pub booth_capacity: u32,
+}
+
+/// A sponsor's payment into a tier, along with the benefits issued for it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsorContribution {
+    pub event_id: u64,
+    pub tier_id: u32,
+    pub sponsor: Address,
+    pub amount: i128,
+    pub contributed_at: u64,
+    pub refunded: bool,
+    pub comp_ticket_ids: Vec<u64>,
+    pub booth_space_id: Option<String>,
+}
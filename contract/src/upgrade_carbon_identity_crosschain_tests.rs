@@ -1,11 +1,23 @@
 #![allow(warnings)]
 #![cfg(test)]
 
+extern crate std;
+
+use crate::error::LumentixError;
 use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
-use crate::types::{CrossChainTransferStatus, IdentityProof, IdentityProvider, UpgradeState};
+use crate::storage;
+use crate::types::{
+    AccessibilityBookingStatus, AccessibilityBookingV1, CancellationReason,
+    CarbonOffsetPurchaseV1, CrossChainTransferStatus, CurrencyConfigV1, EnvironmentalImpactV1,
+    EventReviewV1, EventStatus, GovernanceAction, EnergyMix, IdentityCredentialV1, IdentityProof,
+    IdentityProvider, InsurancePolicyV1, InsurancePoolV1, MigrationStage, OrganizerReputationV1,
+    SignedCredentialPayload, TransportMode, UpgradeKind, UpgradeProposalV1, UpgradeState,
+    VenueType, FEATURE_TRANSFERS, LEGACY_INSURANCE_PRODUCT_ID,
+};
+use ed25519_dalek::{Signer, SigningKey};
 use soroban_sdk::{
     testutils::{Address as _, Events, Ledger},
-    xdr, Address, BytesN, Env, String, Vec,
+    token, xdr::{self, ToXdr}, Address, BytesN, Env, String, Vec,
 };
 
 fn setup_contract(env: &Env) -> (Address, LumentixContractClient) {
@@ -282,6 +294,587 @@ fn test_vote_and_get_vote_events() {
     assert!(found);
 }
 
+fn approved_upgrade(
+    env: &Env,
+    client: &LumentixContractClient,
+    admin: &Address,
+) -> (Address, Address, u64) {
+    let member1 = Address::generate(env);
+    let member2 = Address::generate(env);
+    let members = Vec::from_array(env, [member1.clone(), member2.clone()]);
+    client.configure_upgrade_governance(admin, &3600u64, &50u32, &members);
+
+    let wasm_hash = BytesN::from_array(env, &[11u8; 32]);
+    let proposal_id =
+        client.propose_upgrade(&member1, &wasm_hash, &String::from_str(env, "Upgrade"));
+    client.vote_on_upgrade(&member1, &proposal_id, &true);
+    client.vote_on_upgrade(&member2, &proposal_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    assert_eq!(
+        client.finalize_upgrade_proposal(&proposal_id),
+        UpgradeState::Approved
+    );
+    (member1, member2, proposal_id)
+}
+
+#[test]
+fn test_upgrade_rejected_when_no_votes_win() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = Vec::from_array(&env, [member1.clone(), member2.clone()]);
+    client.configure_upgrade_governance(&admin, &3600u64, &50u32, &members);
+
+    let wasm_hash = BytesN::from_array(&env, &[12u8; 32]);
+    let proposal_id =
+        client.propose_upgrade(&member1, &wasm_hash, &String::from_str(&env, "Upgrade"));
+    client.vote_on_upgrade(&member1, &proposal_id, &true);
+    client.vote_on_upgrade(&member2, &proposal_id, &false);
+
+    let result = client.try_finalize_upgrade_proposal(&proposal_id);
+    assert_eq!(result, Err(Ok(LumentixError::UpgradeVotingOpen)));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    assert_eq!(
+        client.finalize_upgrade_proposal(&proposal_id),
+        UpgradeState::Rejected
+    );
+    let result = client.try_execute_upgrade(&member1, &proposal_id);
+    assert_eq!(result, Err(Ok(LumentixError::UpgradeNotApproved)));
+}
+
+#[test]
+fn test_approved_upgrade_waits_for_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (member1, _, proposal_id) = approved_upgrade(&env, &client, &admin);
+
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(
+        proposal.executable_at,
+        env.ledger().timestamp() + client.get_upgrade_safeguards().timelock_seconds
+    );
+
+    let result = client.try_execute_upgrade(&member1, &proposal_id);
+    assert_eq!(result, Err(Ok(LumentixError::UpgradeTimelockActive)));
+}
+
+#[test]
+fn test_guardian_vetoes_approved_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+
+    let result = client.try_configure_upgrade_safeguards(&admin, &60u64, &None);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidUpgradeTimelock)));
+
+    let guardian = Address::generate(&env);
+    client.configure_upgrade_safeguards(&admin, &86_400u64, &Some(guardian.clone()));
+    let (member1, _, proposal_id) = approved_upgrade(&env, &client, &admin);
+
+    let reason = String::from_str(&env, "Unaudited code");
+    let result = client.try_veto_upgrade(&member1, &proposal_id, &reason);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    client.veto_upgrade(&guardian, &proposal_id, &reason);
+    assert_eq!(
+        client.get_upgrade_proposal(&proposal_id).state,
+        UpgradeState::Vetoed
+    );
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 86_400);
+    let result = client.try_execute_upgrade(&member1, &proposal_id);
+    assert_eq!(result, Err(Ok(LumentixError::UpgradeNotApproved)));
+}
+
+#[test]
+fn test_rollback_targets_previous_wasm_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+
+    let member = Address::generate(&env);
+    let members = Vec::from_array(&env, [member.clone()]);
+    client.configure_upgrade_governance(&admin, &3600u64, &50u32, &members);
+
+    let description = String::from_str(&env, "Roll back");
+    let result = client.try_propose_rollback(&member, &description);
+    assert_eq!(result, Err(Ok(LumentixError::NoPreviousWasmHash)));
+
+    let deployed = BytesN::from_array(&env, &[3u8; 32]);
+    client.record_deployed_wasm_hash(&admin, &deployed);
+    assert_eq!(client.get_current_wasm_hash(), Some(deployed.clone()));
+    let result = client.try_record_deployed_wasm_hash(&admin, &deployed);
+    assert_eq!(result, Err(Ok(LumentixError::WasmHashAlreadyRecorded)));
+
+    // Simulate the state an executed upgrade leaves behind
+    env.as_contract(&client.address, || {
+        storage::set_previous_wasm_hash(&env, &deployed);
+    });
+
+    let proposal_id = client.propose_rollback(&member, &description);
+    let proposal = client.get_upgrade_proposal(&proposal_id);
+    assert_eq!(proposal.kind, UpgradeKind::Rollback);
+    assert_eq!(proposal.new_wasm_hash, deployed);
+
+    let result = client.try_execute_upgrade(&member, &proposal_id);
+    assert_eq!(result, Err(Ok(LumentixError::UpgradeKindMismatch)));
+}
+
+#[test]
+fn test_migrate_rewrites_baseline_records_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+
+    let organizer = Address::generate(&env);
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Legacy Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Venue"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &10u32,
+    );
+    let attendee = Address::generate(&env);
+    let eur = String::from_str(&env, "EUR");
+
+    // Write one record of every changed type in its baseline layout
+    env.as_contract(&client.address, || {
+        let persistent = env.storage().persistent();
+        persistent.set(
+            &("ACCBOOK_", 1u64),
+            &AccessibilityBookingV1 {
+                id: 1,
+                event_id,
+                ticket_id: 7,
+                attendee: attendee.clone(),
+                accommodation_type: String::from_str(&env, "wheelchair"),
+                approved: true,
+            },
+        );
+        storage::increment_accessibility_booking_id(&env);
+
+        env.storage().instance().set(
+            &("CURCFG_", eur.clone()),
+            &CurrencyConfigV1 {
+                code: eur.clone(),
+                decimals: 7,
+                oracle_price: 108_000_000_000_000,
+                last_updated: 0,
+            },
+        );
+
+        persistent.set(
+            &("INSPOL_", 1u64),
+            &InsurancePolicyV1 {
+                id: 1,
+                ticket_id: 7,
+                event_id,
+                holder: attendee.clone(),
+                premium_paid: 5,
+                coverage_amount: 100,
+                purchase_time: 0,
+                active: true,
+                claim_processed: false,
+            },
+        );
+        storage::increment_insurance_policy_id(&env);
+        env.storage().instance().set(
+            &"INSPOOL",
+            &InsurancePoolV1 {
+                total_balance: 500,
+                total_policies: 1,
+                total_claims_paid: 0,
+            },
+        );
+
+        persistent.set(
+            &("REVIEW_", 1u64),
+            &EventReviewV1 {
+                id: 1,
+                event_id,
+                reviewer: attendee.clone(),
+                organizer: organizer.clone(),
+                ticket_id: 7,
+                rating: 4,
+                comment: String::from_str(&env, "Great"),
+                attendance_verified: true,
+                timestamp: 0,
+            },
+        );
+        storage::increment_review_id(&env);
+        persistent.set(
+            &("ORGREP_", organizer.clone()),
+            &OrganizerReputationV1 {
+                organizer: organizer.clone(),
+                reputation_score: 6840,
+                average_rating_x100: 400,
+                total_reviews: 1,
+                total_ratings_sum: 4,
+            },
+        );
+
+        persistent.set(
+            &("UPROP_", 1u64),
+            &UpgradeProposalV1 {
+                proposal_id: 1,
+                proposer: admin.clone(),
+                new_wasm_hash: BytesN::from_array(&env, &[1u8; 32]),
+                description: String::from_str(&env, "v2"),
+                created_at: 0,
+                voting_deadline: 3600,
+                state: UpgradeState::Executed,
+                yes_votes: 1,
+                no_votes: 0,
+                required_yes_votes: 1,
+                total_voters: 1,
+            },
+        );
+        storage::increment_upgrade_proposal_id(&env);
+
+        persistent.set(
+            &("COFFSET_", 1u64),
+            &CarbonOffsetPurchaseV1 {
+                purchase_id: 1,
+                event_id,
+                purchaser: organizer.clone(),
+                offset_amount_kg: 250,
+                cost: 50,
+                project_id: String::from_str(&env, "forest"),
+                timestamp: 0,
+                verified: true,
+            },
+        );
+        storage::increment_carbon_offset_purchase_id(&env);
+        persistent.set(
+            &("ENVIMP_", event_id),
+            &EnvironmentalImpactV1 {
+                event_id,
+                total_footprint_kg: 1000,
+                total_offset_kg: 250,
+                net_impact_kg: 750,
+                total_purchases: 1,
+                neutral_status: false,
+            },
+        );
+
+        persistent.set(
+            &("IDCRED_", 1u64),
+            &IdentityCredentialV1 {
+                credential_id: 1,
+                subject: attendee.clone(),
+                provider: IdentityProvider::Stellar,
+                provider_id: String::from_str(&env, "GABC"),
+                issued_at: 0,
+                expires_at: 10_000,
+                revoked: false,
+                metadata_hash: BytesN::from_array(&env, &[2u8; 32]),
+                level: 2,
+            },
+        );
+        storage::increment_identity_credential_id(&env);
+
+        storage::set_storage_version(&env, 1);
+    });
+
+    // Baseline records decode before migration
+    let booking = client.get_accessibility_booking(&1);
+    assert_eq!(booking.status, AccessibilityBookingStatus::Approved);
+    assert_eq!(booking.seat_id, None);
+    let policy = client.get_insurance_policy_by_ticket(&7);
+    assert_eq!(policy.product_id, LEGACY_INSURANCE_PRODUCT_ID);
+    assert_eq!(policy.paid_out, 0);
+    assert_eq!(client.get_insurance_pool().total_balance, 500);
+    assert!(!client.get_review(&1).hidden);
+    let reputation = client.get_organizer_reputation(&organizer);
+    assert_eq!(reputation.total_reviews, 1);
+    assert_eq!(reputation.event_breakdowns.len(), 0);
+    assert_eq!(client.get_upgrade_proposal(&1).kind, UpgradeKind::Upgrade);
+    assert_eq!(client.get_carbon_offset_purchase(&1).retirement_serial, None);
+    assert_eq!(client.track_environmental_impact(&event_id).pending_offset_kg, 0);
+    assert_eq!(client.get_identity_credential(&1).issuer, None);
+
+    let result = client.try_migrate(&admin, &2u32, &10u32);
+    assert_eq!(result, Err(Ok(LumentixError::StorageVersionMismatch)));
+
+    let progress = client.migrate(&admin, &1u32, &3u32);
+    assert_eq!(progress.records_migrated, 3);
+    assert_eq!(progress.stage, MigrationStage::UpgradeProposals);
+    assert!(!progress.complete);
+    assert_eq!(client.get_storage_version(), 1);
+
+    let progress = client.migrate(&admin, &1u32, &10u32);
+    assert_eq!(progress.records_migrated, 7);
+    assert!(progress.complete);
+    assert_eq!(client.get_storage_version(), 2);
+
+    // Records now decode directly in the current layout
+    assert_eq!(client.get_accessibility_booking(&1).attendee, attendee);
+    assert_eq!(client.get_insurance_policy_by_ticket(&7).coverage_amount, 100);
+    assert_eq!(client.get_insurance_pool().total_shares, 0);
+    assert_eq!(client.get_review(&1).rating, 4);
+    assert_eq!(client.get_upgrade_proposal(&1).state, UpgradeState::Executed);
+    assert_eq!(client.get_carbon_offset_purchase(&1).offset_amount_kg, 250);
+    assert_eq!(client.track_environmental_impact(&event_id).net_impact_kg, 750);
+    assert_eq!(client.get_identity_credential(&1).level, 2);
+
+    // The legacy review is folded into the reputation breakdowns
+    let reputation = client.get_organizer_reputation(&organizer);
+    assert_eq!(reputation.total_reviews, 1);
+    assert_eq!(reputation.total_ratings_sq_sum, 16);
+    assert_eq!(reputation.weighted_average_x100, 400);
+    assert_eq!(reputation.event_breakdowns.get(0).unwrap().review_count, 1);
+
+    // Currency configs are not walked and keep upgrading on read
+    let config = client.get_currency_config(&eur);
+    assert_eq!(config.decimals, 7);
+    assert_eq!(config.max_staleness, 0);
+
+    let result = client.try_migrate(&admin, &2u32, &10u32);
+    assert_eq!(result, Err(Ok(LumentixError::StorageAlreadyMigrated)));
+}
+
+#[test]
+fn test_legacy_reviews_are_moderated_and_scored_before_migration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+
+    let organizer = Address::generate(&env);
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(&env, "Legacy Event"),
+        &String::from_str(&env, "Description"),
+        &String::from_str(&env, "Venue"),
+        &1000u64,
+        &2000u64,
+        &100i128,
+        &10u32,
+    );
+
+    // Two baseline reviews, counted only in the baseline reputation totals
+    env.as_contract(&client.address, || {
+        for (id, rating) in [(1u64, 4u32), (2u64, 2u32)] {
+            env.storage().persistent().set(
+                &("REVIEW_", id),
+                &EventReviewV1 {
+                    id,
+                    event_id,
+                    reviewer: Address::generate(&env),
+                    organizer: organizer.clone(),
+                    ticket_id: id,
+                    rating,
+                    comment: String::from_str(&env, "Review"),
+                    attendance_verified: true,
+                    timestamp: 0,
+                },
+            );
+            storage::increment_review_id(&env);
+        }
+        env.storage().persistent().set(
+            &("ORGREP_", organizer.clone()),
+            &OrganizerReputationV1 {
+                organizer: organizer.clone(),
+                reputation_score: 4400,
+                average_rating_x100: 300,
+                total_reviews: 2,
+                total_ratings_sum: 6,
+            },
+        );
+        storage::set_storage_version(&env, 1);
+    });
+
+    // Scoring falls back to the baseline average without breakdowns
+    let reputation = client.calculate_reputation_score(&organizer);
+    assert_eq!(reputation.weighted_average_x100, 300);
+    assert_eq!(reputation.std_dev_x100, 0);
+
+    // Rewriting a legacy review folds it into the breakdowns
+    client.respond_to_review(&organizer, &1, &String::from_str(&env, "Thanks"));
+    let reputation = client.get_organizer_reputation(&organizer);
+    assert_eq!(reputation.total_reviews, 2);
+    assert_eq!(reputation.total_ratings_sq_sum, 16);
+    assert_eq!(reputation.event_breakdowns.get(0).unwrap().review_count, 1);
+
+    // Hiding a legacy review folds it in before removing it
+    client.hide_review(&admin, &2, &String::from_str(&env, "Abusive"));
+    let reputation = client.get_organizer_reputation(&organizer);
+    assert_eq!(reputation.total_reviews, 1);
+    assert_eq!(reputation.total_ratings_sum, 4);
+    assert_eq!(reputation.total_ratings_sq_sum, 16);
+    assert_eq!(reputation.hidden_reviews, 1);
+    assert_eq!(reputation.weighted_average_x100, 400);
+    assert_eq!(reputation.event_breakdowns.get(0).unwrap().review_count, 1);
+
+    // Migration does not fold the rewritten reviews a second time
+    assert!(client.migrate(&admin, &1u32, &10u32).complete);
+    let reputation = client.get_organizer_reputation(&organizer);
+    assert_eq!(reputation.total_reviews, 1);
+    assert_eq!(reputation.total_ratings_sq_sum, 16);
+    assert_eq!(reputation.event_breakdowns.get(0).unwrap().review_count, 1);
+}
+
+#[test]
+fn test_governance_action_changes_platform_fee_after_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = Vec::from_array(&env, [member1.clone(), member2.clone()]);
+    client.configure_upgrade_governance(&admin, &3600u64, &50u32, &members);
+
+    let action = GovernanceAction::PlatformFee(250);
+    let proposal_id = client.propose_governance_action(
+        &member1,
+        &action,
+        &String::from_str(&env, "Raise platform fee"),
+    );
+    assert_eq!(client.get_governance_action(&proposal_id), action);
+    assert_eq!(
+        client.get_upgrade_proposal(&proposal_id).kind,
+        UpgradeKind::Governance
+    );
+
+    client.vote_on_upgrade(&member1, &proposal_id, &true);
+    client.vote_on_upgrade(&member2, &proposal_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    client.finalize_upgrade_proposal(&proposal_id);
+
+    let result = client.try_execute_governance_action(&member1, &proposal_id);
+    assert_eq!(result, Err(Ok(LumentixError::UpgradeTimelockActive)));
+    let result = client.try_execute_upgrade(&member1, &proposal_id);
+    assert_eq!(result, Err(Ok(LumentixError::UpgradeKindMismatch)));
+
+    let timelock = client.get_upgrade_safeguards().timelock_seconds;
+    env.ledger().set_timestamp(env.ledger().timestamp() + timelock);
+    client.execute_governance_action(&member2, &proposal_id);
+    assert_eq!(client.get_platform_fee(), 250);
+    assert_eq!(
+        client.get_upgrade_proposal(&proposal_id).state,
+        UpgradeState::Executed
+    );
+}
+
+#[test]
+fn test_governance_fee_recipient_change_waits_for_acceptance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = Vec::from_array(&env, [member1.clone(), member2.clone()]);
+    client.configure_upgrade_governance(&admin, &3600u64, &50u32, &members);
+
+    let recipient = Address::generate(&env);
+    let proposal_id = client.propose_governance_action(
+        &member1,
+        &GovernanceAction::PlatformFeeRecipient(recipient.clone()),
+        &String::from_str(&env, "Rotate treasury"),
+    );
+    client.vote_on_upgrade(&member1, &proposal_id, &true);
+    client.vote_on_upgrade(&member2, &proposal_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    client.finalize_upgrade_proposal(&proposal_id);
+    let timelock = client.get_upgrade_safeguards().timelock_seconds;
+    env.ledger().set_timestamp(env.ledger().timestamp() + timelock);
+    client.execute_governance_action(&member1, &proposal_id);
+
+    // The recipient becomes admin only once it proves control of its key
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin().unwrap().candidate, recipient);
+    client.accept_admin(&recipient);
+    assert_eq!(client.get_admin(), recipient);
+}
+
+#[test]
+fn test_governance_control_limits_admin_to_emergencies() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+
+    let result = client.try_enable_governance_control(&admin);
+    assert_eq!(result, Err(Ok(LumentixError::GovernanceNotConfigured)));
+
+    let members = Vec::from_array(&env, [Address::generate(&env)]);
+    client.configure_upgrade_governance(&admin, &3600u64, &50u32, &members);
+    client.enable_governance_control(&admin);
+    assert!(client.is_governance_controlled());
+
+    let result = client.try_set_platform_fee(&admin, &100u32);
+    assert_eq!(result, Err(Ok(LumentixError::GovernanceControlled)));
+    let result = client.try_set_token(&admin, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(LumentixError::GovernanceControlled)));
+
+    // Nor can it pack the governance membership or drop the guardian
+    let takeover = Vec::from_array(&env, [admin.clone()]);
+    let result = client.try_configure_upgrade_governance(&admin, &3600u64, &50u32, &takeover);
+    assert_eq!(result, Err(Ok(LumentixError::GovernanceControlled)));
+    let timelock = client.get_upgrade_safeguards().timelock_seconds;
+    let result = client.try_configure_upgrade_safeguards(&admin, &timelock, &None);
+    assert_eq!(result, Err(Ok(LumentixError::GovernanceControlled)));
+
+    // Pausing the bridge stays available as an emergency action
+    client.set_bridge_paused(&admin, &true);
+    assert!(client.is_bridge_paused());
+    let result = client.try_set_bridge_paused(&admin, &false);
+    assert_eq!(result, Err(Ok(LumentixError::GovernanceControlled)));
+}
+
+#[test]
+fn test_governance_changes_members_and_safeguards_after_control() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let members = Vec::from_array(&env, [member1.clone(), member2.clone()]);
+    client.configure_upgrade_governance(&admin, &3600u64, &50u32, &members);
+    client.enable_governance_control(&admin);
+
+    let pass = |action: &GovernanceAction| {
+        let proposal_id = client.propose_governance_action(
+            &member1,
+            action,
+            &String::from_str(&env, "Governance housekeeping"),
+        );
+        client.vote_on_upgrade(&member1, &proposal_id, &true);
+        client.vote_on_upgrade(&member2, &proposal_id, &true);
+        env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+        client.finalize_upgrade_proposal(&proposal_id);
+        let timelock = client.get_upgrade_safeguards().timelock_seconds;
+        env.ledger().set_timestamp(env.ledger().timestamp() + timelock);
+        client.try_execute_governance_action(&member1, &proposal_id)
+    };
+
+    let guardian = Address::generate(&env);
+    let result = pass(&GovernanceAction::UpgradeSafeguards(60, Some(guardian.clone())));
+    assert_eq!(result, Err(Ok(LumentixError::InvalidUpgradeTimelock)));
+    pass(&GovernanceAction::UpgradeSafeguards(259_200, Some(guardian.clone()))).unwrap();
+    let safeguards = client.get_upgrade_safeguards();
+    assert_eq!(safeguards.timelock_seconds, 259_200);
+    assert_eq!(safeguards.guardian, Some(guardian));
+
+    let member3 = Address::generate(&env);
+    let rotated = Vec::from_array(&env, [member1.clone(), member2.clone(), member3.clone()]);
+    pass(&GovernanceAction::UpgradeGovernance(7200, 60, rotated.clone())).unwrap();
+    let config = client.get_upgrade_governance_config();
+    assert_eq!(config.voting_period_seconds, 7200);
+    assert_eq!(config.required_approval_percentage, 60);
+    assert_eq!(config.governance_members, rotated);
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CARBON OFFSET TESTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -315,8 +908,25 @@ fn test_calculate_carbon_footprint() {
 
     let (admin, client) = setup_contract(&env);
     let event_id = create_event(&env, &client);
+    let organizer = client.get_event(&event_id).organizer;
+
+    let result = client.try_calculate_carbon_footprint(
+        &Address::generate(&env),
+        &event_id,
+        &5000u64,
+        &1000u64,
+        &20u64,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    assert_eq!(client.get_carbon_footprint(&event_id), None);
 
-    let footprint = client.calculate_carbon_footprint(&event_id, &5000u64, &1000u64, &20u64);
+    let footprint = client.calculate_carbon_footprint(
+        &organizer,
+        &event_id,
+        &5000u64,
+        &1000u64,
+        &20u64,
+    );
 
     assert!(footprint.venue_footprint_kg > 0);
     assert!(footprint.attendance_footprint_kg > 0);
@@ -336,11 +946,24 @@ fn test_calculate_carbon_footprint_zero_params_fails() {
 
     let (admin, client) = setup_contract(&env);
     let event_id = create_event(&env, &client);
+    let organizer = client.get_event(&event_id).organizer;
 
-    let result = client.try_calculate_carbon_footprint(&event_id, &0u64, &1000u64, &20u64);
+    let result = client.try_calculate_carbon_footprint(
+        &organizer,
+        &event_id,
+        &0u64,
+        &1000u64,
+        &20u64,
+    );
     assert!(result.is_err());
 
-    let result = client.try_calculate_carbon_footprint(&event_id, &5000u64, &0u64, &20u64);
+    let result = client.try_calculate_carbon_footprint(
+        &organizer,
+        &event_id,
+        &5000u64,
+        &0u64,
+        &20u64,
+    );
     assert!(result.is_err());
 }
 
@@ -351,10 +974,34 @@ fn test_calculate_carbon_footprint_nonexistent_event_fails() {
 
     let (admin, client) = setup_contract(&env);
 
-    let result = client.try_calculate_carbon_footprint(&999u64, &5000u64, &1000u64, &20u64);
+    let result = client.try_calculate_carbon_footprint(
+        &Address::generate(&env),
+        &999u64,
+        &5000u64,
+        &1000u64,
+        &20u64,
+    );
     assert!(result.is_err());
 }
 
+fn register_carbon_project(
+    env: &Env,
+    client: &LumentixContractClient,
+    admin: &Address,
+    project_id: &String,
+) -> Address {
+    let attestor = Address::generate(env);
+    client.register_carbon_project(
+        admin,
+        project_id,
+        &String::from_str(env, "Reforestation"),
+        &1i128,
+        &Address::generate(env),
+        &attestor,
+    );
+    attestor
+}
+
 #[test]
 fn test_purchase_carbon_offset() {
     let env = Env::default();
@@ -362,23 +1009,27 @@ fn test_purchase_carbon_offset() {
 
     let (admin, client) = setup_contract(&env);
     let event_id = create_event(&env, &client);
+    let organizer = client.get_event(&event_id).organizer;
 
     let purchaser = Address::generate(&env);
     let project_id = String::from_str(&env, "PROJ-001");
+    register_carbon_project(&env, &client, &admin, &project_id);
 
-    client.calculate_carbon_footprint(&event_id, &5000u64, &1000u64, &20u64);
+    client.calculate_carbon_footprint(&organizer, &event_id, &5000u64, &1000u64, &20u64);
 
     let purchase_id =
-        client.purchase_carbon_offset(&purchaser, &event_id, &100000i128, &50000i128, &project_id);
+        client.purchase_carbon_offset(&purchaser, &event_id, &100000i128, &100000i128, &project_id);
     assert_eq!(purchase_id, 1);
 
     let purchase = client.get_carbon_offset_purchase(&purchase_id);
     assert_eq!(purchase.purchaser, purchaser);
     assert_eq!(purchase.offset_amount_kg, 100000);
-    assert!(purchase.verified);
+    assert_eq!(purchase.cost, 100000);
+    assert!(!purchase.verified);
 
     let impact = client.track_environmental_impact(&event_id);
-    assert_eq!(impact.total_offset_kg, 100000);
+    assert_eq!(impact.total_offset_kg, 0);
+    assert_eq!(impact.pending_offset_kg, 100000);
     assert_eq!(impact.total_purchases, 1);
 }
 
@@ -392,6 +1043,7 @@ fn test_purchase_carbon_offset_zero_amount_fails() {
 
     let purchaser = Address::generate(&env);
     let project_id = String::from_str(&env, "PROJ-001");
+    register_carbon_project(&env, &client, &admin, &project_id);
 
     let result =
         client.try_purchase_carbon_offset(&purchaser, &event_id, &0i128, &50000i128, &project_id);
@@ -426,6 +1078,7 @@ fn test_track_environmental_impact() {
 
     let (admin, client) = setup_contract(&env);
     let event_id = create_event(&env, &client);
+    let organizer = client.get_event(&event_id).organizer;
 
     let impact = client.track_environmental_impact(&event_id);
     assert_eq!(impact.total_footprint_kg, 0);
@@ -433,14 +1086,23 @@ fn test_track_environmental_impact() {
     assert_eq!(impact.total_purchases, 0);
     assert!(!impact.neutral_status);
 
-    client.calculate_carbon_footprint(&event_id, &5000u64, &1000u64, &20u64);
+    client.calculate_carbon_footprint(&organizer, &event_id, &5000u64, &1000u64, &20u64);
 
     let impact_after = client.track_environmental_impact(&event_id);
     assert_eq!(impact_after.total_footprint_kg, 135100);
 
     let purchaser = Address::generate(&env);
     let project_id = String::from_str(&env, "PROJ-002");
-    client.purchase_carbon_offset(&purchaser, &event_id, &200000i128, &100000i128, &project_id);
+    let attestor = register_carbon_project(&env, &client, &admin, &project_id);
+    let purchase_id =
+        client.purchase_carbon_offset(&purchaser, &event_id, &200000i128, &200000i128, &project_id);
+
+    // Paid but unattested offsets do not make the event neutral
+    let impact_pending = client.track_environmental_impact(&event_id);
+    assert_eq!(impact_pending.total_offset_kg, 0);
+    assert!(!impact_pending.neutral_status);
+
+    client.attest_carbon_retirement(&attestor, &purchase_id, &BytesN::from_array(&env, &[7u8; 32]));
 
     let impact_final = client.track_environmental_impact(&event_id);
     assert_eq!(impact_final.total_offset_kg, 200000);
@@ -455,8 +1117,9 @@ fn test_carbon_footprint_events() {
 
     let (admin, client) = setup_contract(&env);
     let event_id = create_event(&env, &client);
+    let organizer = client.get_event(&event_id).organizer;
 
-    client.calculate_carbon_footprint(&event_id, &5000u64, &1000u64, &20u64);
+    client.calculate_carbon_footprint(&organizer, &event_id, &5000u64, &1000u64, &20u64);
 
     let events = env.events().all();
     let mut found = false;
@@ -480,19 +1143,509 @@ fn test_multiple_carbon_offset_purchases() {
 
     let (admin, client) = setup_contract(&env);
     let event_id = create_event(&env, &client);
+    let organizer = client.get_event(&event_id).organizer;
 
     let purchaser = Address::generate(&env);
     let project_id = String::from_str(&env, "PROJ-003");
+    let attestor = register_carbon_project(&env, &client, &admin, &project_id);
+
+    client.calculate_carbon_footprint(&organizer, &event_id, &1000u64, &500u64, &10u64);
+
+    let first =
+        client.purchase_carbon_offset(&purchaser, &event_id, &50000i128, &50000i128, &project_id);
+    client.purchase_carbon_offset(&purchaser, &event_id, &30000i128, &30000i128, &project_id);
+    client.attest_carbon_retirement(&attestor, &first, &BytesN::from_array(&env, &[1u8; 32]));
+
+    let impact = client.track_environmental_impact(&event_id);
+    assert_eq!(impact.total_purchases, 2);
+    assert_eq!(impact.total_offset_kg, 50000);
+    assert_eq!(impact.pending_offset_kg, 30000);
+}
+
+#[test]
+fn test_carbon_offset_pays_beneficiary_at_project_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = setup_contract(&env);
+    let event_id = create_event(&env, &client);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    client.set_token(&admin, &token);
+
+    let purchaser = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    let attestor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token).mint(&purchaser, &10_000i128);
+
+    let project_id = String::from_str(&env, "VCS-981");
+    client.register_carbon_project(
+        &admin,
+        &project_id,
+        &String::from_str(&env, "Mangrove restoration"),
+        &3i128,
+        &beneficiary,
+        &attestor,
+    );
+    let result = client.try_register_carbon_project(
+        &admin,
+        &project_id,
+        &String::from_str(&env, "Duplicate"),
+        &3i128,
+        &beneficiary,
+        &attestor,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::CarbonProjectAlreadyRegistered)));
+
+    // 1_000 kg at 3 per kg exceeds a 2_000 ceiling
+    let result =
+        client.try_purchase_carbon_offset(&purchaser, &event_id, &1000i128, &2000i128, &project_id);
+    assert_eq!(result, Err(Ok(LumentixError::CarbonOffsetCostExceeded)));
+
+    let purchase_id =
+        client.purchase_carbon_offset(&purchaser, &event_id, &1000i128, &3000i128, &project_id);
+    let token_client = token::Client::new(&env, &token);
+    assert_eq!(token_client.balance(&beneficiary), 3000);
+    assert_eq!(token_client.balance(&purchaser), 7000);
+    assert_eq!(client.get_carbon_project(&project_id).total_sold_kg, 1000);
+
+    let unknown = String::from_str(&env, "UNKNOWN");
+    let result =
+        client.try_purchase_carbon_offset(&purchaser, &event_id, &1000i128, &3000i128, &unknown);
+    assert_eq!(result, Err(Ok(LumentixError::CarbonOffsetProjectNotFound)));
+
+    client.update_carbon_project(&admin, &project_id, &3i128, &beneficiary, &attestor, &false);
+    let result =
+        client.try_purchase_carbon_offset(&purchaser, &event_id, &1000i128, &3000i128, &project_id);
+    assert_eq!(result, Err(Ok(LumentixError::CarbonProjectInactive)));
+    assert!(!client.get_carbon_offset_purchase(&purchase_id).verified);
+}
+
+#[test]
+fn test_carbon_retirement_attestation_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = setup_contract(&env);
+    let event_id = create_event(&env, &client);
+    let purchaser = Address::generate(&env);
+    let project_id = String::from_str(&env, "GS-204");
+    let attestor = register_carbon_project(&env, &client, &admin, &project_id);
+
+    let first =
+        client.purchase_carbon_offset(&purchaser, &event_id, &500i128, &500i128, &project_id);
+    let second =
+        client.purchase_carbon_offset(&purchaser, &event_id, &500i128, &500i128, &project_id);
+    let serial = BytesN::from_array(&env, &[9u8; 32]);
+
+    let result = client.try_attest_carbon_retirement(&purchaser, &first, &serial);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    client.attest_carbon_retirement(&attestor, &first, &serial);
+    let purchase = client.get_carbon_offset_purchase(&first);
+    assert!(purchase.verified);
+    assert_eq!(purchase.retirement_serial, Some(serial.clone()));
+    assert_eq!(client.get_carbon_project(&project_id).total_retired_kg, 500);
+
+    let result = client.try_attest_carbon_retirement(&attestor, &first, &serial);
+    assert_eq!(result, Err(Ok(LumentixError::CarbonOffsetAlreadyVerified)));
+    let result = client.try_attest_carbon_retirement(&attestor, &second, &serial);
+    assert_eq!(result, Err(Ok(LumentixError::RetirementSerialAlreadyUsed)));
+}
+
+struct CheckoutOffsetSetup<'a> {
+    client: LumentixContractClient<'a>,
+    admin: Address,
+    token: Address,
+    organizer: Address,
+    beneficiary: Address,
+    event_id: u64,
+    project_id: String,
+}
+
+fn setup_checkout_offset(env: &Env) -> CheckoutOffsetSetup<'_> {
+    let (admin, client) = setup_contract(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    client.set_token(&admin, &token);
+
+    let organizer = Address::generate(env);
+    let now = env.ledger().timestamp();
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(env, "Green Festival"),
+        &String::from_str(env, "Outdoor festival"),
+        &String::from_str(env, "Eco Park"),
+        &(now + 1_000),
+        &(now + 10_000),
+        &100i128,
+        &100u32,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, &organizer);
+
+    let beneficiary = Address::generate(env);
+    let project_id = String::from_str(env, "VCS-1001");
+    client.register_carbon_project(
+        &admin,
+        &project_id,
+        &String::from_str(env, "Wind farm"),
+        &1i128,
+        &beneficiary,
+        &Address::generate(env),
+    );
+
+    CheckoutOffsetSetup {
+        client,
+        admin,
+        token,
+        organizer,
+        beneficiary,
+        event_id,
+        project_id,
+    }
+}
+
+fn funded_buyer(env: &Env, token: &Address) -> Address {
+    let buyer = Address::generate(env);
+    token::StellarAssetClient::new(env, token).mint(&buyer, &10_000i128);
+    buyer
+}
+
+#[test]
+fn test_checkout_offset_collects_and_settles_at_completion() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_checkout_offset(&env);
+    let client = &s.client;
+    let token_client = token::Client::new(&env, &s.token);
+
+    // Needs a footprint before it can be offered
+    let result = client.try_enable_checkout_carbon_offset(&s.organizer, &s.event_id, &s.project_id);
+    assert_eq!(result, Err(Ok(LumentixError::CarbonOffsetNotConfigured)));
+    let buyer1 = funded_buyer(&env, &s.token);
+    let result = client.try_purchase_ticket(&buyer1, &s.event_id, &100i128, &true, &None);
+    assert_eq!(result, Err(Ok(LumentixError::CheckoutOffsetNotEnabled)));
+
+    // 28_775 kg across 100 seats rounds up to 288 kg per ticket at 1 per kg
+    client.calculate_carbon_footprint(&s.organizer, &s.event_id, &1000u64, &500u64, &10u64);
+    let config = client.enable_checkout_carbon_offset(&s.organizer, &s.event_id, &s.project_id);
+    assert_eq!(config.offset_kg_per_ticket, 288);
+    assert_eq!(config.surcharge_per_ticket, 288);
+
+    let buyer2 = funded_buyer(&env, &s.token);
+    let buyer3 = funded_buyer(&env, &s.token);
+    let ticket1 = client.purchase_ticket(&buyer1, &s.event_id, &100i128, &true, &None);
+    client.purchase_ticket(&buyer2, &s.event_id, &100i128, &true, &None);
+    client.purchase_ticket(&buyer3, &s.event_id, &100i128, &false, &None);
+
+    assert_eq!(token_client.balance(&buyer1), 10_000 - 100 - 288);
+    assert_eq!(token_client.balance(&buyer3), 10_000 - 100);
+    assert_eq!(client.get_carbon_escrow_balance(&s.event_id), 576);
+
+    let offset = client.get_ticket_carbon_offset(&ticket1).unwrap();
+    assert_eq!(offset.attendee, buyer1);
+    assert_eq!(offset.offset_kg, 288);
+    assert_eq!(client.get_attendee_carbon_offsets(&buyer1).len(), 1);
+    assert_eq!(client.get_attendee_carbon_offsets(&buyer3).len(), 0);
+
+    let result = client.try_settle_checkout_carbon_offset(&s.event_id);
+    assert_eq!(result, Err(Ok(LumentixError::EventNotCompleted)));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 10_001);
+    client.complete_event(&s.organizer, &s.event_id);
+    let purchase_id = client.settle_checkout_carbon_offset(&s.event_id);
+
+    let purchase = client.get_carbon_offset_purchase(&purchase_id);
+    assert_eq!(purchase.offset_amount_kg, 576);
+    assert_eq!(purchase.purchaser, client.address);
+    assert!(!purchase.verified);
+    assert_eq!(token_client.balance(&s.beneficiary), 576);
+    assert_eq!(client.get_carbon_escrow_balance(&s.event_id), 0);
+    assert_eq!(client.track_environmental_impact(&s.event_id).pending_offset_kg, 576);
+
+    let config = client.get_checkout_carbon_offset(&s.event_id).unwrap();
+    assert!(config.settled);
+    assert_eq!(config.offset_purchase_id, Some(purchase_id));
+    let result = client.try_settle_checkout_carbon_offset(&s.event_id);
+    assert_eq!(result, Err(Ok(LumentixError::CarbonOffsetAlreadyPurchased)));
+}
+
+#[test]
+fn test_checkout_offset_refunded_with_ticket() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_checkout_offset(&env);
+    let client = &s.client;
+
+    client.calculate_carbon_footprint(&s.organizer, &s.event_id, &1000u64, &500u64, &10u64);
+    client.enable_checkout_carbon_offset(&s.organizer, &s.event_id, &s.project_id);
+
+    let buyer = funded_buyer(&env, &s.token);
+    let ticket_id = client.purchase_ticket(&buyer, &s.event_id, &100i128, &true, &None);
+
+    client.cancel_event(
+        &s.organizer,
+        &s.event_id,
+        &CancellationReason::EventCancelledByOrganizer,
+    );
+    client.refund_ticket(&ticket_id, &buyer);
+
+    assert_eq!(token::Client::new(&env, &s.token).balance(&buyer), 10_000);
+    assert!(client.get_ticket_carbon_offset(&ticket_id).unwrap().refunded);
+    assert_eq!(client.get_carbon_escrow_balance(&s.event_id), 0);
+    let config = client.get_checkout_carbon_offset(&s.event_id).unwrap();
+    assert_eq!(config.opted_in_tickets, 0);
+    assert_eq!(config.total_offset_kg, 0);
+}
+
+#[test]
+fn test_checkout_offset_opt_in_on_batch_and_token_purchases() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_checkout_offset(&env);
+    let client = &s.client;
+
+    client.calculate_carbon_footprint(&s.organizer, &s.event_id, &1000u64, &500u64, &10u64);
+    client.enable_checkout_carbon_offset(&s.organizer, &s.event_id, &s.project_id);
+
+    let buyer = funded_buyer(&env, &s.token);
+    let ticket_ids = client.batch_purchase_tickets(&s.event_id, &2u32, &buyer, &true);
+    for ticket_id in ticket_ids.iter() {
+        assert_eq!(client.get_ticket_carbon_offset(&ticket_id).unwrap().surcharge, 288);
+    }
+
+    let usd = String::from_str(&env, "USD");
+    client.set_currency_oracle(&s.admin, &usd, &2u32, &100i128);
+    let alt_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &alt_token).mint(&buyer, &1_000i128);
+    client.add_accepted_token(&s.organizer, &s.event_id, &alt_token, &usd);
+    let ticket_id =
+        client.purchase_ticket_with_token(&buyer, &s.event_id, &alt_token, &100i128, &true);
+
+    // The surcharge is charged in the platform token whatever the ticket was paid in
+    assert!(client.get_ticket_carbon_offset(&ticket_id).is_some());
+    assert_eq!(token::Client::new(&env, &s.token).balance(&buyer), 10_000 - 200 - 3 * 288);
+    assert_eq!(client.get_carbon_escrow_balance(&s.event_id), 3 * 288);
+    assert_eq!(client.get_checkout_carbon_offset(&s.event_id).unwrap().opted_in_tickets, 3);
+}
+
+#[test]
+fn test_checkout_offset_settlement_remainder_and_refund_after_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_checkout_offset(&env);
+    let client = &s.client;
+
+    client.calculate_carbon_footprint(&s.organizer, &s.event_id, &1000u64, &500u64, &10u64);
+    client.enable_checkout_carbon_offset(&s.organizer, &s.event_id, &s.project_id);
+
+    let buyer = funded_buyer(&env, &s.token);
+    let ticket_id = client.purchase_ticket(&buyer, &s.event_id, &100i128, &true, &None);
+    client.purchase_ticket(&buyer, &s.event_id, &100i128, &true, &None);
+
+    // The project reprices, so 576 in surcharges buys 82 kg at 7 and leaves 2
+    client.update_carbon_project(
+        &s.admin,
+        &s.project_id,
+        &7i128,
+        &s.beneficiary,
+        &Address::generate(&env),
+        &true,
+    );
+    env.ledger().set_timestamp(env.ledger().timestamp() + 10_001);
+    client.complete_event(&s.organizer, &s.event_id);
+    let escrow_before = client.get_escrow_balance(&s.event_id);
+    let purchase_id = client.settle_checkout_carbon_offset(&s.event_id);
+
+    assert_eq!(client.get_carbon_offset_purchase(&purchase_id).offset_amount_kg, 82);
+    assert_eq!(client.get_carbon_escrow_balance(&s.event_id), 0);
+    assert_eq!(client.get_escrow_balance(&s.event_id), escrow_before + 2);
+
+    // A refund that reaches a settled event keeps the spent surcharge
+    let event_id = s.event_id;
+    env.as_contract(&client.address, || {
+        let mut event = storage::get_event(&env, event_id).unwrap();
+        event.status = EventStatus::Cancelled;
+        storage::set_event(&env, event_id, &event);
+    });
+    client.refund_ticket(&ticket_id, &buyer);
+    assert!(!client.get_ticket_carbon_offset(&ticket_id).unwrap().refunded);
+    assert_eq!(token::Client::new(&env, &s.token).balance(&buyer), 10_000 - 100 - 2 * 288);
+}
+
+#[test]
+fn test_checkout_offset_project_locked_and_disable() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_checkout_offset(&env);
+    let client = &s.client;
+
+    client.calculate_carbon_footprint(&s.organizer, &s.event_id, &1000u64, &500u64, &10u64);
+    let result = client.try_enable_checkout_carbon_offset(
+        &Address::generate(&env),
+        &s.event_id,
+        &s.project_id,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    client.enable_checkout_carbon_offset(&s.organizer, &s.event_id, &s.project_id);
+
+    let buyer = funded_buyer(&env, &s.token);
+    client.purchase_ticket(&buyer, &s.event_id, &100i128, &true, &None);
+
+    let admin = client.get_admin();
+    let other_project = String::from_str(&env, "GS-77");
+    client.register_carbon_project(
+        &admin,
+        &other_project,
+        &String::from_str(&env, "Cookstoves"),
+        &2i128,
+        &Address::generate(&env),
+        &Address::generate(&env),
+    );
+    let result =
+        client.try_enable_checkout_carbon_offset(&s.organizer, &s.event_id, &other_project);
+    assert_eq!(result, Err(Ok(LumentixError::CheckoutOffsetProjectLocked)));
+
+    client.disable_checkout_carbon_offset(&s.organizer, &s.event_id);
+    let result = client.try_purchase_ticket(&buyer, &s.event_id, &100i128, &true, &None);
+    assert_eq!(result, Err(Ok(LumentixError::CheckoutOffsetNotEnabled)));
+    client.purchase_ticket(&buyer, &s.event_id, &100i128, &false, &None);
+}
+
+#[test]
+fn test_footprint_profile_uses_emission_factors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_checkout_offset(&env);
+    let client = &s.client;
+
+    let result = client.try_set_event_footprint_profile(
+        &Address::generate(&env),
+        &s.event_id,
+        &VenueType::Outdoor,
+        &EnergyMix::Renewable,
+        &TransportMode::PublicTransit,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+    client.set_event_footprint_profile(
+        &s.organizer,
+        &s.event_id,
+        &VenueType::Outdoor,
+        &EnergyMix::Renewable,
+        &TransportMode::PublicTransit,
+    );
+
+    // 5_000 sqm × 10 kg × 20% + 1_000 × 5 kg + 1_000 × 20 km × 90 g
+    let footprint = client.calculate_carbon_footprint(
+        &s.organizer,
+        &s.event_id,
+        &5000u64,
+        &1000u64,
+        &20u64,
+    );
+    assert_eq!(footprint.venue_footprint_kg, 10_000);
+    assert_eq!(footprint.attendance_footprint_kg, 5_000);
+    assert_eq!(footprint.travel_footprint_kg, 1_800);
+    assert_eq!(footprint.total_footprint_kg, 16_800);
+}
+
+#[test]
+fn test_set_emission_factors_requires_every_category() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let event_id = create_event(&env, &client);
+    let organizer = client.get_event(&event_id).organizer;
+
+    let mut factors = client.get_emission_factors();
+    factors.attendee_kg = 8;
+    factors.venue_kg_per_sqm.set(VenueType::Indoor, 30);
+
+    let result = client.try_set_emission_factors(&Address::generate(&env), &factors);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    let mut incomplete = factors.clone();
+    incomplete.transport_g_per_km.remove(TransportMode::Air);
+    let result = client.try_set_emission_factors(&admin, &incomplete);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidEmissionFactors)));
 
-    client.calculate_carbon_footprint(&event_id, &1000u64, &500u64, &10u64);
+    let mut negative = factors.clone();
+    negative.venue_kg_per_sqm.set(VenueType::Mixed, -1);
+    let result = client.try_set_emission_factors(&admin, &negative);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidEmissionFactors)));
 
-    client.purchase_carbon_offset(&purchaser, &event_id, &50000i128, &25000i128, &project_id);
+    client.set_emission_factors(&admin, &factors);
 
-    client.purchase_carbon_offset(&purchaser, &event_id, &30000i128, &15000i128, &project_id);
+    // 5_000 × 30 + 1_000 × 8 + 1_000 × 20 × 255 / 1000
+    let footprint = client.calculate_carbon_footprint(
+        &organizer,
+        &event_id,
+        &5000u64,
+        &1000u64,
+        &20u64,
+    );
+    assert_eq!(footprint.total_footprint_kg, 150_000 + 8_000 + 5_100);
+}
 
-    let impact = client.track_environmental_impact(&event_id);
-    assert_eq!(impact.total_purchases, 2);
-    assert_eq!(impact.total_offset_kg, 80000);
+#[test]
+fn test_actual_footprint_from_check_ins_and_report() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup_checkout_offset(&env);
+    let client = &s.client;
+
+    client.calculate_carbon_footprint(&s.organizer, &s.event_id, &5000u64, &1000u64, &20u64);
+    let mut tickets = Vec::new(&env);
+    for _ in 0..3 {
+        let buyer = funded_buyer(&env, &s.token);
+        tickets.push_back(client.purchase_ticket(&buyer, &s.event_id, &100i128, &false, &None));
+    }
+    client.use_ticket(&tickets.get(0).unwrap(), &s.organizer);
+    client.batch_use_tickets(&Vec::from_array(&env, [tickets.get(1).unwrap()]), &s.organizer);
+
+    let result = client.try_record_actual_carbon_footprint(&s.event_id);
+    assert_eq!(result, Err(Ok(LumentixError::EventNotCompleted)));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 10_001);
+    client.complete_event(&s.organizer, &s.event_id);
+
+    // The estimate's inputs cannot be rewritten after the event
+    let result = client.try_calculate_carbon_footprint(
+        &s.organizer,
+        &s.event_id,
+        &1u64,
+        &1u64,
+        &0u64,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::CarbonFootprintFinalized)));
+    let result = client.try_set_event_footprint_profile(
+        &s.organizer,
+        &s.event_id,
+        &VenueType::Outdoor,
+        &EnergyMix::Renewable,
+        &TransportMode::Active,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::CarbonFootprintFinalized)));
+
+    // Same venue, but only the two checked-in attendees travel and attend
+    let actual = client.record_actual_carbon_footprint(&s.event_id);
+    assert_eq!(actual.total_footprint_kg, 125_000 + 10 + 10);
+    assert_eq!(client.get_actual_carbon_footprint(&s.event_id), Some(actual));
+
+    let report = client.get_sustainability_report(&s.event_id);
+    assert_eq!(report.estimated_footprint_kg, 135_100);
+    assert_eq!(report.actual_footprint_kg, Some(125_020));
+    assert_eq!(report.footprint_delta_kg, 125_020 - 135_100);
+    assert_eq!(report.expected_attendance, 1000);
+    assert_eq!(report.checked_in_attendance, 2);
+    assert_eq!(report.net_impact_kg, 125_020);
+    assert!(!report.neutral_status);
+    assert_eq!(
+        client.track_environmental_impact(&s.event_id).total_footprint_kg,
+        125_020
+    );
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -691,32 +1844,15 @@ fn test_validate_credential_authenticity() {
     env.mock_all_auths();
 
     let (admin, client) = setup_contract(&env);
+    let (signing_key, issuer_key) = register_kyc_issuer(&env, &client, &admin, 3);
 
     let subject = Address::generate(&env);
-    let provider = IdentityProvider::Stellar;
-    let provider_id = String::from_str(&env, "GBTEST...");
-    let level: u32 = 4;
-    let expires_at = env.ledger().timestamp() + 365 * 24 * 3600;
-    let metadata_hash = BytesN::from_array(&env, &[7u8; 32]);
-
-    let credential_id = client.issue_identity_credential(
-        &admin,
-        &subject,
-        &provider,
-        &provider_id,
-        &level,
-        &expires_at,
-        &metadata_hash,
-    );
-
-    let now = env.ledger().timestamp();
-    let proof = IdentityProof {
-        credential_id,
-        subject: subject.clone(),
-        signature: BytesN::from_array(&env, &[1u8; 64]),
-        timestamp: now,
-    };
+    let payload = signed_payload(&env, &client, &subject, 3);
+    let signature = sign_payload(&env, &signing_key, &payload);
+    let credential_id =
+        client.present_signed_credential(&subject, &issuer_key, &payload, &signature);
 
+    let proof = sign_identity_proof(&env, &client, &signing_key, credential_id, &subject);
     let valid = client.validate_credential_authenticity(&credential_id, &proof);
     assert!(valid);
 }
@@ -727,71 +1863,70 @@ fn test_validate_credential_authenticity_wrong_subject() {
     env.mock_all_auths();
 
     let (admin, client) = setup_contract(&env);
+    let (signing_key, issuer_key) = register_kyc_issuer(&env, &client, &admin, 3);
 
     let subject = Address::generate(&env);
-    let provider = IdentityProvider::Stellar;
-    let provider_id = String::from_str(&env, "GBWRONG...");
-    let level: u32 = 2;
-    let expires_at = env.ledger().timestamp() + 365 * 24 * 3600;
-    let metadata_hash = BytesN::from_array(&env, &[8u8; 32]);
-
-    let credential_id = client.issue_identity_credential(
-        &admin,
-        &subject,
-        &provider,
-        &provider_id,
-        &level,
-        &expires_at,
-        &metadata_hash,
-    );
+    let payload = signed_payload(&env, &client, &subject, 2);
+    let signature = sign_payload(&env, &signing_key, &payload);
+    let credential_id =
+        client.present_signed_credential(&subject, &issuer_key, &payload, &signature);
 
-    let now = env.ledger().timestamp();
     let wrong_subject = Address::generate(&env);
-    let proof = IdentityProof {
-        credential_id,
-        subject: wrong_subject,
-        signature: BytesN::from_array(&env, &[1u8; 64]),
-        timestamp: now,
-    };
+    let proof = sign_identity_proof(&env, &client, &signing_key, credential_id, &wrong_subject);
 
     let result = client.try_validate_credential_authenticity(&credential_id, &proof);
-    assert!(result.is_err());
+    assert_eq!(result, Err(Ok(LumentixError::IdentityVerificationFailed)));
 }
 
 #[test]
-fn test_validate_credential_authenticity_empty_signature() {
+fn test_validate_credential_authenticity_forged_signature() {
     let env = Env::default();
     env.mock_all_auths();
 
     let (admin, client) = setup_contract(&env);
+    let (signing_key, issuer_key) = register_kyc_issuer(&env, &client, &admin, 3);
 
     let subject = Address::generate(&env);
-    let provider = IdentityProvider::Stellar;
-    let provider_id = String::from_str(&env, "GBEMPTY...");
-    let level: u32 = 2;
-    let expires_at = env.ledger().timestamp() + 365 * 24 * 3600;
-    let metadata_hash = BytesN::from_array(&env, &[9u8; 32]);
+    let payload = signed_payload(&env, &client, &subject, 2);
+    let signature = sign_payload(&env, &signing_key, &payload);
+    let credential_id =
+        client.present_signed_credential(&subject, &issuer_key, &payload, &signature);
+
+    // Any non-zero bytes used to pass; now only the issuer's key does
+    let mut proof = sign_identity_proof(&env, &client, &signing_key, credential_id, &subject);
+    proof.signature = BytesN::from_array(&env, &[1u8; 64]);
+    let result = client.try_validate_credential_authenticity(&credential_id, &proof);
+    assert!(result.is_err());
+
+    let rogue_key = SigningKey::from_bytes(&[9u8; 32]);
+    let proof = sign_identity_proof(&env, &client, &rogue_key, credential_id, &subject);
+    let result = client.try_validate_credential_authenticity(&credential_id, &proof);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_validate_credential_authenticity_rejects_admin_issued_credential() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = setup_contract(&env);
 
+    let subject = Address::generate(&env);
     let credential_id = client.issue_identity_credential(
         &admin,
         &subject,
-        &provider,
-        &provider_id,
-        &level,
-        &expires_at,
-        &metadata_hash,
+        &IdentityProvider::Stellar,
+        &String::from_str(&env, "GBEMPTY..."),
+        &2u32,
+        &(env.ledger().timestamp() + 365 * 24 * 3600),
+        &BytesN::from_array(&env, &[9u8; 32]),
     );
 
-    let now = env.ledger().timestamp();
-    let proof = IdentityProof {
-        credential_id,
-        subject: subject.clone(),
-        signature: BytesN::from_array(&env, &[0u8; 64]),
-        timestamp: now,
-    };
-
+    // There is no issuer key to check the proof against
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let proof = sign_identity_proof(&env, &client, &signing_key, credential_id, &subject);
     let result = client.try_validate_credential_authenticity(&credential_id, &proof);
-    assert!(result.is_err());
+    assert_eq!(result, Err(Ok(LumentixError::CredentialIssuerNotTrusted)));
 }
 
 #[test]
@@ -865,6 +2000,407 @@ fn test_credential_by_subject_lookup() {
     assert_eq!(not_found, None);
 }
 
+fn register_kyc_issuer(
+    env: &Env,
+    client: &LumentixContractClient,
+    admin: &Address,
+    max_level: u32,
+) -> (SigningKey, BytesN<32>) {
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let issuer_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    client.register_credential_issuer(
+        admin,
+        &issuer_key,
+        &IdentityProvider::Stellar,
+        &String::from_str(env, "Acme KYC"),
+        &max_level,
+    );
+    (signing_key, issuer_key)
+}
+
+fn signed_payload(
+    env: &Env,
+    client: &LumentixContractClient,
+    subject: &Address,
+    level: u32,
+) -> SignedCredentialPayload {
+    SignedCredentialPayload {
+        verifier: client.address.clone(),
+        subject: subject.clone(),
+        provider: IdentityProvider::Stellar,
+        provider_id: String::from_str(env, "kyc-4242"),
+        level,
+        issued_at: env.ledger().timestamp(),
+        expires_at: env.ledger().timestamp() + 365 * 24 * 3600,
+        metadata_hash: BytesN::from_array(env, &[12u8; 32]),
+    }
+}
+
+fn sign_payload(
+    env: &Env,
+    signing_key: &SigningKey,
+    payload: &SignedCredentialPayload,
+) -> BytesN<64> {
+    let message: std::vec::Vec<u8> = payload.clone().to_xdr(env).iter().collect();
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+fn sign_identity_proof(
+    env: &Env,
+    client: &LumentixContractClient,
+    signing_key: &SigningKey,
+    credential_id: u64,
+    subject: &Address,
+) -> IdentityProof {
+    let timestamp = env.ledger().timestamp();
+    let message: std::vec::Vec<u8> =
+        (client.address.clone(), credential_id, subject.clone(), timestamp)
+            .to_xdr(env)
+            .iter()
+            .collect();
+    IdentityProof {
+        credential_id,
+        subject: subject.clone(),
+        signature: BytesN::from_array(env, &signing_key.sign(&message).to_bytes()),
+        timestamp,
+    }
+}
+
+#[test]
+fn test_present_signed_credential() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (signing_key, issuer_key) = register_kyc_issuer(&env, &client, &admin, 3);
+
+    let subject = Address::generate(&env);
+    let payload = signed_payload(&env, &client, &subject, 3);
+    let signature = sign_payload(&env, &signing_key, &payload);
+
+    let credential_id =
+        client.present_signed_credential(&subject, &issuer_key, &payload, &signature);
+
+    let credential = client.get_identity_credential(&credential_id);
+    assert_eq!(credential.subject, subject);
+    assert_eq!(credential.level, 3);
+    assert_eq!(credential.issuer, Some(issuer_key.clone()));
+    assert!(client.verify_blockchain_identity(&credential_id, &subject));
+    assert_eq!(
+        client.get_credential_by_subject(&subject, &IdentityProvider::Stellar),
+        Some(credential_id)
+    );
+    assert_eq!(
+        client.get_provider_issuers(&IdentityProvider::Stellar),
+        Vec::from_array(&env, [issuer_key])
+    );
+}
+
+#[test]
+fn test_present_signed_credential_tampered_payload_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (signing_key, issuer_key) = register_kyc_issuer(&env, &client, &admin, 3);
+
+    let subject = Address::generate(&env);
+    let mut payload = signed_payload(&env, &client, &subject, 1);
+    let signature = sign_payload(&env, &signing_key, &payload);
+    payload.level = 3;
+
+    let result = client.try_present_signed_credential(&subject, &issuer_key, &payload, &signature);
+    assert!(result.is_err());
+    assert_eq!(
+        client.get_credential_by_subject(&subject, &IdentityProvider::Stellar),
+        None
+    );
+}
+
+#[test]
+fn test_present_signed_credential_untrusted_issuer_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (signing_key, issuer_key) = register_kyc_issuer(&env, &client, &admin, 3);
+
+    let subject = Address::generate(&env);
+    let payload = signed_payload(&env, &client, &subject, 2);
+    let signature = sign_payload(&env, &signing_key, &payload);
+
+    let rogue_key = SigningKey::from_bytes(&[9u8; 32]);
+    let rogue_issuer = BytesN::from_array(&env, &rogue_key.verifying_key().to_bytes());
+    let rogue_signature = sign_payload(&env, &rogue_key, &payload);
+    let result =
+        client.try_present_signed_credential(&subject, &rogue_issuer, &payload, &rogue_signature);
+    assert_eq!(result, Err(Ok(LumentixError::CredentialIssuerNotTrusted)));
+
+    client.set_credential_issuer_active(&admin, &issuer_key, &false);
+    let result = client.try_present_signed_credential(&subject, &issuer_key, &payload, &signature);
+    assert_eq!(result, Err(Ok(LumentixError::CredentialIssuerNotTrusted)));
+
+    client.set_credential_issuer_active(&admin, &issuer_key, &true);
+    client.present_signed_credential(&subject, &issuer_key, &payload, &signature);
+}
+
+#[test]
+fn test_present_signed_credential_level_and_verifier_checks() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (signing_key, issuer_key) = register_kyc_issuer(&env, &client, &admin, 2);
+
+    let subject = Address::generate(&env);
+    let payload = signed_payload(&env, &client, &subject, 3);
+    let signature = sign_payload(&env, &signing_key, &payload);
+    let result = client.try_present_signed_credential(&subject, &issuer_key, &payload, &signature);
+    assert_eq!(result, Err(Ok(LumentixError::CredentialLevelNotAllowed)));
+
+    let mut payload = signed_payload(&env, &client, &subject, 2);
+    payload.verifier = Address::generate(&env);
+    let signature = sign_payload(&env, &signing_key, &payload);
+    let result = client.try_present_signed_credential(&subject, &issuer_key, &payload, &signature);
+    assert_eq!(result, Err(Ok(LumentixError::InvalidIdentityProof)));
+
+    let other = Address::generate(&env);
+    let payload = signed_payload(&env, &client, &subject, 2);
+    let signature = sign_payload(&env, &signing_key, &payload);
+    let result = client.try_present_signed_credential(&other, &issuer_key, &payload, &signature);
+    assert_eq!(result, Err(Ok(LumentixError::IdentityVerificationFailed)));
+}
+
+#[test]
+fn test_register_credential_issuer_duplicate_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (_, issuer_key) = register_kyc_issuer(&env, &client, &admin, 3);
+
+    let issuer = client.get_credential_issuer(&issuer_key).unwrap();
+    assert!(issuer.active);
+    assert_eq!(issuer.max_level, 3);
+
+    let result = client.try_register_credential_issuer(
+        &admin,
+        &issuer_key,
+        &IdentityProvider::Ethereum,
+        &String::from_str(&env, "Again"),
+        &3,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::CredentialIssuerAlreadyRegistered)));
+
+    let stranger = Address::generate(&env);
+    let result = client.try_set_credential_issuer_active(&stranger, &issuer_key, &false);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+}
+
+fn setup_gated_event(env: &Env, client: &LumentixContractClient) -> (Address, u64) {
+    let organizer = Address::generate(env);
+    let event_id = client.create_event(
+        &organizer,
+        &String::from_str(env, "Late Show"),
+        &String::from_str(env, "18+ only"),
+        &String::from_str(env, "Basement"),
+        &(env.ledger().timestamp() + 1000),
+        &(env.ledger().timestamp() + 10000),
+        &100,
+        &50,
+    );
+    client.update_event_status(&event_id, &EventStatus::Published, &organizer);
+    client.set_event_credential_requirement(
+        &organizer,
+        &event_id,
+        &Vec::from_array(env, [IdentityProvider::Stellar]),
+        &3,
+    );
+    (organizer, event_id)
+}
+
+fn issue_kyc_credential(
+    env: &Env,
+    client: &LumentixContractClient,
+    admin: &Address,
+    subject: &Address,
+    level: u32,
+) -> u64 {
+    client.issue_identity_credential(
+        admin,
+        subject,
+        &IdentityProvider::Stellar,
+        &String::from_str(env, "kyc-gate"),
+        &level,
+        &(env.ledger().timestamp() + 365 * 24 * 3600),
+        &BytesN::from_array(env, &[13u8; 32]),
+    )
+}
+
+#[test]
+fn test_gated_event_purchase_requires_credential() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (organizer, event_id) = setup_gated_event(&env, &client);
+
+    let buyer = Address::generate(&env);
+    let result = client.try_purchase_ticket(&buyer, &event_id, &100, &false, &None);
+    assert_eq!(result, Err(Ok(LumentixError::CredentialRequirementNotMet)));
+    let result = client.try_batch_purchase_tickets(&event_id, &2, &buyer, &false);
+    assert_eq!(result, Err(Ok(LumentixError::CredentialRequirementNotMet)));
+
+    let minor = Address::generate(&env);
+    issue_kyc_credential(&env, &client, &admin, &minor, 2);
+    assert!(!client.is_credential_eligible(&event_id, &minor));
+
+    issue_kyc_credential(&env, &client, &admin, &buyer, 3);
+    assert!(client.is_credential_eligible(&event_id, &buyer));
+    client.purchase_ticket(&buyer, &event_id, &100, &false, &None);
+
+    client.remove_credential_requirement(&organizer, &event_id);
+    assert_eq!(client.get_event_credential_requirement(&event_id), None);
+    client.purchase_ticket(&minor, &event_id, &100, &false, &None);
+}
+
+#[test]
+fn test_gated_event_transfer_recipient_must_qualify() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (_, event_id) = setup_gated_event(&env, &client);
+
+    let holder = Address::generate(&env);
+    issue_kyc_credential(&env, &client, &admin, &holder, 4);
+    let ticket_id = client.purchase_ticket(&holder, &event_id, &100, &false, &None);
+
+    let recipient = Address::generate(&env);
+    let result = client.try_transfer_ticket(&ticket_id, &holder, &recipient);
+    assert_eq!(result, Err(Ok(LumentixError::CredentialRequirementNotMet)));
+
+    let credential_id = issue_kyc_credential(&env, &client, &admin, &recipient, 3);
+    client.revoke_identity_credential(&admin, &credential_id);
+    let result = client.try_transfer_ticket(&ticket_id, &holder, &recipient);
+    assert_eq!(result, Err(Ok(LumentixError::CredentialRequirementNotMet)));
+
+    let other = Address::generate(&env);
+    issue_kyc_credential(&env, &client, &admin, &other, 3);
+    client.transfer_ticket(&ticket_id, &holder, &other);
+    assert_eq!(client.get_ticket_info(&ticket_id).owner, other);
+}
+
+#[test]
+fn test_gated_event_check_in_requires_linked_did() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (organizer, event_id) = setup_gated_event(&env, &client);
+
+    let holder = Address::generate(&env);
+    let credential_id = issue_kyc_credential(&env, &client, &admin, &holder, 3);
+    let ticket_id = client.purchase_ticket(&holder, &event_id, &100, &false, &None);
+
+    let result = client.try_use_ticket(&ticket_id, &organizer);
+    assert_eq!(result, Err(Ok(LumentixError::TicketDidLinkNotFound)));
+
+    client.link_ticket_to_did(&holder, &ticket_id, &credential_id);
+    client.revoke_identity_credential(&admin, &credential_id);
+    let result = client.try_use_ticket(&ticket_id, &organizer);
+    assert_eq!(result, Err(Ok(LumentixError::IdentityCredentialRevoked)));
+
+    let second = Address::generate(&env);
+    let second_credential = issue_kyc_credential(&env, &client, &admin, &second, 3);
+    let second_ticket = client.purchase_ticket(&second, &event_id, &100, &false, &None);
+    client.link_ticket_to_did(&second, &second_ticket, &second_credential);
+    client.batch_use_tickets(&Vec::from_array(&env, [second_ticket]), &organizer);
+    assert!(client.get_ticket_info(&second_ticket).used);
+}
+
+#[test]
+fn test_gated_event_new_owner_relinks_did_after_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (organizer, event_id) = setup_gated_event(&env, &client);
+
+    let seller = Address::generate(&env);
+    let seller_credential = issue_kyc_credential(&env, &client, &admin, &seller, 3);
+    let ticket_id = client.purchase_ticket(&seller, &event_id, &100, &false, &None);
+    client.link_ticket_to_did(&seller, &ticket_id, &seller_credential);
+
+    let buyer = Address::generate(&env);
+    let buyer_credential = issue_kyc_credential(&env, &client, &admin, &buyer, 5);
+    client.transfer_ticket(&ticket_id, &seller, &buyer);
+
+    let result = client.try_use_ticket(&ticket_id, &organizer);
+    assert_eq!(result, Err(Ok(LumentixError::IdentityVerificationFailed)));
+
+    client.link_ticket_to_did(&buyer, &ticket_id, &buyer_credential);
+    client.use_ticket(&ticket_id, &organizer);
+    assert!(client.get_ticket_info(&ticket_id).used);
+}
+
+#[test]
+fn test_gated_event_comp_and_bridged_tickets_require_credential() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup_contract(&env);
+    let (organizer, event_id) = setup_gated_event(&env, &client);
+
+    let tier_id = client.register_sponsor_tier(
+        &organizer,
+        &event_id,
+        &String::from_str(&env, "Gold"),
+        &500,
+        &1,
+        &2,
+        &String::from_str(&env, "expo_hall"),
+        &Vec::from_array(&env, [String::from_str(&env, "B1")]),
+        &4,
+    );
+    let sponsor = Address::generate(&env);
+    let result = client.try_contribute(&sponsor, &event_id, &tier_id, &500);
+    assert_eq!(result, Err(Ok(LumentixError::CredentialRequirementNotMet)));
+
+    let chain = String::from_str(&env, "Ethereum");
+    client.register_supported_chain(&admin, &chain);
+    let holder = Address::generate(&env);
+    issue_kyc_credential(&env, &client, &admin, &holder, 3);
+    let ticket_id = client.purchase_ticket(&holder, &event_id, &100, &false, &None);
+    let recipient = Address::generate(&env);
+    let transfer_id =
+        client.initiate_cross_chain_transfer(&holder, &ticket_id, &event_id, &chain, &recipient);
+    let tx_hash = String::from_str(&env, "0xgated");
+    client.validate_bridge_transaction(&admin, &transfer_id, &tx_hash, &1u64);
+
+    let result = client.try_complete_cross_chain_transfer(&holder, &transfer_id);
+    assert_eq!(result, Err(Ok(LumentixError::CredentialRequirementNotMet)));
+    assert_eq!(client.get_ticket_info(&ticket_id).owner, holder);
+}
+
+#[test]
+fn test_set_event_credential_requirement_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_, client) = setup_contract(&env);
+    let (organizer, event_id) = setup_gated_event(&env, &client);
+
+    let requirement = client.get_event_credential_requirement(&event_id).unwrap();
+    assert_eq!(requirement.min_level, 3);
+
+    let result = client.try_set_event_credential_requirement(
+        &organizer,
+        &event_id,
+        &Vec::new(&env),
+        &3,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::InvalidCredentialRequirement)));
+
+    let stranger = Address::generate(&env);
+    let result = client.try_set_event_credential_requirement(
+        &stranger,
+        &event_id,
+        &Vec::from_array(&env, [IdentityProvider::Ethereum]),
+        &1,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CROSS-CHAIN TICKET PORTABILITY TESTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -893,7 +2429,7 @@ fn create_ticket(env: &Env, client: &LumentixContractClient) -> (u64, u64, Addre
     client.update_event_status(&event_id, &crate::types::EventStatus::Published, &organizer);
 
     let buyer = Address::generate(env);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &ticket_price);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &ticket_price, &false, &None);
 
     (event_id, ticket_id, buyer)
 }
@@ -1073,6 +2609,42 @@ fn test_complete_cross_chain_transfer() {
     assert_eq!(ticket.owner, recipient);
 }
 
+#[test]
+fn test_complete_cross_chain_transfer_respects_pauses_and_records_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = setup_contract(&env);
+
+    let chain = String::from_str(&env, "Ethereum");
+    client.register_supported_chain(&admin, &chain);
+
+    let (event_id, ticket_id, buyer) = create_ticket(&env, &client);
+    let recipient = Address::generate(&env);
+
+    let transfer_id =
+        client.initiate_cross_chain_transfer(&buyer, &ticket_id, &event_id, &chain, &recipient);
+    let tx_hash = String::from_str(&env, "0xfeed01...");
+    client.validate_bridge_transaction(&admin, &transfer_id, &tx_hash, &42u64);
+
+    let approvers = Vec::from_array(&env, [admin.clone()]);
+    client.set_global_pause(&approvers, &true);
+    let result = client.try_complete_cross_chain_transfer(&buyer, &transfer_id);
+    assert_eq!(result, Err(Ok(LumentixError::ContractPaused)));
+    client.set_global_pause(&approvers, &false);
+
+    client.set_features_paused(&admin, &FEATURE_TRANSFERS, &true);
+    let result = client.try_complete_cross_chain_transfer(&buyer, &transfer_id);
+    assert_eq!(result, Err(Ok(LumentixError::FeaturePaused)));
+    client.set_features_paused(&admin, &FEATURE_TRANSFERS, &false);
+
+    client.complete_cross_chain_transfer(&buyer, &transfer_id);
+    let history = client.get_ticket_transfer_history(&ticket_id);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().from, buyer);
+    assert_eq!(history.get(0).unwrap().to, recipient);
+}
+
 #[test]
 fn test_complete_cross_chain_transfer_not_validated() {
     let env = Env::default();
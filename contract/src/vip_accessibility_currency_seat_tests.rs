@@ -4,11 +4,13 @@
 use crate::error::LumentixError;
 use crate::lumentix_contract::{LumentixContract, LumentixContractClient};
 use crate::types::{
-    AccessibilityBooking, AccessibilityInventory, CurrencyConfig, EventStatus, Seat, SeatCategory,
-    VenueLayout, VenueSection, VipTier,
+    AccessibilityBooking, AccessibilityBookingStatus, AccessibilityInventory, CancellationReason,
+    CurrencyConfig, EventStatus, OracleAsset, OraclePriceData, Seat, SeatCategory, VenueLayout,
+    VenueSection, VipTier, CURRENCY_PRICE_DECIMALS, MAX_QUOTE_LOCK_SECS,
 };
 use soroban_sdk::{
-    testutils::Address as _, testutils::Events, testutils::Ledger, Address, Env, String, Vec,
+    contract, contractimpl, symbol_short, testutils::Address as _, testutils::Events,
+    testutils::Ledger, token, Address, Env, String, Vec,
 };
 
 fn create_test_contract(env: &Env) -> (Address, LumentixContractClient<'_>) {
@@ -140,7 +142,7 @@ fn test_assign_vip_benefits_success() {
         &benefits,
     );
 
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let result = client.try_assign_vip_benefits(
         &organizer,
@@ -174,7 +176,7 @@ fn test_assign_vip_benefits_tier_full() {
         &benefits,
     );
 
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     client.assign_vip_benefits(
         &organizer,
         &event_id,
@@ -182,7 +184,7 @@ fn test_assign_vip_benefits_tier_full() {
         &String::from_str(&env, "Bronze"),
     );
 
-    let ticket_id2 = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id2 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     let result = client.try_assign_vip_benefits(
         &organizer,
         &event_id,
@@ -202,12 +204,239 @@ fn test_validate_vip_access_no_tier() {
     let buyer = Address::generate(&env);
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let valid = client.validate_vip_access(&ticket_id, &String::from_str(&env, "Gold"));
     assert!(!valid);
 }
 
+fn create_vip_tiers(env: &Env, client: &LumentixContractClient, organizer: &Address, event_id: u64) {
+    let mut gold_benefits = Vec::new(env);
+    gold_benefits.push_back(String::from_str(env, "lounge"));
+    client.create_vip_tier(organizer, &event_id, &String::from_str(env, "Gold"), &200i128, &1u32, &gold_benefits);
+
+    let mut platinum_benefits = Vec::new(env);
+    platinum_benefits.push_back(String::from_str(env, "lounge"));
+    platinum_benefits.push_back(String::from_str(env, "meet_and_greet"));
+    client.create_vip_tier(
+        organizer,
+        &event_id,
+        &String::from_str(env, "Platinum"),
+        &500i128,
+        &1u32,
+        &platinum_benefits,
+    );
+}
+
+#[test]
+fn test_purchase_vip_upgrade_charges_difference() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&buyer, &1_000i128);
+    client.set_token(&admin, &token_address);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    create_vip_tiers(&env, &client, &organizer, event_id);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+
+    assert_eq!(client.purchase_vip_upgrade(&buyer, &ticket_id, &String::from_str(&env, "Gold")), 200);
+    assert_eq!(
+        client.purchase_vip_upgrade(&buyer, &ticket_id, &String::from_str(&env, "Platinum")),
+        300
+    );
+
+    assert_eq!(token::Client::new(&env, &token_address).balance(&buyer), 400);
+    assert_eq!(client.get_escrow_balance(&event_id), 600);
+    assert_eq!(client.get_vip_tier(&event_id, &String::from_str(&env, "Gold")).filled_slots, 0);
+    assert_eq!(client.get_vip_tier(&event_id, &String::from_str(&env, "Platinum")).filled_slots, 1);
+}
+
+#[test]
+fn test_purchase_vip_upgrade_rejects_downgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    create_vip_tiers(&env, &client, &organizer, event_id);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+
+    client.purchase_vip_upgrade(&buyer, &ticket_id, &String::from_str(&env, "Platinum"));
+
+    let result = client.try_purchase_vip_upgrade(&buyer, &ticket_id, &String::from_str(&env, "Gold"));
+    assert_eq!(result, Err(Ok(LumentixError::VipDowngradeNotAllowed)));
+
+    let result =
+        client.try_purchase_vip_upgrade(&buyer, &ticket_id, &String::from_str(&env, "Platinum"));
+    assert_eq!(result, Err(Ok(LumentixError::VipTierAlreadyOwned)));
+}
+
+#[test]
+fn test_purchase_vip_upgrade_not_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    create_vip_tiers(&env, &client, &organizer, event_id);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+
+    let result = client.try_purchase_vip_upgrade(
+        &Address::generate(&env),
+        &ticket_id,
+        &String::from_str(&env, "Gold"),
+    );
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+}
+
+#[test]
+fn test_redeem_vip_benefit_once_by_gate_staff() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let gate_agent = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    create_vip_tiers(&env, &client, &organizer, event_id);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_vip_upgrade(&buyer, &ticket_id, &String::from_str(&env, "Gold"));
+
+    let lounge = String::from_str(&env, "lounge");
+    let result = client.try_redeem_vip_benefit(&gate_agent, &ticket_id, &lounge);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    client.add_validator(&organizer, &event_id, &gate_agent);
+    client.redeem_vip_benefit(&gate_agent, &ticket_id, &lounge);
+
+    let redemptions = client.get_vip_redemptions(&ticket_id);
+    assert_eq!(redemptions.len(), 1);
+    assert_eq!(redemptions.get(0).unwrap().redeemed_by, gate_agent);
+
+    let result = client.try_redeem_vip_benefit(&gate_agent, &ticket_id, &lounge);
+    assert_eq!(result, Err(Ok(LumentixError::VipBenefitAlreadyRedeemed)));
+
+    let result = client.try_redeem_vip_benefit(
+        &gate_agent,
+        &ticket_id,
+        &String::from_str(&env, "meet_and_greet"),
+    );
+    assert_eq!(result, Err(Ok(LumentixError::VipBenefitNotFound)));
+}
+
+#[test]
+fn test_refund_releases_vip_slot_and_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&buyer, &1_000i128);
+    client.set_token(&admin, &token_address);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    create_vip_tiers(&env, &client, &organizer, event_id);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_vip_upgrade(&buyer, &ticket_id, &String::from_str(&env, "Gold"));
+
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+    client.refund_ticket(&ticket_id, &buyer);
+
+    assert_eq!(token::Client::new(&env, &token_address).balance(&buyer), 1_000);
+    assert_eq!(client.get_escrow_balance(&event_id), 0);
+    assert_eq!(client.get_vip_tier(&event_id, &String::from_str(&env, "Gold")).filled_slots, 0);
+}
+
+#[test]
+fn test_revoke_releases_vip_slot_and_payment() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    token::StellarAssetClient::new(&env, &token_address).mint(&buyer, &1_000i128);
+    client.set_token(&admin, &token_address);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    create_vip_tiers(&env, &client, &organizer, event_id);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_vip_upgrade(&buyer, &ticket_id, &String::from_str(&env, "Gold"));
+
+    client.revoke_ticket(&admin, &ticket_id);
+
+    assert_eq!(token::Client::new(&env, &token_address).balance(&buyer), 900);
+    assert_eq!(client.get_escrow_balance(&event_id), 100);
+    assert_eq!(client.get_vip_tier(&event_id, &String::from_str(&env, "Gold")).filled_slots, 0);
+}
+
+#[test]
+fn test_revoke_after_fee_withdrawal_books_vip_fee_as_debt() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let other_buyer = Address::generate(&env);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_client = token::Client::new(&env, &token_address);
+    token::StellarAssetClient::new(&env, &token_address).mint(&buyer, &1_000i128);
+    token::StellarAssetClient::new(&env, &token_address).mint(&other_buyer, &1_000i128);
+    client.set_token(&admin, &token_address);
+    client.set_platform_fee(&admin, &1000u32);
+
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    create_vip_tiers(&env, &client, &organizer, event_id);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.purchase_vip_upgrade(&buyer, &ticket_id, &String::from_str(&env, "Gold"));
+    assert_eq!(client.withdraw_platform_fees(&admin), 30);
+
+    // The escrowed part comes back now; the withdrawn fee is owed
+    client.revoke_ticket(&admin, &ticket_id);
+    assert_eq!(token_client.balance(&buyer), 880);
+    assert_eq!(client.get_platform_fee_debt(&buyer, &None), 20);
+
+    let result = client.try_claim_platform_fee_refund(&buyer, &None);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientPlatformBalance)));
+
+    // New fees repay the debt before the admin can withdraw them
+    client.purchase_ticket(&other_buyer, &event_id, &100i128, &false, &None);
+    let result = client.try_withdraw_platform_fees(&admin);
+    assert_eq!(result, Err(Ok(LumentixError::NoPlatformFees)));
+    assert_eq!(client.claim_platform_fee_refund(&buyer, &None), 10);
+    assert_eq!(token_client.balance(&buyer), 890);
+    assert_eq!(client.get_platform_fee_debt(&buyer, &None), 10);
+
+    let result = client.try_claim_platform_fee_refund(&Address::generate(&env), &None);
+    assert_eq!(result, Err(Ok(LumentixError::RefundNotAllowed)));
+}
+
 // ═════════════════════════════════════════════════════════════════════════════
 // ACCESSIBILITY TESTS
 // ═════════════════════════════════════════════════════════════════════════════
@@ -253,7 +482,7 @@ fn test_request_accessibility_booking_success() {
 
     client.setup_accessibility_inventory(&organizer, &event_id, &5u32, &3u32, &2u32);
 
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let booking_id = client.request_accessibility_booking(
         &buyer,
@@ -276,7 +505,7 @@ fn test_request_accessibility_booking_unavailable() {
 
     client.setup_accessibility_inventory(&organizer, &event_id, &0u32, &0u32, &0u32);
 
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let result = client.try_request_accessibility_booking(
         &buyer,
@@ -287,6 +516,34 @@ fn test_request_accessibility_booking_unavailable() {
     assert_eq!(result, Err(Ok(LumentixError::AccommodationUnavailable)));
 }
 
+#[test]
+fn test_request_accessibility_booking_rejects_revoked_and_used_tickets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let wheelchair = String::from_str(&env, "wheelchair");
+
+    client.setup_accessibility_inventory(&organizer, &event_id, &1u32, &0u32, &0u32);
+
+    let revoked = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.revoke_ticket(&admin, &revoked);
+    let result = client.try_request_accessibility_booking(&buyer, &event_id, &revoked, &wheelchair);
+    assert_eq!(result, Err(Ok(LumentixError::RevokedTicket)));
+
+    let used = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.use_ticket(&used, &organizer);
+    let result = client.try_request_accessibility_booking(&buyer, &event_id, &used, &wheelchair);
+    assert_eq!(result, Err(Ok(LumentixError::TicketAlreadyUsed)));
+
+    // Neither attempt took the only wheelchair space
+    let valid = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.request_accessibility_booking(&buyer, &event_id, &valid, &wheelchair);
+}
+
 #[test]
 fn test_manage_accessibility_inventory_success() {
     let env = Env::default();
@@ -319,115 +576,641 @@ fn test_validate_accessibility_needs_true() {
     assert!(available);
 }
 
-// ═════════════════════════════════════════════════════════════════════════════
-// MULTI-CURRENCY TESTS
-// ═════════════════════════════════════════════════════════════════════════════
+fn create_accessible_layout(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+    event_id: u64,
+) {
+    let mut sections = Vec::new(env);
+    sections.push_back(VenueSection {
+        name: String::from_str(env, "A"),
+        category: SeatCategory::Floor,
+        rows: 1,
+        seats_per_row: 2,
+        price_multiplier: 100,
+    });
+    client.create_venue_layout(organizer, &event_id, &sections);
+}
 
 #[test]
-fn test_set_currency_oracle_success() {
+fn test_custom_accommodation_booking_is_pending() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let quiet_room = String::from_str(&env, "quiet_room");
 
-    let result =
-        client.try_set_currency_oracle(&admin, &String::from_str(&env, "EUR"), &2u32, &90i128);
-    assert!(result.is_ok());
-}
+    client.configure_accommodation_type(
+        &organizer,
+        &event_id,
+        &quiet_room,
+        &1u32,
+        &false,
+        &Vec::new(&env),
+    );
+    assert_eq!(client.get_accommodation_types(&event_id).len(), 1);
+    assert!(client.validate_accessibility_needs(&event_id, &quiet_room));
 
-#[test]
-fn test_set_currency_oracle_unauthorized() {
-    let env = Env::default();
-    env.mock_all_auths();
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let booking_id =
+        client.request_accessibility_booking(&buyer, &event_id, &ticket_id, &quiet_room);
 
-    let (admin, client) = create_test_contract(&env);
-    let stranger = Address::generate(&env);
+    let booking = client.get_accessibility_booking(&booking_id);
+    assert_eq!(booking.status, AccessibilityBookingStatus::Pending);
+    assert_eq!(
+        client
+            .get_accommodation_type(&event_id, &quiet_room)
+            .available,
+        0
+    );
 
+    let ticket_id2 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     let result =
-        client.try_set_currency_oracle(&stranger, &String::from_str(&env, "EUR"), &2u32, &90i128);
-    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+        client.try_request_accessibility_booking(&buyer, &event_id, &ticket_id2, &quiet_room);
+    assert_eq!(result, Err(Ok(LumentixError::AccommodationUnavailable)));
 }
 
 #[test]
-fn test_set_event_currency_success() {
+fn test_configure_accommodation_type_cannot_shrink_below_reserved() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
     let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
     let event_id = create_and_publish_event(&env, &client, &organizer);
+    let interpreter = String::from_str(&env, "interpreter");
 
-    client.set_currency_oracle(&admin, &String::from_str(&env, "EUR"), &2u32, &90i128);
+    client.configure_accommodation_type(
+        &organizer,
+        &event_id,
+        &interpreter,
+        &2u32,
+        &false,
+        &Vec::new(&env),
+    );
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    client.request_accessibility_booking(&buyer, &event_id, &ticket_id, &interpreter);
 
-    let result =
-        client.try_set_event_currency(&organizer, &event_id, &String::from_str(&env, "EUR"));
-    assert!(result.is_ok());
+    let result = client.try_configure_accommodation_type(
+        &organizer,
+        &event_id,
+        &interpreter,
+        &0u32,
+        &false,
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(LumentixError::AccommodationUnavailable)));
+
+    client.configure_accommodation_type(
+        &organizer,
+        &event_id,
+        &interpreter,
+        &4u32,
+        &false,
+        &Vec::new(&env),
+    );
+    let accommodation = client.get_accommodation_type(&event_id, &interpreter);
+    assert_eq!(accommodation.total, 4);
+    assert_eq!(accommodation.available, 3);
 }
 
 #[test]
-fn test_set_event_currency_unsupported() {
+fn test_approve_accessibility_booking_frees_previous_seat() {
     let env = Env::default();
     env.mock_all_auths();
 
     let (_admin, client) = create_test_contract(&env);
     let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
     let event_id = create_and_publish_event(&env, &client, &organizer);
+    create_accessible_layout(&env, &client, &organizer, event_id);
 
-    let result =
-        client.try_set_event_currency(&organizer, &event_id, &String::from_str(&env, "XYZ"));
-    assert_eq!(result, Err(Ok(LumentixError::UnsupportedCurrency)));
-}
+    let result = client.try_configure_accommodation_type(
+        &organizer,
+        &event_id,
+        &String::from_str(&env, ""),
+        &1u32,
+        &false,
+        &Vec::new(&env),
+    );
+    assert_eq!(result, Err(Ok(LumentixError::EmptyString)));
 
-#[test]
-fn test_convert_price_same_currency() {
-    let env = Env::default();
-    env.mock_all_auths();
+    let wheelchair_space = String::from_str(&env, "wheelchair_space");
+    client.configure_accommodation_type(
+        &organizer,
+        &event_id,
+        &wheelchair_space,
+        &1u32,
+        &false,
+        &Vec::new(&env),
+    );
 
-    let (admin, client) = create_test_contract(&env);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let old_seat = String::from_str(&env, "A-1-1");
+    env.as_contract(&client.address, || {
+        let mut ticket = crate::storage::get_ticket(&env, ticket_id).unwrap();
+        ticket.seat_id = Some(old_seat.clone());
+        crate::storage::set_ticket(&env, ticket_id, &ticket);
+        let mut seat = crate::storage::get_seat(&env, event_id, &old_seat).unwrap();
+        seat.occupied = true;
+        crate::storage::set_seat(&env, event_id, &old_seat, &seat);
+    });
 
-    client.set_currency_oracle(&admin, &String::from_str(&env, "USD"), &2u32, &100i128);
+    let booking_id =
+        client.request_accessibility_booking(&buyer, &event_id, &ticket_id, &wheelchair_space);
+    client.approve_accessibility_booking(
+        &organizer,
+        &booking_id,
+        &String::from_str(&env, "ok"),
+        &Some(String::from_str(&env, "A-1-2")),
+    );
 
-    let converted = client.convert_price(
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "USD"),
-        &1000i128,
+    let section = String::from_str(&env, "A");
+    assert!(!client.get_seat_info(&event_id, &section, &1u32, &1u32).occupied);
+    assert!(client.get_seat_info(&event_id, &section, &1u32, &2u32).occupied);
+    assert_eq!(
+        client.get_ticket_info(&ticket_id).seat_id,
+        Some(String::from_str(&env, "A-1-2"))
     );
-    assert_eq!(converted, 1000);
 }
 
 #[test]
-fn test_convert_price_different_currencies() {
+fn test_approve_accessibility_booking_links_seat_and_companion() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    create_accessible_layout(&env, &client, &organizer, event_id);
 
-    client.set_currency_oracle(&admin, &String::from_str(&env, "USD"), &2u32, &100i128);
-    client.set_currency_oracle(&admin, &String::from_str(&env, "EUR"), &2u32, &90i128);
+    let companion_seat = String::from_str(&env, "companion_seat");
+    let mut accessible_seats = Vec::new(&env);
+    accessible_seats.push_back(String::from_str(&env, "A-1-2"));
+    client.configure_accommodation_type(
+        &organizer,
+        &event_id,
+        &companion_seat,
+        &2u32,
+        &true,
+        &accessible_seats,
+    );
 
-    // 1000 USD = 1000 * 100 / 90 = 1111 EUR
-    let converted = client.convert_price(
-        &String::from_str(&env, "USD"),
-        &String::from_str(&env, "EUR"),
-        &1000i128,
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let booking_id =
+        client.request_accessibility_booking(&buyer, &event_id, &ticket_id, &companion_seat);
+
+    let result = client.try_approve_accessibility_booking(
+        &organizer,
+        &booking_id,
+        &String::from_str(&env, "ok"),
+        &Some(String::from_str(&env, "A-1-1")),
+    );
+    assert_eq!(result, Err(Ok(LumentixError::SeatNotAccessible)));
+
+    let booking = client.approve_accessibility_booking(
+        &organizer,
+        &booking_id,
+        &String::from_str(&env, "Row 1 aisle"),
+        &Some(String::from_str(&env, "A-1-2")),
+    );
+    assert_eq!(booking.status, AccessibilityBookingStatus::Approved);
+    assert_eq!(
+        booking.organizer_notes,
+        String::from_str(&env, "Row 1 aisle")
+    );
+    assert_eq!(booking.seat_id, Some(String::from_str(&env, "A-1-2")));
+
+    let ticket = client.get_ticket_info(&ticket_id);
+    assert_eq!(ticket.seat_id, Some(String::from_str(&env, "A-1-2")));
+    assert_eq!(ticket.accessibility_type, Some(companion_seat));
+    assert!(
+        client
+            .get_seat_info(&event_id, &String::from_str(&env, "A"), &1u32, &2u32)
+            .occupied
+    );
+
+    let companion_id = booking.companion_ticket_id.unwrap();
+    assert_eq!(client.get_ticket_info(&companion_id).owner, buyer);
+    assert_eq!(client.get_event(&event_id).tickets_sold, 2);
+
+    let result = client.try_approve_accessibility_booking(
+        &organizer,
+        &booking_id,
+        &String::from_str(&env, "again"),
+        &None,
+    );
+    assert_eq!(
+        result,
+        Err(Ok(LumentixError::AccessibilityBookingNotPending))
     );
-    assert_eq!(converted, 1111);
 }
 
 #[test]
-fn test_handle_currency_fluctuation() {
+fn test_deny_accessibility_booking_releases_slot() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (admin, client) = create_test_contract(&env);
-
-    client.set_currency_oracle(&admin, &String::from_str(&env, "USD"), &2u32, &100i128);
-    client.set_currency_oracle(&admin, &String::from_str(&env, "EUR"), &2u32, &90i128);
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
 
-    let converted = client.handle_currency_fluctuation(
-        &admin,
-        &String::from_str(&env, "USD"),
-        &110i128,
+    client.setup_accessibility_inventory(&organizer, &event_id, &1u32, &0u32, &0u32);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let booking_id = client.request_accessibility_booking(
+        &buyer,
+        &event_id,
+        &ticket_id,
+        &String::from_str(&env, "wheelchair"),
+    );
+    assert!(!client.validate_accessibility_needs(&event_id, &String::from_str(&env, "wheelchair")));
+
+    let result = client.try_deny_accessibility_booking(
+        &Address::generate(&env),
+        &booking_id,
+        &String::from_str(&env, "no"),
+    );
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    client.deny_accessibility_booking(
+        &organizer,
+        &booking_id,
+        &String::from_str(&env, "Venue full"),
+    );
+
+    let booking = client.get_accessibility_booking(&booking_id);
+    assert_eq!(booking.status, AccessibilityBookingStatus::Denied);
+    assert_eq!(
+        booking.organizer_notes,
+        String::from_str(&env, "Venue full")
+    );
+    assert!(client.validate_accessibility_needs(&event_id, &String::from_str(&env, "wheelchair")));
+}
+
+fn book_wheelchair(
+    env: &Env,
+    client: &LumentixContractClient,
+    organizer: &Address,
+    buyer: &Address,
+) -> (u64, u64, u64) {
+    let event_id = create_and_publish_event(env, client, organizer);
+    client.setup_accessibility_inventory(organizer, &event_id, &1u32, &0u32, &0u32);
+    let ticket_id = client.purchase_ticket(buyer, &event_id, &100i128, &false, &None);
+    let booking_id = client.request_accessibility_booking(
+        buyer,
+        &event_id,
+        &ticket_id,
+        &String::from_str(env, "wheelchair"),
+    );
+    (event_id, ticket_id, booking_id)
+}
+
+#[test]
+fn test_refund_releases_accessibility_booking() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (event_id, ticket_id, booking_id) = book_wheelchair(&env, &client, &organizer, &buyer);
+
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+    client.refund_ticket(&ticket_id, &buyer);
+
+    let booking = client.get_ticket_accessibility_booking(&ticket_id);
+    assert_eq!(booking.id, booking_id);
+    assert_eq!(booking.status, AccessibilityBookingStatus::Cancelled);
+    assert!(client.validate_accessibility_needs(&event_id, &String::from_str(&env, "wheelchair")));
+}
+
+#[test]
+fn test_revoke_releases_accessibility_seat_and_companion() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    create_accessible_layout(&env, &client, &organizer, event_id);
+
+    let companion_seat = String::from_str(&env, "companion_seat");
+    client.configure_accommodation_type(
+        &organizer,
+        &event_id,
+        &companion_seat,
+        &1u32,
+        &true,
+        &Vec::new(&env),
+    );
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let booking_id =
+        client.request_accessibility_booking(&buyer, &event_id, &ticket_id, &companion_seat);
+    let booking = client.approve_accessibility_booking(
+        &organizer,
+        &booking_id,
+        &String::from_str(&env, "ok"),
+        &Some(String::from_str(&env, "A-1-1")),
+    );
+    assert_eq!(client.get_event(&event_id).tickets_sold, 2);
+
+    client.revoke_ticket(&admin, &ticket_id);
+
+    assert_eq!(
+        client.get_accessibility_booking(&booking_id).status,
+        AccessibilityBookingStatus::Cancelled
+    );
+    assert_eq!(client.get_accommodation_type(&event_id, &companion_seat).available, 1);
+    assert!(!client.get_seat_info(&event_id, &String::from_str(&env, "A"), &1u32, &1u32).occupied);
+    assert!(client.get_ticket_info(&booking.companion_ticket_id.unwrap()).revoked);
+    assert_eq!(client.get_event(&event_id).tickets_sold, 1);
+}
+
+#[test]
+fn test_cancel_accessibility_booking() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (event_id, ticket_id, booking_id) = book_wheelchair(&env, &client, &organizer, &buyer);
+
+    let result = client.try_cancel_accessibility_booking(&Address::generate(&env), &booking_id);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    client.cancel_accessibility_booking(&buyer, &booking_id);
+    assert!(client.validate_accessibility_needs(&event_id, &String::from_str(&env, "wheelchair")));
+
+    let result = client.try_cancel_accessibility_booking(&buyer, &booking_id);
+    assert_eq!(result, Err(Ok(LumentixError::AccessibilityBookingInactive)));
+
+    // The ticket can be booked again once the earlier booking is released
+    let rebooked = client.request_accessibility_booking(
+        &buyer,
+        &event_id,
+        &ticket_id,
+        &String::from_str(&env, "wheelchair"),
+    );
+    assert_eq!(client.get_ticket_accessibility_booking(&ticket_id).id, rebooked);
+}
+
+#[test]
+fn test_duplicate_accessibility_booking_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (event_id, ticket_id, _booking_id) = book_wheelchair(&env, &client, &organizer, &buyer);
+
+    let result = client.try_request_accessibility_booking(
+        &buyer,
+        &event_id,
+        &ticket_id,
+        &String::from_str(&env, "wheelchair"),
+    );
+    assert_eq!(result, Err(Ok(LumentixError::AccessibilityBookingExists)));
+}
+
+#[test]
+fn test_transfer_releases_accessibility_booking_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let (event_id, ticket_id, booking_id) = book_wheelchair(&env, &client, &organizer, &buyer);
+    assert!(!client.get_accessibility_transfer_rule(&event_id));
+
+    client.transfer_ticket(&ticket_id, &buyer, &Address::generate(&env));
+
+    assert_eq!(
+        client.get_accessibility_booking(&booking_id).status,
+        AccessibilityBookingStatus::Cancelled
+    );
+    assert!(client.validate_accessibility_needs(&event_id, &String::from_str(&env, "wheelchair")));
+}
+
+#[test]
+fn test_transfer_carries_accessibility_booking() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let (event_id, ticket_id, booking_id) = book_wheelchair(&env, &client, &organizer, &buyer);
+
+    client.set_accessibility_transfer_rule(&organizer, &event_id, &true);
+    client.transfer_ticket(&ticket_id, &buyer, &recipient);
+
+    let booking = client.get_accessibility_booking(&booking_id);
+    assert_eq!(booking.status, AccessibilityBookingStatus::Pending);
+    assert_eq!(booking.attendee, recipient);
+    assert!(!client.validate_accessibility_needs(&event_id, &String::from_str(&env, "wheelchair")));
+}
+
+#[test]
+fn test_transfer_carries_companion_ticket() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let companion_seat = String::from_str(&env, "companion_seat");
+    client.configure_accommodation_type(
+        &organizer,
+        &event_id,
+        &companion_seat,
+        &1u32,
+        &true,
+        &Vec::new(&env),
+    );
+
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let booking_id =
+        client.request_accessibility_booking(&buyer, &event_id, &ticket_id, &companion_seat);
+    let booking = client.approve_accessibility_booking(
+        &organizer,
+        &booking_id,
+        &String::from_str(&env, "ok"),
+        &None,
+    );
+    let companion_id = booking.companion_ticket_id.unwrap();
+
+    client.set_accessibility_transfer_rule(&organizer, &event_id, &true);
+    client.transfer_ticket(&ticket_id, &buyer, &recipient);
+
+    assert_eq!(client.get_accessibility_booking(&booking_id).attendee, recipient);
+    assert_eq!(client.get_ticket_info(&companion_id).owner, recipient);
+    assert_eq!(client.get_ticket_transfer_history(&companion_id).len(), 1);
+}
+
+#[test]
+fn test_transfer_leaves_companion_ticket_already_given_away() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let friend = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let companion_seat = String::from_str(&env, "companion_seat");
+    client.configure_accommodation_type(
+        &organizer,
+        &event_id,
+        &companion_seat,
+        &1u32,
+        &true,
+        &Vec::new(&env),
+    );
+
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
+    let booking_id =
+        client.request_accessibility_booking(&buyer, &event_id, &ticket_id, &companion_seat);
+    let booking = client.approve_accessibility_booking(
+        &organizer,
+        &booking_id,
+        &String::from_str(&env, "ok"),
+        &None,
+    );
+    let companion_id = booking.companion_ticket_id.unwrap();
+    client.transfer_ticket(&companion_id, &buyer, &friend);
+
+    client.set_accessibility_transfer_rule(&organizer, &event_id, &true);
+    client.transfer_ticket(&ticket_id, &buyer, &recipient);
+
+    assert_eq!(client.get_accessibility_booking(&booking_id).attendee, recipient);
+    assert_eq!(client.get_ticket_info(&companion_id).owner, friend);
+    assert_eq!(client.get_ticket_transfer_history(&companion_id).len(), 1);
+}
+
+// ═════════════════════════════════════════════════════════════════════════════
+// MULTI-CURRENCY TESTS
+// ═════════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_set_currency_oracle_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+
+    let result =
+        client.try_set_currency_oracle(&admin, &String::from_str(&env, "EUR"), &2u32, &90i128);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_set_currency_oracle_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let stranger = Address::generate(&env);
+
+    let result =
+        client.try_set_currency_oracle(&stranger, &String::from_str(&env, "EUR"), &2u32, &90i128);
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+}
+
+#[test]
+fn test_set_event_currency_success() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    client.set_currency_oracle(&admin, &String::from_str(&env, "EUR"), &2u32, &90i128);
+
+    let result =
+        client.try_set_event_currency(&organizer, &event_id, &String::from_str(&env, "EUR"));
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_set_event_currency_unsupported() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let result =
+        client.try_set_event_currency(&organizer, &event_id, &String::from_str(&env, "XYZ"));
+    assert_eq!(result, Err(Ok(LumentixError::UnsupportedCurrency)));
+}
+
+#[test]
+fn test_convert_price_same_currency() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+
+    client.set_currency_oracle(&admin, &String::from_str(&env, "USD"), &2u32, &100i128);
+
+    let converted = client.convert_price(
+        &String::from_str(&env, "USD"),
+        &String::from_str(&env, "USD"),
+        &1000i128,
+    );
+    assert_eq!(converted, 1000);
+}
+
+#[test]
+fn test_convert_price_different_currencies() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+
+    client.set_currency_oracle(&admin, &String::from_str(&env, "USD"), &2u32, &100i128);
+    client.set_currency_oracle(&admin, &String::from_str(&env, "EUR"), &2u32, &90i128);
+
+    // 1000 USD = 1000 * 100 / 90 = 1111 EUR
+    let converted = client.convert_price(
+        &String::from_str(&env, "USD"),
+        &String::from_str(&env, "EUR"),
+        &1000i128,
+    );
+    assert_eq!(converted, 1111);
+}
+
+#[test]
+fn test_handle_currency_fluctuation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+
+    client.set_currency_oracle(&admin, &String::from_str(&env, "USD"), &2u32, &100i128);
+    client.set_currency_oracle(&admin, &String::from_str(&env, "EUR"), &2u32, &90i128);
+
+    let converted = client.handle_currency_fluctuation(
+        &admin,
+        &String::from_str(&env, "USD"),
+        &110i128,
         &1000i128,
         &String::from_str(&env, "EUR"),
     );
@@ -435,6 +1218,440 @@ fn test_handle_currency_fluctuation() {
     assert_eq!(converted, 1222);
 }
 
+/// Minimal SEP-40 feed returning whatever price was last pushed to it.
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_price(env: Env, price: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("price"), &OraclePriceData { price, timestamp });
+    }
+
+    pub fn set_decimals(env: Env, decimals: u32) {
+        env.storage().instance().set(&symbol_short!("decimals"), &decimals);
+    }
+
+    pub fn lastprice(env: Env, _asset: OracleAsset) -> Option<OraclePriceData> {
+        env.storage().instance().get(&symbol_short!("price"))
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("decimals"))
+            .unwrap_or(CURRENCY_PRICE_DECIMALS)
+    }
+}
+
+/// USD at 100 and EUR at 90 from the admin, with EUR also wired to a mock feed.
+fn setup_eur_feed(env: &Env, client: &LumentixContractClient, admin: &Address) -> MockOracleClient<'static> {
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.set_currency_oracle(admin, &String::from_str(env, "USD"), &2u32, &100i128);
+    client.set_currency_oracle(admin, &String::from_str(env, "EUR"), &2u32, &90i128);
+
+    let oracle_id = env.register(MockOracle, ());
+    client.set_currency_feed(
+        admin,
+        &String::from_str(env, "EUR"),
+        &oracle_id,
+        &OracleAsset::Other(symbol_short!("EUR")),
+    );
+    MockOracleClient::new(env, &oracle_id)
+}
+
+#[test]
+fn test_convert_price_uses_oracle_feed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let oracle = setup_eur_feed(&env, &client, &admin);
+    oracle.set_price(&80i128, &1_000u64);
+
+    // 1000 USD * 100 / 80 = 1250 EUR
+    let converted = client.convert_price(
+        &String::from_str(&env, "USD"),
+        &String::from_str(&env, "EUR"),
+        &1000i128,
+    );
+    assert_eq!(converted, 1250);
+}
+
+#[test]
+fn test_feed_price_is_rescaled_from_feed_decimals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let oracle = setup_eur_feed(&env, &client, &admin);
+    oracle.set_decimals(&(CURRENCY_PRICE_DECIMALS + 2));
+    oracle.set_price(&8_000i128, &1_000u64);
+
+    assert_eq!(client.get_currency_price(&String::from_str(&env, "EUR")), 80);
+    let converted = client.convert_price(
+        &String::from_str(&env, "USD"),
+        &String::from_str(&env, "EUR"),
+        &1000i128,
+    );
+    assert_eq!(converted, 1250);
+
+    oracle.set_decimals(&(CURRENCY_PRICE_DECIMALS - 1));
+    oracle.set_price(&8i128, &1_000u64);
+    assert_eq!(client.get_currency_price(&String::from_str(&env, "EUR")), 80);
+}
+
+#[test]
+fn test_stale_feed_falls_back_to_admin_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let oracle = setup_eur_feed(&env, &client, &admin);
+    client.set_currency_price_guards(&admin, &String::from_str(&env, "EUR"), &300u64, &0u32);
+    oracle.set_price(&80i128, &500u64);
+
+    assert_eq!(client.get_currency_price(&String::from_str(&env, "EUR")), 90);
+}
+
+#[test]
+fn test_convert_price_rejects_stale_prices() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let oracle = setup_eur_feed(&env, &client, &admin);
+    client.set_currency_price_guards(&admin, &String::from_str(&env, "EUR"), &300u64, &0u32);
+    oracle.set_price(&80i128, &1_000u64);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+
+    let result = client.try_convert_price(
+        &String::from_str(&env, "USD"),
+        &String::from_str(&env, "EUR"),
+        &1000i128,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::OraclePriceStale)));
+}
+
+#[test]
+fn test_convert_price_rejects_feed_jump() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let oracle = setup_eur_feed(&env, &client, &admin);
+    client.set_currency_price_guards(&admin, &String::from_str(&env, "EUR"), &0u64, &1000u32);
+    oracle.set_price(&45i128, &1_000u64);
+
+    let result = client.try_convert_price(
+        &String::from_str(&env, "USD"),
+        &String::from_str(&env, "EUR"),
+        &1000i128,
+    );
+    assert_eq!(result, Err(Ok(LumentixError::OraclePriceDeviation)));
+}
+
+#[test]
+fn test_refresh_currency_price_records_feed_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let oracle = setup_eur_feed(&env, &client, &admin);
+    client.set_currency_price_guards(&admin, &String::from_str(&env, "EUR"), &0u64, &1000u32);
+    oracle.set_price(&85i128, &1_000u64);
+
+    let price = client.refresh_currency_price(&String::from_str(&env, "EUR"));
+    assert_eq!(price, 85);
+
+    let config = client.get_currency_config(&String::from_str(&env, "EUR"));
+    assert_eq!(config.oracle_price, 85);
+    assert_eq!(config.last_updated, 1_000);
+}
+
+// ═════════════════════════════════════════════════════════════════════════════
+// MULTI-TOKEN PAYMENT TESTS
+// ═════════════════════════════════════════════════════════════════════════════
+
+/// Registers USD (the event currency) and XLM at half its price, and accepts a
+/// freshly minted XLM SAC for the event.
+fn setup_xlm_payments(
+    env: &Env,
+    client: &LumentixContractClient,
+    admin: &Address,
+    organizer: &Address,
+    event_id: u64,
+    buyer: &Address,
+) -> Address {
+    client.set_currency_oracle(admin, &String::from_str(env, "USD"), &2u32, &100i128);
+    client.set_currency_oracle(admin, &String::from_str(env, "XLM"), &7u32, &50i128);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    token::StellarAssetClient::new(env, &token_address).mint(buyer, &1_000i128);
+
+    client.add_accepted_token(organizer, &event_id, &token_address, &String::from_str(env, "XLM"));
+    token_address
+}
+
+#[test]
+fn test_add_accepted_token_duplicate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let xlm = setup_xlm_payments(&env, &client, &admin, &organizer, event_id, &buyer);
+
+    assert_eq!(client.get_accepted_tokens(&event_id).len(), 1);
+
+    let result =
+        client.try_add_accepted_token(&organizer, &event_id, &xlm, &String::from_str(&env, "XLM"));
+    assert_eq!(result, Err(Ok(LumentixError::TokenAlreadyAccepted)));
+}
+
+#[test]
+fn test_add_accepted_token_unsupported_currency() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+
+    let result = client.try_add_accepted_token(
+        &organizer,
+        &event_id,
+        &Address::generate(&env),
+        &String::from_str(&env, "JPY"),
+    );
+    assert_eq!(result, Err(Ok(LumentixError::UnsupportedCurrency)));
+}
+
+#[test]
+fn test_purchase_ticket_with_token_holds_escrow_in_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let xlm = setup_xlm_payments(&env, &client, &admin, &organizer, event_id, &buyer);
+    client.set_platform_fee(&admin, &1000u32);
+
+    // 100 USD * 100 / 50 = 200 XLM
+    let price = client.quote_ticket_price(&buyer, &event_id, &xlm).amount;
+    assert_eq!(price, 200);
+
+    let ticket_id = client.purchase_ticket_with_token(&buyer, &event_id, &xlm, &price, &false);
+
+    assert_eq!(token::Client::new(&env, &xlm).balance(&buyer), 800);
+    assert_eq!(client.get_token_escrow_balance(&event_id, &xlm), 180);
+    assert_eq!(client.get_escrow_balance(&event_id), 0);
+    assert_eq!(client.get_ticket_payment(&ticket_id).unwrap().token, xlm);
+}
+
+#[test]
+fn test_quote_survives_currency_fluctuation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let xlm = setup_xlm_payments(&env, &client, &admin, &organizer, event_id, &buyer);
+
+    let quote = client.quote_ticket_price(&buyer, &event_id, &xlm);
+    assert_eq!(quote.amount, 200);
+
+    // XLM halves in value; the live price doubles but the quote does not move
+    client.handle_currency_fluctuation(
+        &admin,
+        &String::from_str(&env, "XLM"),
+        &25i128,
+        &100i128,
+        &String::from_str(&env, "USD"),
+    );
+    assert_eq!(client.get_price_quote(&quote.quote_id).amount, 200);
+
+    let ticket_id =
+        client.purchase_ticket(&buyer, &event_id, &200i128, &false, &Some(quote.quote_id));
+    assert_eq!(client.get_ticket_payment(&ticket_id).unwrap().amount, 200);
+    assert_eq!(token::Client::new(&env, &xlm).balance(&buyer), 800);
+
+    // Quotes are single-use
+    let result =
+        client.try_purchase_ticket(&buyer, &event_id, &200i128, &false, &Some(quote.quote_id));
+    assert_eq!(result, Err(Ok(LumentixError::PriceQuoteNotFound)));
+}
+
+#[test]
+fn test_purchase_ticket_with_expired_quote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let xlm = setup_xlm_payments(&env, &client, &admin, &organizer, event_id, &buyer);
+    let result = client.try_set_quote_lock_duration(&admin, &(MAX_QUOTE_LOCK_SECS + 1));
+    assert_eq!(result, Err(Ok(LumentixError::InvalidAmount)));
+    client.set_quote_lock_duration(&admin, &60u64);
+
+    let quote = client.quote_ticket_price(&buyer, &event_id, &xlm);
+    env.ledger().with_mut(|li| li.timestamp = quote.expires_at + 1);
+
+    let result =
+        client.try_purchase_ticket(&buyer, &event_id, &200i128, &false, &Some(quote.quote_id));
+    assert_eq!(result, Err(Ok(LumentixError::PriceQuoteExpired)));
+}
+
+#[test]
+fn test_purchase_ticket_with_quote_wrong_buyer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let xlm = setup_xlm_payments(&env, &client, &admin, &organizer, event_id, &buyer);
+
+    let quote = client.quote_ticket_price(&buyer, &event_id, &xlm);
+
+    let stranger = Address::generate(&env);
+    let result =
+        client.try_purchase_ticket(&stranger, &event_id, &200i128, &false, &Some(quote.quote_id));
+    assert_eq!(result, Err(Ok(LumentixError::Unauthorized)));
+
+    // A quote only redeems against the event it was issued for
+    let other_event = create_and_publish_event(&env, &client, &organizer);
+    let result =
+        client.try_purchase_ticket(&buyer, &other_event, &200i128, &false, &Some(quote.quote_id));
+    assert_eq!(result, Err(Ok(LumentixError::PriceQuoteNotFound)));
+}
+
+#[test]
+fn test_purchase_ticket_with_token_underpaid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let xlm = setup_xlm_payments(&env, &client, &admin, &organizer, event_id, &buyer);
+
+    let result = client.try_purchase_ticket_with_token(&buyer, &event_id, &xlm, &100i128, &false);
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientFunds)));
+}
+
+#[test]
+fn test_purchase_ticket_with_token_not_accepted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let xlm = setup_xlm_payments(&env, &client, &admin, &organizer, event_id, &buyer);
+    client.remove_accepted_token(&organizer, &event_id, &xlm);
+
+    let result = client.try_purchase_ticket_with_token(&buyer, &event_id, &xlm, &200i128, &false);
+    assert_eq!(result, Err(Ok(LumentixError::TokenNotAccepted)));
+}
+
+#[test]
+fn test_refund_token_ticket_in_paid_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let xlm = setup_xlm_payments(&env, &client, &admin, &organizer, event_id, &buyer);
+    client.set_platform_fee(&admin, &1000u32);
+
+    let ticket_id = client.purchase_ticket_with_token(&buyer, &event_id, &xlm, &200i128, &false);
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+    client.refund_ticket(&ticket_id, &buyer);
+
+    assert_eq!(token::Client::new(&env, &xlm).balance(&buyer), 1_000);
+    assert_eq!(client.get_token_escrow_balance(&event_id, &xlm), 0);
+
+    let result = client.try_withdraw_token_platform_fees(&admin, &xlm);
+    assert_eq!(result, Err(Ok(LumentixError::NoPlatformFees)));
+}
+
+#[test]
+fn test_refund_after_token_fee_withdrawal_returns_escrow_and_books_fee_debt() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let xlm = setup_xlm_payments(&env, &client, &admin, &organizer, event_id, &buyer);
+    client.set_platform_fee(&admin, &1000u32);
+
+    let ticket_id = client.purchase_ticket_with_token(&buyer, &event_id, &xlm, &200i128, &false);
+    assert_eq!(client.withdraw_token_platform_fees(&admin, &xlm), 20);
+
+    client.cancel_event(&organizer, &event_id, &CancellationReason::EventCancelledByOrganizer);
+    client.refund_ticket(&ticket_id, &buyer);
+    assert_eq!(token::Client::new(&env, &xlm).balance(&buyer), 980);
+    assert_eq!(client.get_token_escrow_balance(&event_id, &xlm), 0);
+    assert_eq!(client.get_platform_fee_debt(&buyer, &Some(xlm.clone())), 20);
+
+    let result = client.try_claim_platform_fee_refund(&buyer, &Some(xlm));
+    assert_eq!(result, Err(Ok(LumentixError::InsufficientPlatformBalance)));
+}
+
+#[test]
+fn test_release_escrow_settles_each_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (admin, client) = create_test_contract(&env);
+    let organizer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let event_id = create_and_publish_event(&env, &client, &organizer);
+    let xlm = setup_xlm_payments(&env, &client, &admin, &organizer, event_id, &buyer);
+    client.set_platform_fee(&admin, &1000u32);
+
+    client.purchase_ticket_with_token(&buyer, &event_id, &xlm, &200i128, &false);
+
+    env.ledger().with_mut(|li| li.timestamp = 2001);
+    client.complete_event(&organizer, &event_id);
+
+    // Nothing was paid in the global token
+    let released = client.release_escrow(&organizer, &event_id);
+    assert_eq!(released, 0);
+
+    let xlm_client = token::Client::new(&env, &xlm);
+    assert_eq!(xlm_client.balance(&organizer), 180);
+    assert_eq!(client.get_token_escrow_balance(&event_id, &xlm), 0);
+
+    let fees = client.withdraw_token_platform_fees(&admin, &xlm);
+    assert_eq!(fees, 20);
+    assert_eq!(xlm_client.balance(&admin), 20);
+
+    let result = client.try_release_escrow(&organizer, &event_id);
+    assert_eq!(result, Err(Ok(LumentixError::EscrowAlreadyReleased)));
+}
+
 // ═════════════════════════════════════════════════════════════════════════════
 // SEAT SELECTION / VENUE MAPPING TESTS
 // ═════════════════════════════════════════════════════════════════════════════
@@ -777,7 +1994,7 @@ fn test_get_accessibility_booking() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     client.setup_accessibility_inventory(&organizer, &event_id, &5u32, &3u32, &2u32);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let booking_id = client.request_accessibility_booking(
         &buyer,
@@ -788,7 +2005,7 @@ fn test_get_accessibility_booking() {
 
     let booking = client.get_accessibility_booking(&booking_id);
     assert_eq!(booking.event_id, event_id);
-    assert!(booking.approved);
+    assert_eq!(booking.status, AccessibilityBookingStatus::Pending);
 }
 
 #[test]
@@ -802,7 +2019,7 @@ fn test_accessibility_inventory_hearing_visual() {
     let event_id = create_and_publish_event(&env, &client, &organizer);
 
     client.setup_accessibility_inventory(&organizer, &event_id, &0u32, &3u32, &2u32);
-    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let booking_id = client.request_accessibility_booking(
         &buyer,
@@ -812,7 +2029,7 @@ fn test_accessibility_inventory_hearing_visual() {
     );
     assert!(booking_id > 0);
 
-    let ticket_id2 = client.purchase_ticket(&buyer, &event_id, &100i128);
+    let ticket_id2 = client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     let booking_id2 = client.request_accessibility_booking(
         &buyer,
         &event_id,
@@ -120,7 +120,7 @@ fn withdraw_platform_fees_does_not_touch_escrow() {
 
     client.set_platform_fee(&admin, &1000u32);
     let event_id = publish_event(&env, &client, &organizer);
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
 
     let escrow_before = client.get_escrow_balance(&event_id);
     let withdrawn = client.withdraw_platform_fees(&admin);
@@ -139,7 +139,7 @@ fn withdraw_platform_fees_max_bps_accumulation_then_full_withdraw() {
     let event_id = publish_event(&env, &client, &organizer);
 
     for _ in 0..50 {
-        client.purchase_ticket(&buyer, &event_id, &100i128);
+        client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     }
 
     assert_eq!(client.get_platform_balance(), 5_000i128);
@@ -157,10 +157,10 @@ fn withdraw_platform_fees_re_accumulate_after_empty() {
     client.set_platform_fee(&admin, &500u32);
     let event_id = publish_event(&env, &client, &organizer);
 
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(client.withdraw_platform_fees(&admin), 5i128);
 
-    client.purchase_ticket(&buyer, &event_id, &100i128);
+    client.purchase_ticket(&buyer, &event_id, &100i128, &false, &None);
     assert_eq!(client.withdraw_platform_fees(&admin), 5i128);
     assert_eq!(client.get_platform_balance(), 0i128);
 }